@@ -0,0 +1,6 @@
+#[path = "../shared_rust/build_support/dependency_version.rs"]
+mod dependency_version;
+
+fn main() {
+    dependency_version::export("actix", "ACTIX_VERSION");
+}
@@ -1,15 +1,33 @@
 //use super::*;
 
 use actix::*;
+use benchmark_suite_shared::affinity::Placement;
 //use actix::prelude::*;
 use futures::channel::mpsc::{channel, Receiver, Sender};
 use futures::channel::oneshot::channel as promise;
 use futures::{StreamExt, Future};
+use once_cell::sync::OnceCell;
 use std::fmt;
+use std::net::{IpAddr, Ipv4Addr};
 use std::sync::{Arc, Mutex};
 use std::thread;
 //pub struct ActixSystemProvider;
 
+static PUBLIC_IF: OnceCell<IpAddr> = OnceCell::new();
+
+/// Sets the interface networked benchmarks listen on; only the first call has an effect.
+pub fn set_public_if(addr: IpAddr) {
+    let _ = PUBLIC_IF.set(addr);
+}
+
+/// The interface networked benchmarks listen on, `localhost` unless set.
+pub fn public_if() -> IpAddr {
+    PUBLIC_IF
+        .get()
+        .cloned()
+        .unwrap_or(IpAddr::V4(Ipv4Addr::LOCALHOST))
+}
+
 //impl ActixSystemProvider {
 pub fn new_system<I: Into<String>>(name: I) -> ActixSystem {
     ActixSystem::new(name.into())
@@ -31,7 +49,15 @@ impl ActixSystem {
         let core = Arc::new(Mutex::new(core));
         let core2 = core.clone();
 
+        // Actix runs the whole system on a single thread
+        let placement = Placement::from_env(1);
+        if let Some(ref placement) = placement {
+            placement.report(1);
+        }
         thread::spawn(move || {
+            if let Some(placement) = placement {
+                placement.pin_current(0);
+            }
             let mut guard = core.lock().unwrap();
             guard.run();
         });
@@ -0,0 +1,115 @@
+//! Runs actors written against `benchmark_suite_shared::actors` as Actix actors.
+
+use actix::{
+    Actor, ActorContext, Addr, ArbiterHandle, AsyncContext, Context, Handler, Message, System,
+};
+use benchmark_suite_shared::actors::{self as generic, ActorSystem, Envelope, Mailbox};
+use benchmark_suite_shared::affinity::Placement;
+use std::sync::{mpsc, Arc, Mutex};
+use std::thread::{self, JoinHandle};
+
+/// Like [ActixSystem](crate::actix_system_provider::ActixSystem), but actors can also be
+/// spawned from within the system, since `start_in_arbiter` doesn't block.
+#[derive(Clone)]
+pub struct ActixAdapter {
+    arbiter: ArbiterHandle,
+    system: System,
+    runner: Arc<Mutex<Option<JoinHandle<()>>>>,
+}
+
+impl ActorSystem for ActixAdapter {
+    fn start(label: &str, _num_threads: usize) -> Self {
+        let (tx, rx) = mpsc::channel();
+        let name = format!("actix-{}", label);
+        // Actix runs the whole system on a single thread
+        let placement = Placement::from_env(1);
+        if let Some(ref placement) = placement {
+            placement.report(1);
+        }
+        let runner = thread::Builder::new()
+            .name(name)
+            .spawn(move || {
+                if let Some(placement) = placement {
+                    placement.pin_current(0);
+                }
+                let runner = System::new();
+                let system = System::current();
+                tx.send((system.arbiter().clone(), system))
+                    .expect("Adapter should be waiting");
+                runner.run().expect("Should be running.");
+            })
+            .expect("Actix system thread should have started");
+        let (arbiter, system) = rx.recv().expect("Actix system never started");
+        ActixAdapter {
+            arbiter,
+            system,
+            runner: Arc::new(Mutex::new(Some(runner))),
+        }
+    }
+
+    fn spawn<A: generic::Actor>(&self, actor: A) -> generic::ActorRef<A::Message> {
+        let system = self.clone();
+        let addr = ActixActor::start_in_arbiter(&self.arbiter, move |ctx| ActixActor {
+            actor,
+            myself: generic::ActorRef::new(ActixMailbox(ctx.address())),
+            system,
+        });
+        generic::ActorRef::new(ActixMailbox(addr))
+    }
+
+    fn shutdown(self) -> Result<(), String> {
+        self.system.stop();
+        let runner = self
+            .runner
+            .lock()
+            .map_err(|_| "Actix system lock was poisoned".to_string())?
+            .take();
+        match runner {
+            Some(runner) => runner
+                .join()
+                .map_err(|_| "Actix system thread panicked".to_string()),
+            None => Err("Actix system was already shut down".to_string()),
+        }
+    }
+}
+
+struct Mail<M>(Envelope<M>);
+
+impl<M: Send + 'static> Message for Mail<M> {
+    type Result = ();
+}
+
+struct ActixMailbox<A: generic::Actor>(Addr<ActixActor<A>>);
+
+impl<A: generic::Actor> Mailbox<A::Message> for ActixMailbox<A> {
+    fn enqueue(&self, envelope: Envelope<A::Message>) -> () {
+        self.0.do_send(Mail(envelope));
+    }
+}
+
+struct ActixActor<A: generic::Actor> {
+    actor: A,
+    myself: generic::ActorRef<A::Message>,
+    system: ActixAdapter,
+}
+
+impl<A: generic::Actor> Actor for ActixActor<A> {
+    type Context = Context<Self>;
+}
+
+impl<A: generic::Actor> Handler<Mail<A::Message>> for ActixActor<A> {
+    type Result = ();
+
+    fn handle(&mut self, mail: Mail<A::Message>, ctx: &mut Context<Self>) -> Self::Result {
+        match mail.0 {
+            Envelope::Msg(msg) => {
+                let mut generic_ctx = generic::Context::new(&self.myself, &self.system);
+                self.actor.receive(&mut generic_ctx, msg);
+                if generic_ctx.is_stopped() {
+                    ctx.stop();
+                }
+            }
+            Envelope::Stop => ctx.stop(),
+        }
+    }
+}
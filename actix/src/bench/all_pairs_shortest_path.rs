@@ -20,6 +20,10 @@ impl Benchmark for AllPairsShortestPath {
         downcast_msg!(msg; APSPRequest)
     }
 
+    fn validate(c: &Self::Conf) -> Result<(), BenchmarkError> {
+        validation::all_pairs_shortest_path(c)
+    }
+
     fn new_instance() -> Self::Instance {
         AllPairsShortestPathI::new()
     }
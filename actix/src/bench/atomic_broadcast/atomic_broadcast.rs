@@ -0,0 +1,418 @@
+use super::{
+    super::*,
+    client::{Client, MetaResults, Run, Stop},
+    raft::{RaftNode, Reset},
+};
+use crate::actix_system_provider::{new_system, ActixSystem};
+use actix::Addr;
+use benchmark_suite_shared::{
+    kompics_benchmarks::benchmarks::AtomicBroadcastRequest, result_writer,
+};
+use hdrhistogram::Histogram;
+use hocon::HoconLoader;
+use std::{net::SocketAddr, path::PathBuf, sync::Arc, time::Duration};
+use synchronoise::CountdownEvent;
+
+/// Shared with Kompact, so both run Raft with the same settings.
+const CONFIG_PATH: &str = "../kompact/configs/atomic_broadcast.conf";
+
+#[derive(Default)]
+pub struct AtomicBroadcast;
+
+impl DistributedBenchmark for AtomicBroadcast {
+    type MasterConf = AtomicBroadcastRequest;
+    type ClientConf = bool;
+    type ClientData = SocketAddr;
+    type Master = AtomicBroadcastMaster;
+    type Client = AtomicBroadcastClient;
+
+    const LABEL: &'static str = "AtomicBroadcast";
+
+    fn new_master() -> Self::Master {
+        AtomicBroadcastMaster::new()
+    }
+    fn msg_to_master_conf(
+        msg: Box<dyn (::protobuf::Message)>,
+    ) -> Result<Self::MasterConf, BenchmarkError> {
+        downcast_msg!(msg; AtomicBroadcastRequest)
+    }
+
+    fn validate(c: &Self::MasterConf, m: &DeploymentMetaData) -> Result<(), BenchmarkError> {
+        validation::positive("number_of_nodes", c.number_of_nodes)?;
+        validation::positive("number_of_proposals", c.number_of_proposals)?;
+        validation::positive("concurrent_proposals", c.concurrent_proposals)?;
+        if c.concurrent_proposals > c.number_of_proposals {
+            return Err(BenchmarkError::invalid_param(
+                "concurrent_proposals",
+                format!(
+                    "{} should be less or equal to number_of_proposals: {}",
+                    c.concurrent_proposals, c.number_of_proposals
+                ),
+            ));
+        }
+        // only Raft without reconfiguration is implemented for Actix
+        match c.algorithm.to_lowercase().as_ref() {
+            "raft" => (),
+            "paxos" => {
+                return Err(BenchmarkError::NotImplemented(
+                    NotImplementedError::FutureWork,
+                ))
+            }
+            _ => {
+                return Err(BenchmarkError::invalid_param(
+                    "algorithm",
+                    format!("unimplemented atomic broadcast algorithm: {}", &c.algorithm),
+                ));
+            }
+        }
+        match c.reconfiguration.to_lowercase().as_ref() {
+            "off" => {
+                if c.reconfig_policy.to_lowercase() != "none" {
+                    return Err(BenchmarkError::invalid_param(
+                        "reconfig_policy",
+                        format!(
+                            "reconfiguration is off, so it should be none, but found: {}",
+                            &c.reconfig_policy
+                        ),
+                    ));
+                }
+            }
+            "single" | "majority" => {
+                return Err(BenchmarkError::NotImplemented(
+                    NotImplementedError::FutureWork,
+                ))
+            }
+            _ => {
+                return Err(BenchmarkError::invalid_param(
+                    "reconfiguration",
+                    format!("unimplemented Raft reconfiguration: {}", &c.reconfiguration),
+                ));
+            }
+        }
+        if (m.number_of_clients() as u64) < c.number_of_nodes {
+            return Err(BenchmarkError::invalid_param(
+                "number_of_nodes",
+                format!(
+                    "{} nodes are needed, but only {} clients are available",
+                    c.number_of_nodes,
+                    m.number_of_clients()
+                ),
+            ));
+        }
+        Ok(())
+    }
+
+    fn new_client() -> Self::Client {
+        AtomicBroadcastClient::new()
+    }
+    fn str_to_client_conf(str: String) -> Result<Self::ClientConf, BenchmarkError> {
+        str.parse::<bool>().map_err(|e| {
+            BenchmarkError::InvalidMessage(format!(
+                "String '{}' does not represent a client conf: {:?}",
+                str, e
+            ))
+        })
+    }
+    fn str_to_client_data(str: String) -> Result<Self::ClientData, BenchmarkError> {
+        str.parse::<SocketAddr>().map_err(|e| {
+            BenchmarkError::InvalidMessage(format!("Could not read client data: {}", e))
+        })
+    }
+
+    fn client_conf_to_str(c: Self::ClientConf) -> String {
+        c.to_string()
+    }
+    fn client_data_to_str(d: Self::ClientData) -> String {
+        d.to_string()
+    }
+}
+
+/// The `experiment` and `raft` settings from `CONFIG_PATH` that the Raft nodes need.
+#[derive(Debug, Clone)]
+pub struct RaftParams {
+    pub election_timeout: u64,
+    pub outgoing_period: Duration,
+    pub max_inflight: usize,
+    pub tick_period: u64,
+    pub leader_hb_period: u64,
+    pub max_batch_size: u64,
+    pub pre_vote: bool,
+    pub check_quorum: bool,
+}
+
+impl RaftParams {
+    pub fn load_from_file<P>(path: P) -> RaftParams
+    where
+        P: Into<PathBuf>,
+    {
+        let p: PathBuf = path.into();
+        let config = HoconLoader::new()
+            .load_file(p)
+            .expect("Failed to load file")
+            .hocon()
+            .expect("Failed to load as HOCON");
+        RaftParams {
+            election_timeout: config["experiment"]["election_timeout"]
+                .as_i64()
+                .expect("Failed to load election_timeout") as u64,
+            outgoing_period: config["experiment"]["outgoing_period"]
+                .as_duration()
+                .expect("Failed to load outgoing_period"),
+            max_inflight: config["experiment"]["max_inflight"]
+                .as_i64()
+                .expect("Failed to load max_inflight") as usize,
+            tick_period: config["raft"]["tick_period"]
+                .as_i64()
+                .expect("Failed to load tick_period") as u64,
+            leader_hb_period: config["raft"]["leader_hb_period"]
+                .as_i64()
+                .expect("Failed to load leader_hb_period") as u64,
+            max_batch_size: config["raft"]["max_batch_size"]
+                .as_i64()
+                .expect("Failed to load max_batch_size") as u64,
+            pre_vote: config["raft"]["pre_vote"]
+                .as_bool()
+                .expect("Failed to load pre_vote"),
+            check_quorum: config["raft"]["check_quorum"]
+                .as_bool()
+                .expect("Failed to load check_quorum"),
+        }
+    }
+}
+
+fn load_client_timeout<P>(path: P) -> Duration
+where
+    P: Into<PathBuf>,
+{
+    let p: PathBuf = path.into();
+    let config = HoconLoader::new()
+        .load_file(p)
+        .expect("Failed to load file")
+        .hocon()
+        .expect("Failed to load as HOCON");
+    config["experiment"]["client_timeout"]
+        .as_duration()
+        .expect("Failed to load client timeout")
+}
+
+pub struct AtomicBroadcastMaster {
+    num_nodes: Option<u64>,
+    num_proposals: Option<u64>,
+    concurrent_proposals: Option<u64>,
+    tcp_no_delay: bool,
+    system: Option<ActixSystem>,
+    finished_latch: Option<Arc<CountdownEvent>>,
+    iteration_id: u32,
+    client: Option<Addr<Client>>,
+    latency_hist: Option<Histogram<u64>>,
+    num_timed_out: Vec<u64>,
+}
+
+impl AtomicBroadcastMaster {
+    fn new() -> AtomicBroadcastMaster {
+        AtomicBroadcastMaster {
+            num_nodes: None,
+            num_proposals: None,
+            concurrent_proposals: None,
+            tcp_no_delay: true,
+            system: None,
+            finished_latch: None,
+            iteration_id: 0,
+            client: None,
+            latency_hist: None,
+            num_timed_out: vec![],
+        }
+    }
+
+    fn record_latencies(&mut self, meta_results: &MetaResults) {
+        if let Some(hist) = self.latency_hist.as_mut() {
+            for l in meta_results.latencies.iter() {
+                hist.record(l.as_micros() as u64)
+                    .expect("Failed to record histogram");
+            }
+        }
+    }
+
+    fn persist_latency_summary(&mut self) {
+        let hist = std::mem::take(&mut self.latency_hist).unwrap();
+        let quantiles = [
+            0.001, 0.01, 0.005, 0.1, 0.2, 0.3, 0.4, 0.5, 0.6, 0.7, 0.8, 0.9, 0.95, 0.99, 0.999,
+        ];
+        for q in &quantiles {
+            let value = hist.value_at_quantile(*q);
+            result_writer::record_metric(format!("latency_us_q{}", q), value as f64);
+        }
+        result_writer::record_metric("latency_us_min", hist.min() as f64);
+        result_writer::record_metric("latency_us_max", hist.max() as f64);
+        result_writer::record_metric("latency_us_mean", hist.mean());
+        result_writer::record_metric("latency_count", hist.len() as f64);
+    }
+
+    fn persist_timeouts_summary(&mut self) {
+        let sum: u64 = self.num_timed_out.iter().sum();
+        result_writer::record_metric("timed_out_proposals", sum as f64);
+        if sum > 0 {
+            let timed_out_len = self.num_timed_out.iter().filter(|x| **x > 0).count();
+            println!(
+                "{}/{} runs had timeouts. sum: {}",
+                timed_out_len,
+                self.num_timed_out.len(),
+                sum
+            );
+        }
+    }
+}
+
+impl DistributedBenchmarkMaster for AtomicBroadcastMaster {
+    type MasterConf = AtomicBroadcastRequest;
+    type ClientConf = bool;
+    type ClientData = SocketAddr;
+
+    fn setup(
+        &mut self,
+        c: Self::MasterConf,
+        _m: &DeploymentMetaData,
+    ) -> Result<Self::ClientConf, BenchmarkError> {
+        println!("Setting up Atomic Broadcast (Master)");
+        self.num_nodes = Some(c.number_of_nodes);
+        self.num_proposals = Some(c.number_of_proposals);
+        self.concurrent_proposals = Some(c.concurrent_proposals);
+        if c.concurrent_proposals == 1 {
+            self.latency_hist =
+                Some(Histogram::<u64>::new(4).expect("Failed to create latency histogram"));
+        }
+        self.tcp_no_delay = net::tcp_no_delay(c.get_network());
+        self.system = Some(new_system("atomicbroadcast"));
+        Ok(self.tcp_no_delay)
+    }
+
+    fn prepare_iteration(&mut self, d: Vec<Self::ClientData>) -> () {
+        println!("Preparing iteration");
+        let finished_latch = Arc::new(CountdownEvent::new(1));
+        self.finished_latch = Some(finished_latch.clone());
+        self.iteration_id += 1;
+        let num_nodes_needed = self.num_nodes.expect("No cached num_nodes") as usize;
+        let mut nodes = d;
+        nodes.truncate(num_nodes_needed);
+        let client_timeout = load_client_timeout(CONFIG_PATH);
+        let leader_election_latch = Arc::new(CountdownEvent::new(1));
+        let iteration_id = self.iteration_id;
+        let tcp_no_delay = self.tcp_no_delay;
+        let num_proposals = self.num_proposals.unwrap();
+        let concurrent_proposals = self.concurrent_proposals.unwrap();
+        let latch = leader_election_latch.clone();
+        let client = self
+            .system
+            .as_mut()
+            .expect("No ActixSystem found!")
+            .start(move || {
+                Client::with(
+                    iteration_id,
+                    nodes,
+                    tcp_no_delay,
+                    num_proposals,
+                    concurrent_proposals,
+                    client_timeout,
+                    latch,
+                    finished_latch,
+                )
+            })
+            .expect("Client never started!");
+        leader_election_latch.wait(); // wait until leader is established
+        self.client = Some(client);
+    }
+
+    fn run_iteration(&mut self) -> () {
+        println!("Running Atomic Broadcast experiment!");
+        match self.client {
+            Some(ref client) => {
+                futures::executor::block_on(client.send(Run)).expect("Client never ran!");
+                let finished_latch = self.finished_latch.take().unwrap();
+                finished_latch.wait();
+            }
+            _ => panic!("No client found!"),
+        }
+    }
+
+    fn cleanup_iteration(&mut self, last_iteration: bool, exec_time_millis: f64) -> () {
+        println!(
+            "Cleaning up Atomic Broadcast (master) iteration {}. Exec_time: {}",
+            self.iteration_id, exec_time_millis
+        );
+        let mut system = self.system.take().unwrap();
+        let client = self.client.take().unwrap();
+        let (tx, rx) = futures::channel::oneshot::channel();
+        client.do_send(Stop(tx));
+        let meta_results =
+            futures::executor::block_on(rx).expect("Client never replied with results!");
+        self.num_timed_out.push(meta_results.num_timed_out);
+        self.record_latencies(&meta_results);
+        system.stop(client).expect("Client never died!");
+
+        if last_iteration {
+            println!("Cleaning up last iteration");
+            self.persist_timeouts_summary();
+            if self.latency_hist.is_some() {
+                self.persist_latency_summary();
+            }
+            self.num_nodes = None;
+            self.concurrent_proposals = None;
+            self.num_proposals = None;
+            self.num_timed_out.clear();
+            self.iteration_id = 0;
+            system.shutdown().expect("Actix didn't shut down properly");
+        } else {
+            self.system = Some(system);
+        }
+    }
+}
+
+pub struct AtomicBroadcastClient {
+    system: Option<ActixSystem>,
+    raft_node: Option<Addr<RaftNode>>,
+}
+
+impl AtomicBroadcastClient {
+    fn new() -> AtomicBroadcastClient {
+        AtomicBroadcastClient {
+            system: None,
+            raft_node: None,
+        }
+    }
+}
+
+impl DistributedBenchmarkClient for AtomicBroadcastClient {
+    type ClientConf = bool;
+    type ClientData = SocketAddr;
+
+    fn setup(&mut self, c: Self::ClientConf) -> Self::ClientData {
+        println!("Setting up Atomic Broadcast (client)");
+        let mut system = new_system("atomicbroadcast");
+        let params = RaftParams::load_from_file(CONFIG_PATH);
+        let (listener, addr) = net::bind();
+        let raft_node = system
+            .start(move || RaftNode::with(listener, c, params))
+            .expect("RaftNode never started!");
+        println!("Raft node listening on {}", addr);
+        self.system = Some(system);
+        self.raft_node = Some(raft_node);
+        addr
+    }
+
+    fn prepare_iteration(&mut self) -> () {
+        println!("Preparing Atomic Broadcast (client)");
+    }
+
+    fn cleanup_iteration(&mut self, last_iteration: bool) -> () {
+        println!("Cleaning up Atomic Broadcast (client)");
+        if let Some(ref raft_node) = self.raft_node {
+            futures::executor::block_on(raft_node.send(Reset)).expect("RaftNode never reset!");
+        }
+        if last_iteration {
+            let mut system = self.system.take().unwrap();
+            let raft_node = self.raft_node.take().unwrap();
+            system.stop(raft_node).expect("RaftNode never died!");
+            system.shutdown().expect("Actix didn't shut down properly");
+        }
+    }
+}
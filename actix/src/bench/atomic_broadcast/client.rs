@@ -0,0 +1,326 @@
+use super::link::{Link, LinkEvent, Outgoing};
+use super::messages::AbMessage;
+use crate::actix_system_provider::PoisonPill;
+use actix::*;
+use futures::channel::oneshot;
+use std::{
+    collections::HashMap,
+    net::SocketAddr,
+    sync::Arc,
+    time::{Duration, Instant},
+};
+use synchronoise::{event::CountdownError, CountdownEvent};
+
+#[derive(Debug, PartialEq)]
+enum ExperimentState {
+    Setup,
+    LeaderElection,
+    Running,
+    Finished,
+}
+
+/// Starts sending proposals once the first leader is known.
+#[derive(Message)]
+#[rtype(result = "()")]
+pub struct Run;
+
+/// Stops all nodes and replies with the results of the iteration once they have stopped.
+#[derive(Message)]
+#[rtype(result = "()")]
+pub struct Stop(pub oneshot::Sender<MetaResults>);
+
+#[derive(Debug)]
+pub struct MetaResults {
+    pub num_timed_out: u64,
+    pub latencies: Vec<Duration>,
+}
+
+struct ProposalMetaData {
+    start_time: Option<Instant>,
+    timer: SpawnHandle,
+}
+
+/// Proposes to the current leader and collects the responses, like Kompact's `Client`.
+pub struct Client {
+    iteration_id: u32,
+    node_addrs: Vec<SocketAddr>,
+    tcp_no_delay: bool,
+    num_proposals: u64,
+    num_concurrent_proposals: u64,
+    nodes: HashMap<u64, Addr<Link>>,
+    num_init_acks: usize,
+    leader_election_latch: Arc<CountdownEvent>,
+    finished_latch: Arc<CountdownEvent>,
+    latest_proposal_id: u64,
+    responses: HashMap<u64, Option<Duration>>,
+    pending_proposals: HashMap<u64, ProposalMetaData>,
+    timeout: Duration,
+    current_leader: u64,
+    state: ExperimentState,
+    num_timed_out: u64,
+    leader_changes: Vec<u64>,
+    stop_reply: Option<oneshot::Sender<MetaResults>>,
+}
+
+impl Client {
+    pub fn with(
+        iteration_id: u32,
+        node_addrs: Vec<SocketAddr>,
+        tcp_no_delay: bool,
+        num_proposals: u64,
+        num_concurrent_proposals: u64,
+        timeout: Duration,
+        leader_election_latch: Arc<CountdownEvent>,
+        finished_latch: Arc<CountdownEvent>,
+    ) -> Client {
+        Client {
+            iteration_id,
+            node_addrs,
+            tcp_no_delay,
+            num_proposals,
+            num_concurrent_proposals,
+            nodes: HashMap::new(),
+            num_init_acks: 0,
+            leader_election_latch,
+            finished_latch,
+            latest_proposal_id: 0,
+            responses: HashMap::with_capacity(num_proposals as usize),
+            pending_proposals: HashMap::with_capacity(num_concurrent_proposals as usize),
+            timeout,
+            current_leader: 0,
+            state: ExperimentState::Setup,
+            num_timed_out: 0,
+            leader_changes: vec![],
+            stop_reply: None,
+        }
+    }
+
+    fn is_node(&self, pid: u64) -> bool {
+        pid >= 1 && pid <= self.node_addrs.len() as u64
+    }
+
+    fn send_concurrent_proposals(&mut self, ctx: &mut Context<Self>) {
+        let num_inflight = self.pending_proposals.len() as u64;
+        assert!(num_inflight <= self.num_concurrent_proposals);
+        if num_inflight == self.num_concurrent_proposals || self.current_leader == 0 {
+            return;
+        }
+        let available_n = self.num_concurrent_proposals - num_inflight;
+        let from = self.latest_proposal_id + 1;
+        let to = std::cmp::min(self.latest_proposal_id + available_n, self.num_proposals);
+        if from > to {
+            return;
+        }
+        let leader = self
+            .nodes
+            .get(&self.current_leader)
+            .expect("No link to leader")
+            .clone();
+        let cache_start_time = self.num_concurrent_proposals == 1;
+        for id in from..=to {
+            let start_time = if cache_start_time {
+                Some(Instant::now())
+            } else {
+                None
+            };
+            leader.do_send(Outgoing(AbMessage::Proposal(id)));
+            let timer = ctx.run_later(self.timeout, move |c, ctx| c.proposal_timeout(id, ctx));
+            self.pending_proposals
+                .insert(id, ProposalMetaData { start_time, timer });
+        }
+        self.latest_proposal_id = to;
+    }
+
+    fn handle_normal_response(&mut self, id: u64, latency: Option<Duration>) {
+        self.responses.insert(id, latency);
+        if self.responses.len() as u64 == self.num_proposals {
+            self.state = ExperimentState::Finished;
+            self.finished_latch
+                .decrement()
+                .expect("Failed to countdown finished latch");
+            if self.num_timed_out > 0 {
+                println!(
+                    "Got all responses with {} timeouts, Number of leader changes: {}, {:?}, Last leader was: {}",
+                    self.num_timed_out,
+                    self.leader_changes.len(),
+                    self.leader_changes,
+                    self.current_leader
+                );
+            } else {
+                println!(
+                    "Got all responses. Number of leader changes: {}, {:?}, Last leader was: {}",
+                    self.leader_changes.len(),
+                    self.leader_changes,
+                    self.current_leader
+                );
+            }
+        }
+    }
+
+    fn proposal_timeout(&mut self, id: u64, ctx: &mut Context<Self>) {
+        if self.responses.contains_key(&id) {
+            return;
+        }
+        self.num_timed_out += 1;
+        let proposal_meta = self
+            .pending_proposals
+            .remove(&id)
+            .expect("Timed out on proposal not in pending proposals");
+        let latency = proposal_meta.start_time.map(|start| start.elapsed());
+        self.handle_normal_response(id, latency);
+        self.send_concurrent_proposals(ctx);
+    }
+
+    fn handle_proposal_resp(&mut self, id: u64, latest_leader: u64, ctx: &mut Context<Self>) {
+        if self.state != ExperimentState::Running {
+            return;
+        }
+        if let Some(proposal_meta) = self.pending_proposals.remove(&id) {
+            let latency = proposal_meta.start_time.map(|start| start.elapsed());
+            ctx.cancel_future(proposal_meta.timer);
+            if self.is_node(latest_leader) && self.current_leader != latest_leader {
+                self.current_leader = latest_leader;
+                self.leader_changes.push(latest_leader);
+            }
+            self.handle_normal_response(id, latency);
+            self.send_concurrent_proposals(ctx);
+        }
+    }
+
+    fn handle_first_leader(&mut self, pid: u64) {
+        if !self.is_node(pid) || self.state != ExperimentState::LeaderElection {
+            return;
+        }
+        self.current_leader = pid;
+        match self.leader_election_latch.decrement() {
+            Ok(_) => println!("Got first leader: {}", pid),
+            Err(e) => {
+                if e != CountdownError::AlreadySet {
+                    panic!("Failed to decrement election latch: {:?}", e);
+                }
+            }
+        }
+    }
+
+    fn handle_init_ack(&mut self, iteration_id: u32) {
+        assert_eq!(
+            iteration_id, self.iteration_id,
+            "InitAck for wrong iteration"
+        );
+        self.num_init_acks += 1;
+        if self.num_init_acks == self.nodes.len() {
+            self.state = ExperimentState::LeaderElection;
+            for node in self.nodes.values() {
+                node.do_send(Outgoing(AbMessage::Run));
+            }
+        }
+    }
+
+    fn handle_stop_peer(&mut self, pid: u64) {
+        if let Some(node) = self.nodes.remove(&pid) {
+            node.do_send(PoisonPill);
+        } else {
+            panic!("Got stop from unknown pid {}", pid);
+        }
+        if self.nodes.is_empty() {
+            self.reply_stop();
+        }
+    }
+
+    fn reply_stop(&mut self) {
+        let mut responses: Vec<_> = std::mem::take(&mut self.responses).into_iter().collect();
+        responses.sort_unstable_by_key(|(id, _)| *id);
+        let latencies: Vec<Duration> = responses
+            .into_iter()
+            .filter_map(|(_, latency)| latency)
+            .collect();
+        let meta_results = MetaResults {
+            num_timed_out: self.num_timed_out,
+            latencies,
+        };
+        let _ = self
+            .stop_reply
+            .take()
+            .expect("No one to reply to")
+            .send(meta_results);
+    }
+}
+
+impl Actor for Client {
+    type Context = Context<Self>;
+
+    fn started(&mut self, ctx: &mut Context<Self>) {
+        let nodes = self.node_addrs.clone();
+        for (idx, addr) in nodes.iter().enumerate() {
+            let pid = idx as u64 + 1;
+            let link = Link::connect(*addr, self.tcp_no_delay, ctx.address().recipient());
+            link.do_send(Outgoing(AbMessage::Init {
+                pid,
+                iteration_id: self.iteration_id,
+                nodes: nodes.clone(),
+            }));
+            self.nodes.insert(pid, link);
+        }
+    }
+
+    fn stopped(&mut self, _ctx: &mut Context<Self>) {
+        for (_, node) in self.nodes.drain() {
+            node.do_send(PoisonPill);
+        }
+    }
+}
+
+impl Handler<LinkEvent> for Client {
+    type Result = ();
+
+    fn handle(&mut self, event: LinkEvent, ctx: &mut Context<Self>) -> Self::Result {
+        match event {
+            LinkEvent::Received(_link, msg) => match msg {
+                AbMessage::InitAck(iteration_id) => self.handle_init_ack(iteration_id),
+                AbMessage::FirstLeader(pid) => self.handle_first_leader(pid),
+                AbMessage::ProposalResp { id, latest_leader } => {
+                    self.handle_proposal_resp(id, latest_leader, ctx)
+                }
+                AbMessage::StopPeer(pid) => self.handle_stop_peer(pid),
+                msg => eprintln!("Client got unexpected message {:?}", msg),
+            },
+            LinkEvent::Closed(link) => {
+                if self.stop_reply.is_none() && self.nodes.values().any(|node| *node == link) {
+                    eprintln!("Client lost connection to a node");
+                }
+            }
+        }
+    }
+}
+
+impl Handler<Run> for Client {
+    type Result = ();
+
+    fn handle(&mut self, _msg: Run, ctx: &mut Context<Self>) -> Self::Result {
+        assert_ne!(self.current_leader, 0);
+        self.state = ExperimentState::Running;
+        self.send_concurrent_proposals(ctx);
+    }
+}
+
+impl Handler<Stop> for Client {
+    type Result = ();
+
+    fn handle(&mut self, msg: Stop, ctx: &mut Context<Self>) -> Self::Result {
+        for (_, proposal_meta) in self.pending_proposals.drain() {
+            ctx.cancel_future(proposal_meta.timer);
+        }
+        self.stop_reply = Some(msg.0);
+        for node in self.nodes.values() {
+            node.do_send(Outgoing(AbMessage::StopClient));
+        }
+    }
+}
+
+impl Handler<PoisonPill> for Client {
+    type Result = ();
+
+    fn handle(&mut self, _msg: PoisonPill, ctx: &mut Context<Self>) -> Self::Result {
+        ctx.stop();
+    }
+}
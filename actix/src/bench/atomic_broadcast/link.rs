@@ -0,0 +1,133 @@
+use super::messages::{AbCodec, AbMessage};
+use crate::actix_system_provider::PoisonPill;
+use actix::{
+    io::{FramedWrite, WriteHandler},
+    *,
+};
+use std::{io, net::SocketAddr};
+use tokio::net::{tcp::OwnedWriteHalf, TcpStream};
+use tokio_util::codec::FramedRead;
+
+/// What a [Link](Link) reports to its owner.
+#[derive(Message)]
+#[rtype(result = "()")]
+pub enum LinkEvent {
+    Received(Addr<Link>, AbMessage),
+    Closed(Addr<Link>),
+}
+
+/// Makes a [Link](Link) send `AbMessage` to its peer.
+#[derive(Message)]
+#[rtype(result = "()")]
+pub struct Outgoing(pub AbMessage);
+
+/// One end of a TCP connection between two participants of the atomic broadcast.
+///
+/// Links are bidirectional, so there is only one link between any two participants.
+pub struct Link {
+    owner: Recipient<LinkEvent>,
+    /// The peer to connect to once started, with its `TCP_NODELAY` setting.
+    connect_to: Option<(SocketAddr, bool)>,
+    writer: Option<FramedWrite<AbMessage, OwnedWriteHalf, AbCodec>>,
+}
+
+impl Link {
+    /// Must be called from within the Actix system.
+    pub fn start(stream: TcpStream, owner: Recipient<LinkEvent>) -> Addr<Link> {
+        Link::create(|ctx| {
+            let mut link = Link {
+                owner,
+                connect_to: None,
+                writer: None,
+            };
+            link.attach(stream, ctx);
+            link
+        })
+    }
+
+    /// Starts a link that connects to `addr` without blocking the caller.
+    ///
+    /// Messages sent to the link in the meantime are handled once it is connected.
+    /// Must be called from within the Actix system.
+    pub fn connect(
+        addr: SocketAddr,
+        tcp_no_delay: bool,
+        owner: Recipient<LinkEvent>,
+    ) -> Addr<Link> {
+        Actor::start(Link {
+            owner,
+            connect_to: Some((addr, tcp_no_delay)),
+            writer: None,
+        })
+    }
+
+    fn attach(&mut self, stream: TcpStream, ctx: &mut Context<Self>) -> () {
+        let (read, write) = stream.into_split();
+        ctx.add_stream(FramedRead::new(read, AbCodec::new()));
+        self.writer = Some(FramedWrite::new(write, AbCodec::new(), ctx));
+    }
+}
+
+impl Actor for Link {
+    type Context = Context<Self>;
+
+    fn started(&mut self, ctx: &mut Context<Self>) {
+        if let Some((addr, tcp_no_delay)) = self.connect_to.take() {
+            // the mailbox is paused until the future completes
+            ctx.wait(
+                TcpStream::connect(addr)
+                    .into_actor(self)
+                    .map(move |res, link, ctx| {
+                        let stream =
+                            res.unwrap_or_else(|e| panic!("Could not connect to {}: {}", addr, e));
+                        stream
+                            .set_nodelay(tcp_no_delay)
+                            .expect("Could not set TCP_NODELAY");
+                        link.attach(stream, ctx);
+                    }),
+            );
+        }
+    }
+
+    fn stopped(&mut self, ctx: &mut Context<Self>) {
+        let _ = self.owner.do_send(LinkEvent::Closed(ctx.address()));
+    }
+}
+
+impl WriteHandler<io::Error> for Link {}
+
+impl StreamHandler<io::Result<AbMessage>> for Link {
+    fn handle(&mut self, msg: io::Result<AbMessage>, ctx: &mut Context<Self>) {
+        match msg {
+            Ok(msg) => {
+                let _ = self.owner.do_send(LinkEvent::Received(ctx.address(), msg));
+            }
+            Err(e) => {
+                eprintln!("Link failed: {}", e);
+                ctx.stop();
+            }
+        }
+    }
+}
+
+impl Handler<Outgoing> for Link {
+    type Result = ();
+
+    fn handle(&mut self, msg: Outgoing, _ctx: &mut Context<Self>) -> Self::Result {
+        self.writer
+            .as_mut()
+            .expect("Link is not connected")
+            .write(msg.0);
+    }
+}
+
+impl Handler<PoisonPill> for Link {
+    type Result = ();
+
+    fn handle(&mut self, _msg: PoisonPill, ctx: &mut Context<Self>) -> Self::Result {
+        if let Some(writer) = self.writer.as_mut() {
+            writer.close();
+        }
+        ctx.stop();
+    }
+}
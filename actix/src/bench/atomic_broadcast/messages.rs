@@ -0,0 +1,294 @@
+extern crate raft as tikv_raft;
+
+use bytes::{Buf, BufMut, Bytes, BytesMut};
+use protobuf::{parse_from_bytes, Message as PbMessage};
+use std::{io, net::SocketAddr};
+use tikv_raft::prelude::Message as TikvRaftMsg;
+use tokio_util::codec::{Decoder, Encoder, LengthDelimitedCodec};
+
+/// Everything that goes over the wire between the client and the Raft nodes.
+///
+/// `Hello`, `Init`, `InitAck` and `Run` take the role of the partitioning actor in Kompact,
+/// the rest matches Kompact's `AtomicBroadcastMsg`, `RaftMsg` and `StopMsg`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum AbMessage {
+    /// Identifies a connection from node `pid` to a node with a lower pid.
+    Hello(u64),
+    /// Sent by the client to every node at the start of an iteration.
+    Init {
+        pid: u64,
+        iteration_id: u32,
+        nodes: Vec<SocketAddr>,
+    },
+    InitAck(u32),
+    Run,
+    Proposal(u64),
+    ProposalResp {
+        id: u64,
+        latest_leader: u64,
+    },
+    FirstLeader(u64),
+    Raft(TikvRaftMsg),
+    StopClient,
+    StopPeer(u64),
+}
+
+impl AbMessage {
+    const HELLO_ID: u8 = 1;
+    const INIT_ID: u8 = 2;
+    const INIT_ACK_ID: u8 = 3;
+    const RUN_ID: u8 = 4;
+    const PROPOSAL_ID: u8 = 5;
+    const PROPOSAL_RESP_ID: u8 = 6;
+    const FIRST_LEADER_ID: u8 = 7;
+    const RAFT_ID: u8 = 8;
+    const STOP_CLIENT_ID: u8 = 9;
+    const STOP_PEER_ID: u8 = 10;
+
+    fn serialise(&self, buf: &mut BytesMut) -> () {
+        match self {
+            AbMessage::Hello(pid) => {
+                buf.put_u8(AbMessage::HELLO_ID);
+                buf.put_u64(*pid);
+            }
+            AbMessage::Init {
+                pid,
+                iteration_id,
+                nodes,
+            } => {
+                buf.put_u8(AbMessage::INIT_ID);
+                buf.put_u64(*pid);
+                buf.put_u32(*iteration_id);
+                buf.put_u32(nodes.len() as u32);
+                for node in nodes {
+                    let addr = node.to_string();
+                    buf.put_u16(addr.len() as u16);
+                    buf.put_slice(addr.as_bytes());
+                }
+            }
+            AbMessage::InitAck(iteration_id) => {
+                buf.put_u8(AbMessage::INIT_ACK_ID);
+                buf.put_u32(*iteration_id);
+            }
+            AbMessage::Run => buf.put_u8(AbMessage::RUN_ID),
+            AbMessage::Proposal(id) => {
+                buf.put_u8(AbMessage::PROPOSAL_ID);
+                buf.put_u64(*id);
+            }
+            AbMessage::ProposalResp { id, latest_leader } => {
+                buf.put_u8(AbMessage::PROPOSAL_RESP_ID);
+                buf.put_u64(*id);
+                buf.put_u64(*latest_leader);
+            }
+            AbMessage::FirstLeader(pid) => {
+                buf.put_u8(AbMessage::FIRST_LEADER_ID);
+                buf.put_u64(*pid);
+            }
+            AbMessage::Raft(rm) => {
+                buf.put_u8(AbMessage::RAFT_ID);
+                let bytes = rm
+                    .write_to_bytes()
+                    .expect("Protobuf failed to serialise TikvRaftMsg");
+                buf.put_slice(&bytes);
+            }
+            AbMessage::StopClient => buf.put_u8(AbMessage::STOP_CLIENT_ID),
+            AbMessage::StopPeer(pid) => {
+                buf.put_u8(AbMessage::STOP_PEER_ID);
+                buf.put_u64(*pid);
+            }
+        }
+    }
+
+    fn deserialise(mut frame: Bytes) -> io::Result<AbMessage> {
+        ensure(&frame, 1)?;
+        let id = frame.get_u8();
+        let msg = match id {
+            AbMessage::HELLO_ID => {
+                ensure(&frame, 8)?;
+                AbMessage::Hello(frame.get_u64())
+            }
+            AbMessage::INIT_ID => {
+                ensure(&frame, 16)?;
+                let pid = frame.get_u64();
+                let iteration_id = frame.get_u32();
+                let len = frame.get_u32() as usize;
+                let mut nodes = Vec::with_capacity(len);
+                for _ in 0..len {
+                    ensure(&frame, 2)?;
+                    let addr_len = frame.get_u16() as usize;
+                    ensure(&frame, addr_len)?;
+                    let addr = frame.split_to(addr_len);
+                    let addr = std::str::from_utf8(&addr)
+                        .ok()
+                        .and_then(|s| s.parse::<SocketAddr>().ok())
+                        .ok_or_else(|| invalid_data("Invalid node address".to_string()))?;
+                    nodes.push(addr);
+                }
+                AbMessage::Init {
+                    pid,
+                    iteration_id,
+                    nodes,
+                }
+            }
+            AbMessage::INIT_ACK_ID => {
+                ensure(&frame, 4)?;
+                AbMessage::InitAck(frame.get_u32())
+            }
+            AbMessage::RUN_ID => AbMessage::Run,
+            AbMessage::PROPOSAL_ID => {
+                ensure(&frame, 8)?;
+                AbMessage::Proposal(frame.get_u64())
+            }
+            AbMessage::PROPOSAL_RESP_ID => {
+                ensure(&frame, 16)?;
+                let id = frame.get_u64();
+                let latest_leader = frame.get_u64();
+                AbMessage::ProposalResp { id, latest_leader }
+            }
+            AbMessage::FIRST_LEADER_ID => {
+                ensure(&frame, 8)?;
+                AbMessage::FirstLeader(frame.get_u64())
+            }
+            AbMessage::RAFT_ID => {
+                let rm = parse_from_bytes::<TikvRaftMsg>(&frame)
+                    .map_err(|e| invalid_data(format!("Invalid TikvRaftMsg: {}", e)))?;
+                frame.advance(frame.remaining());
+                AbMessage::Raft(rm)
+            }
+            AbMessage::STOP_CLIENT_ID => AbMessage::StopClient,
+            AbMessage::STOP_PEER_ID => {
+                ensure(&frame, 8)?;
+                AbMessage::StopPeer(frame.get_u64())
+            }
+            _ => return Err(invalid_data(format!("Unknown message id {}", id))),
+        };
+        if frame.has_remaining() {
+            Err(invalid_data(format!(
+                "{} trailing bytes after {:?}",
+                frame.remaining(),
+                msg
+            )))
+        } else {
+            Ok(msg)
+        }
+    }
+}
+
+/// The data of a proposal, as it is stored in the Raft log.
+pub fn proposal_data(id: u64) -> Vec<u8> {
+    id.to_be_bytes().to_vec()
+}
+
+/// The proposal id from an entry's data, see [proposal_data](proposal_data).
+pub fn proposal_id(data: &[u8]) -> u64 {
+    let mut bytes = [0u8; 8];
+    bytes.copy_from_slice(&data[..8]);
+    u64::from_be_bytes(bytes)
+}
+
+fn ensure(frame: &Bytes, len: usize) -> io::Result<()> {
+    if frame.remaining() < len {
+        Err(invalid_data(format!(
+            "Frame is too short: needed {} more bytes, but only {} remain",
+            len,
+            frame.remaining()
+        )))
+    } else {
+        Ok(())
+    }
+}
+
+fn invalid_data(msg: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, msg)
+}
+
+/// Length-prefixed framing of [AbMessage](AbMessage)s.
+pub struct AbCodec {
+    frames: LengthDelimitedCodec,
+}
+
+impl AbCodec {
+    pub fn new() -> AbCodec {
+        AbCodec {
+            // Raft batches can get much larger than the default limit of 8MB
+            frames: LengthDelimitedCodec::builder()
+                .max_frame_length(u32::max_value() as usize)
+                .new_codec(),
+        }
+    }
+}
+
+impl Decoder for AbCodec {
+    type Item = AbMessage;
+    type Error = io::Error;
+
+    fn decode(&mut self, src: &mut BytesMut) -> Result<Option<AbMessage>, io::Error> {
+        match self.frames.decode(src)? {
+            Some(frame) => AbMessage::deserialise(frame.freeze()).map(Some),
+            None => Ok(None),
+        }
+    }
+}
+
+impl Encoder<AbMessage> for AbCodec {
+    type Error = io::Error;
+
+    fn encode(&mut self, msg: AbMessage, dst: &mut BytesMut) -> Result<(), io::Error> {
+        let mut buf = BytesMut::new();
+        msg.serialise(&mut buf);
+        self.frames.encode(buf.freeze(), dst)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tikv_raft::prelude::{Entry, MessageType};
+
+    #[test]
+    fn test_ab_message_roundtrip() {
+        let mut rm = TikvRaftMsg::default();
+        rm.set_msg_type(MessageType::MsgAppend);
+        rm.set_to(2);
+        rm.set_from(1);
+        rm.set_term(3);
+        let mut entry = Entry::default();
+        entry.set_data(proposal_data(42));
+        rm.mut_entries().push(entry);
+        let msgs = vec![
+            AbMessage::Hello(3),
+            AbMessage::Init {
+                pid: 2,
+                iteration_id: 7,
+                nodes: vec![
+                    "127.0.0.1:4567".parse().unwrap(),
+                    "[::1]:4568".parse().unwrap(),
+                ],
+            },
+            AbMessage::InitAck(7),
+            AbMessage::Run,
+            AbMessage::Proposal(42),
+            AbMessage::ProposalResp {
+                id: 42,
+                latest_leader: 1,
+            },
+            AbMessage::FirstLeader(1),
+            AbMessage::Raft(rm),
+            AbMessage::StopClient,
+            AbMessage::StopPeer(2),
+        ];
+        let mut codec = AbCodec::new();
+        let mut buf = BytesMut::new();
+        for msg in msgs.iter() {
+            codec.encode(msg.clone(), &mut buf).expect("encode");
+        }
+        for msg in msgs.iter() {
+            let decoded = codec.decode(&mut buf).expect("decode");
+            assert_eq!(decoded.as_ref(), Some(msg));
+        }
+        assert_eq!(codec.decode(&mut buf).expect("decode"), None);
+        assert!(AbMessage::deserialise(Bytes::from_static(&[5, 0, 1])).is_err());
+        assert!(AbMessage::deserialise(Bytes::from_static(&[99])).is_err());
+        assert_eq!(proposal_id(&proposal_data(1234)), 1234);
+    }
+}
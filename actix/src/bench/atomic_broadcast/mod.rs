@@ -0,0 +1,5 @@
+pub mod atomic_broadcast;
+mod client;
+mod link;
+mod messages;
+mod raft;
@@ -0,0 +1,395 @@
+extern crate raft as tikv_raft;
+
+use super::{
+    atomic_broadcast::RaftParams,
+    link::{Link, LinkEvent, Outgoing},
+    messages::{proposal_data, proposal_id, AbMessage},
+};
+use crate::actix_system_provider::PoisonPill;
+use actix::*;
+use std::{collections::HashMap, io, net::SocketAddr, time::Duration};
+use tikv_raft::{prelude::*, storage::MemStorage, StateRole};
+use tokio::net::TcpListener;
+
+type Storage = MemStorage;
+
+/// Drops all state of the current iteration.
+#[derive(Message)]
+#[rtype(result = "()")]
+pub struct Reset;
+
+#[derive(Debug, PartialEq)]
+enum State {
+    Election,
+    Running,
+}
+
+/// The Raft state of a single iteration.
+struct Replica {
+    raw_raft: RawNode<Storage>,
+    state: State,
+    current_leader: u64,
+    held_back: Vec<u64>,
+    timers: Option<(SpawnHandle, SpawnHandle)>,
+    stopped: bool,
+}
+
+/// A Raft replica, which plays the parts of `RaftComp`, `RaftReplica` and `Communicator` in Kompact.
+pub struct RaftNode {
+    listener: Option<std::net::TcpListener>,
+    tcp_no_delay: bool,
+    params: RaftParams,
+    pid: u64,
+    iteration_id: u32,
+    num_nodes: usize,
+    client: Option<Addr<Link>>,
+    peers: HashMap<u64, Addr<Link>>,
+    acked: bool,
+    replica: Option<Replica>,
+}
+
+impl RaftNode {
+    pub fn with(
+        listener: std::net::TcpListener,
+        tcp_no_delay: bool,
+        params: RaftParams,
+    ) -> RaftNode {
+        RaftNode {
+            listener: Some(listener),
+            tcp_no_delay,
+            params,
+            pid: 0,
+            iteration_id: 0,
+            num_nodes: 0,
+            client: None,
+            peers: HashMap::new(),
+            acked: false,
+            replica: None,
+        }
+    }
+
+    fn create_rawraft_config(&self) -> Config {
+        // convert from ms to logical clock ticks
+        let election_tick = (self.params.election_timeout / self.params.tick_period) as usize;
+        let heartbeat_tick = (self.params.leader_hb_period / self.params.tick_period) as usize;
+        let c = Config {
+            id: self.pid,
+            election_tick,  // number of ticks without HB before starting election
+            heartbeat_tick, // leader sends HB every heartbeat_tick
+            max_inflight_msgs: self.params.max_inflight,
+            max_size_per_msg: self.params.max_batch_size,
+            batch_append: true,
+            pre_vote: self.params.pre_vote,
+            check_quorum: self.params.check_quorum,
+            ..Default::default()
+        };
+        assert!(c.validate().is_ok(), "Invalid RawRaft config");
+        c
+    }
+
+    fn init(
+        &mut self,
+        client: Addr<Link>,
+        pid: u64,
+        iteration_id: u32,
+        nodes: Vec<SocketAddr>,
+        ctx: &mut Context<Self>,
+    ) -> () {
+        self.pid = pid;
+        self.iteration_id = iteration_id;
+        self.num_nodes = nodes.len();
+        self.client = Some(client);
+        self.acked = false;
+        // connect to all nodes with lower pids, the others connect to us
+        for (idx, addr) in nodes.iter().enumerate() {
+            let peer_pid = idx as u64 + 1;
+            if peer_pid < pid {
+                let link = Link::connect(*addr, self.tcp_no_delay, ctx.address().recipient());
+                link.do_send(Outgoing(AbMessage::Hello(pid)));
+                self.peers.insert(peer_pid, link);
+            }
+        }
+        let voters: Vec<u64> = (1..=nodes.len() as u64).collect();
+        let store = Storage::new_with_conf_state((voters, vec![]));
+        let raw_raft =
+            RawNode::new(&self.create_rawraft_config(), store).expect("Failed to create tikv Raft");
+        self.replica = Some(Replica {
+            raw_raft,
+            state: State::Election,
+            current_leader: 0,
+            held_back: Vec::new(),
+            timers: None,
+            stopped: false,
+        });
+        self.ack_if_connected();
+    }
+
+    fn ack_if_connected(&mut self) -> () {
+        if self.acked || self.replica.is_none() || self.peers.len() + 1 < self.num_nodes {
+            return;
+        }
+        if let Some(client) = self.client.as_ref() {
+            client.do_send(Outgoing(AbMessage::InitAck(self.iteration_id)));
+            self.acked = true;
+        }
+    }
+
+    fn start_timers(&mut self, ctx: &mut Context<Self>) -> () {
+        let ready_timer = ctx.run_interval(self.params.outgoing_period, |node, _| node.on_ready());
+        let tick_timer = ctx
+            .run_interval(Duration::from_millis(self.params.tick_period), |node, _| {
+                node.tick()
+            });
+        let replica = self.replica.as_mut().expect("Got Run before Init");
+        replica.timers = Some((ready_timer, tick_timer));
+    }
+
+    fn stop_timers(&mut self, ctx: &mut Context<Self>) -> () {
+        if let Some(timers) = self.replica.as_mut().and_then(|r| r.timers.take()) {
+            ctx.cancel_future(timers.0);
+            ctx.cancel_future(timers.1);
+        }
+    }
+
+    fn tell_client(&self, msg: AbMessage) -> () {
+        tell_client(self.client.as_ref(), msg);
+    }
+
+    fn handle_proposal(&mut self, id: u64) -> () {
+        let replica = match self.replica.as_mut() {
+            Some(replica) if !replica.stopped => replica,
+            _ => return,
+        };
+        if replica.current_leader == self.pid || replica.current_leader == 0 {
+            propose(replica, id);
+        } else {
+            let leader = self.peers.get(&replica.current_leader).unwrap_or_else(|| {
+                panic!(
+                    "Could not get leader's link. Pid: {}",
+                    replica.current_leader
+                )
+            });
+            leader.do_send(Outgoing(AbMessage::Proposal(id)));
+        }
+    }
+
+    fn stop(&mut self, ctx: &mut Context<Self>) -> () {
+        self.stop_timers(ctx);
+        if let Some(replica) = self.replica.as_mut() {
+            assert!(!replica.stopped);
+            replica.stopped = true;
+        }
+        for peer in self.peers.values() {
+            peer.do_send(Outgoing(AbMessage::StopPeer(self.pid)));
+        }
+        self.tell_client(AbMessage::StopPeer(self.pid));
+    }
+
+    fn reset(&mut self, ctx: &mut Context<Self>) -> () {
+        self.stop_timers(ctx);
+        self.replica = None;
+        for (_, peer) in self.peers.drain() {
+            peer.do_send(PoisonPill);
+        }
+        if let Some(client) = self.client.take() {
+            client.do_send(PoisonPill);
+        }
+        self.acked = false;
+    }
+
+    fn tick(&mut self) -> () {
+        let replica = match self.replica.as_mut() {
+            Some(replica) => replica,
+            None => return,
+        };
+        replica.raw_raft.tick();
+        let leader = replica.raw_raft.raft.leader_id;
+        if leader != 0 {
+            if !replica.held_back.is_empty() {
+                let proposals = std::mem::take(&mut replica.held_back);
+                for id in proposals {
+                    propose(replica, id);
+                }
+            }
+            if leader != replica.current_leader {
+                replica.current_leader = leader;
+                if replica.state == State::Election {
+                    replica.state = State::Running;
+                    self.tell_client(AbMessage::FirstLeader(leader));
+                }
+            }
+        }
+    }
+
+    fn on_ready(&mut self) -> () {
+        let replica = match self.replica.as_mut() {
+            Some(replica) if replica.raw_raft.has_ready() => replica,
+            _ => return,
+        };
+        let raw_raft = &mut replica.raw_raft;
+        let store = raw_raft.raft.raft_log.store.clone();
+
+        // Get the `Ready` with `RawNode::ready` interface.
+        let mut ready = raw_raft.ready();
+
+        // Persistent raft logs. It's necessary because in `RawNode::advance` we stabilize
+        // raft logs to the latest position.
+        if let Err(e) = store.wl().append(ready.entries()) {
+            eprintln!("persist raft log fail: {:?}, need to retry or panic", e);
+            return;
+        }
+
+        // Apply the snapshot. It's necessary because in `RawNode::advance` we stabilize the snapshot.
+        if *ready.snapshot() != Snapshot::default() {
+            unimplemented!("Should not be any snapshots to handle!");
+        }
+
+        // Send out the messages come from the node.
+        for msg in ready.messages.drain(..) {
+            let receiver = self.peers.get(&msg.get_to()).unwrap_or_else(|| {
+                panic!(
+                    "Could not find link for id={}. Known peers: {:?}. RaftMsg: {:?}",
+                    msg.get_to(),
+                    self.peers.keys(),
+                    msg
+                )
+            });
+            receiver.do_send(Outgoing(AbMessage::Raft(msg)));
+        }
+
+        // Apply all committed proposals.
+        if let Some(committed_entries) = ready.committed_entries.take() {
+            for entry in &committed_entries {
+                if entry.data.is_empty() {
+                    // From new elected leaders.
+                    continue;
+                }
+                if let EntryType::EntryConfChange = entry.get_entry_type() {
+                    unimplemented!("Reconfiguration is not supported on Actix!");
+                }
+                if raw_raft.raft.state == StateRole::Leader {
+                    let pr = AbMessage::ProposalResp {
+                        id: proposal_id(&entry.data),
+                        latest_leader: raw_raft.raft.id,
+                    };
+                    tell_client(self.client.as_ref(), pr);
+                }
+            }
+            if let Some(last_committed) = committed_entries.last() {
+                let mut store = store.wl();
+                store.mut_hard_state().commit = last_committed.index;
+                store.mut_hard_state().term = last_committed.term;
+            }
+        }
+        // Call `RawNode::advance` interface to update position flags in the raft.
+        raw_raft.advance(ready);
+    }
+}
+
+/// Sends `msg` to the client, unless its link has been closed in the meantime.
+fn tell_client(client: Option<&Addr<Link>>, msg: AbMessage) -> () {
+    match client {
+        Some(client) => client.do_send(Outgoing(msg)),
+        None => eprintln!("Dropping {:?}, the link to the client is closed", msg),
+    }
+}
+
+fn propose(replica: &mut Replica, id: u64) -> () {
+    if replica.raw_raft.raft.leader_id == 0 {
+        replica.held_back.push(id);
+        return;
+    }
+    replica
+        .raw_raft
+        .propose(vec![], proposal_data(id))
+        .unwrap_or_else(|_| {
+            panic!(
+                "Failed to propose. leader: {}, lead_transferee: {:?}",
+                replica.raw_raft.raft.leader_id, replica.raw_raft.raft.lead_transferee
+            )
+        });
+}
+
+impl Actor for RaftNode {
+    type Context = Context<Self>;
+
+    fn started(&mut self, ctx: &mut Context<Self>) {
+        let listener = self.listener.take().expect("RaftNode started twice");
+        let listener = TcpListener::from_std(listener).expect("Could not register listener");
+        let connections = futures::stream::unfold(listener, |listener| async move {
+            let conn = listener.accept().await.map(|(stream, _peer)| stream);
+            Some((conn, listener))
+        });
+        ctx.add_stream(connections);
+    }
+}
+
+impl StreamHandler<io::Result<tokio::net::TcpStream>> for RaftNode {
+    fn handle(&mut self, conn: io::Result<tokio::net::TcpStream>, ctx: &mut Context<Self>) {
+        match conn {
+            Ok(stream) => {
+                stream
+                    .set_nodelay(self.tcp_no_delay)
+                    .expect("Could not set TCP_NODELAY");
+                // the link is identified by its first message
+                Link::start(stream, ctx.address().recipient());
+            }
+            Err(e) => eprintln!("Could not accept connection: {}", e),
+        }
+    }
+}
+
+impl Handler<LinkEvent> for RaftNode {
+    type Result = ();
+
+    fn handle(&mut self, event: LinkEvent, ctx: &mut Context<Self>) -> Self::Result {
+        match event {
+            LinkEvent::Received(link, msg) => match msg {
+                AbMessage::Hello(pid) => {
+                    self.peers.insert(pid, link);
+                    self.ack_if_connected();
+                }
+                AbMessage::Init {
+                    pid,
+                    iteration_id,
+                    nodes,
+                } => self.init(link, pid, iteration_id, nodes, ctx),
+                AbMessage::Run => self.start_timers(ctx),
+                AbMessage::Proposal(id) => self.handle_proposal(id),
+                AbMessage::Raft(rm) => {
+                    if let Some(replica) = self.replica.as_mut() {
+                        if !replica.stopped {
+                            let _ = replica.raw_raft.step(rm);
+                        }
+                    }
+                }
+                AbMessage::StopClient => self.stop(ctx),
+                AbMessage::StopPeer(_) => (), // peers stop on the client's request, too
+                msg => eprintln!("RaftNode got unexpected message {:?}", msg),
+            },
+            LinkEvent::Closed(link) => {
+                self.peers.retain(|_, peer| *peer != link);
+                if self.client.as_ref() == Some(&link) {
+                    self.client = None;
+                }
+            }
+        }
+    }
+}
+
+impl Handler<Reset> for RaftNode {
+    type Result = ();
+
+    fn handle(&mut self, _msg: Reset, ctx: &mut Context<Self>) -> Self::Result {
+        self.reset(ctx);
+    }
+}
+
+impl Handler<PoisonPill> for RaftNode {
+    type Result = ();
+
+    fn handle(&mut self, _msg: PoisonPill, ctx: &mut Context<Self>) -> Self::Result {
+        self.reset(ctx);
+        ctx.stop();
+    }
+}
@@ -0,0 +1,337 @@
+use super::*;
+
+use actix::*;
+use actix_system_provider::{ActixSystem, PoisonPill};
+use benchmark_suite_shared::helpers::bank::{TransferGenerator, INITIAL_BALANCE};
+use benchmark_suite_shared::kompics_benchmarks::benchmarks::BankRequest;
+use std::convert::TryInto;
+use std::sync::Arc;
+use synchronoise::CountdownEvent;
+
+#[derive(Default)]
+pub struct Bank;
+
+impl Benchmark for Bank {
+    type Conf = BankRequest;
+    type Instance = BankI;
+
+    fn msg_to_conf(msg: Box<dyn (::protobuf::Message)>) -> Result<Self::Conf, BenchmarkError> {
+        downcast_msg!(msg; BankRequest)
+    }
+
+    fn validate(c: &Self::Conf) -> Result<(), BenchmarkError> {
+        validation::bank(c)
+    }
+
+    fn new_instance() -> Self::Instance {
+        BankI::new()
+    }
+
+    const LABEL: &'static str = "Bank";
+}
+
+pub struct BankI {
+    num_accounts: Option<usize>,
+    num_transactions: Option<u64>,
+    seed: Option<u64>,
+    system: Option<ActixSystem>,
+    teller: Option<Addr<Teller>>,
+    accounts: Vec<Addr<Account>>,
+    latch: Option<Arc<CountdownEvent>>,
+}
+
+impl BankI {
+    fn new() -> BankI {
+        BankI {
+            num_accounts: None,
+            num_transactions: None,
+            seed: None,
+            system: None,
+            teller: None,
+            accounts: Vec::new(),
+            latch: None,
+        }
+    }
+}
+
+impl BenchmarkInstance for BankI {
+    type Conf = BankRequest;
+
+    fn setup(&mut self, c: &Self::Conf) -> () {
+        self.num_accounts = Some(c.number_of_accounts.try_into().unwrap());
+        self.num_transactions = Some(c.number_of_transactions);
+        self.seed = Some(c.seed);
+        let system = crate::actix_system_provider::new_system("bank");
+        self.system = Some(system);
+    }
+
+    fn prepare_iteration(&mut self) -> () {
+        match (
+            self.system.as_mut(),
+            self.num_accounts,
+            self.num_transactions,
+            self.seed,
+        ) {
+            (Some(system), Some(num_accounts), Some(num_transactions), Some(seed)) => {
+                let latch = Arc::new(CountdownEvent::new(1));
+                let teller_latch = latch.clone();
+                let generator = TransferGenerator::new(seed, num_accounts);
+                let teller = system
+                    .start(move || Teller::with(num_transactions, generator, teller_latch))
+                    .expect("Should start Teller!");
+                for id in 0..num_accounts {
+                    let teller = teller.clone();
+                    let account = system
+                        .start(move || Account::with(id, teller))
+                        .expect("Should start Account!");
+                    self.accounts.push(account);
+                }
+                for account in self.accounts.iter() {
+                    futures::executor::block_on(account.send(Peers(self.accounts.clone())))
+                        .expect("Account should have peers!");
+                }
+                futures::executor::block_on(teller.send(Peers(self.accounts.clone())))
+                    .expect("Teller should have accounts!");
+                self.teller = Some(teller);
+                self.latch = Some(latch);
+            }
+            _ => unimplemented!(),
+        }
+    }
+
+    fn run_iteration(&mut self) -> () {
+        match self.teller {
+            Some(ref teller) => {
+                let latch = self.latch.take().unwrap();
+                teller.do_send(Start);
+                latch.wait();
+            }
+            None => unimplemented!(),
+        }
+    }
+
+    fn cleanup_iteration(&mut self, last_iteration: bool, _exec_time_millis: f64) -> () {
+        let total: i64 = self
+            .accounts
+            .iter()
+            .map(|a| futures::executor::block_on(a.send(GetBalance)).expect("Should get balance!"))
+            .sum();
+        let num_accounts = self.num_accounts.unwrap() as i64;
+        assert_eq!(
+            total,
+            num_accounts * INITIAL_BALANCE,
+            "Money was not conserved!"
+        );
+
+        let mut system = self.system.take().unwrap();
+        system
+            .stop(self.teller.take().unwrap())
+            .expect("Teller should have stopped!");
+        for account in self.accounts.drain(..) {
+            system.stop(account).expect("Account should have stopped!");
+        }
+
+        if last_iteration {
+            system.shutdown().expect("Actix didn't shut down properly");
+            self.num_accounts = None;
+            self.num_transactions = None;
+            self.seed = None;
+        } else {
+            self.system = Some(system);
+        }
+    }
+}
+
+#[derive(Message)]
+#[rtype(result = "()")]
+struct Peers(Vec<Addr<Account>>);
+
+#[derive(Message)]
+#[rtype(result = "()")]
+struct Start;
+
+#[derive(Message)]
+#[rtype(result = "()")]
+struct TransferDone;
+
+#[derive(Message)]
+#[rtype(result = "()")]
+struct Debit {
+    amount: i64,
+    destination: usize,
+}
+
+#[derive(Message)]
+#[rtype(result = "()")]
+struct Credit {
+    amount: i64,
+    source: usize,
+}
+
+#[derive(Message)]
+#[rtype(result = "()")]
+struct CreditAck;
+
+#[derive(Message)]
+#[rtype(result = "i64")]
+struct GetBalance;
+
+struct Teller {
+    num_transactions: u64,
+    remaining: u64,
+    generator: TransferGenerator,
+    accounts: Vec<Addr<Account>>,
+    latch: Arc<CountdownEvent>,
+}
+
+impl Teller {
+    fn with(
+        num_transactions: u64,
+        generator: TransferGenerator,
+        latch: Arc<CountdownEvent>,
+    ) -> Teller {
+        Teller {
+            num_transactions,
+            remaining: num_transactions,
+            generator,
+            accounts: Vec::new(),
+            latch,
+        }
+    }
+}
+
+impl Actor for Teller {
+    type Context = Context<Self>;
+
+    fn started(&mut self, _ctx: &mut Context<Self>) {
+        // nothing
+    }
+
+    fn stopped(&mut self, _ctx: &mut Context<Self>) {
+        self.accounts.clear(); // the accounts hold a reference to the teller
+    }
+}
+
+impl Handler<PoisonPill> for Teller {
+    type Result = ();
+
+    fn handle(&mut self, _msg: PoisonPill, ctx: &mut Context<Self>) -> Self::Result {
+        ctx.stop();
+    }
+}
+
+impl Handler<Peers> for Teller {
+    type Result = ();
+
+    fn handle(&mut self, msg: Peers, _ctx: &mut Context<Self>) -> Self::Result {
+        self.accounts = msg.0;
+    }
+}
+
+impl Handler<Start> for Teller {
+    type Result = ();
+
+    fn handle(&mut self, _msg: Start, _ctx: &mut Context<Self>) -> Self::Result {
+        for _ in 0..self.num_transactions {
+            let transfer = self.generator.next_transfer();
+            self.accounts[transfer.source].do_send(Debit {
+                amount: transfer.amount,
+                destination: transfer.destination,
+            });
+        }
+    }
+}
+
+impl Handler<TransferDone> for Teller {
+    type Result = ();
+
+    fn handle(&mut self, _msg: TransferDone, _ctx: &mut Context<Self>) -> Self::Result {
+        self.remaining -= 1u64;
+        if self.remaining == 0 {
+            self.latch.decrement().expect("Should decrement!");
+        }
+    }
+}
+
+struct Account {
+    id: usize,
+    balance: i64,
+    peers: Vec<Addr<Account>>,
+    teller: Addr<Teller>,
+}
+
+impl Account {
+    fn with(id: usize, teller: Addr<Teller>) -> Account {
+        Account {
+            id,
+            balance: INITIAL_BALANCE,
+            peers: Vec::new(),
+            teller,
+        }
+    }
+}
+
+impl Actor for Account {
+    type Context = Context<Self>;
+
+    fn started(&mut self, _ctx: &mut Context<Self>) {
+        // nothing
+    }
+
+    fn stopped(&mut self, _ctx: &mut Context<Self>) {
+        self.peers.clear(); // the accounts all hold references to each other
+    }
+}
+
+impl Handler<PoisonPill> for Account {
+    type Result = ();
+
+    fn handle(&mut self, _msg: PoisonPill, ctx: &mut Context<Self>) -> Self::Result {
+        ctx.stop();
+    }
+}
+
+impl Handler<Peers> for Account {
+    type Result = ();
+
+    fn handle(&mut self, msg: Peers, _ctx: &mut Context<Self>) -> Self::Result {
+        self.peers = msg.0;
+    }
+}
+
+impl Handler<Debit> for Account {
+    type Result = ();
+
+    fn handle(&mut self, msg: Debit, _ctx: &mut Context<Self>) -> Self::Result {
+        self.balance -= msg.amount;
+        self.peers[msg.destination].do_send(Credit {
+            amount: msg.amount,
+            source: self.id,
+        });
+    }
+}
+
+impl Handler<Credit> for Account {
+    type Result = ();
+
+    fn handle(&mut self, msg: Credit, _ctx: &mut Context<Self>) -> Self::Result {
+        self.balance += msg.amount;
+        self.peers[msg.source].do_send(CreditAck);
+    }
+}
+
+impl Handler<CreditAck> for Account {
+    type Result = ();
+
+    fn handle(&mut self, _msg: CreditAck, _ctx: &mut Context<Self>) -> Self::Result {
+        self.teller.do_send(TransferDone);
+    }
+}
+
+impl Handler<GetBalance> for Account {
+    type Result = MessageResult<GetBalance>;
+
+    fn handle(&mut self, _msg: GetBalance, _ctx: &mut Context<Self>) -> Self::Result {
+        MessageResult(self.balance)
+    }
+}
@@ -0,0 +1,276 @@
+use super::*;
+
+use actix::*;
+use actix_system_provider::{ActixSystem, PoisonPill};
+use benchmark_suite_shared::helpers::big::PeerSampler;
+use benchmark_suite_shared::kompics_benchmarks::benchmarks::BigRequest;
+use std::convert::TryInto;
+use std::sync::Arc;
+use synchronoise::CountdownEvent;
+
+#[derive(Default)]
+pub struct Big;
+
+impl Benchmark for Big {
+    type Conf = BigRequest;
+    type Instance = BigI;
+
+    fn msg_to_conf(msg: Box<dyn (::protobuf::Message)>) -> Result<Self::Conf, BenchmarkError> {
+        downcast_msg!(msg; BigRequest)
+    }
+
+    fn validate(c: &Self::Conf) -> Result<(), BenchmarkError> {
+        validation::big(c)
+    }
+
+    fn new_instance() -> Self::Instance {
+        BigI::new()
+    }
+
+    const LABEL: &'static str = "Big";
+}
+
+pub struct BigI {
+    num_actors: Option<usize>,
+    num_pings: Option<u64>,
+    seed: Option<u64>,
+    system: Option<ActixSystem>,
+    sink: Option<Addr<Sink>>,
+    actors: Vec<Addr<BigActor>>,
+    latch: Option<Arc<CountdownEvent>>,
+}
+
+impl BigI {
+    fn new() -> BigI {
+        BigI {
+            num_actors: None,
+            num_pings: None,
+            seed: None,
+            system: None,
+            sink: None,
+            actors: Vec::new(),
+            latch: None,
+        }
+    }
+}
+
+impl BenchmarkInstance for BigI {
+    type Conf = BigRequest;
+
+    fn setup(&mut self, c: &Self::Conf) -> () {
+        self.num_actors = Some(c.number_of_actors.try_into().unwrap());
+        self.num_pings = Some(c.number_of_pings);
+        self.seed = Some(c.seed);
+        let system = crate::actix_system_provider::new_system("big");
+        self.system = Some(system);
+    }
+
+    fn prepare_iteration(&mut self) -> () {
+        match (
+            self.system.as_mut(),
+            self.num_actors,
+            self.num_pings,
+            self.seed,
+        ) {
+            (Some(system), Some(num_actors), Some(num_pings), Some(seed)) => {
+                let latch = Arc::new(CountdownEvent::new(1));
+                let sink_latch = latch.clone();
+                let sink = system
+                    .start(move || Sink::with(num_actors, sink_latch))
+                    .expect("Should start Sink!");
+                for id in 0..num_actors {
+                    let sampler = PeerSampler::new(seed, id, num_actors);
+                    let sink = sink.clone();
+                    let actor = system
+                        .start(move || BigActor::with(id, num_pings, sampler, sink))
+                        .expect("Should start BigActor!");
+                    self.actors.push(actor);
+                }
+                for actor in self.actors.iter() {
+                    futures::executor::block_on(actor.send(Peers(self.actors.clone())))
+                        .expect("BigActor should have peers!");
+                }
+                self.sink = Some(sink);
+                self.latch = Some(latch);
+            }
+            _ => unimplemented!(),
+        }
+    }
+
+    fn run_iteration(&mut self) -> () {
+        let latch = self.latch.take().unwrap();
+        for actor in self.actors.iter() {
+            actor.do_send(Start);
+        }
+        latch.wait();
+    }
+
+    fn cleanup_iteration(&mut self, last_iteration: bool, _exec_time_millis: f64) -> () {
+        let mut system = self.system.take().unwrap();
+        for actor in self.actors.drain(..) {
+            system.stop(actor).expect("BigActor should have stopped!");
+        }
+        system
+            .stop(self.sink.take().unwrap())
+            .expect("Sink should have stopped!");
+
+        if last_iteration {
+            system.shutdown().expect("Actix didn't shut down properly");
+            self.num_actors = None;
+            self.num_pings = None;
+            self.seed = None;
+        } else {
+            self.system = Some(system);
+        }
+    }
+}
+
+#[derive(Message)]
+#[rtype(result = "()")]
+struct Peers(Vec<Addr<BigActor>>);
+
+#[derive(Message)]
+#[rtype(result = "()")]
+struct Start;
+
+#[derive(Message)]
+#[rtype(result = "()")]
+struct Ping {
+    sender: usize,
+}
+
+#[derive(Message)]
+#[rtype(result = "()")]
+struct Pong;
+
+/// Sent to the sink by every actor that got all its pongs.
+#[derive(Message)]
+#[rtype(result = "()")]
+struct Done;
+
+struct BigActor {
+    id: usize,
+    pings_left: u64,
+    sampler: PeerSampler,
+    peers: Vec<Addr<BigActor>>,
+    sink: Addr<Sink>,
+}
+
+impl BigActor {
+    fn with(id: usize, num_pings: u64, sampler: PeerSampler, sink: Addr<Sink>) -> BigActor {
+        BigActor {
+            id,
+            pings_left: num_pings,
+            sampler,
+            peers: Vec::new(),
+            sink,
+        }
+    }
+
+    fn send_ping(&mut self) -> () {
+        self.pings_left -= 1u64;
+        let peer = self.sampler.next_peer();
+        self.peers[peer].do_send(Ping { sender: self.id });
+    }
+}
+
+impl Actor for BigActor {
+    type Context = Context<Self>;
+
+    fn started(&mut self, _ctx: &mut Context<Self>) {
+        // nothing
+    }
+
+    fn stopped(&mut self, _ctx: &mut Context<Self>) {
+        self.peers.clear(); // the actors all hold references to each other
+    }
+}
+
+impl Handler<PoisonPill> for BigActor {
+    type Result = ();
+
+    fn handle(&mut self, _msg: PoisonPill, ctx: &mut Context<Self>) -> Self::Result {
+        ctx.stop();
+    }
+}
+
+impl Handler<Peers> for BigActor {
+    type Result = ();
+
+    fn handle(&mut self, msg: Peers, _ctx: &mut Context<Self>) -> Self::Result {
+        self.peers = msg.0;
+    }
+}
+
+impl Handler<Start> for BigActor {
+    type Result = ();
+
+    fn handle(&mut self, _msg: Start, _ctx: &mut Context<Self>) -> Self::Result {
+        self.send_ping();
+    }
+}
+
+impl Handler<Ping> for BigActor {
+    type Result = ();
+
+    fn handle(&mut self, msg: Ping, _ctx: &mut Context<Self>) -> Self::Result {
+        self.peers[msg.sender].do_send(Pong);
+    }
+}
+
+impl Handler<Pong> for BigActor {
+    type Result = ();
+
+    fn handle(&mut self, _msg: Pong, _ctx: &mut Context<Self>) -> Self::Result {
+        if self.pings_left > 0 {
+            self.send_ping();
+        } else {
+            self.sink.do_send(Done);
+        }
+    }
+}
+
+struct Sink {
+    remaining: usize,
+    latch: Arc<CountdownEvent>,
+}
+
+impl Sink {
+    fn with(num_actors: usize, latch: Arc<CountdownEvent>) -> Sink {
+        Sink {
+            remaining: num_actors,
+            latch,
+        }
+    }
+}
+
+impl Actor for Sink {
+    type Context = Context<Self>;
+
+    fn started(&mut self, _ctx: &mut Context<Self>) {
+        // nothing
+    }
+
+    fn stopped(&mut self, _ctx: &mut Context<Self>) {
+        // nothing
+    }
+}
+
+impl Handler<PoisonPill> for Sink {
+    type Result = ();
+
+    fn handle(&mut self, _msg: PoisonPill, ctx: &mut Context<Self>) -> Self::Result {
+        ctx.stop();
+    }
+}
+
+impl Handler<Done> for Sink {
+    type Result = ();
+
+    fn handle(&mut self, _msg: Done, _ctx: &mut Context<Self>) -> Self::Result {
+        self.remaining -= 1;
+        if self.remaining == 0 {
+            self.latch.decrement().expect("Should decrement!");
+        }
+    }
+}
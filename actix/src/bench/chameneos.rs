@@ -19,6 +19,10 @@ impl Benchmark for Chameneos {
         downcast_msg!(msg; ChameneosRequest)
     }
 
+    fn validate(c: &Self::Conf) -> Result<(), BenchmarkError> {
+        validation::chameneos(c)
+    }
+
     fn new_instance() -> Self::Instance {
         ChameneosI::new()
     }
@@ -0,0 +1,341 @@
+use super::*;
+
+use actix::*;
+use actix_system_provider::{ActixSystem, PoisonPill};
+use benchmark_suite_shared::kompics_benchmarks::benchmarks::DiningPhilosophersRequest;
+use benchmark_suite_shared::result_writer;
+use std::convert::TryInto;
+use std::sync::Arc;
+use synchronoise::CountdownEvent;
+
+#[derive(Default)]
+pub struct DiningPhilosophers;
+
+impl Benchmark for DiningPhilosophers {
+    type Conf = DiningPhilosophersRequest;
+    type Instance = DiningPhilosophersI;
+
+    fn msg_to_conf(msg: Box<dyn (::protobuf::Message)>) -> Result<Self::Conf, BenchmarkError> {
+        downcast_msg!(msg; DiningPhilosophersRequest)
+    }
+
+    fn validate(c: &Self::Conf) -> Result<(), BenchmarkError> {
+        validation::dining_philosophers(c)
+    }
+
+    fn new_instance() -> Self::Instance {
+        DiningPhilosophersI::new()
+    }
+
+    const LABEL: &'static str = "DiningPhilosophers";
+}
+
+pub struct DiningPhilosophersI {
+    num_philosophers: Option<usize>,
+    num_rounds: Option<u64>,
+    system: Option<ActixSystem>,
+    arbitrator: Option<Addr<Arbitrator>>,
+    philosophers: Vec<Addr<Philosopher>>,
+    latch: Option<Arc<CountdownEvent>>,
+    total_denied: u64,
+    iterations: u32,
+}
+
+impl DiningPhilosophersI {
+    fn new() -> DiningPhilosophersI {
+        DiningPhilosophersI {
+            num_philosophers: None,
+            num_rounds: None,
+            system: None,
+            arbitrator: None,
+            philosophers: Vec::new(),
+            latch: None,
+            total_denied: 0,
+            iterations: 0,
+        }
+    }
+}
+
+impl BenchmarkInstance for DiningPhilosophersI {
+    type Conf = DiningPhilosophersRequest;
+
+    fn setup(&mut self, c: &Self::Conf) -> () {
+        self.num_philosophers = Some(c.number_of_philosophers.try_into().unwrap());
+        self.num_rounds = Some(c.number_of_rounds);
+        self.total_denied = 0;
+        self.iterations = 0;
+        let system = crate::actix_system_provider::new_system("dining_philosophers");
+        self.system = Some(system);
+    }
+
+    fn prepare_iteration(&mut self) -> () {
+        match (self.system.as_mut(), self.num_philosophers, self.num_rounds) {
+            (Some(system), Some(num_philosophers), Some(num_rounds)) => {
+                let latch = Arc::new(CountdownEvent::new(1));
+                let arbitrator_latch = latch.clone();
+                let arbitrator = system
+                    .start(move || Arbitrator::with(num_philosophers, arbitrator_latch))
+                    .expect("Should start Arbitrator!");
+                for id in 0..num_philosophers {
+                    let arbitrator = arbitrator.clone();
+                    let philosopher = system
+                        .start(move || Philosopher::with(id, num_rounds, arbitrator))
+                        .expect("Should start Philosopher!");
+                    self.philosophers.push(philosopher);
+                }
+                futures::executor::block_on(
+                    arbitrator.send(Philosophers(self.philosophers.clone())),
+                )
+                .expect("Arbitrator should have philosophers!");
+                self.arbitrator = Some(arbitrator);
+                self.latch = Some(latch);
+            }
+            _ => unimplemented!(),
+        }
+    }
+
+    fn run_iteration(&mut self) -> () {
+        let latch = self.latch.take().unwrap();
+        for philosopher in self.philosophers.iter() {
+            philosopher.do_send(Start);
+        }
+        latch.wait();
+    }
+
+    fn cleanup_iteration(&mut self, last_iteration: bool, _exec_time_millis: f64) -> () {
+        let mut system = self.system.take().unwrap();
+        let arbitrator = self.arbitrator.take().unwrap();
+        self.total_denied += futures::executor::block_on(arbitrator.send(GetDenied))
+            .expect("Should get denied attempts!");
+        self.iterations += 1;
+        system
+            .stop(arbitrator)
+            .expect("Arbitrator should have stopped!");
+        for philosopher in self.philosophers.drain(..) {
+            system
+                .stop(philosopher)
+                .expect("Philosopher should have stopped!");
+        }
+
+        if last_iteration {
+            result_writer::record_metric(
+                "denied_attempts",
+                (self.total_denied as f64) / (self.iterations as f64),
+            );
+            system.shutdown().expect("Actix didn't shut down properly");
+            self.num_philosophers = None;
+            self.num_rounds = None;
+        } else {
+            self.system = Some(system);
+        }
+    }
+}
+
+#[derive(Message)]
+#[rtype(result = "()")]
+struct Philosophers(Vec<Addr<Philosopher>>);
+
+#[derive(Message)]
+#[rtype(result = "()")]
+struct Hungry {
+    philosopher: usize,
+}
+
+#[derive(Message)]
+#[rtype(result = "()")]
+struct Done {
+    philosopher: usize,
+}
+
+#[derive(Message)]
+#[rtype(result = "()")]
+struct Exit;
+
+#[derive(Message)]
+#[rtype(result = "u64")]
+struct GetDenied;
+
+#[derive(Message)]
+#[rtype(result = "()")]
+struct Start;
+
+#[derive(Message)]
+#[rtype(result = "()")]
+struct Denied;
+
+#[derive(Message)]
+#[rtype(result = "()")]
+struct Eat;
+
+/// Owns all the forks, and hands out both of a philosopher's forks at once, or none at all.
+struct Arbitrator {
+    forks: Vec<bool>,
+    denied: u64,
+    remaining: usize,
+    philosophers: Vec<Addr<Philosopher>>,
+    latch: Arc<CountdownEvent>,
+}
+
+impl Arbitrator {
+    fn with(num_philosophers: usize, latch: Arc<CountdownEvent>) -> Arbitrator {
+        Arbitrator {
+            forks: vec![false; num_philosophers],
+            denied: 0u64,
+            remaining: num_philosophers,
+            philosophers: Vec::new(),
+            latch,
+        }
+    }
+
+    /// Philosopher `i` eats with forks `i` and `i + 1`.
+    fn forks_of(&self, philosopher: usize) -> (usize, usize) {
+        (philosopher, (philosopher + 1) % self.forks.len())
+    }
+}
+
+impl Actor for Arbitrator {
+    type Context = Context<Self>;
+
+    fn started(&mut self, _ctx: &mut Context<Self>) {
+        // nothing
+    }
+
+    fn stopped(&mut self, _ctx: &mut Context<Self>) {
+        self.philosophers.clear(); // the philosophers hold a reference to the arbitrator
+    }
+}
+
+impl Handler<PoisonPill> for Arbitrator {
+    type Result = ();
+
+    fn handle(&mut self, _msg: PoisonPill, ctx: &mut Context<Self>) -> Self::Result {
+        ctx.stop();
+    }
+}
+
+impl Handler<Philosophers> for Arbitrator {
+    type Result = ();
+
+    fn handle(&mut self, msg: Philosophers, _ctx: &mut Context<Self>) -> Self::Result {
+        self.philosophers = msg.0;
+    }
+}
+
+impl Handler<Hungry> for Arbitrator {
+    type Result = ();
+
+    fn handle(&mut self, msg: Hungry, _ctx: &mut Context<Self>) -> Self::Result {
+        let (left, right) = self.forks_of(msg.philosopher);
+        if self.forks[left] || self.forks[right] {
+            self.denied += 1u64;
+            self.philosophers[msg.philosopher].do_send(Denied);
+        } else {
+            self.forks[left] = true;
+            self.forks[right] = true;
+            self.philosophers[msg.philosopher].do_send(Eat);
+        }
+    }
+}
+
+impl Handler<Done> for Arbitrator {
+    type Result = ();
+
+    fn handle(&mut self, msg: Done, _ctx: &mut Context<Self>) -> Self::Result {
+        let (left, right) = self.forks_of(msg.philosopher);
+        self.forks[left] = false;
+        self.forks[right] = false;
+    }
+}
+
+impl Handler<Exit> for Arbitrator {
+    type Result = ();
+
+    fn handle(&mut self, _msg: Exit, _ctx: &mut Context<Self>) -> Self::Result {
+        self.remaining -= 1;
+        if self.remaining == 0 {
+            self.latch.decrement().expect("Should decrement!");
+        }
+    }
+}
+
+impl Handler<GetDenied> for Arbitrator {
+    type Result = MessageResult<GetDenied>;
+
+    fn handle(&mut self, _msg: GetDenied, _ctx: &mut Context<Self>) -> Self::Result {
+        MessageResult(self.denied)
+    }
+}
+
+struct Philosopher {
+    id: usize,
+    rounds_left: u64,
+    arbitrator: Addr<Arbitrator>,
+}
+
+impl Philosopher {
+    fn with(id: usize, num_rounds: u64, arbitrator: Addr<Arbitrator>) -> Philosopher {
+        Philosopher {
+            id,
+            rounds_left: num_rounds,
+            arbitrator,
+        }
+    }
+
+    fn request_forks(&self) -> () {
+        self.arbitrator.do_send(Hungry {
+            philosopher: self.id,
+        });
+    }
+}
+
+impl Actor for Philosopher {
+    type Context = Context<Self>;
+
+    fn started(&mut self, _ctx: &mut Context<Self>) {
+        // nothing
+    }
+
+    fn stopped(&mut self, _ctx: &mut Context<Self>) {
+        // nothing
+    }
+}
+
+impl Handler<PoisonPill> for Philosopher {
+    type Result = ();
+
+    fn handle(&mut self, _msg: PoisonPill, ctx: &mut Context<Self>) -> Self::Result {
+        ctx.stop();
+    }
+}
+
+impl Handler<Start> for Philosopher {
+    type Result = ();
+
+    fn handle(&mut self, _msg: Start, _ctx: &mut Context<Self>) -> Self::Result {
+        self.request_forks();
+    }
+}
+
+impl Handler<Denied> for Philosopher {
+    type Result = ();
+
+    fn handle(&mut self, _msg: Denied, _ctx: &mut Context<Self>) -> Self::Result {
+        self.request_forks();
+    }
+}
+
+impl Handler<Eat> for Philosopher {
+    type Result = ();
+
+    fn handle(&mut self, _msg: Eat, _ctx: &mut Context<Self>) -> Self::Result {
+        self.rounds_left -= 1u64;
+        self.arbitrator.do_send(Done {
+            philosopher: self.id,
+        });
+        if self.rounds_left > 0 {
+            self.request_forks();
+        } else {
+            self.arbitrator.do_send(Exit);
+        }
+    }
+}
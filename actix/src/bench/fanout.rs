@@ -0,0 +1,258 @@
+use super::*;
+
+use actix::*;
+use actix_system_provider::{ActixSystem, PoisonPill};
+use benchmark_suite_shared::kompics_benchmarks::benchmarks::FanoutRequest;
+use std::convert::TryInto;
+use std::sync::Arc;
+use synchronoise::CountdownEvent;
+
+#[derive(Default)]
+pub struct Fanout;
+
+impl Benchmark for Fanout {
+    type Conf = FanoutRequest;
+    type Instance = FanoutI;
+
+    fn msg_to_conf(msg: Box<dyn (::protobuf::Message)>) -> Result<Self::Conf, BenchmarkError> {
+        downcast_msg!(msg; FanoutRequest)
+    }
+
+    fn validate(c: &Self::Conf) -> Result<(), BenchmarkError> {
+        validation::fanout(c)
+    }
+
+    fn new_instance() -> Self::Instance {
+        FanoutI::new()
+    }
+
+    const LABEL: &'static str = "Fanout";
+}
+
+pub struct FanoutI {
+    num_subscribers: Option<usize>,
+    num_rounds: Option<u64>,
+    system: Option<ActixSystem>,
+    publisher: Option<Addr<Publisher>>,
+    subscribers: Vec<Addr<Subscriber>>,
+    latch: Option<Arc<CountdownEvent>>,
+}
+
+impl FanoutI {
+    fn new() -> FanoutI {
+        FanoutI {
+            num_subscribers: None,
+            num_rounds: None,
+            system: None,
+            publisher: None,
+            subscribers: Vec::new(),
+            latch: None,
+        }
+    }
+}
+
+impl BenchmarkInstance for FanoutI {
+    type Conf = FanoutRequest;
+
+    fn setup(&mut self, c: &Self::Conf) -> () {
+        self.num_subscribers = Some(c.number_of_subscribers.try_into().unwrap());
+        self.num_rounds = Some(c.number_of_rounds);
+        let system = crate::actix_system_provider::new_system("fanout");
+        self.system = Some(system);
+    }
+
+    fn prepare_iteration(&mut self) -> () {
+        match (self.system.as_mut(), self.num_subscribers, self.num_rounds) {
+            (Some(system), Some(num_subscribers), Some(num_rounds)) => {
+                let latch = Arc::new(CountdownEvent::new(1));
+                let publisher_latch = latch.clone();
+                let publisher = system
+                    .start(move || Publisher::with(num_rounds, publisher_latch))
+                    .expect("Should start Publisher!");
+                for _ in 0..num_subscribers {
+                    let publisher = publisher.clone();
+                    let subscriber = system
+                        .start(move || Subscriber::with(publisher))
+                        .expect("Should start Subscriber!");
+                    self.subscribers.push(subscriber);
+                }
+                futures::executor::block_on(publisher.send(Subscribe(self.subscribers.clone())))
+                    .expect("Publisher should have subscribers!");
+                self.publisher = Some(publisher);
+                self.latch = Some(latch);
+            }
+            _ => unimplemented!(),
+        }
+    }
+
+    fn run_iteration(&mut self) -> () {
+        match self.publisher {
+            Some(ref publisher) => {
+                let latch = self.latch.take().unwrap();
+                publisher.do_send(Start);
+                latch.wait();
+            }
+            None => unimplemented!(),
+        }
+    }
+
+    fn cleanup_iteration(&mut self, last_iteration: bool, _exec_time_millis: f64) -> () {
+        let mut system = self.system.take().unwrap();
+        let publisher = self.publisher.take().unwrap();
+        system
+            .stop(publisher)
+            .expect("Publisher should have stopped!");
+        for subscriber in self.subscribers.drain(..) {
+            system
+                .stop(subscriber)
+                .expect("Subscriber should have stopped!");
+        }
+
+        if last_iteration {
+            system.shutdown().expect("Actix didn't shut down properly");
+            self.num_subscribers = None;
+            self.num_rounds = None;
+        } else {
+            self.system = Some(system);
+        }
+    }
+}
+
+#[derive(Message)]
+#[rtype(result = "()")]
+struct Subscribe(Vec<Addr<Subscriber>>);
+
+#[derive(Message)]
+#[rtype(result = "()")]
+struct Start;
+
+#[derive(Message, Clone, Copy)]
+#[rtype(result = "()")]
+struct Round {
+    index: u64,
+}
+
+#[derive(Message)]
+#[rtype(result = "()")]
+struct Ack {
+    round: u64,
+}
+
+struct Publisher {
+    subscribers: Vec<Addr<Subscriber>>,
+    num_rounds: u64,
+    round: u64,
+    pending_acks: usize,
+    latch: Arc<CountdownEvent>,
+}
+
+impl Publisher {
+    fn with(num_rounds: u64, latch: Arc<CountdownEvent>) -> Publisher {
+        Publisher {
+            subscribers: Vec::new(),
+            num_rounds,
+            round: 0u64,
+            pending_acks: 0usize,
+            latch,
+        }
+    }
+
+    fn publish(&mut self) -> () {
+        let round = Round { index: self.round };
+        for subscriber in self.subscribers.iter() {
+            subscriber.do_send(round);
+        }
+        self.pending_acks = self.subscribers.len();
+    }
+}
+
+impl Actor for Publisher {
+    type Context = Context<Self>;
+
+    fn started(&mut self, _ctx: &mut Context<Self>) {
+        // nothing
+    }
+
+    fn stopped(&mut self, _ctx: &mut Context<Self>) {
+        self.subscribers.clear(); // the subscribers hold a reference to the publisher
+    }
+}
+
+impl Handler<PoisonPill> for Publisher {
+    type Result = ();
+
+    fn handle(&mut self, _msg: PoisonPill, ctx: &mut Context<Self>) -> Self::Result {
+        ctx.stop();
+    }
+}
+
+impl Handler<Subscribe> for Publisher {
+    type Result = ();
+
+    fn handle(&mut self, msg: Subscribe, _ctx: &mut Context<Self>) -> Self::Result {
+        self.subscribers = msg.0;
+    }
+}
+
+impl Handler<Start> for Publisher {
+    type Result = ();
+
+    fn handle(&mut self, _msg: Start, _ctx: &mut Context<Self>) -> Self::Result {
+        self.publish();
+    }
+}
+
+impl Handler<Ack> for Publisher {
+    type Result = ();
+
+    fn handle(&mut self, msg: Ack, _ctx: &mut Context<Self>) -> Self::Result {
+        debug_assert_eq!(msg.round, self.round);
+        self.pending_acks -= 1;
+        if self.pending_acks == 0 {
+            self.round += 1u64;
+            if self.round < self.num_rounds {
+                self.publish();
+            } else {
+                self.latch.decrement().expect("Should decrement!");
+            }
+        }
+    }
+}
+
+struct Subscriber {
+    publisher: Addr<Publisher>,
+}
+
+impl Subscriber {
+    fn with(publisher: Addr<Publisher>) -> Subscriber {
+        Subscriber { publisher }
+    }
+}
+
+impl Actor for Subscriber {
+    type Context = Context<Self>;
+
+    fn started(&mut self, _ctx: &mut Context<Self>) {
+        // nothing
+    }
+
+    fn stopped(&mut self, _ctx: &mut Context<Self>) {
+        // nothing
+    }
+}
+
+impl Handler<PoisonPill> for Subscriber {
+    type Result = ();
+
+    fn handle(&mut self, _msg: PoisonPill, ctx: &mut Context<Self>) -> Self::Result {
+        ctx.stop();
+    }
+}
+
+impl Handler<Round> for Subscriber {
+    type Result = ();
+
+    fn handle(&mut self, msg: Round, _ctx: &mut Context<Self>) -> Self::Result {
+        self.publisher.do_send(Ack { round: msg.index });
+    }
+}
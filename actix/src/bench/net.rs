@@ -0,0 +1,291 @@
+//! TCP transport for the networked benchmarks.
+//!
+//! Actix has no remoting of its own, so remote actors talk over plain tokio TCP streams,
+//! framed with a length-prefixed codec.
+//! The ponger side runs a [PongerServer](PongerServer), which accepts connections
+//! and answers every ping on a connection with the matching pong.
+
+use super::*;
+
+use actix::{
+    io::{FramedWrite, WriteHandler},
+    *,
+};
+use actix_system_provider::PoisonPill;
+use benchmark_suite_shared::kompics_benchmarks::benchmarks::{NetworkTuning, TcpNoDelay};
+use bytes::{Buf, BufMut, Bytes, BytesMut};
+use std::{io, net::SocketAddr};
+use tokio::net::{tcp::OwnedWriteHalf, TcpListener, TcpStream};
+use tokio_util::codec::{Decoder, Encoder, FramedRead, LengthDelimitedCodec};
+
+/// The messages exchanged between pingers and pongers.
+///
+/// Static messages are encoded once and shared,
+/// like Kompact's `StaticPing`/`StaticPong`,
+/// while `Ping`/`Pong` are serialised for every send.
+#[derive(Debug, Clone, PartialEq)]
+pub enum NetMessage {
+    StaticPing,
+    StaticPong,
+    Ping(u64),
+    Pong(u64),
+}
+
+impl NetMessage {
+    const STATIC_PING_ID: u8 = 1;
+    const STATIC_PONG_ID: u8 = 2;
+    const PING_ID: u8 = 3;
+    const PONG_ID: u8 = 4;
+
+    const STATIC_PING_FRAME: &'static [u8] = &[NetMessage::STATIC_PING_ID];
+    const STATIC_PONG_FRAME: &'static [u8] = &[NetMessage::STATIC_PONG_ID];
+
+    pub fn into_bytes(self) -> Bytes {
+        match self {
+            NetMessage::StaticPing => Bytes::from_static(NetMessage::STATIC_PING_FRAME),
+            NetMessage::StaticPong => Bytes::from_static(NetMessage::STATIC_PONG_FRAME),
+            NetMessage::Ping(index) => NetMessage::indexed(NetMessage::PING_ID, index),
+            NetMessage::Pong(index) => NetMessage::indexed(NetMessage::PONG_ID, index),
+        }
+    }
+
+    pub fn from_bytes(mut frame: Bytes) -> io::Result<NetMessage> {
+        if !frame.has_remaining() {
+            return Err(invalid_data("Empty frame".to_string()));
+        }
+        let id = frame.get_u8();
+        let msg = match id {
+            NetMessage::STATIC_PING_ID => NetMessage::StaticPing,
+            NetMessage::STATIC_PONG_ID => NetMessage::StaticPong,
+            NetMessage::PING_ID | NetMessage::PONG_ID => {
+                if frame.remaining() < 8 {
+                    return Err(invalid_data(format!(
+                        "Frame for message {} is too short ({} bytes)",
+                        id,
+                        frame.remaining()
+                    )));
+                }
+                let index = frame.get_u64();
+                if id == NetMessage::PING_ID {
+                    NetMessage::Ping(index)
+                } else {
+                    NetMessage::Pong(index)
+                }
+            }
+            _ => return Err(invalid_data(format!("Unknown message id {}", id))),
+        };
+        if frame.has_remaining() {
+            Err(invalid_data(format!(
+                "{} trailing bytes after {:?}",
+                frame.remaining(),
+                msg
+            )))
+        } else {
+            Ok(msg)
+        }
+    }
+
+    fn indexed(id: u8, index: u64) -> Bytes {
+        let mut buf = BytesMut::with_capacity(9);
+        buf.put_u8(id);
+        buf.put_u64(index);
+        buf.freeze()
+    }
+}
+
+fn invalid_data(msg: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, msg)
+}
+
+/// Length-prefixed framing of [NetMessage](NetMessage)s.
+pub struct NetCodec {
+    frames: LengthDelimitedCodec,
+}
+
+impl NetCodec {
+    pub fn new() -> NetCodec {
+        NetCodec {
+            frames: LengthDelimitedCodec::new(),
+        }
+    }
+}
+
+impl Decoder for NetCodec {
+    type Item = NetMessage;
+    type Error = io::Error;
+
+    fn decode(&mut self, src: &mut BytesMut) -> Result<Option<NetMessage>, io::Error> {
+        match self.frames.decode(src)? {
+            Some(frame) => NetMessage::from_bytes(frame.freeze()).map(Some),
+            None => Ok(None),
+        }
+    }
+}
+
+impl Encoder<NetMessage> for NetCodec {
+    type Error = io::Error;
+
+    fn encode(&mut self, msg: NetMessage, dst: &mut BytesMut) -> Result<(), io::Error> {
+        self.frames.encode(msg.into_bytes(), dst)
+    }
+}
+
+pub type NetWriter = FramedWrite<NetMessage, OwnedWriteHalf, NetCodec>;
+
+/// Hooks `stream` up to the actor owning `ctx`.
+///
+/// Incoming messages are delivered to the actor's `StreamHandler`,
+/// outgoing messages go through the returned writer.
+/// Must be called on the actor's arbiter.
+pub fn connect<A>(stream: TcpStream, ctx: &mut Context<A>) -> NetWriter
+where
+    A: Actor<Context = Context<A>>
+        + StreamHandler<io::Result<NetMessage>>
+        + WriteHandler<io::Error>,
+{
+    let (read, write) = stream.into_split();
+    ctx.add_stream(FramedRead::new(read, NetCodec::new()));
+    FramedWrite::new(write, NetCodec::new(), ctx)
+}
+
+/// Converts a connected (blocking) std stream for use with [connect](connect).
+///
+/// Must be called from within the Actix system.
+pub fn from_std(stream: std::net::TcpStream, tcp_no_delay: bool) -> TcpStream {
+    stream
+        .set_nodelay(tcp_no_delay)
+        .expect("Could not set TCP_NODELAY");
+    stream
+        .set_nonblocking(true)
+        .expect("Could not make stream non-blocking");
+    TcpStream::from_std(stream).expect("Could not register stream with tokio")
+}
+
+/// Whether to set `TCP_NODELAY`, which is on unless the request turns it off.
+///
+/// The buffer settings in `NetworkTuning` are specific to Kompact and ignored here.
+pub fn tcp_no_delay(tuning: &NetworkTuning) -> bool {
+    tuning.tcp_no_delay != TcpNoDelay::TCP_NO_DELAY_OFF
+}
+
+/// Binds a listener on the public interface of this process.
+pub fn bind() -> (std::net::TcpListener, SocketAddr) {
+    let addr = SocketAddr::new(crate::actix_system_provider::public_if(), 0);
+    let listener = std::net::TcpListener::bind(addr).expect("Could not bind listener");
+    listener
+        .set_nonblocking(true)
+        .expect("Could not make listener non-blocking");
+    let local_addr = listener.local_addr().expect("Listener address");
+    (listener, local_addr)
+}
+
+/// Accepts pinger connections and starts a [PongerConnection](PongerConnection) for each.
+pub struct PongerServer {
+    listener: Option<std::net::TcpListener>,
+    tcp_no_delay: bool,
+}
+
+impl PongerServer {
+    pub fn with(listener: std::net::TcpListener, tcp_no_delay: bool) -> PongerServer {
+        PongerServer {
+            listener: Some(listener),
+            tcp_no_delay,
+        }
+    }
+}
+
+impl Actor for PongerServer {
+    type Context = Context<Self>;
+
+    fn started(&mut self, ctx: &mut Context<Self>) {
+        println!("Ponger server is alive");
+        let listener = self.listener.take().expect("PongerServer started twice");
+        let listener = TcpListener::from_std(listener).expect("Could not register listener");
+        let connections = futures::stream::unfold(listener, |listener| async move {
+            let conn = listener.accept().await.map(|(stream, _peer)| stream);
+            Some((conn, listener))
+        });
+        ctx.add_stream(connections);
+    }
+
+    fn stopped(&mut self, _ctx: &mut Context<Self>) {
+        println!("Ponger server is stopped");
+    }
+}
+
+impl StreamHandler<io::Result<TcpStream>> for PongerServer {
+    fn handle(&mut self, conn: io::Result<TcpStream>, _ctx: &mut Context<Self>) {
+        match conn {
+            Ok(stream) => {
+                stream
+                    .set_nodelay(self.tcp_no_delay)
+                    .expect("Could not set TCP_NODELAY");
+                PongerConnection::create(|ctx| PongerConnection {
+                    writer: connect(stream, ctx),
+                });
+            }
+            Err(e) => eprintln!("Could not accept connection: {}", e),
+        }
+    }
+}
+
+impl Handler<PoisonPill> for PongerServer {
+    type Result = ();
+
+    fn handle(&mut self, _msg: PoisonPill, ctx: &mut Context<Self>) -> Self::Result {
+        println!("PoisonPill received, shutting down.");
+        ctx.stop();
+    }
+}
+
+/// Answers the pings from a single pinger, until it disconnects.
+pub struct PongerConnection {
+    writer: NetWriter,
+}
+
+impl Actor for PongerConnection {
+    type Context = Context<Self>;
+}
+
+impl WriteHandler<io::Error> for PongerConnection {}
+
+impl StreamHandler<io::Result<NetMessage>> for PongerConnection {
+    fn handle(&mut self, msg: io::Result<NetMessage>, ctx: &mut Context<Self>) {
+        match msg {
+            Ok(NetMessage::StaticPing) => self.writer.write(NetMessage::StaticPong),
+            Ok(NetMessage::Ping(index)) => self.writer.write(NetMessage::Pong(index)),
+            Ok(msg) => eprintln!("Ponger got unexpected message {:?}", msg),
+            Err(e) => {
+                eprintln!("Ponger connection failed: {}", e);
+                ctx.stop();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_net_message_roundtrip() {
+        let msgs = vec![
+            NetMessage::StaticPing,
+            NetMessage::StaticPong,
+            NetMessage::Ping(42),
+            NetMessage::Pong(u64::max_value()),
+        ];
+        let mut codec = NetCodec::new();
+        let mut buf = BytesMut::new();
+        for msg in msgs.iter() {
+            codec.encode(msg.clone(), &mut buf).expect("encode");
+        }
+        for msg in msgs.iter() {
+            let decoded = codec.decode(&mut buf).expect("decode");
+            assert_eq!(decoded.as_ref(), Some(msg));
+        }
+        assert_eq!(codec.decode(&mut buf).expect("decode"), None);
+        assert!(NetMessage::from_bytes(Bytes::from_static(&[3, 0, 1])).is_err());
+        assert!(NetMessage::from_bytes(Bytes::from_static(&[9])).is_err());
+    }
+}
@@ -0,0 +1,316 @@
+use super::*;
+
+use actix::{io::WriteHandler, *};
+use actix_system_provider::{ActixSystem, PoisonPill};
+use benchmark_suite_shared::kompics_benchmarks::benchmarks::ThroughputPingPongRequest;
+use net::{NetMessage, NetWriter, PongerServer};
+use std::{io, net::SocketAddr, sync::Arc};
+use synchronoise::CountdownEvent;
+use throughput_pingpong::{Params, Start};
+
+#[derive(Default)]
+pub struct PingPong;
+
+impl DistributedBenchmark for PingPong {
+    type MasterConf = ThroughputPingPongRequest;
+    type ClientConf = bool;
+    type ClientData = SocketAddr;
+    type Master = PingPongMaster;
+    type Client = PingPongClient;
+
+    const LABEL: &'static str = "NetThroughputPingPong";
+
+    fn new_master() -> Self::Master {
+        PingPongMaster::new()
+    }
+    fn msg_to_master_conf(
+        msg: Box<dyn (::protobuf::Message)>,
+    ) -> Result<Self::MasterConf, BenchmarkError> {
+        downcast_msg!(msg; ThroughputPingPongRequest)
+    }
+
+    fn validate(c: &Self::MasterConf, _meta: &DeploymentMetaData) -> Result<(), BenchmarkError> {
+        validation::throughput_ping_pong(c)
+    }
+
+    fn new_client() -> Self::Client {
+        PingPongClient::new()
+    }
+    fn str_to_client_conf(str: String) -> Result<Self::ClientConf, BenchmarkError> {
+        str.parse::<bool>().map_err(|e| {
+            BenchmarkError::InvalidMessage(format!(
+                "String '{}' does not represent a client conf: {:?}",
+                str, e
+            ))
+        })
+    }
+    fn str_to_client_data(str: String) -> Result<Self::ClientData, BenchmarkError> {
+        str.parse::<SocketAddr>().map_err(|e| {
+            BenchmarkError::InvalidMessage(format!("Could not read client data: {}", e))
+        })
+    }
+
+    fn client_conf_to_str(c: Self::ClientConf) -> String {
+        c.to_string()
+    }
+    fn client_data_to_str(d: Self::ClientData) -> String {
+        d.to_string()
+    }
+}
+
+pub struct PingPongMaster {
+    params: Option<Params>,
+    tcp_no_delay: bool,
+    system: Option<ActixSystem>,
+    pingers: Vec<Addr<Pinger>>,
+    latch: Option<Arc<CountdownEvent>>,
+}
+
+impl PingPongMaster {
+    fn new() -> PingPongMaster {
+        PingPongMaster {
+            params: None,
+            tcp_no_delay: true,
+            system: None,
+            pingers: Vec::new(),
+            latch: None,
+        }
+    }
+}
+
+impl DistributedBenchmarkMaster for PingPongMaster {
+    type MasterConf = ThroughputPingPongRequest;
+    type ClientConf = bool;
+    type ClientData = SocketAddr;
+
+    fn setup(
+        &mut self,
+        c: Self::MasterConf,
+        _m: &DeploymentMetaData,
+    ) -> Result<Self::ClientConf, BenchmarkError> {
+        self.params = Some(Params::from_req(&c));
+        self.tcp_no_delay = net::tcp_no_delay(c.get_network());
+        let system = crate::actix_system_provider::new_system("throughputpingpong");
+        self.system = Some(system);
+        Ok(self.tcp_no_delay)
+    }
+    fn prepare_iteration(&mut self, d: Vec<Self::ClientData>) -> () {
+        let ponger_addr = d[0];
+        match self.params {
+            Some(ref params) => match self.system {
+                Some(ref mut system) => {
+                    let latch = Arc::new(CountdownEvent::new(params.num_pairs as usize));
+                    let num_msgs = params.num_msgs;
+                    let pipeline = params.pipeline;
+                    let static_only = params.static_only;
+                    let tcp_no_delay = self.tcp_no_delay;
+                    for _ in 1..=params.num_pairs {
+                        // every pinger gets its own connection, and thus its own ponger
+                        let stream = std::net::TcpStream::connect(ponger_addr)
+                            .expect("Could not connect to pongers");
+                        let platch = latch.clone();
+                        let pinger = system
+                            .start(move || {
+                                Pinger::with(
+                                    num_msgs,
+                                    pipeline,
+                                    static_only,
+                                    platch,
+                                    stream,
+                                    tcp_no_delay,
+                                )
+                            })
+                            .expect("Couldn't create Pinger");
+                        self.pingers.push(pinger);
+                    }
+                    println!(
+                        "Connected {} pingers to pongers at {}",
+                        params.num_pairs, ponger_addr
+                    );
+                    self.latch = Some(latch);
+                }
+                None => unimplemented!(),
+            },
+            None => unimplemented!(),
+        }
+    }
+    fn run_iteration(&mut self) -> () {
+        let latch = self.latch.take().unwrap();
+        let start_fs: Vec<_> = self.pingers.iter().map(|p| p.send(Start)).collect();
+        for f in start_fs {
+            futures::executor::block_on(f).expect("Couldn't start all pingers");
+        }
+        latch.wait();
+    }
+    fn cleanup_iteration(&mut self, last_iteration: bool, _exec_time_millis: f64) -> () {
+        println!("Cleaning up pinger side");
+        let mut system = self.system.take().unwrap();
+        for pinger in self.pingers.drain(..) {
+            system.stop(pinger).expect("Pinger never died!");
+        }
+
+        if last_iteration {
+            system.shutdown().expect("Actix didn't shut down properly");
+            self.params = None;
+        } else {
+            self.system = Some(system);
+        }
+    }
+}
+
+pub struct PingPongClient {
+    system: Option<ActixSystem>,
+    server: Option<Addr<PongerServer>>,
+}
+
+impl PingPongClient {
+    fn new() -> PingPongClient {
+        PingPongClient {
+            system: None,
+            server: None,
+        }
+    }
+}
+
+impl DistributedBenchmarkClient for PingPongClient {
+    type ClientConf = bool;
+    type ClientData = SocketAddr;
+
+    fn setup(&mut self, c: Self::ClientConf) -> Self::ClientData {
+        println!("Setting up pongers.");
+
+        let mut system = crate::actix_system_provider::new_system("throughputpingpong");
+        let (listener, addr) = net::bind();
+        let server = system
+            .start(move || PongerServer::with(listener, c))
+            .expect("Should start PongerServer");
+
+        println!("Pongers listening on {}", addr);
+
+        self.system = Some(system);
+        self.server = Some(server);
+
+        addr
+    }
+
+    fn prepare_iteration(&mut self) -> () {
+        // nothing to do
+        println!("Preparing ponger iteration");
+    }
+
+    fn cleanup_iteration(&mut self, last_iteration: bool) -> () {
+        println!("Cleaning up ponger side");
+        if last_iteration {
+            let mut system = self.system.take().unwrap();
+            let server = self.server.take().unwrap();
+            system.stop(server).expect("Pongers never died!");
+            system.shutdown().expect("Actix didn't shut down properly");
+        }
+    }
+}
+
+/// Sends `StaticPing`s if `static_only` is set, and indexed `Ping`s otherwise.
+struct Pinger {
+    latch: Arc<CountdownEvent>,
+    count: u64,
+    pipeline: u64,
+    static_only: bool,
+    sent_count: u64,
+    recv_count: u64,
+    stream: Option<std::net::TcpStream>,
+    tcp_no_delay: bool,
+    writer: Option<NetWriter>,
+}
+
+impl Pinger {
+    fn with(
+        count: u64,
+        pipeline: u64,
+        static_only: bool,
+        latch: Arc<CountdownEvent>,
+        stream: std::net::TcpStream,
+        tcp_no_delay: bool,
+    ) -> Pinger {
+        Pinger {
+            latch,
+            count,
+            pipeline,
+            static_only,
+            sent_count: 0,
+            recv_count: 0,
+            stream: Some(stream),
+            tcp_no_delay,
+            writer: None,
+        }
+    }
+
+    fn send_ping(&mut self) -> () {
+        let msg = if self.static_only {
+            NetMessage::StaticPing
+        } else {
+            NetMessage::Ping(self.sent_count)
+        };
+        self.writer
+            .as_mut()
+            .expect("Pinger is not connected")
+            .write(msg);
+        self.sent_count += 1;
+    }
+
+    fn receive_pong(&mut self) -> () {
+        self.recv_count += 1;
+        if self.recv_count < self.count {
+            if self.sent_count < self.count {
+                self.send_ping();
+            }
+        } else {
+            let _ = self.latch.decrement();
+        }
+    }
+}
+
+impl Actor for Pinger {
+    type Context = Context<Self>;
+
+    fn started(&mut self, ctx: &mut Context<Self>) {
+        let stream = self.stream.take().expect("Pinger started twice");
+        let stream = net::from_std(stream, self.tcp_no_delay);
+        self.writer = Some(net::connect(stream, ctx));
+    }
+}
+
+impl WriteHandler<io::Error> for Pinger {}
+
+impl Handler<Start> for Pinger {
+    type Result = ();
+
+    fn handle(&mut self, _msg: Start, _ctx: &mut Context<Self>) -> Self::Result {
+        let mut pipelined: u64 = 0;
+        while (pipelined < self.pipeline) && (self.sent_count < self.count) {
+            self.send_ping();
+            pipelined += 1;
+        }
+    }
+}
+
+impl StreamHandler<io::Result<NetMessage>> for Pinger {
+    fn handle(&mut self, msg: io::Result<NetMessage>, ctx: &mut Context<Self>) {
+        match msg {
+            Ok(NetMessage::StaticPong) if self.static_only => self.receive_pong(),
+            Ok(NetMessage::Pong(_)) if !self.static_only => self.receive_pong(),
+            Ok(msg) => eprintln!("Pinger got unexpected message {:?}", msg),
+            Err(e) => {
+                eprintln!("Pinger connection failed: {}", e);
+                ctx.stop();
+            }
+        }
+    }
+}
+
+impl Handler<PoisonPill> for Pinger {
+    type Result = ();
+
+    fn handle(&mut self, _msg: PoisonPill, ctx: &mut Context<Self>) -> Self::Result {
+        ctx.stop();
+    }
+}
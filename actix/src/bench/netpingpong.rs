@@ -0,0 +1,275 @@
+use super::*;
+
+use actix::{io::WriteHandler, *};
+use actix_system_provider::{ActixSystem, PoisonPill};
+use benchmark_suite_shared::kompics_benchmarks::benchmarks::PingPongRequest;
+use net::{NetMessage, NetWriter, PongerServer};
+use std::{io, net::SocketAddr, sync::Arc};
+use synchronoise::CountdownEvent;
+use throughput_pingpong::Start;
+
+#[derive(Default)]
+pub struct PingPong;
+
+impl DistributedBenchmark for PingPong {
+    type MasterConf = PingPongRequest;
+    type ClientConf = bool;
+    type ClientData = SocketAddr;
+    type Master = PingPongMaster;
+    type Client = PingPongClient;
+
+    const LABEL: &'static str = "NetPingPong";
+
+    fn new_master() -> Self::Master {
+        PingPongMaster::new()
+    }
+    fn msg_to_master_conf(
+        msg: Box<dyn (::protobuf::Message)>,
+    ) -> Result<Self::MasterConf, BenchmarkError> {
+        downcast_msg!(msg; PingPongRequest)
+    }
+
+    fn validate(c: &Self::MasterConf, _meta: &DeploymentMetaData) -> Result<(), BenchmarkError> {
+        validation::ping_pong(c)
+    }
+
+    fn new_client() -> Self::Client {
+        PingPongClient::new()
+    }
+    fn str_to_client_conf(str: String) -> Result<Self::ClientConf, BenchmarkError> {
+        str.parse::<bool>().map_err(|e| {
+            BenchmarkError::InvalidMessage(format!(
+                "String '{}' does not represent a client conf: {:?}",
+                str, e
+            ))
+        })
+    }
+    fn str_to_client_data(str: String) -> Result<Self::ClientData, BenchmarkError> {
+        str.parse::<SocketAddr>().map_err(|e| {
+            BenchmarkError::InvalidMessage(format!("Could not read client data: {}", e))
+        })
+    }
+
+    fn client_conf_to_str(c: Self::ClientConf) -> String {
+        c.to_string()
+    }
+    fn client_data_to_str(d: Self::ClientData) -> String {
+        d.to_string()
+    }
+}
+
+pub struct PingPongMaster {
+    num: Option<u64>,
+    tcp_no_delay: bool,
+    system: Option<ActixSystem>,
+    pinger: Option<Addr<Pinger>>,
+    latch: Option<Arc<CountdownEvent>>,
+}
+
+impl PingPongMaster {
+    fn new() -> PingPongMaster {
+        PingPongMaster {
+            num: None,
+            tcp_no_delay: true,
+            system: None,
+            pinger: None,
+            latch: None,
+        }
+    }
+}
+
+impl DistributedBenchmarkMaster for PingPongMaster {
+    type MasterConf = PingPongRequest;
+    type ClientConf = bool;
+    type ClientData = SocketAddr;
+
+    fn setup(
+        &mut self,
+        c: Self::MasterConf,
+        _m: &DeploymentMetaData,
+    ) -> Result<Self::ClientConf, BenchmarkError> {
+        self.num = Some(c.number_of_messages);
+        self.tcp_no_delay = net::tcp_no_delay(c.get_network());
+        let system = crate::actix_system_provider::new_system("netpingpong");
+        self.system = Some(system);
+        Ok(self.tcp_no_delay)
+    }
+    fn prepare_iteration(&mut self, d: Vec<Self::ClientData>) -> () {
+        let ponger_addr = d[0];
+        match self.num {
+            Some(num) => match self.system {
+                Some(ref mut system) => {
+                    let stream = std::net::TcpStream::connect(ponger_addr)
+                        .expect("Could not connect to ponger");
+                    println!("Connected to ponger at {}", ponger_addr);
+                    let latch = Arc::new(CountdownEvent::new(1));
+                    let platch = latch.clone();
+                    let tcp_no_delay = self.tcp_no_delay;
+                    let pinger = system
+                        .start(move || Pinger::with(num, platch, stream, tcp_no_delay))
+                        .expect("Should start Pinger");
+
+                    self.pinger = Some(pinger);
+                    self.latch = Some(latch);
+                }
+                None => unimplemented!(),
+            },
+            None => unimplemented!(),
+        }
+    }
+    fn run_iteration(&mut self) -> () {
+        match self.pinger {
+            Some(ref pinger) => {
+                let latch = self.latch.take().unwrap();
+                let pinger_start_f = pinger.send(Start);
+                futures::executor::block_on(pinger_start_f).expect("Pinger never started!");
+                latch.wait();
+            }
+            None => unimplemented!(),
+        }
+    }
+    fn cleanup_iteration(&mut self, last_iteration: bool, _exec_time_millis: f64) -> () {
+        println!("Cleaning up pinger side");
+        let mut system = self.system.take().unwrap();
+        let pinger = self.pinger.take().unwrap();
+        system.stop(pinger).expect("Pinger never died!");
+
+        if last_iteration {
+            system.shutdown().expect("Actix didn't shut down properly");
+            self.num = None;
+        } else {
+            self.system = Some(system);
+        }
+    }
+}
+
+pub struct PingPongClient {
+    system: Option<ActixSystem>,
+    server: Option<Addr<PongerServer>>,
+}
+
+impl PingPongClient {
+    fn new() -> PingPongClient {
+        PingPongClient {
+            system: None,
+            server: None,
+        }
+    }
+}
+
+impl DistributedBenchmarkClient for PingPongClient {
+    type ClientConf = bool;
+    type ClientData = SocketAddr;
+
+    fn setup(&mut self, c: Self::ClientConf) -> Self::ClientData {
+        println!("Setting up ponger.");
+
+        let mut system = crate::actix_system_provider::new_system("netpingpong");
+        let (listener, addr) = net::bind();
+        let server = system
+            .start(move || PongerServer::with(listener, c))
+            .expect("Should start PongerServer");
+
+        println!("Ponger listening on {}", addr);
+
+        self.system = Some(system);
+        self.server = Some(server);
+
+        addr
+    }
+
+    fn prepare_iteration(&mut self) -> () {
+        // nothing to do
+        println!("Preparing ponger iteration");
+    }
+
+    fn cleanup_iteration(&mut self, last_iteration: bool) -> () {
+        println!("Cleaning up ponger side");
+        if last_iteration {
+            let mut system = self.system.take().unwrap();
+            let server = self.server.take().unwrap();
+            system.stop(server).expect("Ponger never died!");
+            system.shutdown().expect("Actix didn't shut down properly");
+        }
+    }
+}
+
+struct Pinger {
+    latch: Arc<CountdownEvent>,
+    count_down: u64,
+    stream: Option<std::net::TcpStream>,
+    tcp_no_delay: bool,
+    writer: Option<NetWriter>,
+}
+
+impl Pinger {
+    fn with(
+        count: u64,
+        latch: Arc<CountdownEvent>,
+        stream: std::net::TcpStream,
+        tcp_no_delay: bool,
+    ) -> Pinger {
+        Pinger {
+            latch,
+            count_down: count,
+            stream: Some(stream),
+            tcp_no_delay,
+            writer: None,
+        }
+    }
+
+    fn send_ping(&mut self) -> () {
+        self.writer
+            .as_mut()
+            .expect("Pinger is not connected")
+            .write(NetMessage::StaticPing);
+    }
+}
+
+impl Actor for Pinger {
+    type Context = Context<Self>;
+
+    fn started(&mut self, ctx: &mut Context<Self>) {
+        let stream = self.stream.take().expect("Pinger started twice");
+        let stream = net::from_std(stream, self.tcp_no_delay);
+        self.writer = Some(net::connect(stream, ctx));
+    }
+}
+
+impl WriteHandler<io::Error> for Pinger {}
+
+impl Handler<Start> for Pinger {
+    type Result = ();
+
+    fn handle(&mut self, _msg: Start, _ctx: &mut Context<Self>) -> Self::Result {
+        self.send_ping();
+    }
+}
+
+impl StreamHandler<io::Result<NetMessage>> for Pinger {
+    fn handle(&mut self, msg: io::Result<NetMessage>, ctx: &mut Context<Self>) {
+        match msg {
+            Ok(NetMessage::StaticPong) => {
+                if self.count_down > 0 {
+                    self.count_down -= 1;
+                    self.send_ping();
+                } else {
+                    let _ = self.latch.decrement();
+                }
+            }
+            Ok(msg) => eprintln!("Pinger got unexpected message {:?}", msg),
+            Err(e) => {
+                eprintln!("Pinger connection failed: {}", e);
+                ctx.stop();
+            }
+        }
+    }
+}
+
+impl Handler<PoisonPill> for Pinger {
+    type Result = ();
+
+    fn handle(&mut self, _msg: PoisonPill, ctx: &mut Context<Self>) -> Self::Result {
+        ctx.stop();
+    }
+}
@@ -0,0 +1,210 @@
+use super::*;
+
+use actix::*;
+use actix_system_provider::ActixSystem;
+use benchmark_suite_shared::helpers::parallel_sort::{check_sorted, merge, random_data, Half};
+use benchmark_suite_shared::kompics_benchmarks::benchmarks::ParallelSortRequest;
+use futures::channel::oneshot;
+use std::convert::TryInto;
+
+#[derive(Default)]
+pub struct ParallelSort;
+
+impl Benchmark for ParallelSort {
+    type Conf = ParallelSortRequest;
+    type Instance = ParallelSortI;
+
+    fn msg_to_conf(msg: Box<dyn (::protobuf::Message)>) -> Result<Self::Conf, BenchmarkError> {
+        downcast_msg!(msg; ParallelSortRequest)
+    }
+
+    fn validate(c: &Self::Conf) -> Result<(), BenchmarkError> {
+        validation::parallel_sort(c)
+    }
+
+    fn new_instance() -> Self::Instance {
+        ParallelSortI::new()
+    }
+
+    const LABEL: &'static str = "ParallelSort";
+}
+
+pub struct ParallelSortI {
+    data_size: Option<usize>,
+    threshold: Option<usize>,
+    seed: Option<u64>,
+    system: Option<ActixSystem>,
+    data: Option<Vec<u64>>,
+    sorter: Option<Addr<Sorter>>,
+    result: Option<oneshot::Receiver<Vec<u64>>>,
+    sorted: Option<Vec<u64>>,
+}
+
+impl ParallelSortI {
+    fn new() -> ParallelSortI {
+        ParallelSortI {
+            data_size: None,
+            threshold: None,
+            seed: None,
+            system: None,
+            data: None,
+            sorter: None,
+            result: None,
+            sorted: None,
+        }
+    }
+}
+
+impl BenchmarkInstance for ParallelSortI {
+    type Conf = ParallelSortRequest;
+
+    fn setup(&mut self, c: &Self::Conf) -> () {
+        self.data_size = Some(c.data_size.try_into().unwrap());
+        self.threshold = Some(c.threshold.try_into().unwrap());
+        self.seed = Some(c.seed);
+        let system = crate::actix_system_provider::new_system("parallel_sort");
+        self.system = Some(system);
+    }
+
+    fn prepare_iteration(&mut self) -> () {
+        match (
+            self.system.as_mut(),
+            self.data_size,
+            self.threshold,
+            self.seed,
+        ) {
+            (Some(system), Some(data_size), Some(threshold), Some(seed)) => {
+                self.data = Some(random_data(data_size, seed));
+                let (result_tx, result) = oneshot::channel();
+                let sorter = system
+                    .start(move || Sorter::with(threshold, ResultTarget::Done(result_tx)))
+                    .expect("Create Sorter");
+                self.sorter = Some(sorter);
+                self.result = Some(result);
+            }
+            _ => unimplemented!(),
+        }
+    }
+
+    fn run_iteration(&mut self) -> () {
+        match (self.data.take(), self.sorter.as_ref()) {
+            (Some(data), Some(sorter)) => {
+                let result = self.result.take().unwrap();
+                sorter.do_send(Sort(data));
+                let sorted =
+                    futures::executor::block_on(result).expect("Sorter dropped the result");
+                self.sorted = Some(sorted);
+            }
+            _ => unimplemented!(),
+        }
+    }
+
+    fn cleanup_iteration(&mut self, last_iteration: bool, _exec_time_millis: f64) -> () {
+        let _ = self.sorter.take(); // kills itself, so just drop
+        let sorted = self.sorted.take().expect("No sorted data was returned");
+        check_sorted(&sorted, self.data_size.unwrap());
+
+        if last_iteration {
+            let system = self.system.take().unwrap();
+            system.shutdown().expect("Actix didn't shut down properly");
+            self.data_size = None;
+            self.threshold = None;
+            self.seed = None;
+        }
+    }
+}
+
+enum ResultTarget {
+    Parent(Addr<Sorter>, Half),
+    Done(oneshot::Sender<Vec<u64>>),
+}
+
+#[derive(Message)]
+#[rtype(result = "()")]
+struct Sort(Vec<u64>);
+
+#[derive(Message)]
+#[rtype(result = "()")]
+struct Sorted {
+    half: Half,
+    data: Vec<u64>,
+}
+
+/// Sorts its part directly if it is small enough, and otherwise has two children sort
+/// the halves and merges their results.
+struct Sorter {
+    threshold: usize,
+    report_to: Option<ResultTarget>,
+    left: Option<Vec<u64>>,
+    right: Option<Vec<u64>>,
+}
+
+impl Sorter {
+    fn with(threshold: usize, report_to: ResultTarget) -> Sorter {
+        Sorter {
+            threshold,
+            report_to: Some(report_to),
+            left: None,
+            right: None,
+        }
+    }
+
+    fn send_result(&mut self, data: Vec<u64>, ctx: &mut Context<Self>) {
+        match self.report_to.take() {
+            Some(ResultTarget::Parent(parent, half)) => {
+                parent.do_send(Sorted { half, data });
+            }
+            Some(ResultTarget::Done(result)) => {
+                result.send(data).expect("Should send result!");
+            }
+            None => unreachable!("Sorter reported twice!"),
+        }
+        ctx.stop();
+    }
+}
+
+impl Actor for Sorter {
+    type Context = Context<Self>;
+
+    fn started(&mut self, _ctx: &mut Context<Self>) {
+        // nothing
+    }
+
+    fn stopped(&mut self, _ctx: &mut Context<Self>) {
+        // nothing
+    }
+}
+
+impl Handler<Sort> for Sorter {
+    type Result = ();
+
+    fn handle(&mut self, msg: Sort, ctx: &mut Context<Self>) -> Self::Result {
+        let mut data = msg.0;
+        if data.len() <= self.threshold {
+            data.sort_unstable();
+            self.send_result(data, ctx);
+        } else {
+            let right = data.split_off(data.len() / 2);
+            for (half, part) in vec![(Half::Left, data), (Half::Right, right)] {
+                let parent = ResultTarget::Parent(ctx.address(), half);
+                let child = Sorter::with(self.threshold, parent).start();
+                child.do_send(Sort(part));
+            }
+        }
+    }
+}
+
+impl Handler<Sorted> for Sorter {
+    type Result = ();
+
+    fn handle(&mut self, msg: Sorted, ctx: &mut Context<Self>) -> Self::Result {
+        match msg.half {
+            Half::Left => self.left = Some(msg.data),
+            Half::Right => self.right = Some(msg.data),
+        }
+        if self.left.is_some() && self.right.is_some() {
+            let merged = merge(self.left.take().unwrap(), self.right.take().unwrap());
+            self.send_result(merged, ctx);
+        }
+    }
+}
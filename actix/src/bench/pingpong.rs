@@ -17,6 +17,10 @@ impl Benchmark for PingPong {
         downcast_msg!(msg; PingPongRequest)
     }
 
+    fn validate(c: &Self::Conf) -> Result<(), BenchmarkError> {
+        validation::ping_pong(c)
+    }
+
     fn new_instance() -> Self::Instance {
         PingPongI::new()
     }
@@ -0,0 +1,422 @@
+use super::*;
+
+use actix::*;
+use actix_system_provider::{ActixSystem, PoisonPill};
+use benchmark_suite_shared::helpers::producer_consumer::{items_of_producer, synthetic_work};
+use benchmark_suite_shared::kompics_benchmarks::benchmarks::ProducerConsumerRequest;
+use futures::channel::oneshot;
+use std::collections::VecDeque;
+use std::convert::TryInto;
+use std::sync::Arc;
+use synchronoise::CountdownEvent;
+
+#[derive(Default)]
+pub struct ProducerConsumer;
+
+impl Benchmark for ProducerConsumer {
+    type Conf = ProducerConsumerRequest;
+    type Instance = ProducerConsumerI;
+
+    fn msg_to_conf(msg: Box<dyn (::protobuf::Message)>) -> Result<Self::Conf, BenchmarkError> {
+        downcast_msg!(msg; ProducerConsumerRequest)
+    }
+
+    fn validate(c: &Self::Conf) -> Result<(), BenchmarkError> {
+        validation::producer_consumer(c)
+    }
+
+    fn new_instance() -> Self::Instance {
+        ProducerConsumerI::new()
+    }
+
+    const LABEL: &'static str = "ProducerConsumer";
+}
+
+pub struct ProducerConsumerI {
+    buffer_size: Option<usize>,
+    num_producers: Option<usize>,
+    num_consumers: Option<usize>,
+    num_items: Option<u64>,
+    work_per_item: Option<u64>,
+    system: Option<ActixSystem>,
+    buffer: Option<Addr<Buffer>>,
+    producers: Vec<Addr<Producer>>,
+    consumers: Vec<Addr<Consumer>>,
+    latch: Option<Arc<CountdownEvent>>,
+}
+
+impl ProducerConsumerI {
+    fn new() -> ProducerConsumerI {
+        ProducerConsumerI {
+            buffer_size: None,
+            num_producers: None,
+            num_consumers: None,
+            num_items: None,
+            work_per_item: None,
+            system: None,
+            buffer: None,
+            producers: Vec::new(),
+            consumers: Vec::new(),
+            latch: None,
+        }
+    }
+}
+
+impl BenchmarkInstance for ProducerConsumerI {
+    type Conf = ProducerConsumerRequest;
+
+    fn setup(&mut self, c: &Self::Conf) -> () {
+        self.buffer_size = Some(c.buffer_size.try_into().unwrap());
+        self.num_producers = Some(c.number_of_producers.try_into().unwrap());
+        self.num_consumers = Some(c.number_of_consumers.try_into().unwrap());
+        self.num_items = Some(c.number_of_items);
+        self.work_per_item = Some(c.work_per_item);
+        let system = crate::actix_system_provider::new_system("producer_consumer");
+        self.system = Some(system);
+    }
+
+    fn prepare_iteration(&mut self) -> () {
+        match (
+            self.system.as_mut(),
+            self.buffer_size,
+            self.num_producers,
+            self.num_consumers,
+            self.num_items,
+            self.work_per_item,
+        ) {
+            (
+                Some(system),
+                Some(buffer_size),
+                Some(num_producers),
+                Some(num_consumers),
+                Some(num_items),
+                Some(work_per_item),
+            ) => {
+                let latch = Arc::new(CountdownEvent::new(num_consumers));
+                let buffer = system
+                    .start(move || Buffer::with(buffer_size, num_items))
+                    .expect("Should start Buffer!");
+                for id in 0..num_producers {
+                    let buffer = buffer.clone();
+                    let items = items_of_producer(id, num_producers, num_items);
+                    let producer = system
+                        .start(move || Producer::with(items, work_per_item, buffer))
+                        .expect("Should start Producer!");
+                    self.producers.push(producer);
+                }
+                for _ in 0..num_consumers {
+                    let buffer = buffer.clone();
+                    let latch = latch.clone();
+                    let consumer = system
+                        .start(move || Consumer::with(work_per_item, buffer, latch))
+                        .expect("Should start Consumer!");
+                    self.consumers.push(consumer);
+                }
+                self.buffer = Some(buffer);
+                self.latch = Some(latch);
+            }
+            _ => unimplemented!(),
+        }
+    }
+
+    fn run_iteration(&mut self) -> () {
+        let latch = self.latch.take().unwrap();
+        for consumer in self.consumers.iter() {
+            consumer.do_send(Start);
+        }
+        for producer in self.producers.iter() {
+            producer.do_send(Start);
+        }
+        latch.wait();
+    }
+
+    fn cleanup_iteration(&mut self, last_iteration: bool, _exec_time_millis: f64) -> () {
+        let mut system = self.system.take().unwrap();
+        let mut consumed = 0u64;
+        for consumer in self.consumers.drain(..) {
+            let (items, _checksum) = futures::executor::block_on(consumer.send(GetConsumed))
+                .expect("Should get consumed items!");
+            consumed += items;
+            system
+                .stop(consumer)
+                .expect("Consumer should have stopped!");
+        }
+        assert_eq!(
+            consumed,
+            self.num_items.unwrap(),
+            "Items were lost or duplicated!"
+        );
+        for producer in self.producers.drain(..) {
+            system
+                .stop(producer)
+                .expect("Producer should have stopped!");
+        }
+        system
+            .stop(self.buffer.take().unwrap())
+            .expect("Buffer should have stopped!");
+
+        if last_iteration {
+            system.shutdown().expect("Actix didn't shut down properly");
+            self.buffer_size = None;
+            self.num_producers = None;
+            self.num_consumers = None;
+            self.num_items = None;
+            self.work_per_item = None;
+        } else {
+            self.system = Some(system);
+        }
+    }
+}
+
+/// Answered once the item has been taken into the buffer.
+#[derive(Message)]
+#[rtype(result = "()")]
+struct Produce(u64);
+
+/// Answered with the next item, or with `None` once all items have been handed out.
+#[derive(Message)]
+#[rtype(result = "Option<u64>")]
+struct Consume;
+
+#[derive(Message)]
+#[rtype(result = "()")]
+struct Start;
+
+/// The number of items a consumer consumed, and the checksum over them.
+#[derive(Message)]
+#[rtype(result = "(u64, u64)")]
+struct GetConsumed;
+
+/// Holds up to `capacity` items, and defers the answers to the requests it can't serve yet.
+///
+/// A full buffer keeps the answer to a `Produce` until a consumer makes room,
+/// and an empty one keeps the answer to a `Consume` until an item arrives.
+struct Buffer {
+    capacity: usize,
+    items: VecDeque<u64>,
+    waiting_producers: VecDeque<(u64, oneshot::Sender<()>)>,
+    waiting_consumers: VecDeque<oneshot::Sender<Option<u64>>>,
+    undelivered: u64,
+}
+
+impl Buffer {
+    fn with(capacity: usize, num_items: u64) -> Buffer {
+        Buffer {
+            capacity,
+            items: VecDeque::with_capacity(capacity),
+            waiting_producers: VecDeque::new(),
+            waiting_consumers: VecDeque::new(),
+            undelivered: num_items,
+        }
+    }
+
+    fn deliver(&mut self, consumer: oneshot::Sender<Option<u64>>, item: u64) -> () {
+        consumer
+            .send(Some(item))
+            .expect("Consumer should be waiting");
+        self.undelivered -= 1u64;
+        if self.undelivered == 0 {
+            for consumer in self.waiting_consumers.drain(..) {
+                consumer.send(None).expect("Consumer should be waiting");
+            }
+        }
+    }
+}
+
+impl Actor for Buffer {
+    type Context = Context<Self>;
+
+    fn started(&mut self, _ctx: &mut Context<Self>) {
+        // nothing
+    }
+
+    fn stopped(&mut self, _ctx: &mut Context<Self>) {
+        // nothing
+    }
+}
+
+impl Handler<PoisonPill> for Buffer {
+    type Result = ();
+
+    fn handle(&mut self, _msg: PoisonPill, ctx: &mut Context<Self>) -> Self::Result {
+        ctx.stop();
+    }
+}
+
+impl Handler<Produce> for Buffer {
+    type Result = ResponseFuture<()>;
+
+    fn handle(&mut self, msg: Produce, _ctx: &mut Context<Self>) -> Self::Result {
+        let (tx, rx) = oneshot::channel();
+        if let Some(consumer) = self.waiting_consumers.pop_front() {
+            // the buffer is empty, since a consumer is waiting
+            self.deliver(consumer, msg.0);
+            tx.send(()).expect("Receiver is right here");
+        } else if self.items.len() < self.capacity {
+            self.items.push_back(msg.0);
+            tx.send(()).expect("Receiver is right here");
+        } else {
+            self.waiting_producers.push_back((msg.0, tx));
+        }
+        Box::pin(async move { rx.await.expect("Buffer dropped the item") })
+    }
+}
+
+impl Handler<Consume> for Buffer {
+    type Result = ResponseFuture<Option<u64>>;
+
+    fn handle(&mut self, _msg: Consume, _ctx: &mut Context<Self>) -> Self::Result {
+        let (tx, rx) = oneshot::channel();
+        if let Some(item) = self.items.pop_front() {
+            self.deliver(tx, item);
+            if let Some((item, producer)) = self.waiting_producers.pop_front() {
+                self.items.push_back(item);
+                producer.send(()).expect("Producer should be waiting");
+            }
+        } else if self.undelivered == 0 {
+            tx.send(None).expect("Receiver is right here");
+        } else {
+            self.waiting_consumers.push_back(tx);
+        }
+        Box::pin(async move { rx.await.expect("Buffer dropped the request") })
+    }
+}
+
+/// Produces its items one at a time.
+///
+/// The mailbox is paused until the buffer has taken an item,
+/// so the producer doesn't produce the next one before that.
+struct Producer {
+    remaining: u64,
+    work_per_item: u64,
+    buffer: Addr<Buffer>,
+}
+
+impl Producer {
+    fn with(num_items: u64, work_per_item: u64, buffer: Addr<Buffer>) -> Producer {
+        Producer {
+            remaining: num_items,
+            work_per_item,
+            buffer,
+        }
+    }
+
+    fn produce(&mut self, ctx: &mut Context<Self>) -> () {
+        if self.remaining > 0 {
+            self.remaining -= 1u64;
+            let item = synthetic_work(self.work_per_item, self.remaining);
+            ctx.wait(
+                self.buffer
+                    .send(Produce(item))
+                    .into_actor(self)
+                    .map(|res, producer, ctx| {
+                        res.expect("Buffer should have taken the item");
+                        producer.produce(ctx);
+                    }),
+            );
+        }
+    }
+}
+
+impl Actor for Producer {
+    type Context = Context<Self>;
+
+    fn started(&mut self, _ctx: &mut Context<Self>) {
+        // nothing
+    }
+
+    fn stopped(&mut self, _ctx: &mut Context<Self>) {
+        // nothing
+    }
+}
+
+impl Handler<PoisonPill> for Producer {
+    type Result = ();
+
+    fn handle(&mut self, _msg: PoisonPill, ctx: &mut Context<Self>) -> Self::Result {
+        ctx.stop();
+    }
+}
+
+impl Handler<Start> for Producer {
+    type Result = ();
+
+    fn handle(&mut self, _msg: Start, ctx: &mut Context<Self>) -> Self::Result {
+        self.produce(ctx);
+    }
+}
+
+/// Asks the buffer for one item at a time, until there are none left.
+struct Consumer {
+    work_per_item: u64,
+    consumed: u64,
+    checksum: u64,
+    buffer: Addr<Buffer>,
+    latch: Arc<CountdownEvent>,
+}
+
+impl Consumer {
+    fn with(work_per_item: u64, buffer: Addr<Buffer>, latch: Arc<CountdownEvent>) -> Consumer {
+        Consumer {
+            work_per_item,
+            consumed: 0u64,
+            checksum: 0u64,
+            buffer,
+            latch,
+        }
+    }
+
+    fn consume(&mut self, ctx: &mut Context<Self>) -> () {
+        ctx.wait(
+            self.buffer
+                .send(Consume)
+                .into_actor(self)
+                .map(
+                    |res, consumer, ctx| match res.expect("Buffer should have answered") {
+                        Some(item) => {
+                            consumer.checksum ^= synthetic_work(consumer.work_per_item, item);
+                            consumer.consumed += 1u64;
+                            consumer.consume(ctx);
+                        }
+                        None => consumer.latch.decrement().expect("Should decrement!"),
+                    },
+                ),
+        );
+    }
+}
+
+impl Actor for Consumer {
+    type Context = Context<Self>;
+
+    fn started(&mut self, _ctx: &mut Context<Self>) {
+        // nothing
+    }
+
+    fn stopped(&mut self, _ctx: &mut Context<Self>) {
+        // nothing
+    }
+}
+
+impl Handler<PoisonPill> for Consumer {
+    type Result = ();
+
+    fn handle(&mut self, _msg: PoisonPill, ctx: &mut Context<Self>) -> Self::Result {
+        ctx.stop();
+    }
+}
+
+impl Handler<Start> for Consumer {
+    type Result = ();
+
+    fn handle(&mut self, _msg: Start, ctx: &mut Context<Self>) -> Self::Result {
+        self.consume(ctx);
+    }
+}
+
+impl Handler<GetConsumed> for Consumer {
+    type Result = MessageResult<GetConsumed>;
+
+    fn handle(&mut self, _msg: GetConsumed, _ctx: &mut Context<Self>) -> Self::Result {
+        MessageResult((self.consumed, self.checksum))
+    }
+}
@@ -0,0 +1,184 @@
+use super::*;
+
+use actix::*;
+use actix_system_provider::{ActixSystem, PoisonPill};
+use benchmark_suite_shared::helpers::thread_ring::token_holders;
+use benchmark_suite_shared::kompics_benchmarks::benchmarks::ThreadRingRequest;
+use std::convert::TryInto;
+use std::sync::Arc;
+use synchronoise::CountdownEvent;
+
+#[derive(Default)]
+pub struct ThreadRing;
+
+impl Benchmark for ThreadRing {
+    type Conf = ThreadRingRequest;
+    type Instance = ThreadRingI;
+
+    fn msg_to_conf(msg: Box<dyn (::protobuf::Message)>) -> Result<Self::Conf, BenchmarkError> {
+        downcast_msg!(msg; ThreadRingRequest)
+    }
+
+    fn validate(c: &Self::Conf) -> Result<(), BenchmarkError> {
+        validation::thread_ring(c)
+    }
+
+    fn new_instance() -> Self::Instance {
+        ThreadRingI::new()
+    }
+
+    const LABEL: &'static str = "ThreadRing";
+}
+
+pub struct ThreadRingI {
+    num_actors: Option<usize>,
+    num_passes: Option<u64>,
+    num_tokens: Option<usize>,
+    system: Option<ActixSystem>,
+    nodes: Vec<Addr<RingNode>>,
+    latch: Option<Arc<CountdownEvent>>,
+}
+
+impl ThreadRingI {
+    fn new() -> ThreadRingI {
+        ThreadRingI {
+            num_actors: None,
+            num_passes: None,
+            num_tokens: None,
+            system: None,
+            nodes: Vec::new(),
+            latch: None,
+        }
+    }
+}
+
+impl BenchmarkInstance for ThreadRingI {
+    type Conf = ThreadRingRequest;
+
+    fn setup(&mut self, c: &Self::Conf) -> () {
+        self.num_actors = Some(c.number_of_actors.try_into().unwrap());
+        self.num_passes = Some(c.number_of_passes);
+        self.num_tokens = Some(c.number_of_tokens.try_into().unwrap());
+        let system = crate::actix_system_provider::new_system("threadring");
+        self.system = Some(system);
+    }
+
+    fn prepare_iteration(&mut self) -> () {
+        match (self.system.as_mut(), self.num_actors, self.num_tokens) {
+            (Some(system), Some(num_actors), Some(num_tokens)) => {
+                let latch = Arc::new(CountdownEvent::new(num_tokens));
+                for _ in 0..num_actors {
+                    let node_latch = latch.clone();
+                    let node = system
+                        .start(move || RingNode::with(node_latch))
+                        .expect("Should start RingNode!");
+                    self.nodes.push(node);
+                }
+                for (i, node) in self.nodes.iter().enumerate() {
+                    let next = self.nodes[(i + 1) % num_actors].clone();
+                    futures::executor::block_on(node.send(Connect(next)))
+                        .expect("RingNode should be connected!");
+                }
+                self.latch = Some(latch);
+            }
+            _ => unimplemented!(),
+        }
+    }
+
+    fn run_iteration(&mut self) -> () {
+        match (self.num_actors, self.num_passes, self.num_tokens) {
+            (Some(num_actors), Some(num_passes), Some(num_tokens)) => {
+                let latch = self.latch.take().unwrap();
+                for i in token_holders(num_actors, num_tokens) {
+                    self.nodes[i].do_send(Token {
+                        remaining: num_passes,
+                    });
+                }
+                latch.wait();
+            }
+            _ => unimplemented!(),
+        }
+    }
+
+    fn cleanup_iteration(&mut self, last_iteration: bool, _exec_time_millis: f64) -> () {
+        let mut system = self.system.take().unwrap();
+        for node in self.nodes.drain(..) {
+            system.stop(node).expect("RingNode should have stopped!");
+        }
+
+        if last_iteration {
+            system.shutdown().expect("Actix didn't shut down properly");
+            self.num_actors = None;
+            self.num_passes = None;
+            self.num_tokens = None;
+        } else {
+            self.system = Some(system);
+        }
+    }
+}
+
+#[derive(Message)]
+#[rtype(result = "()")]
+struct Connect(Addr<RingNode>);
+
+#[derive(Message)]
+#[rtype(result = "()")]
+struct Token {
+    remaining: u64,
+}
+
+struct RingNode {
+    next: Option<Addr<RingNode>>,
+    latch: Arc<CountdownEvent>,
+}
+
+impl RingNode {
+    fn with(latch: Arc<CountdownEvent>) -> RingNode {
+        RingNode { next: None, latch }
+    }
+}
+
+impl Actor for RingNode {
+    type Context = Context<Self>;
+
+    fn started(&mut self, _ctx: &mut Context<Self>) {
+        // nothing
+    }
+
+    fn stopped(&mut self, _ctx: &mut Context<Self>) {
+        self.next = None;
+    }
+}
+
+impl Handler<PoisonPill> for RingNode {
+    type Result = ();
+
+    fn handle(&mut self, _msg: PoisonPill, ctx: &mut Context<Self>) -> Self::Result {
+        ctx.stop();
+    }
+}
+
+impl Handler<Connect> for RingNode {
+    type Result = ();
+
+    fn handle(&mut self, msg: Connect, _ctx: &mut Context<Self>) -> Self::Result {
+        self.next = Some(msg.0);
+    }
+}
+
+impl Handler<Token> for RingNode {
+    type Result = ();
+
+    fn handle(&mut self, msg: Token, _ctx: &mut Context<Self>) -> Self::Result {
+        if msg.remaining > 0 {
+            self.next
+                .as_ref()
+                .expect("Ring was never closed")
+                .do_send(Token {
+                    remaining: msg.remaining - 1,
+                });
+        } else {
+            self.latch.decrement().expect("Should decrement!");
+        }
+    }
+}
@@ -163,6 +163,10 @@ impl Benchmark for PingPong {
         downcast_msg!(msg; ThroughputPingPongRequest)
     }
 
+    fn validate(c: &Self::Conf) -> Result<(), BenchmarkError> {
+        validation::throughput_ping_pong(c)
+    }
+
     fn new_instance() -> Self::Instance {
         PingPongI::new()
     }
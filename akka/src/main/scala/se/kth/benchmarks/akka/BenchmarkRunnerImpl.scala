@@ -75,4 +75,31 @@ class BenchmarkRunnerImpl extends BenchmarkRunnerGrpc.BenchmarkRunner {
 
   override def sizedThroughput(request: SizedThroughputRequest): Future[TestResult] =
     Future.successful(NotImplemented());
+
+  override def threadRing(request: ThreadRingRequest): Future[TestResult] =
+    Future.successful(NotImplemented());
+
+  override def fanIn(request: FanInRequest): Future[TestResult] =
+    Future.successful(NotImplemented());
+
+  override def fanout(request: FanoutRequest): Future[TestResult] =
+    Future.successful(NotImplemented());
+
+  override def big(request: BigRequest): Future[TestResult] =
+    Future.successful(NotImplemented());
+
+  override def bank(request: BankRequest): Future[TestResult] =
+    Future.successful(NotImplemented());
+
+  override def producerConsumer(request: ProducerConsumerRequest): Future[TestResult] =
+    Future.successful(NotImplemented());
+
+  override def diningPhilosophers(request: DiningPhilosophersRequest): Future[TestResult] =
+    Future.successful(NotImplemented());
+
+  override def nqueens(request: NQueensRequest): Future[TestResult] =
+    Future.successful(NotImplemented());
+
+  override def parallelSort(request: ParallelSortRequest): Future[TestResult] =
+    Future.successful(NotImplemented());
 }
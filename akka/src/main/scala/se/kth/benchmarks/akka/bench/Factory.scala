@@ -14,4 +14,13 @@ object Factory extends BenchmarkFactory {
   override def fibonacci: se.kth.benchmarks.Benchmark = Fibonacci;
   override def sizedThroughput(): DistributedBenchmark = SizedThroughput;
   override def atomicBroadcast(): DistributedBenchmark = ???
+  override def threadRing(): Benchmark = ???;
+  override def fanIn(): Benchmark = ???;
+  override def fanout(): Benchmark = ???;
+  override def big(): Benchmark = ???;
+  override def bank(): Benchmark = ???;
+  override def producerConsumer(): Benchmark = ???;
+  override def diningPhilosophers(): Benchmark = ???;
+  override def nqueens(): Benchmark = ???;
+  override def parallelSort(): Benchmark = ???;
 }
@@ -0,0 +1,232 @@
+use super::*;
+
+use benchmark_suite_shared::helpers::graphs::*;
+use benchmark_suite_shared::kompics_benchmarks::benchmarks::APSPRequest;
+use crossbeam::channel::{unbounded, Receiver, Sender};
+use std::collections::BTreeMap;
+use std::convert::TryInto;
+use std::sync::Arc;
+use std::thread::{self, JoinHandle};
+
+#[derive(Default)]
+pub struct AllPairsShortestPath;
+
+impl Benchmark for AllPairsShortestPath {
+    type Conf = APSPRequest;
+    type Instance = AllPairsShortestPathI;
+
+    fn msg_to_conf(msg: Box<dyn (::protobuf::Message)>) -> Result<Self::Conf, BenchmarkError> {
+        downcast_msg!(msg; APSPRequest)
+    }
+
+    fn validate(c: &Self::Conf) -> Result<(), BenchmarkError> {
+        validation::all_pairs_shortest_path(c)
+    }
+
+    fn new_instance() -> Self::Instance {
+        AllPairsShortestPathI::new()
+    }
+
+    const LABEL: &'static str = "AllPairsShortestPath";
+}
+
+pub struct AllPairsShortestPathI {
+    num_nodes: Option<usize>,
+    block_size: Option<usize>,
+    graph: Option<Arc<Graph<f64>>>,
+}
+
+impl AllPairsShortestPathI {
+    fn new() -> AllPairsShortestPathI {
+        AllPairsShortestPathI {
+            num_nodes: None,
+            block_size: None,
+            graph: None,
+        }
+    }
+}
+
+impl BenchmarkInstance for AllPairsShortestPathI {
+    type Conf = APSPRequest;
+
+    fn setup(&mut self, c: &Self::Conf) -> () {
+        let num_nodes: usize = c
+            .number_of_nodes
+            .try_into()
+            .expect("Nodes should fit into usize");
+        self.num_nodes = Some(num_nodes);
+        let block_size: usize = c
+            .block_size
+            .try_into()
+            .expect("Block Size should fit into usize");
+        self.block_size = Some(block_size);
+        let graph = generate_graph(num_nodes);
+        self.graph = Some(Arc::new(graph));
+    }
+
+    fn prepare_iteration(&mut self) -> () {
+        // nothing to do, blocks are distributed as part of the run
+    }
+
+    fn run_iteration(&mut self) -> () {
+        match (self.graph.as_ref(), self.block_size) {
+            (Some(graph), Some(block_size)) => {
+                #[allow(unused_variables)] // used during test
+                let result = compute_apsp(graph, block_size);
+                #[cfg(test)]
+                {
+                    let mut expected = (**graph).clone();
+                    expected.compute_floyd_warshall();
+                    assert_eq!(expected, result, "Wrong APSP result!");
+                }
+            }
+            _ => unimplemented!(),
+        }
+    }
+
+    fn cleanup_iteration(&mut self, last_iteration: bool, _exec_time_millis: f64) -> () {
+        if last_iteration {
+            self.graph = None;
+            self.num_nodes = None;
+            self.block_size = None;
+        }
+    }
+}
+
+/// Runs one thread per block and assembles the graph from their final blocks.
+fn compute_apsp(graph: &Graph<f64>, block_size: usize) -> Graph<f64> {
+    let blocks = graph.break_into_blocks(block_size);
+    let num_nodes = graph.num_nodes();
+    let blocks_per_dim = blocks.len();
+    let (result_tx, result_rx) = unbounded::<Block<f64>>();
+    let channels: Vec<Vec<(Sender<PhaseResult>, Receiver<PhaseResult>)>> = (0..blocks_per_dim)
+        .map(|_| (0..blocks_per_dim).map(|_| unbounded()).collect())
+        .collect();
+    let mut workers: Vec<JoinHandle<()>> = Vec::with_capacity(blocks_per_dim * blocks_per_dim);
+    for (bi, row) in blocks.into_iter().enumerate() {
+        for (bj, block) in row.into_iter().enumerate() {
+            let mut neighbours: Vec<Sender<PhaseResult>> =
+                Vec::with_capacity(blocks_per_dim * 2 - 2);
+            // add neighbours in the same row
+            for r in 0usize..blocks_per_dim {
+                if r != bi {
+                    neighbours.push(channels[r][bj].0.clone());
+                }
+            }
+            // add neighbours in the same column
+            for c in 0usize..blocks_per_dim {
+                if c != bj {
+                    neighbours.push(channels[bi][c].0.clone());
+                }
+            }
+            let worker = BlockWorker {
+                num_nodes,
+                neighbours,
+                inbox: channels[bi][bj].1.clone(),
+                current_data: Arc::new(block),
+            };
+            let results = result_tx.clone();
+            workers.push(thread::spawn(move || worker.run(results)));
+        }
+    }
+    drop(channels);
+    drop(result_tx);
+
+    let mut assembly: Vec<Vec<Option<Block<f64>>>> = (0..blocks_per_dim)
+        .map(|_| (0..blocks_per_dim).map(|_| None).collect())
+        .collect();
+    for block in result_rx.iter() {
+        let (i, j) = block.block_position();
+        assembly[i][j] = Some(block);
+    }
+    for worker in workers.drain(..) {
+        worker.join().expect("Block worker panicked");
+    }
+    let blocks_owned: Vec<Vec<Block<f64>>> = assembly
+        .into_iter()
+        .map(|row| {
+            row.into_iter()
+                .map(|block| block.expect("Every block should have reported a result"))
+                .collect()
+        })
+        .collect();
+    Graph::assemble_from_blocks(blocks_owned)
+}
+
+struct PhaseResult {
+    k: Phase,
+    data: Arc<Block<f64>>,
+}
+
+struct BlockWorker {
+    num_nodes: usize,
+    neighbours: Vec<Sender<PhaseResult>>,
+    inbox: Receiver<PhaseResult>,
+    current_data: Arc<Block<f64>>,
+}
+
+impl BlockWorker {
+    fn run(mut self, results: Sender<Block<f64>>) -> () {
+        let mut k = Phase::default();
+        let mut neighbour_data: BTreeMap<usize, Arc<Block<f64>>> = BTreeMap::new();
+        let mut next_neighbour_data: BTreeMap<usize, Arc<Block<f64>>> = BTreeMap::new();
+        self.notify_neighbours(k);
+        loop {
+            while neighbour_data.len() < self.neighbours.len() {
+                let PhaseResult { k: other_k, data } =
+                    self.inbox.recv().expect("Neighbours should be alive");
+                if other_k == k {
+                    neighbour_data.insert(data.block_id(), data);
+                } else if other_k == k.incremented() {
+                    next_neighbour_data.insert(data.block_id(), data);
+                } else {
+                    unimplemented!("Got k={}, but I'm in phase k={}", other_k, k);
+                }
+            }
+            k.inc();
+            self.perform_computation(k, &neighbour_data);
+            neighbour_data.clear();
+            std::mem::swap(&mut neighbour_data, &mut next_neighbour_data);
+            if k == self.num_nodes - 1usize {
+                break;
+            }
+            self.notify_neighbours(k);
+        }
+        let block = match Arc::try_unwrap(self.current_data) {
+            Ok(block_owned) => block_owned,
+            Err(block_shared) => (*block_shared).clone(), // still in use :(
+        };
+        results.send(block).expect("Collector should be alive");
+    }
+
+    fn notify_neighbours(&self, k: Phase) -> () {
+        for neighbour in self.neighbours.iter() {
+            // neighbours that have already finished don't need our data anymore
+            let _ = neighbour.send(PhaseResult {
+                k,
+                data: self.current_data.clone(),
+            });
+        }
+    }
+
+    fn perform_computation(
+        &mut self,
+        k: Phase,
+        neighbour_data: &BTreeMap<usize, Arc<Block<f64>>>,
+    ) -> () {
+        let lookup_fun = |block_id: usize| {
+            neighbour_data
+                .get(&block_id)
+                .expect("Block id should have been in neighbour data")
+                .as_ref()
+        };
+        if let Some(current_data) = Arc::get_mut(&mut self.current_data) {
+            current_data.compute_floyd_warshall_inner(&lookup_fun, k.k());
+        } else {
+            // our data is still in use somewhere...gotta clone :(
+            let mut current_data = (*self.current_data).clone();
+            current_data.compute_floyd_warshall_inner(&lookup_fun, k.k());
+            self.current_data = Arc::new(current_data);
+        }
+    }
+}
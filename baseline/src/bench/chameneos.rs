@@ -0,0 +1,245 @@
+use super::*;
+
+use benchmark_suite_shared::helpers::chameneos::ChameneosColour;
+use benchmark_suite_shared::kompics_benchmarks::benchmarks::ChameneosRequest;
+use crossbeam::channel::{unbounded, Receiver, Sender};
+use std::convert::TryInto;
+use std::sync::Arc;
+use std::thread::{self, JoinHandle};
+use synchronoise::CountdownEvent;
+
+#[derive(Default)]
+pub struct Chameneos;
+
+impl Benchmark for Chameneos {
+    type Conf = ChameneosRequest;
+    type Instance = ChameneosI;
+
+    fn msg_to_conf(msg: Box<dyn (::protobuf::Message)>) -> Result<Self::Conf, BenchmarkError> {
+        downcast_msg!(msg; ChameneosRequest)
+    }
+
+    fn validate(c: &Self::Conf) -> Result<(), BenchmarkError> {
+        validation::chameneos(c)
+    }
+
+    fn new_instance() -> Self::Instance {
+        ChameneosI::new()
+    }
+
+    const LABEL: &'static str = "Chameneos";
+}
+
+pub struct ChameneosI {
+    num_chameneos: Option<usize>,
+    num_meetings: Option<u64>,
+    chameneos: Vec<Sender<ChameneoMsg>>,
+    threads: Vec<JoinHandle<()>>,
+    latch: Option<Arc<CountdownEvent>>,
+}
+
+impl ChameneosI {
+    fn new() -> ChameneosI {
+        ChameneosI {
+            num_chameneos: None,
+            num_meetings: None,
+            chameneos: Vec::new(),
+            threads: Vec::new(),
+            latch: None,
+        }
+    }
+}
+
+impl BenchmarkInstance for ChameneosI {
+    type Conf = ChameneosRequest;
+
+    fn setup(&mut self, c: &Self::Conf) -> () {
+        self.num_chameneos = Some(c.number_of_chameneos.try_into().unwrap());
+        self.num_meetings = Some(c.number_of_meetings);
+    }
+
+    fn prepare_iteration(&mut self) -> () {
+        match (self.num_chameneos, self.num_meetings) {
+            (Some(num_chameneos), Some(num_meetings)) => {
+                let latch = Arc::new(CountdownEvent::new(1));
+                let (mall_tx, mall_rx) = unbounded::<MallMsg>();
+                let mall = Mall::with(num_meetings, num_chameneos, latch.clone());
+                self.threads.push(thread::spawn(move || mall.run(mall_rx)));
+                for i in 0usize..num_chameneos {
+                    let (tx, rx) = unbounded::<ChameneoMsg>();
+                    let chameneo =
+                        Chameneo::with(mall_tx.clone(), tx.clone(), ChameneosColour::for_id(i));
+                    self.threads.push(thread::spawn(move || chameneo.run(rx)));
+                    self.chameneos.push(tx);
+                }
+                self.latch = Some(latch);
+            }
+            _ => unimplemented!(),
+        }
+    }
+
+    fn run_iteration(&mut self) -> () {
+        let latch = self.latch.take().unwrap();
+        for chameneo in self.chameneos.drain(..) {
+            chameneo
+                .send(ChameneoMsg::Start)
+                .expect("Chameneo should be alive");
+        }
+        latch.wait();
+    }
+
+    fn cleanup_iteration(&mut self, last_iteration: bool, _exec_time_millis: f64) -> () {
+        // the mall and all chameneos have exited once the latch was released
+        for t in self.threads.drain(..) {
+            t.join().expect("Chameneos thread panicked");
+        }
+
+        if last_iteration {
+            self.num_chameneos = None;
+            self.num_meetings = None;
+        }
+    }
+}
+
+enum MallMsg {
+    MeetingCount(u64),
+    Meet {
+        colour: ChameneosColour,
+        chameneo: Sender<ChameneoMsg>,
+    },
+}
+
+enum ChameneoMsg {
+    Start,
+    Meet {
+        colour: ChameneosColour,
+        chameneo: Sender<ChameneoMsg>,
+    },
+    Change {
+        colour: ChameneosColour,
+    },
+    Exit,
+}
+
+struct Mall {
+    num_meetings: u64,
+    num_chameneos: usize,
+    latch: Arc<CountdownEvent>,
+    waiting_chameneo: Option<Sender<ChameneoMsg>>,
+    sum_meetings: u64,
+    meetings_count: u64,
+    num_faded: usize,
+}
+
+impl Mall {
+    fn with(num_meetings: u64, num_chameneos: usize, latch: Arc<CountdownEvent>) -> Mall {
+        Mall {
+            num_meetings,
+            num_chameneos,
+            latch,
+            waiting_chameneo: None,
+            sum_meetings: 0u64,
+            meetings_count: 0u64,
+            num_faded: 0usize,
+        }
+    }
+
+    fn run(mut self, inbox: Receiver<MallMsg>) -> () {
+        while let Ok(msg) = inbox.recv() {
+            match msg {
+                MallMsg::MeetingCount(count) => {
+                    self.num_faded += 1usize;
+                    self.sum_meetings += count;
+                    if self.num_faded == self.num_chameneos {
+                        debug_assert_eq!(self.sum_meetings, 2u64 * self.meetings_count);
+                        self.latch.decrement().expect("Should count down");
+                        return;
+                    }
+                }
+                MallMsg::Meet { colour, chameneo } => {
+                    if self.meetings_count < self.num_meetings {
+                        match self.waiting_chameneo.take() {
+                            Some(other) => {
+                                self.meetings_count += 1u64;
+                                other
+                                    .send(ChameneoMsg::Meet { colour, chameneo })
+                                    .expect("Chameneo should be alive");
+                            }
+                            None => {
+                                self.waiting_chameneo = Some(chameneo);
+                            }
+                        }
+                    } else {
+                        chameneo
+                            .send(ChameneoMsg::Exit)
+                            .expect("Chameneo should be alive");
+                    }
+                }
+            }
+        }
+    }
+}
+
+struct Chameneo {
+    mall: Sender<MallMsg>,
+    myself: Sender<ChameneoMsg>,
+    colour: ChameneosColour,
+    meetings: u64,
+}
+
+impl Chameneo {
+    fn with(
+        mall: Sender<MallMsg>,
+        myself: Sender<ChameneoMsg>,
+        initial_colour: ChameneosColour,
+    ) -> Chameneo {
+        Chameneo {
+            mall,
+            myself,
+            colour: initial_colour,
+            meetings: 0u64,
+        }
+    }
+
+    fn go_to_mall(&self) -> () {
+        self.mall
+            .send(MallMsg::Meet {
+                colour: self.colour,
+                chameneo: self.myself.clone(),
+            })
+            .expect("Mall should be alive");
+    }
+
+    fn run(mut self, inbox: Receiver<ChameneoMsg>) -> () {
+        while let Ok(msg) = inbox.recv() {
+            match msg {
+                ChameneoMsg::Start => self.go_to_mall(),
+                ChameneoMsg::Meet {
+                    colour: other_colour,
+                    chameneo,
+                } => {
+                    self.colour = self.colour.complement(other_colour);
+                    self.meetings += 1u64;
+                    chameneo
+                        .send(ChameneoMsg::Change {
+                            colour: self.colour,
+                        })
+                        .expect("Chameneo should be alive");
+                    self.go_to_mall();
+                }
+                ChameneoMsg::Change { colour: new_colour } => {
+                    self.colour = new_colour;
+                    self.meetings += 1u64;
+                    self.go_to_mall();
+                }
+                ChameneoMsg::Exit => {
+                    self.colour = ChameneosColour::Faded;
+                    self.mall
+                        .send(MallMsg::MeetingCount(self.meetings))
+                        .expect("Mall should be alive");
+                    return;
+                }
+            }
+        }
+    }
+}
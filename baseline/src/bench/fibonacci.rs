@@ -0,0 +1,159 @@
+use super::*;
+
+use crate::pool::{Spawner, ThreadPool};
+use benchmark_suite_shared::kompics_benchmarks::benchmarks::FibonacciRequest;
+use std::sync::{
+    atomic::{AtomicU64, AtomicU8, Ordering},
+    Arc,
+};
+use synchronoise::CountdownEvent;
+
+#[derive(Default)]
+pub struct Fibonacci;
+
+impl Benchmark for Fibonacci {
+    type Conf = FibonacciRequest;
+    type Instance = FibonacciI;
+
+    fn msg_to_conf(msg: Box<dyn (::protobuf::Message)>) -> Result<Self::Conf, BenchmarkError> {
+        downcast_msg!(msg; FibonacciRequest)
+    }
+
+    fn validate(c: &Self::Conf) -> Result<(), BenchmarkError> {
+        validation::fibonacci(c)
+    }
+
+    fn new_instance() -> Self::Instance {
+        FibonacciI::new()
+    }
+
+    const LABEL: &'static str = "Fibonacci";
+}
+
+pub struct FibonacciI {
+    fib_number: Option<u32>,
+    pool: Option<ThreadPool>,
+    result: Option<Arc<AtomicU64>>,
+    latch: Option<Arc<CountdownEvent>>,
+}
+
+impl FibonacciI {
+    fn new() -> FibonacciI {
+        FibonacciI {
+            fib_number: None,
+            pool: None,
+            result: None,
+            latch: None,
+        }
+    }
+}
+
+impl BenchmarkInstance for FibonacciI {
+    type Conf = FibonacciRequest;
+
+    fn setup(&mut self, c: &Self::Conf) -> () {
+        self.fib_number = Some(c.fib_number);
+        self.pool = Some(ThreadPool::new(num_cpus::get()));
+    }
+
+    fn prepare_iteration(&mut self) -> () {
+        self.result = Some(Arc::new(AtomicU64::new(0u64)));
+        self.latch = Some(Arc::new(CountdownEvent::new(1)));
+    }
+
+    fn run_iteration(&mut self) -> () {
+        match self.fib_number {
+            Some(fib_number) => match self.pool {
+                Some(ref pool) => {
+                    let latch = self.latch.take().unwrap();
+                    let target = ResultTarget::Root {
+                        result: self.result.clone().unwrap(),
+                        latch: latch.clone(),
+                    };
+                    pool.spawn(move |spawner| fib_task(fib_number, target, spawner));
+                    latch.wait();
+                }
+                None => unimplemented!(),
+            },
+            None => unimplemented!(),
+        }
+    }
+
+    fn cleanup_iteration(&mut self, last_iteration: bool, _exec_time_millis: f64) -> () {
+        let result = self.result.take().unwrap().load(Ordering::SeqCst);
+        debug_assert_eq!(
+            result,
+            expected_fib(self.fib_number.unwrap()),
+            "Wrong fibonacci result!"
+        );
+
+        if last_iteration {
+            if let Some(pool) = self.pool.take() {
+                pool.shutdown();
+            }
+            self.fib_number = None;
+        }
+    }
+}
+
+/// Where a task delivers its value, replacing the parent reference of the actor variants.
+enum ResultTarget {
+    Parent(Arc<Join>),
+    Root {
+        result: Arc<AtomicU64>,
+        latch: Arc<CountdownEvent>,
+    },
+}
+
+impl ResultTarget {
+    fn deliver(&self, value: u64) -> () {
+        match self {
+            ResultTarget::Parent(join) => join.complete(value),
+            ResultTarget::Root { result, latch } => {
+                result.store(value, Ordering::SeqCst);
+                latch.decrement().expect("Should decrement!");
+            }
+        }
+    }
+}
+
+/// Sums the results of the two child tasks and passes the sum on once both have arrived.
+struct Join {
+    outstanding: AtomicU8,
+    sum: AtomicU64,
+    target: ResultTarget,
+}
+
+impl Join {
+    fn complete(&self, value: u64) -> () {
+        self.sum.fetch_add(value, Ordering::SeqCst);
+        if self.outstanding.fetch_sub(1u8, Ordering::SeqCst) == 1u8 {
+            self.target.deliver(self.sum.load(Ordering::SeqCst));
+        }
+    }
+}
+
+fn fib_task(n: u32, target: ResultTarget, spawner: &Spawner) -> () {
+    if n <= 2u32 {
+        target.deliver(1u64);
+    } else {
+        let join = Arc::new(Join {
+            outstanding: AtomicU8::new(2u8),
+            sum: AtomicU64::new(0u64),
+            target,
+        });
+        let join2 = join.clone();
+        spawner.spawn(move |s| fib_task(n - 1u32, ResultTarget::Parent(join), s));
+        spawner.spawn(move |s| fib_task(n - 2u32, ResultTarget::Parent(join2), s));
+    }
+}
+
+fn expected_fib(n: u32) -> u64 {
+    let (mut a, mut b) = (1u64, 1u64);
+    for _ in 2..n {
+        let next = a + b;
+        a = b;
+        b = next;
+    }
+    b
+}
@@ -0,0 +1,112 @@
+use super::*;
+
+use benchmark_suite_shared::kompics_benchmarks::benchmarks::PingPongRequest;
+use crossbeam::channel::{unbounded, Sender};
+use std::sync::Arc;
+use std::thread::{self, JoinHandle};
+use synchronoise::CountdownEvent;
+
+#[derive(Default)]
+pub struct PingPong;
+
+impl Benchmark for PingPong {
+    type Conf = PingPongRequest;
+    type Instance = PingPongI;
+
+    fn msg_to_conf(msg: Box<dyn (::protobuf::Message)>) -> Result<Self::Conf, BenchmarkError> {
+        downcast_msg!(msg; PingPongRequest)
+    }
+
+    fn validate(c: &Self::Conf) -> Result<(), BenchmarkError> {
+        validation::ping_pong(c)
+    }
+
+    fn new_instance() -> Self::Instance {
+        PingPongI::new()
+    }
+
+    const LABEL: &'static str = "PingPong";
+}
+
+pub struct PingPongI {
+    num: Option<u64>,
+    start: Option<Sender<()>>,
+    pinger: Option<JoinHandle<()>>,
+    ponger: Option<JoinHandle<()>>,
+    latch: Option<Arc<CountdownEvent>>,
+}
+
+impl PingPongI {
+    fn new() -> PingPongI {
+        PingPongI {
+            num: None,
+            start: None,
+            pinger: None,
+            ponger: None,
+            latch: None,
+        }
+    }
+}
+
+impl BenchmarkInstance for PingPongI {
+    type Conf = PingPongRequest;
+
+    fn setup(&mut self, c: &Self::Conf) -> () {
+        self.num = Some(c.number_of_messages);
+    }
+
+    fn prepare_iteration(&mut self) -> () {
+        match self.num {
+            Some(num) => {
+                let (start_tx, start_rx) = unbounded::<()>();
+                let (ping_tx, ping_rx) = unbounded::<()>();
+                let (pong_tx, pong_rx) = unbounded::<()>();
+                let latch = Arc::new(CountdownEvent::new(1));
+
+                let ponger = thread::spawn(move || {
+                    while let Ok(()) = ping_rx.recv() {
+                        pong_tx.send(()).expect("Pinger should be alive");
+                    }
+                });
+                let platch = latch.clone();
+                let pinger = thread::spawn(move || {
+                    if let Ok(()) = start_rx.recv() {
+                        for _ in 0..num {
+                            ping_tx.send(()).expect("Ponger should be alive");
+                            pong_rx.recv().expect("Ponger should be alive");
+                        }
+                        platch.decrement().expect("Should decrement!");
+                    }
+                    // dropping ping_tx stops the ponger
+                });
+
+                self.start = Some(start_tx);
+                self.pinger = Some(pinger);
+                self.ponger = Some(ponger);
+                self.latch = Some(latch);
+            }
+            None => unimplemented!(),
+        }
+    }
+
+    fn run_iteration(&mut self) -> () {
+        match self.start {
+            Some(ref start) => {
+                let latch = self.latch.take().unwrap();
+                start.send(()).expect("Pinger should be waiting");
+                latch.wait();
+            }
+            None => unimplemented!(),
+        }
+    }
+
+    fn cleanup_iteration(&mut self, last_iteration: bool, _exec_time_millis: f64) -> () {
+        self.start = None;
+        self.pinger.take().unwrap().join().expect("Pinger panicked");
+        self.ponger.take().unwrap().join().expect("Ponger panicked");
+
+        if last_iteration {
+            self.num = None;
+        }
+    }
+}
@@ -0,0 +1,174 @@
+use super::*;
+
+use benchmark_suite_shared::kompics_benchmarks::benchmarks::ThroughputPingPongRequest;
+use crossbeam::channel::{unbounded, Receiver, Sender};
+use std::sync::Arc;
+use std::thread::{self, JoinHandle};
+use synchronoise::CountdownEvent;
+
+pub struct Params {
+    pub num_msgs: u64,
+    pub num_pairs: u32,
+    pub pipeline: u64,
+    pub static_only: bool,
+}
+impl Params {
+    pub fn from_req(r: &ThroughputPingPongRequest) -> Params {
+        Params {
+            num_msgs: r.messages_per_pair,
+            num_pairs: r.parallelism,
+            pipeline: r.pipeline_size,
+            static_only: r.static_only,
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct PingPong;
+
+impl Benchmark for PingPong {
+    type Conf = ThroughputPingPongRequest;
+    type Instance = PingPongI;
+
+    fn msg_to_conf(msg: Box<dyn (::protobuf::Message)>) -> Result<Self::Conf, BenchmarkError> {
+        downcast_msg!(msg; ThroughputPingPongRequest)
+    }
+
+    fn validate(c: &Self::Conf) -> Result<(), BenchmarkError> {
+        validation::throughput_ping_pong(c)
+    }
+
+    fn new_instance() -> Self::Instance {
+        PingPongI::new()
+    }
+
+    const LABEL: &'static str = "ThroughputPingPong";
+}
+
+pub struct PingPongI {
+    params: Option<Params>,
+    starts: Vec<Sender<()>>,
+    threads: Vec<JoinHandle<()>>,
+    latch: Option<Arc<CountdownEvent>>,
+}
+
+impl PingPongI {
+    fn new() -> PingPongI {
+        PingPongI {
+            params: None,
+            starts: Vec::new(),
+            threads: Vec::new(),
+            latch: None,
+        }
+    }
+
+    /// Starts one pinger/ponger thread pair exchanging messages created by `make_ping`.
+    ///
+    /// Static pings are `()`, while the others carry their index like in the actor variants.
+    fn spawn_pair<M, F>(
+        &mut self,
+        num_msgs: u64,
+        pipeline: u64,
+        latch: Arc<CountdownEvent>,
+        make_ping: F,
+    ) where
+        M: Send + 'static,
+        F: Fn(u64) -> M + Send + 'static,
+    {
+        let (start_tx, start_rx) = unbounded::<()>();
+        let (ping_tx, ping_rx) = unbounded::<M>();
+        let (pong_tx, pong_rx) = unbounded::<M>();
+        let ponger = thread::spawn(move || {
+            while let Ok(ping) = ping_rx.recv() {
+                pong_tx.send(ping).expect("Pinger should be alive");
+            }
+        });
+        let pinger = thread::spawn(move || {
+            if let Ok(()) = start_rx.recv() {
+                Self::run_pinger(num_msgs, pipeline, &ping_tx, &pong_rx, make_ping);
+                latch.decrement().expect("Should decrement!");
+            }
+        });
+        self.starts.push(start_tx);
+        self.threads.push(pinger);
+        self.threads.push(ponger);
+    }
+
+    fn run_pinger<M, F>(
+        num_msgs: u64,
+        pipeline: u64,
+        pings: &Sender<M>,
+        pongs: &Receiver<M>,
+        make_ping: F,
+    ) -> ()
+    where
+        F: Fn(u64) -> M,
+    {
+        let mut sent_count = 0u64;
+        let mut recv_count = 0u64;
+        while sent_count < pipeline && sent_count < num_msgs {
+            pings
+                .send(make_ping(sent_count))
+                .expect("Ponger should be alive");
+            sent_count += 1u64;
+        }
+        while recv_count < num_msgs {
+            pongs.recv().expect("Ponger should be alive");
+            recv_count += 1u64;
+            if sent_count < num_msgs {
+                pings
+                    .send(make_ping(sent_count))
+                    .expect("Ponger should be alive");
+                sent_count += 1u64;
+            }
+        }
+    }
+}
+
+impl BenchmarkInstance for PingPongI {
+    type Conf = ThroughputPingPongRequest;
+
+    fn setup(&mut self, c: &Self::Conf) -> () {
+        self.params = Some(Params::from_req(c));
+    }
+
+    fn prepare_iteration(&mut self) -> () {
+        let (num_msgs, num_pairs, pipeline, static_only) = match self.params {
+            Some(ref params) => (
+                params.num_msgs,
+                params.num_pairs,
+                params.pipeline,
+                params.static_only,
+            ),
+            None => unimplemented!(),
+        };
+        let latch = Arc::new(CountdownEvent::new(num_pairs as usize));
+        for _ in 0..num_pairs {
+            if static_only {
+                self.spawn_pair(num_msgs, pipeline, latch.clone(), |_| ());
+            } else {
+                self.spawn_pair(num_msgs, pipeline, latch.clone(), |index| index);
+            }
+        }
+        self.latch = Some(latch);
+    }
+
+    fn run_iteration(&mut self) -> () {
+        let latch = self.latch.take().unwrap();
+        for start in self.starts.iter() {
+            start.send(()).expect("Pinger should be waiting");
+        }
+        latch.wait();
+    }
+
+    fn cleanup_iteration(&mut self, last_iteration: bool, _exec_time_millis: f64) -> () {
+        self.starts.clear();
+        for t in self.threads.drain(..) {
+            t.join().expect("Pinger or ponger panicked");
+        }
+
+        if last_iteration {
+            self.params = None;
+        }
+    }
+}
@@ -0,0 +1,42 @@
+use benchmark_suite_shared;
+
+use benchmark_suite_shared::downcast_msg;
+use benchmark_suite_shared::{result_writer, BenchmarkMain};
+use grpc;
+use std::env;
+
+mod bench;
+mod benchmark_runner;
+pub mod pool;
+
+fn main() {
+    let args: Vec<String> = env::args().collect();
+    result_writer::init(
+        "Baseline",
+        &[(env!("CARGO_PKG_NAME"), env!("CARGO_PKG_VERSION"))],
+    );
+    BenchmarkMain::run_with(
+        args,
+        benchmark_runner::BenchmarkRunnerImpl::new(),
+        bench::factory(),
+        |_| (), // no networked benchmarks
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use benchmark_suite_shared::test_utils::{test_implementation, test_local_implementation};
+
+    #[test]
+    fn test_master_client() {
+        let benchmarks = Box::new(bench::Factory {});
+        test_implementation(benchmarks);
+    }
+
+    #[test]
+    fn test_local() {
+        let runner = benchmark_runner::BenchmarkRunnerImpl::new();
+        test_local_implementation(runner);
+    }
+}
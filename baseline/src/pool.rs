@@ -0,0 +1,87 @@
+//! A minimal fixed-size thread pool, used in place of an actor system's scheduler.
+
+use crossbeam::channel::{unbounded, Receiver, Sender};
+use std::thread::{self, JoinHandle};
+
+type Task = Box<dyn FnOnce(&Spawner) + Send>;
+
+enum Job {
+    Run(Task),
+    Stop,
+}
+
+/// Schedules tasks on the pool it was obtained from.
+///
+/// Every task is handed a `Spawner`, so tasks can fork further tasks.
+#[derive(Clone)]
+pub struct Spawner {
+    jobs: Sender<Job>,
+}
+
+impl Spawner {
+    pub fn spawn<F>(&self, f: F) -> ()
+    where
+        F: FnOnce(&Spawner) + Send + 'static,
+    {
+        self.jobs
+            .send(Job::Run(Box::new(f)))
+            .expect("Pool should still be running");
+    }
+}
+
+pub struct ThreadPool {
+    spawner: Spawner,
+    workers: Vec<JoinHandle<()>>,
+}
+
+impl ThreadPool {
+    pub fn new(num_threads: usize) -> ThreadPool {
+        assert!(num_threads > 0, "A pool needs at least one thread");
+        let (tx, rx) = unbounded();
+        let spawner = Spawner { jobs: tx };
+        let workers = (0..num_threads)
+            .map(|i| {
+                let rx = rx.clone();
+                let spawner = spawner.clone();
+                thread::Builder::new()
+                    .name(format!("baseline-pool-{}", i))
+                    .spawn(move || ThreadPool::run_worker(rx, spawner))
+                    .expect("Pool thread should have started")
+            })
+            .collect();
+        ThreadPool { spawner, workers }
+    }
+
+    fn run_worker(jobs: Receiver<Job>, spawner: Spawner) -> () {
+        while let Ok(job) = jobs.recv() {
+            match job {
+                Job::Run(task) => task(&spawner),
+                Job::Stop => return,
+            }
+        }
+    }
+
+    pub fn spawner(&self) -> &Spawner {
+        &self.spawner
+    }
+
+    pub fn spawn<F>(&self, f: F) -> ()
+    where
+        F: FnOnce(&Spawner) + Send + 'static,
+    {
+        self.spawner.spawn(f);
+    }
+
+    /// Lets already queued tasks finish and then joins all threads.
+    pub fn shutdown(mut self) -> () {
+        for _ in 0..self.workers.len() {
+            self.spawner
+                .jobs
+                .send(Job::Stop)
+                .expect("Pool should still be running");
+        }
+        for worker in self.workers.drain(..) {
+            worker.join().expect("Pool thread panicked");
+        }
+    }
+}
@@ -19,6 +19,16 @@ val runnerAddr = "127.0.0.1:45678";
 //val masterAddr = "192.168.0.106:45679";
 val masterAddr = "127.0.0.1:45679";
 
+// Reusing an existing run id makes the runners pick up that run's journal and skip completed experiments.
+def runIdOrResume(resume: String, totalStart: Long): String = {
+	if (resume.isEmpty) {
+		s"run-${totalStart}"
+	} else {
+		println(s"Resuming run ${resume}.");
+		resume
+	}
+}
+
 def getExperimentRunner(prefix: String, results: Path, testing: Boolean, benchmarks_string: String): BenchmarkRunner = {
 	var params: Seq[Shellable] = Seq(
 		"-jar",
@@ -53,7 +63,7 @@ val defaultNodesFile = pwd / "nodes.conf";
 def client(name: String, master: AddressArg, runid: String, publicif: String, clientPort: Int = 45678): Unit = {
 	val runId = runid;
 	val publicIf = publicif;
-	implementations.get(name) match {
+	lookupImpl(name) match {
 		case Some(impl) => {
 			println(s"Found Benchmark ${impl.label} for ${name}. Master is at $master");
 			val logdir = logs / runId;
@@ -78,11 +88,11 @@ def client(name: String, master: AddressArg, runid: String, publicif: String, cl
 
 @arg(doc ="Run benchmarks using a cluster of nodes.")
 @main
-def remote(withNodes: Path = defaultNodesFile, testing: Boolean = false, impls: String = "", benchmarks: String = ""): Unit = {
+def remote(withNodes: Path = defaultNodesFile, testing: Boolean = false, impls: String = "", benchmarks: String = "", resume: String = ""): Unit = {
 	val nodes = readNodes(withNodes);
 	val masters = runnersForImpl(impls, _.remoteRunner(runnerAddr, masterAddr, nodes.size));
 	val totalStart = System.currentTimeMillis();
-	val runId = s"run-${totalStart}";
+	val runId = runIdOrResume(resume, totalStart);
 	val logdir = logs / runId;
 	mkdir! logdir;
 	val resultsdir = results / runId;
@@ -120,7 +130,7 @@ def remote(withNodes: Path = defaultNodesFile, testing: Boolean = false, impls:
 
 @arg(doc ="Run benchmarks using a cluster of nodes.")
 @main
-def fakeRemote(withClients: Int = 1, testing: Boolean = false, impls: String = "", benchmarks: String = "", remoteDir: os.Path = tmp.dir()): Unit = {
+def fakeRemote(withClients: Int = 1, testing: Boolean = false, impls: String = "", benchmarks: String = "", remoteDir: os.Path = tmp.dir(), resume: String = ""): Unit = {
 	val alwaysCopyFiles = List[Path](relp("bench.sc"), relp("benchmarks.sc"), relp("build.sc"), relp("client.sh"));
 	val masterBenches = runnersForImpl(impls, identity);
 	val (copyFiles: List[RelPath], copyDirectories: List[RelPath]) = masterBenches.map(_.mustCopy).flatten.distinct.partition(_.isFile) match {
@@ -128,7 +138,7 @@ def fakeRemote(withClients: Int = 1, testing: Boolean = false, impls: String = "
 	};
 	println(s"Going to copy files=${copyFiles.mkString("[", ",", "]")} and folders==${copyDirectories.mkString("[", ",", "]")}.");
 	val totalStart = System.currentTimeMillis();
-	val runId = s"run-${totalStart}";
+	val runId = runIdOrResume(resume, totalStart);
 	val nodes = (0 until withClients).map(45700 + _).map { p =>
 		val ip = "127.0.0.1";
 		val addr = s"${ip}:${p}";
@@ -185,10 +195,10 @@ def fakeRemote(withClients: Int = 1, testing: Boolean = false, impls: String = "
 
 @arg(doc ="Run local benchmarks only.")
 @main
-def local(testing: Boolean = false, impls: String = "", benchmarks: String = ""): Unit = {
+def local(testing: Boolean = false, impls: String = "", benchmarks: String = "", resume: String = ""): Unit = {
 	val runners = runnersForImpl(impls, _.localRunner(runnerAddr));
 	val totalStart = System.currentTimeMillis();
-	val runId = s"run-${totalStart}";
+	val runId = runIdOrResume(resume, totalStart);
 	val logdir = logs / runId;
 	mkdir! logdir;
 	val resultsdir = results / runId;
@@ -203,7 +213,7 @@ def local(testing: Boolean = false, impls: String = "", benchmarks: String = "")
 			val experimentRunner = getExperimentRunner(r.symbol, resultsdir, testing, benchmarks);
 			println(s"Starting run [${i+1}/$nRunners]: ${r.label}");
 			val start = System.currentTimeMillis();
-			val runner = r.run(logdir);
+			val runner = r.run(logdir, Some(resultsdir / "records"));
 			val experimenter = experimentRunner.run(logdir);
 			experimenter.waitFor();
 			runner.destroy();
@@ -233,8 +243,8 @@ private def runnersForImpl[T](impl_string: String, mapper: BenchmarkImpl => T):
 	val runners: List[T] = if (impls.isEmpty) {
 		implementations.values.map(mapper).toList;
 	} else {
-		impls.map(_.toUpperCase).flatMap(impl => {
-			val res: Option[BenchmarkImpl] = implementations.get(impl);
+		impls.map(_.toUpperCase).flatMap(impl => implementationGroups.getOrElse(impl, List(impl))).flatMap(impl => {
+			val res: Option[BenchmarkImpl] = lookupImpl(impl);
 			if (res.isEmpty) {
 				Console.err.println(s"No benchmark found for impl ${impl}!");
 			}
@@ -262,7 +272,7 @@ private def endSeparator(label: String, log: File): Unit = {
 
 private def remoteExperiment(experimentRunner: BenchmarkRunner, master: BenchmarkRunner, runId: String, logDir: Path, nodes: List[NodeEntry]): Try[Unit] = {
 	Try {
-		val runner = master.run(logDir);
+		val runner = master.run(logDir, Some(results / runId / "records"));
 		val pids = nodes.map { node =>
 			val pid = startClient(node, master.symbol, runId, masterAddr);
 			(node -> pid)
@@ -283,7 +293,7 @@ private def remoteExperiment(experimentRunner: BenchmarkRunner, master: Benchmar
 
 private def fakeRemoteExperiment(experimentRunner: BenchmarkRunner, master: BenchmarkRunner, runId: String, logDir: Path, nodes: List[NodeEntry]): Try[Unit] = {
 	Try {
-		val runner = master.run(logDir);
+		val runner = master.run(logDir, Some(results / runId / "records"));
 		val pids = nodes.map { node =>
 			val pid = startFakeClient(node, master.symbol, runId, masterAddr);
 			(node -> pid)
@@ -24,19 +24,34 @@ case class BenchmarkImpl(
 	def clientRunner(benchMasterAddr: AddressArg, benchClientAddr: AddressArg): BenchmarkRunner =
 		BenchmarkRunner(info, client(benchMasterAddr, benchClientAddr));
 	def info: BenchmarkInfo = BenchmarkInfo(symbol, label);
+	def withEnv(variantSymbol: String, variantLabel: String, vars: Map[String, String]): BenchmarkImpl = {
+		def addVars(r: Runner): Runner = r.copy(vars = r.vars ++ vars);
+		BenchmarkImpl(
+			symbol = s"${symbol}-${variantSymbol}",
+			label = s"${label} (${variantLabel})",
+			local = (benchRunnerAddr) => addVars(local(benchRunnerAddr)),
+			remote = (benchRunnerAddr, benchMasterAddr, numClients) => addVars(remote(benchRunnerAddr, benchMasterAddr, numClients)),
+			client = (benchMasterAddr, benchClientAddr) => addVars(client(benchMasterAddr, benchClientAddr)),
+			mustCopy = mustCopy)
+	}
 }
 
 case class BenchmarkInfo(symbol: String, label: String)
 
-case class Runner(env: Path, exec: Path, args: Seq[Shellable])
+case class Runner(env: Path, exec: Path, args: Seq[Shellable], vars: Map[String, String] = Map.empty)
 
 case class BenchmarkRunner(bench: BenchmarkInfo, runner: Runner) {
 	def symbol: String = bench.symbol;
 	def label: String = bench.label;
-	def run(logFolder: Path): Process = {
+	def run(logFolder: Path, recordsFolder: Option[Path] = None): Process = {
 		val command = (runner.exec.toString +: runner.args.flatMap(_.s)).toList.asJava;
 		val pb = new ProcessBuilder(command);
 		val env = pb.environment();
+		runner.vars.foreach { case (k, v) => env.put(k, v) };
+		recordsFolder.foreach { folder =>
+			mkdir! folder;
+			env.put("BENCHMARK_RESULTS", (folder / s"${fileLabel}.jsonl").toString);
+		};
 		//env.put("RUST_BACKTRACE", "1"); // TODO remove this for non-testing!
 		//env.put("JAVA_OPTS", "-Xms1G -Xmx32G -XX:+UseG1GC");
 		pb.directory(runner.env.toIO);
@@ -131,7 +146,7 @@ val implementations: Map[String, BenchmarkImpl] = Map(
 		local = (benchRunnerAddr) => Runner(relp("actix"), relp("actix/target/release/actix_benchmarks"), Seq(benchRunnerAddr)),
 		remote = (benchRunnerAddr, benchMasterAddr, numClients) => Runner(relp("actix"), relp("actix/target/release/actix_benchmarks"), Seq(benchRunnerAddr, benchMasterAddr, numClients)),
 		client = (benchMasterAddr, benchClientAddr) => Runner(relp("actix"), relp("actix/target/release/actix_benchmarks"), Seq(benchMasterAddr, benchClientAddr)),
-		mustCopy = List(relp("actix/target/release/actix_benchmarks"))
+		mustCopy = List(relp("actix/target/release/actix_benchmarks"), relp("kompact/configs"))
 	),
 	"ERLANG" -> BenchmarkImpl(
 		symbol="ERLANG",
@@ -148,9 +163,69 @@ val implementations: Map[String, BenchmarkImpl] = Map(
 		remote = (benchRunnerAddr, benchMasterAddr, numClients) => Runner(relp("riker"), relp("riker/target/release/riker_benchmarks"), Seq(benchRunnerAddr, benchMasterAddr, numClients)),
 		client = (benchMasterAddr, benchClientAddr) => Runner(relp("riker"), relp("riker/target/release/riker_benchmarks"), Seq(benchMasterAddr, benchClientAddr)),
 		mustCopy = List(relp("riker/target/release/riker_benchmarks"))
+	),
+	"BASELINE" -> BenchmarkImpl(
+		symbol="BASELINE",
+		label="Baseline",
+		local = (benchRunnerAddr) => Runner(relp("baseline"), relp("baseline/target/release/baseline_benchmarks"), Seq(benchRunnerAddr)),
+		remote = (benchRunnerAddr, benchMasterAddr, numClients) => Runner(relp("baseline"), relp("baseline/target/release/baseline_benchmarks"), Seq(benchRunnerAddr, benchMasterAddr, numClients)),
+		client = (benchMasterAddr, benchClientAddr) => Runner(relp("baseline"), relp("baseline/target/release/baseline_benchmarks"), Seq(benchMasterAddr, benchClientAddr)),
+		mustCopy = List(relp("baseline/target/release/baseline_benchmarks"))
 	)
 );
 
+// dyn_pool is left out, since it hasn't been shown to run the benchmarks yet
+val kompactExecutors: List[String] = List("small_pool", "large_pool", "channel_pool");
+
+/** Kompact implementations with a fixed executor, e.g. KOMPACTAC-CHANNEL_POOL.
+  * Not run by default, only when selected explicitly or via KOMPACT-EXECUTORS.
+  */
+val executorVariants: Map[String, BenchmarkImpl] = (for {
+	base <- List("KOMPACTAC", "KOMPACTCO", "KOMPACTMIX");
+	executor <- kompactExecutors
+} yield {
+	val variant = implementations(base).withEnv(executor.toUpperCase, executor, Map("KOMPACT_EXECUTOR" -> executor));
+	variant.symbol -> variant
+}).toMap;
+
+/** The benchmarks written once against the shared actor abstraction, run on each framework.
+  * Not run by default, only when selected explicitly or via GENERIC.
+  */
+val genericVariants: Map[String, BenchmarkImpl] = Map(
+	"KOMPACTGEN" -> BenchmarkImpl(
+		symbol="KOMPACTGEN",
+		label="Kompact Generic",
+		local = (benchRunnerAddr) => Runner(relp("kompact"), relp("kompact/target/release/kompact_benchmarks"), Seq("generic", benchRunnerAddr)),
+		remote = (benchRunnerAddr, benchMasterAddr, numClients) => Runner(relp("kompact"), relp("kompact/target/release/kompact_benchmarks"), Seq("generic", benchRunnerAddr, benchMasterAddr, numClients)),
+		client = (benchMasterAddr, benchClientAddr) => Runner(relp("kompact"), relp("kompact/target/release/kompact_benchmarks"), Seq("generic", benchMasterAddr, benchClientAddr)),
+		mustCopy = List(relp("kompact/target/release/kompact_benchmarks"), relp("kompact/configs"))
+	),
+	"ACTIXGEN" -> BenchmarkImpl(
+		symbol="ACTIXGEN",
+		label="Actix Generic",
+		local = (benchRunnerAddr) => Runner(relp("actix"), relp("actix/target/release/actix_benchmarks"), Seq("generic", benchRunnerAddr)),
+		remote = (benchRunnerAddr, benchMasterAddr, numClients) => Runner(relp("actix"), relp("actix/target/release/actix_benchmarks"), Seq("generic", benchRunnerAddr, benchMasterAddr, numClients)),
+		client = (benchMasterAddr, benchClientAddr) => Runner(relp("actix"), relp("actix/target/release/actix_benchmarks"), Seq("generic", benchMasterAddr, benchClientAddr)),
+		mustCopy = List(relp("actix/target/release/actix_benchmarks"))
+	),
+	"RIKERGEN" -> BenchmarkImpl(
+		symbol="RIKERGEN",
+		label="Riker Generic",
+		local = (benchRunnerAddr) => Runner(relp("riker"), relp("riker/target/release/riker_benchmarks"), Seq("generic", benchRunnerAddr)),
+		remote = (benchRunnerAddr, benchMasterAddr, numClients) => Runner(relp("riker"), relp("riker/target/release/riker_benchmarks"), Seq("generic", benchRunnerAddr, benchMasterAddr, numClients)),
+		client = (benchMasterAddr, benchClientAddr) => Runner(relp("riker"), relp("riker/target/release/riker_benchmarks"), Seq("generic", benchMasterAddr, benchClientAddr)),
+		mustCopy = List(relp("riker/target/release/riker_benchmarks"))
+	)
+);
+
+val implementationGroups: Map[String, List[String]] = Map(
+	"KOMPACT-EXECUTORS" -> executorVariants.keys.toList.sorted,
+	"GENERIC" -> genericVariants.keys.toList.sorted
+);
+
+def lookupImpl(symbol: String): Option[BenchmarkImpl] =
+	implementations.get(symbol).orElse(executorVariants.get(symbol)).orElse(genericVariants.get(symbol));
+
 implicit class AddressArgImpl(arg: AddressArg) {
 	private lazy val (first, second) = {
 		val split = arg.split(":");
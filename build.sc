@@ -45,6 +45,7 @@ val builders: List[Builder] = List(
 	Builder("Actix", relp("actix"), cargoEnv, cargo,  cargoBuildCmd, Seq("clean")),
 	Builder("Erlang", relp("erlang"), makeEnv, make, Seq(), Seq("clean")),
 	Builder("Riker", relp("riker"), cargoEnv, cargo,  cargoBuildCmd, Seq("clean")),
+	Builder("Baseline", relp("baseline"), cargoEnv, cargo,  cargoBuildCmd, Seq("clean")),
 );
 
 @main
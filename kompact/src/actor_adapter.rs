@@ -0,0 +1,97 @@
+//! Runs actors written against `benchmark_suite_shared::actors` as Kompact actors.
+
+use benchmark_suite_shared::actors::{self as generic, ActorSystem, Envelope, Mailbox};
+use kompact::prelude::*;
+
+#[derive(Clone)]
+pub struct KompactAdapter {
+    system: KompactSystem,
+}
+
+impl ActorSystem for KompactAdapter {
+    fn start(label: &str, num_threads: usize) -> Self {
+        let system =
+            crate::kompact_system_provider::current().new_system_with_threads(label, num_threads);
+        KompactAdapter { system }
+    }
+
+    fn spawn<A: generic::Actor>(&self, actor: A) -> generic::ActorRef<A::Message> {
+        let adapter = self.clone();
+        let component = self
+            .system
+            .create(move || KompactActor::with(actor, adapter));
+        self.system.start(&component); // don't use create_and_start to avoid unnecessary registration
+        let actor_ref = component.actor_ref().hold().expect("Live ref");
+        generic::ActorRef::new(KompactMailbox(actor_ref))
+    }
+
+    fn shutdown(self) -> Result<(), String> {
+        self.system
+            .shutdown()
+            .map_err(|e| format!("Kompact didn't shut down properly: {:?}", e))
+    }
+}
+
+struct KompactMailbox<M: Send + 'static>(ActorRefStrong<Envelope<M>>);
+
+impl<M: Send + 'static> Mailbox<M> for KompactMailbox<M> {
+    fn enqueue(&self, envelope: Envelope<M>) -> () {
+        self.0.tell(envelope);
+    }
+}
+
+#[derive(ComponentDefinition)]
+struct KompactActor<A: generic::Actor> {
+    ctx: ComponentContext<Self>,
+    actor: A,
+    system: KompactAdapter,
+    myself: Option<generic::ActorRef<A::Message>>,
+}
+
+impl<A: generic::Actor> KompactActor<A> {
+    fn with(actor: A, system: KompactAdapter) -> KompactActor<A> {
+        KompactActor {
+            ctx: ComponentContext::uninitialised(),
+            actor,
+            system,
+            myself: None,
+        }
+    }
+
+    fn stop(&mut self) -> () {
+        self.myself = None; // don't keep ourselves alive
+        self.ctx.suicide();
+    }
+}
+
+impl<A: generic::Actor> ComponentLifecycle for KompactActor<A> {}
+
+impl<A: generic::Actor> Actor for KompactActor<A> {
+    type Message = Envelope<A::Message>;
+
+    fn receive_local(&mut self, msg: Self::Message) -> Handled {
+        match msg {
+            Envelope::Msg(msg) => {
+                if self.myself.is_none() {
+                    let actor_ref = self.actor_ref().hold().expect("Live ref");
+                    self.myself = Some(generic::ActorRef::new(KompactMailbox(actor_ref)));
+                }
+                let stopped = {
+                    let myself = self.myself.as_ref().unwrap();
+                    let mut ctx = generic::Context::new(myself, &self.system);
+                    self.actor.receive(&mut ctx, msg);
+                    ctx.is_stopped()
+                };
+                if stopped {
+                    self.stop();
+                }
+            }
+            Envelope::Stop => self.stop(),
+        }
+        Handled::Ok
+    }
+
+    fn receive_network(&mut self, _msg: NetMessage) -> Handled {
+        unimplemented!(); // generic actors are local only
+    }
+}
@@ -24,6 +24,10 @@ pub mod actor_apsp {
             downcast_msg!(msg; APSPRequest)
         }
 
+        fn validate(c: &Self::Conf) -> Result<(), BenchmarkError> {
+            validation::all_pairs_shortest_path(c)
+        }
+
         fn new_instance() -> Self::Instance {
             AllPairsShortestPathI::new()
         }
@@ -61,7 +65,7 @@ pub mod actor_apsp {
                 .block_size
                 .try_into()
                 .expect("Block Size should fit into usize");
-            let system = crate::kompact_system_provider::global().new_system("apsp");
+            let system = crate::kompact_system_provider::current().new_system("apsp");
             let manager = system.create(|| ManagerActor::with(block_size));
             let manager_f = system.start_notify(&manager);
             manager_f
@@ -447,6 +451,10 @@ pub mod component_apsp {
             downcast_msg!(msg; APSPRequest)
         }
 
+        fn validate(c: &Self::Conf) -> Result<(), BenchmarkError> {
+            validation::all_pairs_shortest_path(c)
+        }
+
         fn new_instance() -> Self::Instance {
             AllPairsShortestPathI::new()
         }
@@ -484,7 +492,7 @@ pub mod component_apsp {
                 .block_size
                 .try_into()
                 .expect("Block Size should fit into usize");
-            let system = crate::kompact_system_provider::global().new_system("apsp");
+            let system = crate::kompact_system_provider::current().new_system("apsp");
             let manager = system.create(|| ManagerActor::with(block_size));
             let manager_f = system.start_notify(&manager);
             manager_f
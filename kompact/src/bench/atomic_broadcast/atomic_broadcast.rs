@@ -7,8 +7,13 @@ use super::{
     raft::{RaftComp, ReconfigurationPolicy as RaftReconfigurationPolicy},
     storage::paxos::{MemorySequence, MemoryState},
 };
-use crate::partitioning_actor::IterationControlMsg;
-use benchmark_suite_shared::kompics_benchmarks::benchmarks::AtomicBroadcastRequest;
+use crate::{
+    kompact_system_provider::{BufferSettings, NetworkSettings},
+    partitioning_actor::IterationControlMsg,
+};
+use benchmark_suite_shared::{
+    kompics_benchmarks::benchmarks::AtomicBroadcastRequest, result_writer,
+};
 use hashbrown::HashMap;
 use hdrhistogram::Histogram;
 use kompact::prelude::*;
@@ -20,7 +25,6 @@ use synchronoise::CountdownEvent;
 use super::storage::raft::DiskStorage;
 use crate::bench::atomic_broadcast::{paxos::PaxosCompMsg, raft::RaftCompMsg};
 use hocon::HoconLoader;
-use kompact::net::buffers::BufferConfig;
 use std::{
     fs::{create_dir_all, OpenOptions},
     io::Write,
@@ -38,14 +42,21 @@ pub struct ClientParams {
     algorithm: String,
     last_node_id: u64,
     reconfig_policy: String,
+    network: NetworkSettings,
 }
 
 impl ClientParams {
-    fn with(algorithm: String, last_node_id: u64, reconfig_policy: String) -> ClientParams {
+    fn with(
+        algorithm: String,
+        last_node_id: u64,
+        reconfig_policy: String,
+        network: NetworkSettings,
+    ) -> ClientParams {
         ClientParams {
             algorithm,
             last_node_id,
             reconfig_policy,
+            network,
         }
     }
 }
@@ -53,6 +64,19 @@ impl ClientParams {
 #[derive(Debug, Clone)]
 pub struct Done;
 
+/// Creates a system from `CONFIG_PATH`, with the requested `network` settings on top.
+fn create_system(name: &str, network: &NetworkSettings) -> KompactSystem {
+    let mut conf = KompactConfig::default();
+    conf.load_config_file(CONFIG_PATH);
+    crate::kompact_system_provider::current().new_remote_system_with_network_config(
+        name,
+        4,
+        conf,
+        &BufferSettings::from_config_file(CONFIG_PATH),
+        network,
+    )
+}
+
 #[derive(Default)]
 pub struct AtomicBroadcast;
 
@@ -74,15 +98,119 @@ impl DistributedBenchmark for AtomicBroadcast {
         downcast_msg!(msg; AtomicBroadcastRequest)
     }
 
+    fn validate(c: &Self::MasterConf, m: &DeploymentMetaData) -> Result<(), BenchmarkError> {
+        crate::kompact_system_provider::current().validate_network(
+            "atomicbroadcast",
+            &BufferSettings::from_config_file(CONFIG_PATH),
+            c.get_network(),
+        )?;
+        validation::positive("number_of_nodes", c.number_of_nodes)?;
+        validation::positive("number_of_proposals", c.number_of_proposals)?;
+        validation::positive("concurrent_proposals", c.concurrent_proposals)?;
+        // TODO reconfiguration
+        if c.concurrent_proposals > c.number_of_proposals {
+            return Err(BenchmarkError::invalid_param(
+                "concurrent_proposals",
+                format!(
+                    "{} should be less or equal to number_of_proposals: {}",
+                    c.concurrent_proposals, c.number_of_proposals
+                ),
+            ));
+        }
+        let reconfig_policy: &str = &c.reconfig_policy.to_lowercase();
+        match c.algorithm.to_lowercase().as_ref() {
+            "paxos" => match c.reconfiguration.to_lowercase().as_ref() {
+                "off" => {
+                    if reconfig_policy != "none" {
+                        return Err(BenchmarkError::invalid_param(
+                            "reconfig_policy",
+                            format!(
+                                "reconfiguration is off, so it should be none, but found: {}",
+                                &c.reconfig_policy
+                            ),
+                        ));
+                    }
+                }
+                "single" | "majority" => {
+                    if reconfig_policy != "eager" && reconfig_policy != "pull" {
+                        return Err(BenchmarkError::invalid_param(
+                            "reconfig_policy",
+                            format!(
+                                "unimplemented Paxos transfer policy: {}",
+                                &c.reconfig_policy
+                            ),
+                        ));
+                    }
+                }
+                _ => {
+                    return Err(BenchmarkError::invalid_param(
+                        "reconfiguration",
+                        format!(
+                            "unimplemented Paxos reconfiguration: {}",
+                            &c.reconfiguration
+                        ),
+                    ));
+                }
+            },
+            "raft" => match c.reconfiguration.to_lowercase().as_ref() {
+                "off" => {
+                    if reconfig_policy != "none" {
+                        return Err(BenchmarkError::invalid_param(
+                            "reconfig_policy",
+                            format!(
+                                "reconfiguration is off, so it should be none, but found: {}",
+                                &c.reconfig_policy
+                            ),
+                        ));
+                    }
+                }
+                "single" | "majority" => {
+                    if reconfig_policy != "replace-leader" && reconfig_policy != "replace-follower"
+                    {
+                        return Err(BenchmarkError::invalid_param(
+                            "reconfig_policy",
+                            format!("unimplemented Raft transfer policy: {}", &c.reconfig_policy),
+                        ));
+                    }
+                }
+                _ => {
+                    return Err(BenchmarkError::invalid_param(
+                        "reconfiguration",
+                        format!("unimplemented Raft reconfiguration: {}", &c.reconfiguration),
+                    ));
+                }
+            },
+            _ => {
+                return Err(BenchmarkError::invalid_param(
+                    "algorithm",
+                    format!("unimplemented atomic broadcast algorithm: {}", &c.algorithm),
+                ));
+            }
+        };
+        let (additional_n, _) = get_reconfig_data(&c.reconfiguration, c.number_of_nodes)?;
+        let n = c.number_of_nodes + additional_n;
+        if (m.number_of_clients() as u64) < n {
+            return Err(BenchmarkError::invalid_param(
+                "number_of_nodes",
+                format!(
+                    "{} nodes (including reconfiguration) are needed, but only {} clients are available",
+                    n,
+                    m.number_of_clients()
+                ),
+            ));
+        }
+        Ok(())
+    }
+
     fn new_client() -> Self::Client {
         AtomicBroadcastClient::new()
     }
 
     fn str_to_client_conf(s: String) -> Result<Self::ClientConf, BenchmarkError> {
         let split: Vec<_> = s.split(',').collect();
-        if split.len() != 3 {
+        if split.len() != 4 {
             Err(BenchmarkError::InvalidMessage(format!(
-                "String '{}' does not represent a client conf! Split length should be 3",
+                "String '{}' does not represent a client conf! Split length should be 4",
                 s
             )))
         } else {
@@ -94,7 +222,18 @@ impl DistributedBenchmark for AtomicBroadcast {
                 ))
             })?;
             let reconfig_policy = split[2].to_lowercase();
-            Ok(ClientParams::with(algorithm, last_node_id, reconfig_policy))
+            let network = split[3].parse::<NetworkSettings>().map_err(|e| {
+                BenchmarkError::InvalidMessage(format!(
+                    "String to ClientConf error: '{}' does not represent network settings: {}",
+                    split[3], e
+                ))
+            })?;
+            Ok(ClientParams::with(
+                algorithm,
+                last_node_id,
+                reconfig_policy,
+                network,
+            ))
         }
     }
 
@@ -106,7 +245,10 @@ impl DistributedBenchmark for AtomicBroadcast {
     }
 
     fn client_conf_to_str(c: Self::ClientConf) -> String {
-        format!("{},{},{}", c.algorithm, c.last_node_id, c.reconfig_policy)
+        format!(
+            "{},{},{},{}",
+            c.algorithm, c.last_node_id, c.reconfig_policy, c.network
+        )
     }
 
     fn client_data_to_str(d: Self::ClientData) -> String {
@@ -310,94 +452,6 @@ impl AtomicBroadcastMaster {
         (client_comp, client_path)
     }
 
-    fn validate_experiment_params(
-        &mut self,
-        c: &AtomicBroadcastRequest,
-        num_clients: u32,
-    ) -> Result<(), BenchmarkError> {
-        // TODO reconfiguration
-        if c.concurrent_proposals > c.number_of_proposals {
-            return Err(BenchmarkError::InvalidTest(format!(
-                "Concurrent proposals: {} should be less or equal to number of proposals: {}",
-                c.concurrent_proposals, c.number_of_proposals
-            )));
-        }
-        match &c.algorithm.to_lowercase() {
-            paxos if paxos == "paxos" => match c.reconfiguration.to_lowercase().as_ref() {
-                "off" => {
-                    if c.reconfig_policy.to_lowercase() != "none" {
-                        return Err(BenchmarkError::InvalidTest(format!(
-                            "Reconfiguration is off, transfer policy should be none, but found: {}",
-                            &c.reconfig_policy
-                        )));
-                    }
-                }
-                s if s == "single" || s == "majority" => {
-                    let reconfig_policy: &str = &c.reconfig_policy.to_lowercase();
-                    if reconfig_policy != "eager" && reconfig_policy != "pull" {
-                        return Err(BenchmarkError::InvalidTest(format!(
-                            "Unimplemented Paxos transfer policy: {}",
-                            &c.reconfig_policy
-                        )));
-                    }
-                }
-                _ => {
-                    return Err(BenchmarkError::InvalidTest(format!(
-                        "Unimplemented Paxos reconfiguration: {}",
-                        &c.reconfiguration
-                    )));
-                }
-            },
-            raft if raft == "raft" => match c.reconfiguration.to_lowercase().as_ref() {
-                "off" => {
-                    if c.reconfig_policy.to_lowercase() != "none" {
-                        return Err(BenchmarkError::InvalidTest(format!(
-                            "Reconfiguration is off, transfer policy should be none, but found: {}",
-                            &c.reconfig_policy
-                        )));
-                    }
-                }
-                s if s == "single" || s == "majority" => {
-                    let reconfig_policy: &str = &c.reconfig_policy.to_lowercase();
-                    if reconfig_policy != "replace-leader" && reconfig_policy != "replace-follower"
-                    {
-                        return Err(BenchmarkError::InvalidTest(format!(
-                            "Unimplemented Raft transfer policy: {}",
-                            &c.reconfig_policy
-                        )));
-                    }
-                }
-                _ => {
-                    return Err(BenchmarkError::InvalidTest(format!(
-                        "Unimplemented Raft reconfiguration: {}",
-                        &c.reconfiguration
-                    )));
-                }
-            },
-            _ => {
-                return Err(BenchmarkError::InvalidTest(format!(
-                    "Unimplemented atomic broadcast algorithm: {}",
-                    &c.algorithm
-                )));
-            }
-        };
-        match get_reconfig_data(&c.reconfiguration, c.number_of_nodes) {
-            Ok((additional_n, reconfig)) => {
-                let n = c.number_of_nodes + additional_n;
-                if (num_clients as u64) < n {
-                    return Err(BenchmarkError::InvalidTest(format!(
-                        "Not enough clients: {}, Required: {}",
-                        num_clients, n
-                    )));
-                }
-                self.reconfiguration = reconfig;
-                self.num_nodes = Some(n);
-            }
-            Err(e) => return Err(e),
-        }
-        Ok(())
-    }
-
     pub fn load_benchmark_config<P>(path: P) -> (Duration, Option<String>)
     where
         P: Into<PathBuf>,
@@ -504,13 +558,10 @@ impl AtomicBroadcastMaster {
             0.001, 0.01, 0.005, 0.1, 0.2, 0.3, 0.4, 0.5, 0.6, 0.7, 0.8, 0.9, 0.95, 0.99, 0.999,
         ];
         for q in &quantiles {
-            writeln!(
-                file,
-                "Value at quantile {}: {} micro s",
-                q,
-                hist.value_at_quantile(*q)
-            )
-            .expect("Failed to write summary latency file");
+            let value = hist.value_at_quantile(*q);
+            writeln!(file, "Value at quantile {}: {} micro s", q, value)
+                .expect("Failed to write summary latency file");
+            result_writer::record_metric(format!("latency_us_q{}", q), value as f64);
         }
         let max = hist.max();
         writeln!(
@@ -523,11 +574,16 @@ impl AtomicBroadcastMaster {
         .expect("Failed to write histogram summary");
         writeln!(file, "Total elements: {}", hist.len())
             .expect("Failed to write histogram summary");
+        result_writer::record_metric("latency_us_min", hist.min() as f64);
+        result_writer::record_metric("latency_us_max", max as f64);
+        result_writer::record_metric("latency_us_mean", hist.mean());
+        result_writer::record_metric("latency_count", hist.len() as f64);
         file.flush().expect("Failed to flush histogram file");
     }
 
     fn persist_timeouts_summary(&mut self) {
         let sum: u64 = self.num_timed_out.iter().sum();
+        result_writer::record_metric("timed_out_proposals", sum as f64);
         if sum > 0 {
             let meta_path = self
                 .meta_results_path
@@ -570,10 +626,13 @@ impl DistributedBenchmarkMaster for AtomicBroadcastMaster {
     fn setup(
         &mut self,
         c: Self::MasterConf,
-        m: &DeploymentMetaData,
+        _m: &DeploymentMetaData,
     ) -> Result<Self::ClientConf, BenchmarkError> {
         println!("Setting up Atomic Broadcast (Master)");
-        self.validate_experiment_params(&c, m.number_of_clients())?;
+        let (additional_n, reconfiguration) =
+            get_reconfig_data(&c.reconfiguration, c.number_of_nodes)?;
+        self.reconfiguration = reconfiguration;
+        self.num_nodes = Some(c.number_of_nodes + additional_n);
         let experiment_str = format!(
             "{},{},{},{},{},{}",
             c.algorithm,
@@ -592,20 +651,15 @@ impl DistributedBenchmarkMaster for AtomicBroadcastMaster {
             self.latency_hist =
                 Some(Histogram::<u64>::new(4).expect("Failed to create latency histogram"));
         }
-        let mut conf = KompactConfig::default();
-        conf.load_config_file(CONFIG_PATH);
-        let bc = BufferConfig::from_config_file(CONFIG_PATH);
-        bc.validate();
-        let tcp_no_delay = true;
-        let system = crate::kompact_system_provider::global()
-            .new_remote_system_with_threads_config("atomicbroadcast", 4, conf, bc, tcp_no_delay);
+        let network = NetworkSettings::from_request(c.get_network());
+        let system = create_system("atomicbroadcast", &network);
         self.system = Some(system);
         let last_node_id = if self.reconfiguration.is_some() {
             c.number_of_nodes + 1
         } else {
             c.number_of_nodes
         };
-        let params = ClientParams::with(c.algorithm, last_node_id, c.reconfig_policy);
+        let params = ClientParams::with(c.algorithm, last_node_id, c.reconfig_policy, network);
         Ok(params)
     }
 
@@ -733,13 +787,7 @@ impl DistributedBenchmarkClient for AtomicBroadcastClient {
 
     fn setup(&mut self, c: Self::ClientConf) -> Self::ClientData {
         println!("Setting up Atomic Broadcast (client)");
-        let mut conf = KompactConfig::default();
-        conf.load_config_file(CONFIG_PATH);
-        let bc = BufferConfig::from_config_file(CONFIG_PATH);
-        bc.validate();
-        let tcp_no_delay = true;
-        let system = crate::kompact_system_provider::global()
-            .new_remote_system_with_threads_config("atomicbroadcast", 4, conf, bc, tcp_no_delay);
+        let system = create_system("atomicbroadcast", &c.network);
         let named_path = match c.algorithm.as_ref() {
             "paxos" => {
                 let experiment_configs = get_experiment_configs(c.last_node_id);
@@ -845,6 +893,7 @@ impl DistributedBenchmarkClient for AtomicBroadcastClient {
 pub mod tests {
     use super::*;
     use crate::bench::atomic_broadcast::paxos::ballot_leader_election::Ballot;
+    use kompact::net::buffers::BufferConfig;
     use leaderpaxos::storage::SequenceTraits;
 
     #[derive(Debug)]
@@ -896,7 +945,7 @@ pub mod tests {
         bc.validate();
         let tcp_no_delay = true;
         for i in 1..=n {
-            let system = kompact_benchmarks::kompact_system_provider::global()
+            let system = kompact_benchmarks::kompact_system_provider::current()
                 .new_remote_system_with_threads_config(
                     format!("node{}", i),
                     4,
@@ -1,6 +1,9 @@
 use super::*;
 
-use crate::partitioning_actor::*;
+use crate::{
+    kompact_system_provider::{BufferSettings, NetworkSettings},
+    partitioning_actor::*,
+};
 #[cfg(test)]
 use benchmark_suite_shared::test_utils::all_linearizable;
 use benchmark_suite_shared::{
@@ -20,13 +23,20 @@ pub struct ClientParams {
     num_keys: u64,
     read_workload: f32,
     write_workload: f32,
+    network: NetworkSettings,
 }
 impl ClientParams {
-    fn new(num_keys: u64, read_workload: f32, write_workload: f32) -> ClientParams {
+    fn new(
+        num_keys: u64,
+        read_workload: f32,
+        write_workload: f32,
+        network: NetworkSettings,
+    ) -> ClientParams {
         ClientParams {
             num_keys,
             read_workload,
             write_workload,
+            network,
         }
     }
 }
@@ -56,13 +66,22 @@ pub mod actor_atomicregister {
             downcast_msg!(msg; AtomicRegisterRequest)
         }
 
+        fn validate(c: &Self::MasterConf, meta: &DeploymentMetaData) -> Result<(), BenchmarkError> {
+            validation::atomic_register(c, meta)?;
+            crate::kompact_system_provider::current().validate_network(
+                "atomicregister",
+                &BufferSettings::default(),
+                c.get_network(),
+            )
+        }
+
         fn new_client() -> Self::Client {
             AtomicRegisterClient::new()
         }
 
         fn str_to_client_conf(str: String) -> Result<Self::ClientConf, BenchmarkError> {
             let split: Vec<_> = str.split(',').collect();
-            if split.len() != 3 {
+            if split.len() != 4 {
                 Err(BenchmarkError::InvalidMessage(format!(
                     "String '{}' does not represent a client conf!",
                     str
@@ -89,7 +108,18 @@ pub mod actor_atomicregister {
                         str, e
                     ))
                 })?;
-                Ok(ClientParams::new(num_keys, read_workload, write_workload))
+                let network = split[3].parse::<NetworkSettings>().map_err(|e| {
+                    BenchmarkError::InvalidMessage(format!(
+                        "String '{}' does not represent a client conf: {}",
+                        str, e
+                    ))
+                })?;
+                Ok(ClientParams::new(
+                    num_keys,
+                    read_workload,
+                    write_workload,
+                    network,
+                ))
             }
         }
 
@@ -101,7 +131,10 @@ pub mod actor_atomicregister {
         }
 
         fn client_conf_to_str(c: Self::ClientConf) -> String {
-            format!("{},{},{}", c.num_keys, c.read_workload, c.write_workload)
+            format!(
+                "{},{},{},{}",
+                c.num_keys, c.read_workload, c.write_workload, c.network
+            )
         }
 
         fn client_data_to_str(d: Self::ClientData) -> String {
@@ -145,27 +178,25 @@ pub mod actor_atomicregister {
         fn setup(
             &mut self,
             c: Self::MasterConf,
-            m: &DeploymentMetaData,
+            _m: &DeploymentMetaData,
         ) -> Result<Self::ClientConf, BenchmarkError> {
             println!("Setting up Atomic Register(Master)");
-            if m.number_of_clients() < c.partition_size - 1 {
-                return Err(BenchmarkError::InvalidTest(format!(
-                    "Not enough clients: {}, Partition size: {}",
-                    &m.number_of_clients(),
-                    &c.partition_size
-                )));
-            }
             self.read_workload = Some(c.read_workload);
             self.write_workload = Some(c.write_workload);
             self.partition_size = Some(c.partition_size);
             self.num_keys = Some(c.number_of_keys);
-            let system = crate::kompact_system_provider::global()
-                .new_remote_system_with_threads("atomicregister", 4);
+            let network = NetworkSettings::from_request(c.get_network());
+            let system = crate::kompact_system_provider::current().new_remote_system_with_network(
+                "atomicregister",
+                4,
+                &network,
+            );
             self.system = Some(system);
             let params = ClientParams {
                 num_keys: c.number_of_keys,
                 read_workload: c.read_workload,
                 write_workload: c.write_workload,
+                network,
             };
             Ok(params)
         }
@@ -315,8 +346,11 @@ pub mod actor_atomicregister {
 
         fn setup(&mut self, c: Self::ClientConf) -> Self::ClientData {
             println!("Setting up Atomic Register(client)");
-            let system = crate::kompact_system_provider::global()
-                .new_remote_system_with_threads("atomicregister", 4);
+            let system = crate::kompact_system_provider::current().new_remote_system_with_network(
+                "atomicregister",
+                4,
+                &c.network,
+            );
             let (atomic_register, unique_reg_f) = system.create_and_register(|| {
                 AtomicRegisterActor::with(c.num_keys, c.read_workload, c.write_workload, false)
             });
@@ -711,7 +745,7 @@ pub mod actor_atomicregister {
                 (read_workload, write_workload)
             );
             for i in 0..partition_size {
-                let system = crate::kompact_system_provider::global()
+                let system = crate::kompact_system_provider::current()
                     .new_remote_system_with_threads(format!("atomicregister{}", i), 4);
                 let (atomic_register, unique_reg_f) = system.create_and_register(|| {
                     AtomicRegisterActor::with(
@@ -804,13 +838,22 @@ pub mod mixed_atomicregister {
             downcast_msg!(msg; AtomicRegisterRequest)
         }
 
+        fn validate(c: &Self::MasterConf, meta: &DeploymentMetaData) -> Result<(), BenchmarkError> {
+            validation::atomic_register(c, meta)?;
+            crate::kompact_system_provider::current().validate_network(
+                "atomicregister",
+                &BufferSettings::default(),
+                c.get_network(),
+            )
+        }
+
         fn new_client() -> Self::Client {
             AtomicRegisterClient::new()
         }
 
         fn str_to_client_conf(str: String) -> Result<Self::ClientConf, BenchmarkError> {
             let split: Vec<_> = str.split(',').collect();
-            if split.len() != 3 {
+            if split.len() != 4 {
                 Err(BenchmarkError::InvalidMessage(format!(
                     "String '{}' does not represent a client conf!",
                     str
@@ -838,7 +881,18 @@ pub mod mixed_atomicregister {
                         str, e
                     ))
                 })?;
-                Ok(ClientParams::new(num_keys, read_workload, write_workload))
+                let network = split[3].parse::<NetworkSettings>().map_err(|e| {
+                    BenchmarkError::InvalidMessage(format!(
+                        "String '{}' does not represent a client conf: {}",
+                        str, e
+                    ))
+                })?;
+                Ok(ClientParams::new(
+                    num_keys,
+                    read_workload,
+                    write_workload,
+                    network,
+                ))
             }
         }
 
@@ -850,7 +904,10 @@ pub mod mixed_atomicregister {
         }
 
         fn client_conf_to_str(c: Self::ClientConf) -> String {
-            format!("{},{},{}", c.num_keys, c.read_workload, c.write_workload)
+            format!(
+                "{},{},{},{}",
+                c.num_keys, c.read_workload, c.write_workload, c.network
+            )
         }
 
         fn client_data_to_str(d: Self::ClientData) -> String {
@@ -896,27 +953,25 @@ pub mod mixed_atomicregister {
         fn setup(
             &mut self,
             c: Self::MasterConf,
-            m: &DeploymentMetaData,
+            _m: &DeploymentMetaData,
         ) -> Result<Self::ClientConf, BenchmarkError> {
             println!("Setting up Atomic Register(Master)");
-            if m.number_of_clients() < c.partition_size - 1 {
-                return Err(BenchmarkError::InvalidTest(format!(
-                    "Not enough clients: {}, Partition size: {}",
-                    &m.number_of_clients(),
-                    &c.partition_size
-                )));
-            }
             self.read_workload = Some(c.read_workload);
             self.write_workload = Some(c.write_workload);
             self.partition_size = Some(c.partition_size);
             self.num_keys = Some(c.number_of_keys);
-            let system = crate::kompact_system_provider::global()
-                .new_remote_system_with_threads("atomicregister", 4);
+            let network = NetworkSettings::from_request(c.get_network());
+            let system = crate::kompact_system_provider::current().new_remote_system_with_network(
+                "atomicregister",
+                4,
+                &network,
+            );
             self.system = Some(system);
             let params = ClientParams {
                 num_keys: c.number_of_keys,
                 read_workload: c.read_workload,
                 write_workload: c.write_workload,
+                network,
             };
             Ok(params)
         }
@@ -992,7 +1047,6 @@ pub mod mixed_atomicregister {
                     //     },
                     // )
 
-
                     /*** Setup partitioning actor ***/
                     let (partitioning_actor, unique_reg_f) = system.create_and_register(|| {
                         PartitioningActor::with(
@@ -1107,8 +1161,11 @@ pub mod mixed_atomicregister {
 
         fn setup(&mut self, c: Self::ClientConf) -> Self::ClientData {
             println!("Setting up Atomic Register(client)");
-            let system = crate::kompact_system_provider::global()
-                .new_remote_system_with_threads("atomicregister", 4);
+            let system = crate::kompact_system_provider::current().new_remote_system_with_network(
+                "atomicregister",
+                4,
+                &c.network,
+            );
             /*** Setup Broadcast component ***/
             let (bcast_comp, unique_reg_f) = system.create_and_register(BroadcastComp::new);
             let bcast_comp_f = system.start_notify(&bcast_comp);
@@ -1634,7 +1691,7 @@ pub mod mixed_atomicregister {
                 (read_workload, write_workload)
             );
             for i in 0..partition_size {
-                let system = crate::kompact_system_provider::global()
+                let system = crate::kompact_system_provider::current()
                     .new_remote_system_with_threads(format!("atomicregister{}", i), 4);
 
                 /*** Setup Broadcast component ***/
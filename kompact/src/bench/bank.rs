@@ -0,0 +1,289 @@
+use super::*;
+
+use benchmark_suite_shared::{
+    helpers::bank::{TransferGenerator, INITIAL_BALANCE},
+    kompics_benchmarks::benchmarks::BankRequest,
+};
+use kompact::prelude::*;
+use std::{convert::TryInto, sync::Arc};
+use synchronoise::CountdownEvent;
+
+#[derive(Default)]
+pub struct Bank;
+
+impl Benchmark for Bank {
+    type Conf = BankRequest;
+    type Instance = BankI;
+
+    fn msg_to_conf(msg: Box<dyn (::protobuf::Message)>) -> Result<Self::Conf, BenchmarkError> {
+        downcast_msg!(msg; BankRequest)
+    }
+
+    fn validate(c: &Self::Conf) -> Result<(), BenchmarkError> {
+        validation::bank(c)
+    }
+
+    fn new_instance() -> Self::Instance {
+        BankI::new()
+    }
+
+    const LABEL: &'static str = "Bank";
+}
+
+pub struct BankI {
+    num_accounts: Option<usize>,
+    num_transactions: Option<u64>,
+    seed: Option<u64>,
+    system: Option<KompactSystem>,
+    teller: Option<Arc<Component<Teller>>>,
+    accounts: Vec<Arc<Component<Account>>>,
+    latch: Option<Arc<CountdownEvent>>,
+}
+
+impl BankI {
+    fn new() -> BankI {
+        BankI {
+            num_accounts: None,
+            num_transactions: None,
+            seed: None,
+            system: None,
+            teller: None,
+            accounts: Vec::new(),
+            latch: None,
+        }
+    }
+}
+
+impl BenchmarkInstance for BankI {
+    type Conf = BankRequest;
+
+    fn setup(&mut self, c: &Self::Conf) -> () {
+        self.num_accounts = Some(c.number_of_accounts.try_into().unwrap());
+        self.num_transactions = Some(c.number_of_transactions);
+        self.seed = Some(c.seed);
+        let system = crate::kompact_system_provider::current().new_system("bank");
+        self.system = Some(system);
+    }
+
+    fn prepare_iteration(&mut self) -> () {
+        match (
+            self.system.as_ref(),
+            self.num_accounts,
+            self.num_transactions,
+            self.seed,
+        ) {
+            (Some(system), Some(num_accounts), Some(num_transactions), Some(seed)) => {
+                let latch = Arc::new(CountdownEvent::new(1));
+                let generator = TransferGenerator::new(seed, num_accounts);
+                let teller =
+                    system.create(|| Teller::with(num_transactions, generator, latch.clone()));
+                let teller_ref = teller.actor_ref().hold().expect("Live ref");
+                let accounts: Vec<Arc<Component<Account>>> = (0..num_accounts)
+                    .map(|id| {
+                        let teller_ref = teller_ref.clone();
+                        system.create(move || Account::with(id, teller_ref))
+                    })
+                    .collect();
+                let account_refs: Vec<ActorRefStrong<AccountMsg>> = accounts
+                    .iter()
+                    .map(|a| a.actor_ref().hold().expect("Live ref"))
+                    .collect();
+                for account in accounts.iter() {
+                    let peers = account_refs.clone();
+                    account.on_definition(|def| def.peers = peers);
+                }
+                teller.on_definition(|def| def.accounts = account_refs);
+                let mut start_fs: Vec<_> =
+                    accounts.iter().map(|a| system.start_notify(a)).collect();
+                start_fs.push(system.start_notify(&teller));
+                for f in start_fs {
+                    f.wait_timeout(Duration::from_millis(1000))
+                        .expect("Component never started!");
+                }
+                self.teller = Some(teller);
+                self.accounts = accounts;
+                self.latch = Some(latch);
+            }
+            _ => unimplemented!(),
+        }
+    }
+
+    fn run_iteration(&mut self) -> () {
+        match self.teller {
+            Some(ref teller) => {
+                let latch = self.latch.take().unwrap();
+                teller.actor_ref().tell(TellerMsg::Start);
+                latch.wait();
+            }
+            None => unimplemented!(),
+        }
+    }
+
+    fn cleanup_iteration(&mut self, last_iteration: bool, _exec_time_millis: f64) -> () {
+        let total: i64 = self
+            .accounts
+            .iter()
+            .map(|a| a.on_definition(|def| def.balance))
+            .sum();
+        let num_accounts = self.num_accounts.unwrap() as i64;
+        assert_eq!(
+            total,
+            num_accounts * INITIAL_BALANCE,
+            "Money was not conserved!"
+        );
+
+        let system = self.system.take().unwrap();
+        let f = system.kill_notify(self.teller.take().unwrap());
+        f.wait_timeout(Duration::from_millis(1000))
+            .expect("Teller never died!");
+        for account in self.accounts.drain(..) {
+            let f = system.kill_notify(account);
+            f.wait_timeout(Duration::from_millis(1000))
+                .expect("Account never died!");
+        }
+
+        if last_iteration {
+            system
+                .shutdown()
+                .expect("Kompics didn't shut down properly");
+            self.num_accounts = None;
+            self.num_transactions = None;
+            self.seed = None;
+        } else {
+            self.system = Some(system);
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum TellerMsg {
+    Start,
+    TransferDone,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum AccountMsg {
+    Debit { amount: i64, destination: usize },
+    Credit { amount: i64, source: usize },
+    CreditAck,
+}
+
+#[derive(ComponentDefinition)]
+struct Teller {
+    ctx: ComponentContext<Self>,
+    num_transactions: u64,
+    remaining: u64,
+    generator: TransferGenerator,
+    accounts: Vec<ActorRefStrong<AccountMsg>>,
+    latch: Arc<CountdownEvent>,
+}
+
+impl Teller {
+    fn with(
+        num_transactions: u64,
+        generator: TransferGenerator,
+        latch: Arc<CountdownEvent>,
+    ) -> Teller {
+        Teller {
+            ctx: ComponentContext::uninitialised(),
+            num_transactions,
+            remaining: num_transactions,
+            generator,
+            accounts: Vec::new(),
+            latch,
+        }
+    }
+}
+
+impl ComponentLifecycle for Teller {
+    fn on_kill(&mut self) -> Handled {
+        self.accounts.clear(); // the accounts hold a reference to the teller
+        Handled::Ok
+    }
+}
+
+impl Actor for Teller {
+    type Message = TellerMsg;
+
+    fn receive_local(&mut self, msg: Self::Message) -> Handled {
+        match msg {
+            TellerMsg::Start => {
+                for _ in 0..self.num_transactions {
+                    let transfer = self.generator.next_transfer();
+                    self.accounts[transfer.source].tell(AccountMsg::Debit {
+                        amount: transfer.amount,
+                        destination: transfer.destination,
+                    });
+                }
+            }
+            TellerMsg::TransferDone => {
+                self.remaining -= 1u64;
+                if self.remaining == 0 {
+                    self.latch.decrement().expect("Should decrement!");
+                }
+            }
+        }
+        Handled::Ok
+    }
+
+    fn receive_network(&mut self, _msg: NetMessage) -> Handled {
+        unimplemented!();
+    }
+}
+
+#[derive(ComponentDefinition)]
+struct Account {
+    ctx: ComponentContext<Self>,
+    id: usize,
+    balance: i64,
+    peers: Vec<ActorRefStrong<AccountMsg>>,
+    teller: ActorRefStrong<TellerMsg>,
+}
+
+impl Account {
+    fn with(id: usize, teller: ActorRefStrong<TellerMsg>) -> Account {
+        Account {
+            ctx: ComponentContext::uninitialised(),
+            id,
+            balance: INITIAL_BALANCE,
+            peers: Vec::new(),
+            teller,
+        }
+    }
+}
+
+impl ComponentLifecycle for Account {
+    fn on_kill(&mut self) -> Handled {
+        self.peers.clear(); // the accounts all hold references to each other
+        Handled::Ok
+    }
+}
+
+impl Actor for Account {
+    type Message = AccountMsg;
+
+    fn receive_local(&mut self, msg: Self::Message) -> Handled {
+        match msg {
+            AccountMsg::Debit {
+                amount,
+                destination,
+            } => {
+                self.balance -= amount;
+                self.peers[destination].tell(AccountMsg::Credit {
+                    amount,
+                    source: self.id,
+                });
+            }
+            AccountMsg::Credit { amount, source } => {
+                self.balance += amount;
+                self.peers[source].tell(AccountMsg::CreditAck);
+            }
+            AccountMsg::CreditAck => self.teller.tell(TellerMsg::TransferDone),
+        }
+        Handled::Ok
+    }
+
+    fn receive_network(&mut self, _msg: NetMessage) -> Handled {
+        unimplemented!();
+    }
+}
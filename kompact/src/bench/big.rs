@@ -0,0 +1,247 @@
+use super::*;
+
+use benchmark_suite_shared::{
+    helpers::big::PeerSampler, kompics_benchmarks::benchmarks::BigRequest,
+};
+use kompact::prelude::*;
+use std::{convert::TryInto, sync::Arc};
+use synchronoise::CountdownEvent;
+
+#[derive(Default)]
+pub struct Big;
+
+impl Benchmark for Big {
+    type Conf = BigRequest;
+    type Instance = BigI;
+
+    fn msg_to_conf(msg: Box<dyn (::protobuf::Message)>) -> Result<Self::Conf, BenchmarkError> {
+        downcast_msg!(msg; BigRequest)
+    }
+
+    fn validate(c: &Self::Conf) -> Result<(), BenchmarkError> {
+        validation::big(c)
+    }
+
+    fn new_instance() -> Self::Instance {
+        BigI::new()
+    }
+
+    const LABEL: &'static str = "Big";
+}
+
+pub struct BigI {
+    num_actors: Option<usize>,
+    num_pings: Option<u64>,
+    seed: Option<u64>,
+    system: Option<KompactSystem>,
+    sink: Option<Arc<Component<Sink>>>,
+    actors: Vec<Arc<Component<BigActor>>>,
+    latch: Option<Arc<CountdownEvent>>,
+}
+
+impl BigI {
+    fn new() -> BigI {
+        BigI {
+            num_actors: None,
+            num_pings: None,
+            seed: None,
+            system: None,
+            sink: None,
+            actors: Vec::new(),
+            latch: None,
+        }
+    }
+}
+
+impl BenchmarkInstance for BigI {
+    type Conf = BigRequest;
+
+    fn setup(&mut self, c: &Self::Conf) -> () {
+        self.num_actors = Some(c.number_of_actors.try_into().unwrap());
+        self.num_pings = Some(c.number_of_pings);
+        self.seed = Some(c.seed);
+        let system = crate::kompact_system_provider::current().new_system("big");
+        self.system = Some(system);
+    }
+
+    fn prepare_iteration(&mut self) -> () {
+        match (
+            self.system.as_ref(),
+            self.num_actors,
+            self.num_pings,
+            self.seed,
+        ) {
+            (Some(system), Some(num_actors), Some(num_pings), Some(seed)) => {
+                let latch = Arc::new(CountdownEvent::new(1));
+                let sink = system.create(|| Sink::with(num_actors, latch.clone()));
+                let sink_ref = sink.actor_ref().hold().expect("Live ref");
+                let actors: Vec<Arc<Component<BigActor>>> = (0..num_actors)
+                    .map(|id| {
+                        let sampler = PeerSampler::new(seed, id, num_actors);
+                        let sink_ref = sink_ref.clone();
+                        system.create(move || BigActor::with(id, num_pings, sampler, sink_ref))
+                    })
+                    .collect();
+                let peers: Vec<ActorRefStrong<BigMsg>> = actors
+                    .iter()
+                    .map(|a| a.actor_ref().hold().expect("Live ref"))
+                    .collect();
+                for actor in actors.iter() {
+                    let peers = peers.clone();
+                    actor.on_definition(|def| def.peers = peers);
+                }
+                let mut start_fs: Vec<_> = actors.iter().map(|a| system.start_notify(a)).collect();
+                start_fs.push(system.start_notify(&sink));
+                for f in start_fs {
+                    f.wait_timeout(Duration::from_millis(1000))
+                        .expect("Component never started!");
+                }
+                self.sink = Some(sink);
+                self.actors = actors;
+                self.latch = Some(latch);
+            }
+            _ => unimplemented!(),
+        }
+    }
+
+    fn run_iteration(&mut self) -> () {
+        let latch = self.latch.take().unwrap();
+        for actor in self.actors.iter() {
+            actor.actor_ref().tell(BigMsg::Start);
+        }
+        latch.wait();
+    }
+
+    fn cleanup_iteration(&mut self, last_iteration: bool, _exec_time_millis: f64) -> () {
+        let system = self.system.take().unwrap();
+        for actor in self.actors.drain(..) {
+            let f = system.kill_notify(actor);
+            f.wait_timeout(Duration::from_millis(1000))
+                .expect("BigActor never died!");
+        }
+        let f = system.kill_notify(self.sink.take().unwrap());
+        f.wait_timeout(Duration::from_millis(1000))
+            .expect("Sink never died!");
+
+        if last_iteration {
+            system
+                .shutdown()
+                .expect("Kompics didn't shut down properly");
+            self.num_actors = None;
+            self.num_pings = None;
+            self.seed = None;
+        } else {
+            self.system = Some(system);
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum BigMsg {
+    Start,
+    Ping { sender: usize },
+    Pong,
+}
+
+/// Sent to the sink by every actor that got all its pongs.
+#[derive(Debug, Clone, Copy)]
+struct Done;
+
+#[derive(ComponentDefinition)]
+struct BigActor {
+    ctx: ComponentContext<Self>,
+    id: usize,
+    pings_left: u64,
+    sampler: PeerSampler,
+    peers: Vec<ActorRefStrong<BigMsg>>,
+    sink: ActorRefStrong<Done>,
+}
+
+impl BigActor {
+    fn with(
+        id: usize,
+        num_pings: u64,
+        sampler: PeerSampler,
+        sink: ActorRefStrong<Done>,
+    ) -> BigActor {
+        BigActor {
+            ctx: ComponentContext::uninitialised(),
+            id,
+            pings_left: num_pings,
+            sampler,
+            peers: Vec::new(),
+            sink,
+        }
+    }
+
+    fn send_ping(&mut self) -> () {
+        self.pings_left -= 1u64;
+        let peer = self.sampler.next_peer();
+        self.peers[peer].tell(BigMsg::Ping { sender: self.id });
+    }
+}
+
+impl ComponentLifecycle for BigActor {
+    fn on_kill(&mut self) -> Handled {
+        self.peers.clear(); // the actors all hold references to each other
+        Handled::Ok
+    }
+}
+
+impl Actor for BigActor {
+    type Message = BigMsg;
+
+    fn receive_local(&mut self, msg: Self::Message) -> Handled {
+        match msg {
+            BigMsg::Start => self.send_ping(),
+            BigMsg::Ping { sender } => self.peers[sender].tell(BigMsg::Pong),
+            BigMsg::Pong => {
+                if self.pings_left > 0 {
+                    self.send_ping();
+                } else {
+                    self.sink.tell(Done);
+                }
+            }
+        }
+        Handled::Ok
+    }
+
+    fn receive_network(&mut self, _msg: NetMessage) -> Handled {
+        unimplemented!();
+    }
+}
+
+#[derive(ComponentDefinition)]
+struct Sink {
+    ctx: ComponentContext<Self>,
+    remaining: usize,
+    latch: Arc<CountdownEvent>,
+}
+
+impl Sink {
+    fn with(num_actors: usize, latch: Arc<CountdownEvent>) -> Sink {
+        Sink {
+            ctx: ComponentContext::uninitialised(),
+            remaining: num_actors,
+            latch,
+        }
+    }
+}
+
+ignore_lifecycle!(Sink);
+
+impl Actor for Sink {
+    type Message = Done;
+
+    fn receive_local(&mut self, _msg: Self::Message) -> Handled {
+        self.remaining -= 1;
+        if self.remaining == 0 {
+            self.latch.decrement().expect("Should decrement!");
+        }
+        Handled::Ok
+    }
+
+    fn receive_network(&mut self, _msg: NetMessage) -> Handled {
+        unimplemented!();
+    }
+}
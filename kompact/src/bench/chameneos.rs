@@ -21,6 +21,10 @@ pub mod actor_chameneos {
             downcast_msg!(msg; ChameneosRequest)
         }
 
+        fn validate(c: &Self::Conf) -> Result<(), BenchmarkError> {
+            validation::chameneos(c)
+        }
+
         fn new_instance() -> Self::Instance {
             ChameneosI::new()
         }
@@ -56,7 +60,7 @@ pub mod actor_chameneos {
         fn setup(&mut self, c: &Self::Conf) -> () {
             self.num_chameneos = Some(c.number_of_chameneos.try_into().unwrap());
             self.num_meetings = Some(c.number_of_meetings);
-            let system = crate::kompact_system_provider::global().new_system("chameneos");
+            let system = crate::kompact_system_provider::current().new_system("chameneos");
             self.system = Some(system);
         }
 
@@ -311,6 +315,10 @@ pub mod mixed_chameneos {
             downcast_msg!(msg; ChameneosRequest)
         }
 
+        fn validate(c: &Self::Conf) -> Result<(), BenchmarkError> {
+            validation::chameneos(c)
+        }
+
         fn new_instance() -> Self::Instance {
             ChameneosI::new()
         }
@@ -346,7 +354,7 @@ pub mod mixed_chameneos {
         fn setup(&mut self, c: &Self::Conf) -> () {
             self.num_chameneos = Some(c.number_of_chameneos.try_into().unwrap());
             self.num_meetings = Some(c.number_of_meetings);
-            let system = crate::kompact_system_provider::global().new_system("chameneos");
+            let system = crate::kompact_system_provider::current().new_system("chameneos");
             self.system = Some(system);
         }
 
@@ -601,3 +609,314 @@ pub mod mixed_chameneos {
         }
     }
 }
+
+pub mod component_chameneos {
+    use super::*;
+
+    #[derive(Default)]
+    pub struct Chameneos;
+
+    impl Benchmark for Chameneos {
+        type Conf = ChameneosRequest;
+        type Instance = ChameneosI;
+
+        fn msg_to_conf(msg: Box<dyn (::protobuf::Message)>) -> Result<Self::Conf, BenchmarkError> {
+            downcast_msg!(msg; ChameneosRequest)
+        }
+
+        fn validate(c: &Self::Conf) -> Result<(), BenchmarkError> {
+            validation::chameneos(c)
+        }
+
+        fn new_instance() -> Self::Instance {
+            ChameneosI::new()
+        }
+
+        const LABEL: &'static str = "Chameneos";
+    }
+
+    pub struct ChameneosI {
+        num_chameneos: Option<usize>,
+        num_meetings: Option<u64>,
+        system: Option<KompactSystem>,
+        mall: Option<Arc<Component<ChameneosMall>>>,
+        chameneos: Vec<Arc<Component<Chameneo>>>,
+        latch: Option<Arc<CountdownEvent>>,
+    }
+
+    impl ChameneosI {
+        fn new() -> ChameneosI {
+            ChameneosI {
+                num_chameneos: None,
+                num_meetings: None,
+                system: None,
+                mall: None,
+                chameneos: Vec::new(),
+                latch: None,
+            }
+        }
+    }
+
+    impl BenchmarkInstance for ChameneosI {
+        type Conf = ChameneosRequest;
+
+        fn setup(&mut self, c: &Self::Conf) -> () {
+            self.num_chameneos = Some(c.number_of_chameneos.try_into().unwrap());
+            self.num_meetings = Some(c.number_of_meetings);
+            let system = crate::kompact_system_provider::current().new_system("chameneos");
+            self.system = Some(system);
+        }
+
+        fn prepare_iteration(&mut self) -> () {
+            if let Some(ref system) = self.system {
+                if let Some(num_chameneos) = self.num_chameneos {
+                    if let Some(num_meetings) = self.num_meetings {
+                        let latch = Arc::new(CountdownEvent::new(1));
+                        for i in 0usize..num_chameneos {
+                            let initial_colour = ChameneosColour::for_id(i);
+                            let chameneo = system.create(|| Chameneo::with(i, initial_colour));
+                            self.chameneos.push(chameneo);
+                        }
+                        let chameneo_ports: Vec<RequiredRef<MallPort>> =
+                            self.chameneos.iter().map(|c| c.required_ref()).collect();
+                        let mall = system.create(|| {
+                            ChameneosMall::with(num_meetings, chameneo_ports, latch.clone())
+                        });
+                        self.latch = Some(latch);
+                        let mall_f = system.start_notify(&mall);
+                        mall_f
+                            .wait_timeout(Duration::from_millis(1000))
+                            .expect("ChameneosMall never started!");
+                        let mall_port: ProvidedRef<MallPort> = mall.provided_ref();
+                        for chameneo in self.chameneos.iter() {
+                            chameneo.connect_to_provided(mall_port.clone());
+                        }
+                        self.mall = Some(mall);
+                    } else {
+                        unimplemented!();
+                    }
+                } else {
+                    unimplemented!();
+                }
+            } else {
+                unimplemented!();
+            }
+        }
+
+        fn run_iteration(&mut self) -> () {
+            if let Some(ref system) = self.system {
+                let latch = self.latch.take().unwrap();
+                for chameneo in self.chameneos.drain(..) {
+                    system.start(&chameneo);
+                }
+                latch.wait();
+            } else {
+                unimplemented!();
+            }
+        }
+
+        fn cleanup_iteration(&mut self, last_iteration: bool, _exec_time_millis: f64) -> () {
+            let system = self.system.take().unwrap();
+            // self.chameneos.clear(); // they stop themselves and got drained when run
+
+            if let Some(mall) = self.mall.take() {
+                let f = system.kill_notify(mall);
+                f.wait_timeout(Duration::from_millis(1000))
+                    .expect("Mall never died!");
+            }
+
+            if last_iteration {
+                system
+                    .shutdown()
+                    .expect("Kompics didn't shut down properly");
+                self.num_chameneos = None;
+                self.num_meetings = None;
+            } else {
+                self.system = Some(system);
+            }
+        }
+    }
+
+    #[derive(Debug, Clone)]
+    enum MallMsg {
+        MeetingCount(u64),
+        Meet {
+            colour: ChameneosColour,
+            id: usize,
+        },
+        Change {
+            target: usize,
+            colour: ChameneosColour,
+        },
+    }
+    impl MallMsg {
+        fn count(meeting_count: u64) -> MallMsg {
+            MallMsg::MeetingCount(meeting_count)
+        }
+        fn meet(colour: ChameneosColour, id: usize) -> MallMsg {
+            MallMsg::Meet { colour, id }
+        }
+        fn change(target: usize, colour: ChameneosColour) -> MallMsg {
+            MallMsg::Change { target, colour }
+        }
+    }
+
+    #[derive(Debug, Clone)]
+    enum ChameneoMsg {
+        Meet {
+            colour: ChameneosColour,
+            partner: usize,
+        },
+        Change(ChameneosColour),
+        Exit,
+    }
+
+    /// Chameneos only ever talk to the mall, which forwards their `Change`s to each other.
+    ///
+    /// The mall doesn't trigger indications on its own end of the port, since Kompact would
+    /// deliver them to every chameneo. It sends each one to a single chameneo's end instead.
+    struct MallPort;
+    impl Port for MallPort {
+        type Indication = ChameneoMsg;
+        type Request = MallMsg;
+    }
+
+    #[derive(ComponentDefinition, Actor)]
+    struct ChameneosMall {
+        ctx: ComponentContext<Self>,
+        mall_port: ProvidedPort<MallPort>,
+        chameneos: Vec<RequiredRef<MallPort>>,
+        num_meetings: u64,
+        latch: Arc<CountdownEvent>,
+        waiting_chameneo: Option<usize>,
+        sum_meetings: u64,
+        meetings_count: u64,
+        num_faded: usize,
+        sent_exit: bool,
+    }
+
+    impl ChameneosMall {
+        fn with(
+            num_meetings: u64,
+            chameneos: Vec<RequiredRef<MallPort>>,
+            latch: Arc<CountdownEvent>,
+        ) -> ChameneosMall {
+            ChameneosMall {
+                ctx: ComponentContext::uninitialised(),
+                mall_port: ProvidedPort::uninitialised(),
+                chameneos,
+                num_meetings,
+                latch,
+                waiting_chameneo: None,
+                sum_meetings: 0u64,
+                meetings_count: 0u64,
+                num_faded: 0usize,
+                sent_exit: false,
+            }
+        }
+
+        fn tell(&self, chameneo: usize, msg: ChameneoMsg) -> () {
+            self.ctx.system().trigger_i(msg, &self.chameneos[chameneo]);
+        }
+    }
+
+    ignore_lifecycle!(ChameneosMall);
+
+    impl Provide<MallPort> for ChameneosMall {
+        fn handle(&mut self, msg: MallMsg) -> Handled {
+            match msg {
+                MallMsg::MeetingCount(count) => {
+                    self.num_faded += 1usize;
+                    self.sum_meetings += count;
+                    if self.num_faded == self.chameneos.len() {
+                        self.latch.decrement().expect("Should count down");
+                        info!(self.ctx.log(), "Done!");
+                    }
+                }
+                MallMsg::Meet { colour, id } => {
+                    if self.meetings_count < self.num_meetings {
+                        match self.waiting_chameneo.take() {
+                            Some(other) => {
+                                self.meetings_count += 1u64;
+                                self.tell(
+                                    other,
+                                    ChameneoMsg::Meet {
+                                        colour,
+                                        partner: id,
+                                    },
+                                );
+                            }
+                            None => {
+                                self.waiting_chameneo = Some(id);
+                            }
+                        }
+                    } else if !self.sent_exit {
+                        for chameneo in 0..self.chameneos.len() {
+                            self.tell(chameneo, ChameneoMsg::Exit);
+                        }
+                        self.sent_exit = true;
+                    } // else just drop, since we already sent exit
+                }
+                MallMsg::Change { target, colour } => {
+                    self.tell(target, ChameneoMsg::Change(colour));
+                }
+            }
+            Handled::Ok
+        }
+    }
+
+    #[derive(ComponentDefinition, Actor)]
+    struct Chameneo {
+        ctx: ComponentContext<Self>,
+        mall_port: RequiredPort<MallPort>,
+        id: usize,
+        colour: ChameneosColour,
+        meetings: u64,
+    }
+    impl Chameneo {
+        fn with(id: usize, initial_colour: ChameneosColour) -> Chameneo {
+            Chameneo {
+                ctx: ComponentContext::uninitialised(),
+                mall_port: RequiredPort::uninitialised(),
+                id,
+                colour: initial_colour,
+                meetings: 0u64,
+            }
+        }
+    }
+
+    impl ComponentLifecycle for Chameneo {
+        fn on_start(&mut self) -> Handled {
+            self.mall_port.trigger(MallMsg::meet(self.colour, self.id));
+            Handled::Ok
+        }
+    }
+
+    impl Require<MallPort> for Chameneo {
+        fn handle(&mut self, msg: ChameneoMsg) -> Handled {
+            match msg {
+                ChameneoMsg::Meet {
+                    colour: other_colour,
+                    partner,
+                } => {
+                    self.colour = self.colour.complement(other_colour);
+                    self.meetings += 1u64;
+                    self.mall_port
+                        .trigger(MallMsg::change(partner, self.colour));
+                    self.mall_port.trigger(MallMsg::meet(self.colour, self.id));
+                }
+                ChameneoMsg::Change(new_colour) => {
+                    self.colour = new_colour;
+                    self.meetings += 1u64;
+                    self.mall_port.trigger(MallMsg::meet(self.colour, self.id));
+                }
+                ChameneoMsg::Exit => {
+                    self.colour = ChameneosColour::Faded;
+                    self.mall_port.trigger(MallMsg::count(self.meetings));
+                    self.ctx.suicide();
+                }
+            }
+            Handled::Ok
+        }
+    }
+}
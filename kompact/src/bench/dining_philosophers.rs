@@ -0,0 +1,280 @@
+use super::*;
+
+use benchmark_suite_shared::{
+    kompics_benchmarks::benchmarks::DiningPhilosophersRequest, result_writer,
+};
+use kompact::prelude::*;
+use std::{convert::TryInto, sync::Arc};
+use synchronoise::CountdownEvent;
+
+#[derive(Default)]
+pub struct DiningPhilosophers;
+
+impl Benchmark for DiningPhilosophers {
+    type Conf = DiningPhilosophersRequest;
+    type Instance = DiningPhilosophersI;
+
+    fn msg_to_conf(msg: Box<dyn (::protobuf::Message)>) -> Result<Self::Conf, BenchmarkError> {
+        downcast_msg!(msg; DiningPhilosophersRequest)
+    }
+
+    fn validate(c: &Self::Conf) -> Result<(), BenchmarkError> {
+        validation::dining_philosophers(c)
+    }
+
+    fn new_instance() -> Self::Instance {
+        DiningPhilosophersI::new()
+    }
+
+    const LABEL: &'static str = "DiningPhilosophers";
+}
+
+pub struct DiningPhilosophersI {
+    num_philosophers: Option<usize>,
+    num_rounds: Option<u64>,
+    system: Option<KompactSystem>,
+    arbitrator: Option<Arc<Component<Arbitrator>>>,
+    philosophers: Vec<Arc<Component<Philosopher>>>,
+    latch: Option<Arc<CountdownEvent>>,
+    total_denied: u64,
+    iterations: u32,
+}
+
+impl DiningPhilosophersI {
+    fn new() -> DiningPhilosophersI {
+        DiningPhilosophersI {
+            num_philosophers: None,
+            num_rounds: None,
+            system: None,
+            arbitrator: None,
+            philosophers: Vec::new(),
+            latch: None,
+            total_denied: 0,
+            iterations: 0,
+        }
+    }
+}
+
+impl BenchmarkInstance for DiningPhilosophersI {
+    type Conf = DiningPhilosophersRequest;
+
+    fn setup(&mut self, c: &Self::Conf) -> () {
+        self.num_philosophers = Some(c.number_of_philosophers.try_into().unwrap());
+        self.num_rounds = Some(c.number_of_rounds);
+        self.total_denied = 0;
+        self.iterations = 0;
+        let system = crate::kompact_system_provider::current().new_system("dining_philosophers");
+        self.system = Some(system);
+    }
+
+    fn prepare_iteration(&mut self) -> () {
+        match (self.system.as_ref(), self.num_philosophers, self.num_rounds) {
+            (Some(system), Some(num_philosophers), Some(num_rounds)) => {
+                let latch = Arc::new(CountdownEvent::new(1));
+                let arbitrator =
+                    system.create(|| Arbitrator::with(num_philosophers, latch.clone()));
+                let arbitrator_ref = arbitrator.actor_ref().hold().expect("Live ref");
+                let philosophers: Vec<Arc<Component<Philosopher>>> = (0..num_philosophers)
+                    .map(|id| {
+                        let arbitrator_ref = arbitrator_ref.clone();
+                        system.create(move || Philosopher::with(id, num_rounds, arbitrator_ref))
+                    })
+                    .collect();
+                let philosopher_refs: Vec<ActorRefStrong<PhilosopherMsg>> = philosophers
+                    .iter()
+                    .map(|p| p.actor_ref().hold().expect("Live ref"))
+                    .collect();
+                arbitrator.on_definition(|def| def.philosophers = philosopher_refs);
+                let mut start_fs: Vec<_> = philosophers
+                    .iter()
+                    .map(|p| system.start_notify(p))
+                    .collect();
+                start_fs.push(system.start_notify(&arbitrator));
+                for f in start_fs {
+                    f.wait_timeout(Duration::from_millis(1000))
+                        .expect("Component never started!");
+                }
+                self.arbitrator = Some(arbitrator);
+                self.philosophers = philosophers;
+                self.latch = Some(latch);
+            }
+            _ => unimplemented!(),
+        }
+    }
+
+    fn run_iteration(&mut self) -> () {
+        let latch = self.latch.take().unwrap();
+        for philosopher in self.philosophers.iter() {
+            philosopher.actor_ref().tell(PhilosopherMsg::Start);
+        }
+        latch.wait();
+    }
+
+    fn cleanup_iteration(&mut self, last_iteration: bool, _exec_time_millis: f64) -> () {
+        let system = self.system.take().unwrap();
+        let arbitrator = self.arbitrator.take().unwrap();
+        self.total_denied += arbitrator.on_definition(|def| def.denied);
+        self.iterations += 1;
+        let f = system.kill_notify(arbitrator);
+        f.wait_timeout(Duration::from_millis(1000))
+            .expect("Arbitrator never died!");
+        for philosopher in self.philosophers.drain(..) {
+            let f = system.kill_notify(philosopher);
+            f.wait_timeout(Duration::from_millis(1000))
+                .expect("Philosopher never died!");
+        }
+
+        if last_iteration {
+            result_writer::record_metric(
+                "denied_attempts",
+                (self.total_denied as f64) / (self.iterations as f64),
+            );
+            system
+                .shutdown()
+                .expect("Kompics didn't shut down properly");
+            self.num_philosophers = None;
+            self.num_rounds = None;
+        } else {
+            self.system = Some(system);
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum ArbitratorMsg {
+    Hungry { philosopher: usize },
+    Done { philosopher: usize },
+    Exit,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum PhilosopherMsg {
+    Start,
+    Denied,
+    Eat,
+}
+
+/// Owns all the forks, and hands out both of a philosopher's forks at once, or none at all.
+#[derive(ComponentDefinition)]
+struct Arbitrator {
+    ctx: ComponentContext<Self>,
+    forks: Vec<bool>,
+    denied: u64,
+    remaining: usize,
+    philosophers: Vec<ActorRefStrong<PhilosopherMsg>>,
+    latch: Arc<CountdownEvent>,
+}
+
+impl Arbitrator {
+    fn with(num_philosophers: usize, latch: Arc<CountdownEvent>) -> Arbitrator {
+        Arbitrator {
+            ctx: ComponentContext::uninitialised(),
+            forks: vec![false; num_philosophers],
+            denied: 0u64,
+            remaining: num_philosophers,
+            philosophers: Vec::new(),
+            latch,
+        }
+    }
+
+    /// Philosopher `i` eats with forks `i` and `i + 1`.
+    fn forks_of(&self, philosopher: usize) -> (usize, usize) {
+        (philosopher, (philosopher + 1) % self.forks.len())
+    }
+}
+
+impl ComponentLifecycle for Arbitrator {
+    fn on_kill(&mut self) -> Handled {
+        self.philosophers.clear(); // the philosophers hold a reference to the arbitrator
+        Handled::Ok
+    }
+}
+
+impl Actor for Arbitrator {
+    type Message = ArbitratorMsg;
+
+    fn receive_local(&mut self, msg: Self::Message) -> Handled {
+        match msg {
+            ArbitratorMsg::Hungry { philosopher } => {
+                let (left, right) = self.forks_of(philosopher);
+                if self.forks[left] || self.forks[right] {
+                    self.denied += 1u64;
+                    self.philosophers[philosopher].tell(PhilosopherMsg::Denied);
+                } else {
+                    self.forks[left] = true;
+                    self.forks[right] = true;
+                    self.philosophers[philosopher].tell(PhilosopherMsg::Eat);
+                }
+            }
+            ArbitratorMsg::Done { philosopher } => {
+                let (left, right) = self.forks_of(philosopher);
+                self.forks[left] = false;
+                self.forks[right] = false;
+            }
+            ArbitratorMsg::Exit => {
+                self.remaining -= 1;
+                if self.remaining == 0 {
+                    self.latch.decrement().expect("Should decrement!");
+                }
+            }
+        }
+        Handled::Ok
+    }
+
+    fn receive_network(&mut self, _msg: NetMessage) -> Handled {
+        unimplemented!();
+    }
+}
+
+#[derive(ComponentDefinition)]
+struct Philosopher {
+    ctx: ComponentContext<Self>,
+    id: usize,
+    rounds_left: u64,
+    arbitrator: ActorRefStrong<ArbitratorMsg>,
+}
+
+impl Philosopher {
+    fn with(id: usize, num_rounds: u64, arbitrator: ActorRefStrong<ArbitratorMsg>) -> Philosopher {
+        Philosopher {
+            ctx: ComponentContext::uninitialised(),
+            id,
+            rounds_left: num_rounds,
+            arbitrator,
+        }
+    }
+
+    fn request_forks(&self) -> () {
+        self.arbitrator.tell(ArbitratorMsg::Hungry {
+            philosopher: self.id,
+        });
+    }
+}
+
+ignore_lifecycle!(Philosopher);
+
+impl Actor for Philosopher {
+    type Message = PhilosopherMsg;
+
+    fn receive_local(&mut self, msg: Self::Message) -> Handled {
+        match msg {
+            PhilosopherMsg::Start | PhilosopherMsg::Denied => self.request_forks(),
+            PhilosopherMsg::Eat => {
+                self.rounds_left -= 1u64;
+                self.arbitrator.tell(ArbitratorMsg::Done {
+                    philosopher: self.id,
+                });
+                if self.rounds_left > 0 {
+                    self.request_forks();
+                } else {
+                    self.arbitrator.tell(ArbitratorMsg::Exit);
+                }
+            }
+        }
+        Handled::Ok
+    }
+
+    fn receive_network(&mut self, _msg: NetMessage) -> Handled {
+        unimplemented!();
+    }
+}
@@ -0,0 +1,452 @@
+use super::*;
+
+use benchmark_suite_shared::kompics_benchmarks::benchmarks::FanoutRequest;
+use kompact::prelude::*;
+use std::{convert::TryInto, sync::Arc};
+use synchronoise::CountdownEvent;
+
+/// Sent to every subscriber once per round.
+#[derive(Debug, Clone, Copy)]
+struct Round {
+    index: u64,
+}
+
+/// Sent back to the publisher by every subscriber.
+#[derive(Debug, Clone, Copy)]
+struct Ack {
+    round: u64,
+}
+
+pub mod actor_fanout {
+    use super::*;
+
+    #[derive(Default)]
+    pub struct Fanout;
+
+    impl Benchmark for Fanout {
+        type Conf = FanoutRequest;
+        type Instance = FanoutI;
+
+        fn msg_to_conf(msg: Box<dyn (::protobuf::Message)>) -> Result<Self::Conf, BenchmarkError> {
+            downcast_msg!(msg; FanoutRequest)
+        }
+
+        fn validate(c: &Self::Conf) -> Result<(), BenchmarkError> {
+            validation::fanout(c)
+        }
+
+        fn new_instance() -> Self::Instance {
+            FanoutI::new()
+        }
+
+        const LABEL: &'static str = "Fanout";
+    }
+
+    pub struct FanoutI {
+        num_subscribers: Option<usize>,
+        num_rounds: Option<u64>,
+        system: Option<KompactSystem>,
+        publisher: Option<Arc<Component<Publisher>>>,
+        subscribers: Vec<Arc<Component<Subscriber>>>,
+        latch: Option<Arc<CountdownEvent>>,
+    }
+
+    impl FanoutI {
+        fn new() -> FanoutI {
+            FanoutI {
+                num_subscribers: None,
+                num_rounds: None,
+                system: None,
+                publisher: None,
+                subscribers: Vec::new(),
+                latch: None,
+            }
+        }
+    }
+
+    impl BenchmarkInstance for FanoutI {
+        type Conf = FanoutRequest;
+
+        fn setup(&mut self, c: &Self::Conf) -> () {
+            self.num_subscribers = Some(c.number_of_subscribers.try_into().unwrap());
+            self.num_rounds = Some(c.number_of_rounds);
+            let system = crate::kompact_system_provider::current().new_system("fanout");
+            self.system = Some(system);
+        }
+
+        fn prepare_iteration(&mut self) -> () {
+            match (self.system.as_ref(), self.num_subscribers, self.num_rounds) {
+                (Some(system), Some(num_subscribers), Some(num_rounds)) => {
+                    let latch = Arc::new(CountdownEvent::new(1));
+                    let publisher = system.create(|| Publisher::with(num_rounds, latch.clone()));
+                    let publisher_ref = publisher.actor_ref().hold().expect("Live ref");
+                    let subscribers: Vec<Arc<Component<Subscriber>>> = (0..num_subscribers)
+                        .map(|_| {
+                            let publisher_ref = publisher_ref.clone();
+                            system.create(move || Subscriber::with(publisher_ref))
+                        })
+                        .collect();
+                    let subscriber_refs: Vec<ActorRefStrong<Round>> = subscribers
+                        .iter()
+                        .map(|s| s.actor_ref().hold().expect("Live ref"))
+                        .collect();
+                    publisher.on_definition(|def| def.subscribers = subscriber_refs);
+                    let mut start_fs: Vec<_> =
+                        subscribers.iter().map(|s| system.start_notify(s)).collect();
+                    start_fs.push(system.start_notify(&publisher));
+                    for f in start_fs {
+                        f.wait_timeout(Duration::from_millis(1000))
+                            .expect("Component never started!");
+                    }
+                    self.publisher = Some(publisher);
+                    self.subscribers = subscribers;
+                    self.latch = Some(latch);
+                }
+                _ => unimplemented!(),
+            }
+        }
+
+        fn run_iteration(&mut self) -> () {
+            match self.publisher {
+                Some(ref publisher) => {
+                    let latch = self.latch.take().unwrap();
+                    publisher.actor_ref().tell(PublisherMsg::Start);
+                    latch.wait();
+                }
+                None => unimplemented!(),
+            }
+        }
+
+        fn cleanup_iteration(&mut self, last_iteration: bool, _exec_time_millis: f64) -> () {
+            let system = self.system.take().unwrap();
+            let publisher = self.publisher.take().unwrap();
+            let f = system.kill_notify(publisher);
+            f.wait_timeout(Duration::from_millis(1000))
+                .expect("Publisher never died!");
+            for subscriber in self.subscribers.drain(..) {
+                let f = system.kill_notify(subscriber);
+                f.wait_timeout(Duration::from_millis(1000))
+                    .expect("Subscriber never died!");
+            }
+
+            if last_iteration {
+                system
+                    .shutdown()
+                    .expect("Kompics didn't shut down properly");
+                self.num_subscribers = None;
+                self.num_rounds = None;
+            } else {
+                self.system = Some(system);
+            }
+        }
+    }
+
+    #[derive(Debug)]
+    enum PublisherMsg {
+        Start,
+        Ack(Ack),
+    }
+
+    #[derive(ComponentDefinition)]
+    struct Publisher {
+        ctx: ComponentContext<Self>,
+        subscribers: Vec<ActorRefStrong<Round>>,
+        num_rounds: u64,
+        round: u64,
+        pending_acks: usize,
+        latch: Arc<CountdownEvent>,
+    }
+
+    impl Publisher {
+        fn with(num_rounds: u64, latch: Arc<CountdownEvent>) -> Publisher {
+            Publisher {
+                ctx: ComponentContext::uninitialised(),
+                subscribers: Vec::new(),
+                num_rounds,
+                round: 0u64,
+                pending_acks: 0usize,
+                latch,
+            }
+        }
+
+        fn publish(&mut self) -> () {
+            let round = Round { index: self.round };
+            for subscriber in self.subscribers.iter() {
+                subscriber.tell(round);
+            }
+            self.pending_acks = self.subscribers.len();
+        }
+    }
+
+    impl ComponentLifecycle for Publisher {
+        fn on_kill(&mut self) -> Handled {
+            self.subscribers.clear(); // the subscribers hold a reference to the publisher
+            Handled::Ok
+        }
+    }
+
+    impl Actor for Publisher {
+        type Message = PublisherMsg;
+
+        fn receive_local(&mut self, msg: Self::Message) -> Handled {
+            match msg {
+                PublisherMsg::Start => self.publish(),
+                PublisherMsg::Ack(ack) => {
+                    debug_assert_eq!(ack.round, self.round);
+                    self.pending_acks -= 1;
+                    if self.pending_acks == 0 {
+                        self.round += 1u64;
+                        if self.round < self.num_rounds {
+                            self.publish();
+                        } else {
+                            self.latch.decrement().expect("Should decrement!");
+                        }
+                    }
+                }
+            }
+            Handled::Ok
+        }
+
+        fn receive_network(&mut self, _msg: NetMessage) -> Handled {
+            unimplemented!();
+        }
+    }
+
+    #[derive(ComponentDefinition)]
+    struct Subscriber {
+        ctx: ComponentContext<Self>,
+        publisher: ActorRefStrong<PublisherMsg>,
+    }
+
+    impl Subscriber {
+        fn with(publisher: ActorRefStrong<PublisherMsg>) -> Subscriber {
+            Subscriber {
+                ctx: ComponentContext::uninitialised(),
+                publisher,
+            }
+        }
+    }
+
+    ignore_lifecycle!(Subscriber);
+
+    impl Actor for Subscriber {
+        type Message = Round;
+
+        fn receive_local(&mut self, msg: Self::Message) -> Handled {
+            self.publisher
+                .tell(PublisherMsg::Ack(Ack { round: msg.index }));
+            Handled::Ok
+        }
+
+        fn receive_network(&mut self, _msg: NetMessage) -> Handled {
+            unimplemented!();
+        }
+    }
+}
+
+pub mod component_fanout {
+    use super::*;
+
+    /// Provided once by the publisher and required by every subscriber,
+    /// so that each round is a single broadcast trigger.
+    struct FanoutPort;
+    impl Port for FanoutPort {
+        type Indication = Round;
+        type Request = Ack;
+    }
+
+    #[derive(Default)]
+    pub struct Fanout;
+
+    impl Benchmark for Fanout {
+        type Conf = FanoutRequest;
+        type Instance = FanoutI;
+
+        fn msg_to_conf(msg: Box<dyn (::protobuf::Message)>) -> Result<Self::Conf, BenchmarkError> {
+            downcast_msg!(msg; FanoutRequest)
+        }
+
+        fn validate(c: &Self::Conf) -> Result<(), BenchmarkError> {
+            validation::fanout(c)
+        }
+
+        fn new_instance() -> Self::Instance {
+            FanoutI::new()
+        }
+
+        const LABEL: &'static str = "Fanout";
+    }
+
+    pub struct FanoutI {
+        num_subscribers: Option<usize>,
+        num_rounds: Option<u64>,
+        system: Option<KompactSystem>,
+        publisher: Option<Arc<Component<Publisher>>>,
+        subscribers: Vec<Arc<Component<Subscriber>>>,
+        latch: Option<Arc<CountdownEvent>>,
+    }
+
+    impl FanoutI {
+        fn new() -> FanoutI {
+            FanoutI {
+                num_subscribers: None,
+                num_rounds: None,
+                system: None,
+                publisher: None,
+                subscribers: Vec::new(),
+                latch: None,
+            }
+        }
+    }
+
+    impl BenchmarkInstance for FanoutI {
+        type Conf = FanoutRequest;
+
+        fn setup(&mut self, c: &Self::Conf) -> () {
+            self.num_subscribers = Some(c.number_of_subscribers.try_into().unwrap());
+            self.num_rounds = Some(c.number_of_rounds);
+            let system = crate::kompact_system_provider::current().new_system("fanout");
+            self.system = Some(system);
+        }
+
+        fn prepare_iteration(&mut self) -> () {
+            match (self.system.as_ref(), self.num_subscribers, self.num_rounds) {
+                (Some(system), Some(num_subscribers), Some(num_rounds)) => {
+                    let latch = Arc::new(CountdownEvent::new(1));
+                    let publisher = system
+                        .create(|| Publisher::with(num_subscribers, num_rounds, latch.clone()));
+                    let subscribers: Vec<Arc<Component<Subscriber>>> = (0..num_subscribers)
+                        .map(|_| system.create(Subscriber::new))
+                        .collect();
+                    let publisher_port: ProvidedRef<FanoutPort> = publisher.provided_ref();
+                    for subscriber in subscribers.iter() {
+                        subscriber.connect_to_provided(publisher_port.clone());
+                        publisher.connect_to_required(subscriber.required_ref());
+                    }
+                    // the publisher is only started in run_iteration
+                    let start_fs: Vec<_> =
+                        subscribers.iter().map(|s| system.start_notify(s)).collect();
+                    for f in start_fs {
+                        f.wait_timeout(Duration::from_millis(1000))
+                            .expect("Subscriber never started!");
+                    }
+                    self.publisher = Some(publisher);
+                    self.subscribers = subscribers;
+                    self.latch = Some(latch);
+                }
+                _ => unimplemented!(),
+            }
+        }
+
+        fn run_iteration(&mut self) -> () {
+            match (self.system.as_ref(), self.publisher.as_ref()) {
+                (Some(system), Some(publisher)) => {
+                    let latch = self.latch.take().unwrap();
+                    system.start(publisher);
+                    latch.wait();
+                }
+                _ => unimplemented!(),
+            }
+        }
+
+        fn cleanup_iteration(&mut self, last_iteration: bool, _exec_time_millis: f64) -> () {
+            let system = self.system.take().unwrap();
+            let publisher = self.publisher.take().unwrap();
+            let f = system.kill_notify(publisher);
+            f.wait_timeout(Duration::from_millis(1000))
+                .expect("Publisher never died!");
+            for subscriber in self.subscribers.drain(..) {
+                let f = system.kill_notify(subscriber);
+                f.wait_timeout(Duration::from_millis(1000))
+                    .expect("Subscriber never died!");
+            }
+
+            if last_iteration {
+                system
+                    .shutdown()
+                    .expect("Kompics didn't shut down properly");
+                self.num_subscribers = None;
+                self.num_rounds = None;
+            } else {
+                self.system = Some(system);
+            }
+        }
+    }
+
+    #[derive(ComponentDefinition, Actor)]
+    struct Publisher {
+        ctx: ComponentContext<Self>,
+        subscribers: ProvidedPort<FanoutPort>,
+        num_subscribers: usize,
+        num_rounds: u64,
+        round: u64,
+        pending_acks: usize,
+        latch: Arc<CountdownEvent>,
+    }
+
+    impl Publisher {
+        fn with(num_subscribers: usize, num_rounds: u64, latch: Arc<CountdownEvent>) -> Publisher {
+            Publisher {
+                ctx: ComponentContext::uninitialised(),
+                subscribers: ProvidedPort::uninitialised(),
+                num_subscribers,
+                num_rounds,
+                round: 0u64,
+                pending_acks: 0usize,
+                latch,
+            }
+        }
+
+        fn publish(&mut self) -> () {
+            self.pending_acks = self.num_subscribers;
+            self.subscribers.trigger(Round { index: self.round });
+        }
+    }
+
+    impl ComponentLifecycle for Publisher {
+        fn on_start(&mut self) -> Handled {
+            self.publish();
+            Handled::Ok
+        }
+    }
+
+    impl Provide<FanoutPort> for Publisher {
+        fn handle(&mut self, ack: Ack) -> Handled {
+            debug_assert_eq!(ack.round, self.round);
+            self.pending_acks -= 1;
+            if self.pending_acks == 0 {
+                self.round += 1u64;
+                if self.round < self.num_rounds {
+                    self.publish();
+                } else {
+                    self.latch.decrement().expect("Should decrement!");
+                }
+            }
+            Handled::Ok
+        }
+    }
+
+    #[derive(ComponentDefinition, Actor)]
+    struct Subscriber {
+        ctx: ComponentContext<Self>,
+        publisher: RequiredPort<FanoutPort>,
+    }
+
+    impl Subscriber {
+        fn new() -> Subscriber {
+            Subscriber {
+                ctx: ComponentContext::uninitialised(),
+                publisher: RequiredPort::uninitialised(),
+            }
+        }
+    }
+
+    ignore_lifecycle!(Subscriber);
+
+    impl Require<FanoutPort> for Subscriber {
+        fn handle(&mut self, round: Round) -> Handled {
+            self.publisher.trigger(Ack { round: round.index });
+            Handled::Ok
+        }
+    }
+}
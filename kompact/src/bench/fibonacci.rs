@@ -5,193 +5,396 @@ use kompact::prelude::*;
 use std::sync::Arc;
 use synchronoise::CountdownEvent;
 
-#[derive(Default)]
-pub struct Fibonacci;
+pub mod actor_fibonacci {
+    use super::*;
 
-impl Benchmark for Fibonacci {
-    type Conf = FibonacciRequest;
-    type Instance = FibonacciI;
+    #[derive(Default)]
+    pub struct Fibonacci;
 
-    fn msg_to_conf(msg: Box<dyn (::protobuf::Message)>) -> Result<Self::Conf, BenchmarkError> {
-        downcast_msg!(msg; FibonacciRequest)
+    impl Benchmark for Fibonacci {
+        type Conf = FibonacciRequest;
+        type Instance = FibonacciI;
+
+        fn msg_to_conf(msg: Box<dyn (::protobuf::Message)>) -> Result<Self::Conf, BenchmarkError> {
+            downcast_msg!(msg; FibonacciRequest)
+        }
+
+        fn validate(c: &Self::Conf) -> Result<(), BenchmarkError> {
+            validation::fibonacci(c)
+        }
+
+        fn new_instance() -> Self::Instance {
+            FibonacciI::new()
+        }
+
+        const LABEL: &'static str = "Fibonacci";
     }
 
-    fn new_instance() -> Self::Instance {
-        FibonacciI::new()
+    pub struct FibonacciI {
+        fib_number: Option<u32>,
+        system: Option<KompactSystem>,
+        fib: Option<Arc<Component<FibonacciActor>>>,
+        latch: Option<Arc<CountdownEvent>>,
     }
 
-    const LABEL: &'static str = "Fibonacci";
-}
+    impl FibonacciI {
+        fn new() -> FibonacciI {
+            FibonacciI {
+                fib_number: None,
+                system: None,
+                fib: None,
+                latch: None,
+            }
+        }
+    }
 
-pub struct FibonacciI {
-    fib_number: Option<u32>,
-    system: Option<KompactSystem>,
-    fib: Option<Arc<Component<FibonacciActor>>>,
-    latch: Option<Arc<CountdownEvent>>,
-}
+    impl BenchmarkInstance for FibonacciI {
+        type Conf = FibonacciRequest;
+
+        fn setup(&mut self, c: &Self::Conf) -> () {
+            self.fib_number = Some(c.fib_number);
+            let system = crate::kompact_system_provider::current().new_system("fibonacci");
+            self.system = Some(system);
+        }
+
+        fn prepare_iteration(&mut self) -> () {
+            match self.system {
+                Some(ref system) => {
+                    let latch = Arc::new(CountdownEvent::new(1));
+                    let fib =
+                        system.create(|| FibonacciActor::with(ResultTarget::Latch(latch.clone())));
+
+                    let fib_f = system.start_notify(&fib);
+
+                    fib_f
+                        .wait_timeout(Duration::from_millis(30000)) // wait longer to be sure the system is cleaned out
+                        .expect("FibonacciActor never started!");
+
+                    self.fib = Some(fib);
+                    self.latch = Some(latch);
+                }
+                None => unimplemented!(),
+            }
+        }
+
+        fn run_iteration(&mut self) -> () {
+            match self.fib_number {
+                Some(fib_number) => match self.fib {
+                    Some(ref fib) => {
+                        let latch = self.latch.take().unwrap();
+                        let fib_ref = fib.actor_ref();
 
-impl FibonacciI {
-    fn new() -> FibonacciI {
-        FibonacciI {
-            fib_number: None,
-            system: None,
-            fib: None,
-            latch: None,
+                        fib_ref.tell(FibonacciMsg::request(fib_number));
+                        latch.wait();
+                    }
+                    None => unimplemented!(),
+                },
+                None => unimplemented!(),
+            }
+        }
+
+        fn cleanup_iteration(&mut self, last_iteration: bool, _exec_time_millis: f64) -> () {
+            let _ = self.fib.take(); // kills itself, so just drop
+            std::thread::sleep(Duration::from_millis(500)); // reduce bleed over effect from still dying actors into next test
+            if last_iteration {
+                let system = self.system.take().unwrap();
+                system
+                    .shutdown()
+                    .expect("Kompics didn't shut down properly");
+                self.fib_number = None;
+            }
         }
     }
-}
 
-impl BenchmarkInstance for FibonacciI {
-    type Conf = FibonacciRequest;
+    enum ResultTarget {
+        Latch(Arc<CountdownEvent>),
+        Parent(ActorRefStrong<FibonacciMsg>),
+    }
 
-    fn setup(&mut self, c: &Self::Conf) -> () {
-        self.fib_number = Some(c.fib_number);
-        let system = crate::kompact_system_provider::global().new_system("fibonacci");
-        self.system = Some(system);
+    #[derive(Debug)]
+    enum FibonacciMsg {
+        Request { n: u32 },
+        Response { value: u64 },
     }
+    impl FibonacciMsg {
+        fn request(n: u32) -> FibonacciMsg {
+            FibonacciMsg::Request { n }
+        }
 
-    fn prepare_iteration(&mut self) -> () {
-        match self.system {
-            Some(ref system) => {
-                let latch = Arc::new(CountdownEvent::new(1));
-                let fib =
-                    system.create(|| FibonacciActor::with(ResultTarget::Latch(latch.clone())));
+        fn response(value: u64) -> FibonacciMsg {
+            FibonacciMsg::Response { value }
+        }
 
-                let fib_f = system.start_notify(&fib);
+        const RESPONSE_ONE: FibonacciMsg = FibonacciMsg::Response { value: 1u64 };
+    }
 
-                fib_f
-                    .wait_timeout(Duration::from_millis(30000)) // wait longer to be sure the system is cleaned out
-                    .expect("FibonacciActor never started!");
+    #[derive(ComponentDefinition)]
+    struct FibonacciActor {
+        ctx: ComponentContext<Self>,
+        report_to: ResultTarget,
+        result: u64,
+        num_responses: u8,
+    }
+    impl FibonacciActor {
+        fn with(report_to: ResultTarget) -> FibonacciActor {
+            FibonacciActor {
+                ctx: ComponentContext::uninitialised(),
+                report_to,
+                result: 0u64,
+                num_responses: 0u8,
+            }
+        }
 
-                self.fib = Some(fib);
-                self.latch = Some(latch);
+        fn send_result(&mut self, response: FibonacciMsg) {
+            match self.report_to {
+                ResultTarget::Parent(ref parent_ref) => {
+                    parent_ref.tell(response);
+                }
+                ResultTarget::Latch(ref latch) => {
+                    latch.decrement().expect("Should decrement!");
+                }
             }
-            None => unimplemented!(),
+            self.ctx.suicide();
         }
     }
 
-    fn run_iteration(&mut self) -> () {
-        match self.fib_number {
-            Some(fib_number) => match self.fib {
-                Some(ref fib) => {
-                    let latch = self.latch.take().unwrap();
-                    let fib_ref = fib.actor_ref();
+    ignore_lifecycle!(FibonacciActor);
 
-                    fib_ref.tell(FibonacciMsg::request(fib_number));
-                    latch.wait();
+    impl Actor for FibonacciActor {
+        type Message = FibonacciMsg;
+
+        fn receive_local(&mut self, msg: Self::Message) -> Handled {
+            match msg {
+                FibonacciMsg::Request { n } => {
+                    debug!(self.ctx.log(), "Got Request n={}", n);
+                    if n <= 2u32 {
+                        self.send_result(FibonacciMsg::RESPONSE_ONE);
+                    } else {
+                        let self_ref = self.actor_ref().hold().expect("Live ref");
+                        let f1 = self.ctx.system().create(|| {
+                            FibonacciActor::with(ResultTarget::Parent(self_ref.clone()))
+                        });
+                        self.ctx.system().start(&f1); // don't use create_and_start to avoid unnecessary registration
+                        f1.actor_ref().tell(FibonacciMsg::request(n - 1u32));
+                        let f2 = self.ctx.system().create(|| {
+                            FibonacciActor::with(ResultTarget::Parent(self_ref.clone()))
+                        });
+                        self.ctx.system().start(&f2); // don't use create_and_start to avoid unnecessary registration
+                        f2.actor_ref().tell(FibonacciMsg::request(n - 2u32));
+                    }
                 }
-                None => unimplemented!(),
-            },
-            None => unimplemented!(),
+                FibonacciMsg::Response { value } => {
+                    debug!(self.ctx.log(), "Got Response value={}", value);
+                    self.num_responses += 1u8;
+                    self.result += value;
+
+                    if self.num_responses == 2u8 {
+                        self.send_result(FibonacciMsg::response(self.result));
+                    }
+                }
+            }
+            Handled::Ok
         }
-    }
 
-    fn cleanup_iteration(&mut self, last_iteration: bool, _exec_time_millis: f64) -> () {
-        let _ = self.fib.take(); // kills itself, so just drop
-        std::thread::sleep(Duration::from_millis(500)); // reduce bleed over effect from still dying actors into next test
-        if last_iteration {
-            let system = self.system.take().unwrap();
-            system
-                .shutdown()
-                .expect("Kompics didn't shut down properly");
-            self.fib_number = None;
+        fn receive_network(&mut self, _msg: NetMessage) -> Handled {
+            unimplemented!();
         }
     }
 }
 
-enum ResultTarget {
-    Latch(Arc<CountdownEvent>),
-    Parent(ActorRefStrong<FibonacciMsg>),
-}
+pub mod component_fibonacci {
+    use super::*;
 
-#[derive(Debug)]
-enum FibonacciMsg {
-    Request { n: u32 },
-    Response { value: u64 },
-}
-impl FibonacciMsg {
-    fn request(n: u32) -> FibonacciMsg {
-        FibonacciMsg::Request { n }
+    #[derive(Clone, Debug)]
+    struct FibonacciResult {
+        value: u64,
     }
 
-    fn response(value: u64) -> FibonacciMsg {
-        FibonacciMsg::Response { value }
+    /// Children report their results to their parent over this port.
+    ///
+    /// Each component gets its `n` when it is created, so nothing ever travels down the port.
+    struct FibonacciPort;
+    impl Port for FibonacciPort {
+        type Indication = FibonacciResult;
+        type Request = Never;
     }
 
-    const RESPONSE_ONE: FibonacciMsg = FibonacciMsg::Response { value: 1u64 };
-}
+    #[derive(Default)]
+    pub struct Fibonacci;
 
-#[derive(ComponentDefinition)]
-struct FibonacciActor {
-    ctx: ComponentContext<Self>,
-    report_to: ResultTarget,
-    result: u64,
-    num_responses: u8,
-}
-impl FibonacciActor {
-    fn with(report_to: ResultTarget) -> FibonacciActor {
-        FibonacciActor {
-            ctx: ComponentContext::uninitialised(),
-            report_to,
-            result: 0u64,
-            num_responses: 0u8,
+    impl Benchmark for Fibonacci {
+        type Conf = FibonacciRequest;
+        type Instance = FibonacciI;
+
+        fn msg_to_conf(msg: Box<dyn (::protobuf::Message)>) -> Result<Self::Conf, BenchmarkError> {
+            downcast_msg!(msg; FibonacciRequest)
+        }
+
+        fn validate(c: &Self::Conf) -> Result<(), BenchmarkError> {
+            validation::fibonacci(c)
+        }
+
+        fn new_instance() -> Self::Instance {
+            FibonacciI::new()
         }
+
+        const LABEL: &'static str = "Fibonacci";
+    }
+
+    pub struct FibonacciI {
+        fib_number: Option<u32>,
+        system: Option<KompactSystem>,
+        fib: Option<Arc<Component<FibonacciComponent>>>,
+        latch: Option<Arc<CountdownEvent>>,
     }
 
-    fn send_result(&mut self, response: FibonacciMsg) {
-        match self.report_to {
-            ResultTarget::Parent(ref parent_ref) => {
-                parent_ref.tell(response);
+    impl FibonacciI {
+        fn new() -> FibonacciI {
+            FibonacciI {
+                fib_number: None,
+                system: None,
+                fib: None,
+                latch: None,
             }
-            ResultTarget::Latch(ref latch) => {
-                latch.decrement().expect("Should decrement!");
+        }
+    }
+
+    impl BenchmarkInstance for FibonacciI {
+        type Conf = FibonacciRequest;
+
+        fn setup(&mut self, c: &Self::Conf) -> () {
+            self.fib_number = Some(c.fib_number);
+            let system = crate::kompact_system_provider::current().new_system("fibonacci");
+            self.system = Some(system);
+        }
+
+        fn prepare_iteration(&mut self) -> () {
+            match self.fib_number {
+                Some(fib_number) => match self.system {
+                    Some(ref system) => {
+                        let latch = Arc::new(CountdownEvent::new(1));
+                        let fib = system
+                            .create(|| FibonacciComponent::with(fib_number, Some(latch.clone())));
+
+                        self.fib = Some(fib);
+                        self.latch = Some(latch);
+                    }
+                    None => unimplemented!(),
+                },
+                None => unimplemented!(),
+            }
+        }
+
+        fn run_iteration(&mut self) -> () {
+            match self.system {
+                Some(ref system) => match self.fib {
+                    Some(ref fib) => {
+                        let latch = self.latch.take().unwrap();
+
+                        system.start(fib);
+
+                        latch.wait();
+                    }
+                    None => unimplemented!(),
+                },
+                None => unimplemented!(),
+            }
+        }
+
+        fn cleanup_iteration(&mut self, last_iteration: bool, _exec_time_millis: f64) -> () {
+            let _ = self.fib.take(); // kills itself, so just drop
+            std::thread::sleep(Duration::from_millis(500)); // reduce bleed over effect from still dying components into next test
+            if last_iteration {
+                let system = self.system.take().unwrap();
+                system
+                    .shutdown()
+                    .expect("Kompics didn't shut down properly");
+                self.fib_number = None;
             }
         }
-        self.ctx.suicide();
     }
-}
 
-ignore_lifecycle!(FibonacciActor);
-
-impl Actor for FibonacciActor {
-    type Message = FibonacciMsg;
-
-    fn receive_local(&mut self, msg: Self::Message) -> Handled {
-        match msg {
-            FibonacciMsg::Request { n } => {
-                debug!(self.ctx.log(), "Got Request n={}", n);
-                if n <= 2u32 {
-                    self.send_result(FibonacciMsg::RESPONSE_ONE);
-                } else {
-                    let self_ref = self.actor_ref().hold().expect("Live ref");
-                    let f1 = self
-                        .ctx
-                        .system()
-                        .create(|| FibonacciActor::with(ResultTarget::Parent(self_ref.clone())));
-                    self.ctx.system().start(&f1); // don't use create_and_start to avoid unnecessary registration
-                    f1.actor_ref().tell(FibonacciMsg::request(n - 1u32));
-                    let f2 = self
-                        .ctx
-                        .system()
-                        .create(|| FibonacciActor::with(ResultTarget::Parent(self_ref.clone())));
-                    self.ctx.system().start(&f2); // don't use create_and_start to avoid unnecessary registration
-                    f2.actor_ref().tell(FibonacciMsg::request(n - 2u32));
-                }
+    /// Computes `fib(n)` by starting two children for `n-1` and `n-2` and adding up their results.
+    ///
+    /// The root reports to the latch, everyone else to their parent via `parent_port`.
+    #[derive(ComponentDefinition, Actor)]
+    struct FibonacciComponent {
+        ctx: ComponentContext<Self>,
+        parent_port: ProvidedPort<FibonacciPort>,
+        children_port: RequiredPort<FibonacciPort>,
+        n: u32,
+        latch: Option<Arc<CountdownEvent>>,
+        children: Vec<Arc<Component<FibonacciComponent>>>,
+        result: u64,
+        num_responses: u8,
+    }
+
+    impl FibonacciComponent {
+        fn with(n: u32, latch: Option<Arc<CountdownEvent>>) -> FibonacciComponent {
+            FibonacciComponent {
+                ctx: ComponentContext::uninitialised(),
+                parent_port: ProvidedPort::uninitialised(),
+                children_port: RequiredPort::uninitialised(),
+                n,
+                latch,
+                children: Vec::with_capacity(2),
+                result: 0u64,
+                num_responses: 0u8,
             }
-            FibonacciMsg::Response { value } => {
-                debug!(self.ctx.log(), "Got Response value={}", value);
-                self.num_responses += 1u8;
-                self.result += value;
+        }
+
+        fn spawn_child(&mut self, n: u32) -> () {
+            let system = self.ctx.system();
+            let child = system.create(|| FibonacciComponent::with(n, None));
+            child.connect_to_required(self.children_port.share());
+            system.start(&child);
+            self.children.push(child);
+        }
 
-                if self.num_responses == 2u8 {
-                    self.send_result(FibonacciMsg::response(self.result));
+        fn send_result(&mut self, value: u64) -> () {
+            match self.latch {
+                Some(ref latch) => {
+                    latch.decrement().expect("Should decrement!");
                 }
+                None => {
+                    self.parent_port.trigger(FibonacciResult { value });
+                }
+            }
+            self.children.clear();
+            self.ctx.suicide();
+        }
+    }
+
+    impl ComponentLifecycle for FibonacciComponent {
+        fn on_start(&mut self) -> Handled {
+            debug!(self.ctx.log(), "Started with n={}", self.n);
+            if self.n <= 2u32 {
+                self.send_result(1u64);
+            } else {
+                self.spawn_child(self.n - 1u32);
+                self.spawn_child(self.n - 2u32);
             }
+            Handled::Ok
         }
-        Handled::Ok
     }
 
-    fn receive_network(&mut self, _msg: NetMessage) -> Handled {
-        unimplemented!();
+    impl Provide<FibonacciPort> for FibonacciComponent {
+        fn handle(&mut self, _event: Never) -> Handled {
+            // ignore
+            Handled::Ok
+        }
+    }
+
+    impl Require<FibonacciPort> for FibonacciComponent {
+        fn handle(&mut self, event: FibonacciResult) -> Handled {
+            debug!(self.ctx.log(), "Got Result value={}", event.value);
+            self.num_responses += 1u8;
+            self.result += event.value;
+
+            if self.num_responses == 2u8 {
+                self.send_result(self.result);
+            }
+            Handled::Ok
+        }
     }
 }
@@ -1,18 +1,25 @@
 use super::*;
-use benchmark_suite_shared::benchmark::*;
+use benchmark_suite_shared::{benchmark::*, helpers::validation};
 use std::time::Duration;
 
 pub mod all_pairs_shortest_path;
 pub mod atomic_broadcast;
 pub mod atomicregister;
+pub mod bank;
+pub mod big;
 pub mod chameneos;
+pub mod dining_philosophers;
+pub mod fanout;
 pub mod fibonacci;
 mod messages;
 pub mod net_throughput_pingpong;
 pub mod netpingpong;
+pub mod parallel_sort;
 pub mod pingpong;
+pub mod producer_consumer;
 pub mod sized_throughput;
 pub mod streaming_windows;
+pub mod thread_ring;
 pub mod throughput_pingpong;
 
 pub trait ReceiveRun {
@@ -31,6 +38,13 @@ impl BenchmarkFactory for ComponentFactory {
             throughput_pingpong::component_pingpong::PingPong::LABEL => {
                 self.throughput_ping_pong().map_into()
             }
+            fibonacci::component_fibonacci::Fibonacci::LABEL => self.fibonacci().map_into(),
+            chameneos::component_chameneos::Chameneos::LABEL => self.chameneos().map_into(),
+            all_pairs_shortest_path::component_apsp::AllPairsShortestPath::LABEL => {
+                self.all_pairs_shortest_path().map_into()
+            }
+            thread_ring::component_thread_ring::ThreadRing::LABEL => self.thread_ring().map_into(),
+            fanout::component_fanout::Fanout::LABEL => self.fanout().map_into(),
             _ => Err(NotImplementedError::NotFound),
         }
     }
@@ -65,10 +79,10 @@ impl BenchmarkFactory for ComponentFactory {
         Err(NotImplementedError::NotImplementable)
     }
     fn fibonacci(&self) -> Result<Box<dyn AbstractBenchmark>, NotImplementedError> {
-        Err(NotImplementedError::NotImplementable)
+        Ok(fibonacci::component_fibonacci::Fibonacci {}.into())
     }
     fn chameneos(&self) -> Result<Box<dyn AbstractBenchmark>, NotImplementedError> {
-        Err(NotImplementedError::NotImplementable)
+        Ok(chameneos::component_chameneos::Chameneos {}.into())
     }
     fn all_pairs_shortest_path(&self) -> Result<Box<dyn AbstractBenchmark>, NotImplementedError> {
         Ok(all_pairs_shortest_path::component_apsp::AllPairsShortestPath {}.into())
@@ -85,6 +99,42 @@ impl BenchmarkFactory for ComponentFactory {
     ) -> Result<Box<dyn AbstractDistributedBenchmark>, NotImplementedError> {
         Err(NotImplementedError::NotImplementable)
     }
+
+    fn thread_ring(&self) -> Result<Box<dyn AbstractBenchmark>, NotImplementedError> {
+        Ok(thread_ring::component_thread_ring::ThreadRing {}.into())
+    }
+
+    fn fan_in(&self) -> Result<Box<dyn AbstractBenchmark>, NotImplementedError> {
+        Err(NotImplementedError::FutureWork)
+    }
+
+    fn fanout(&self) -> Result<Box<dyn AbstractBenchmark>, NotImplementedError> {
+        Ok(fanout::component_fanout::Fanout {}.into())
+    }
+
+    fn big(&self) -> Result<Box<dyn AbstractBenchmark>, NotImplementedError> {
+        Err(NotImplementedError::FutureWork)
+    }
+
+    fn bank(&self) -> Result<Box<dyn AbstractBenchmark>, NotImplementedError> {
+        Err(NotImplementedError::FutureWork)
+    }
+
+    fn producer_consumer(&self) -> Result<Box<dyn AbstractBenchmark>, NotImplementedError> {
+        Err(NotImplementedError::FutureWork)
+    }
+
+    fn dining_philosophers(&self) -> Result<Box<dyn AbstractBenchmark>, NotImplementedError> {
+        Err(NotImplementedError::FutureWork)
+    }
+
+    fn nqueens(&self) -> Result<Box<dyn AbstractBenchmark>, NotImplementedError> {
+        Err(NotImplementedError::FutureWork)
+    }
+
+    fn parallel_sort(&self) -> Result<Box<dyn AbstractBenchmark>, NotImplementedError> {
+        Err(NotImplementedError::FutureWork)
+    }
 }
 
 pub fn actor() -> Box<dyn BenchmarkFactory> {
@@ -106,6 +156,13 @@ impl BenchmarkFactory for ActorFactory {
             }
             streaming_windows::StreamingWindows::LABEL => self.streaming_windows().map_into(),
             sized_throughput::SizedThroughputBenchmark::LABEL => self.sized_throughput().map_into(),
+            thread_ring::actor_thread_ring::ThreadRing::LABEL => self.thread_ring().map_into(),
+            fanout::actor_fanout::Fanout::LABEL => self.fanout().map_into(),
+            big::Big::LABEL => self.big().map_into(),
+            bank::Bank::LABEL => self.bank().map_into(),
+            producer_consumer::ProducerConsumer::LABEL => self.producer_consumer().map_into(),
+            dining_philosophers::DiningPhilosophers::LABEL => self.dining_philosophers().map_into(),
+            parallel_sort::ParallelSort::LABEL => self.parallel_sort().map_into(),
             _ => Err(NotImplementedError::NotFound),
         }
     }
@@ -141,7 +198,7 @@ impl BenchmarkFactory for ActorFactory {
         Ok(streaming_windows::StreamingWindows {}.into())
     }
     fn fibonacci(&self) -> Result<Box<dyn AbstractBenchmark>, NotImplementedError> {
-        Ok(fibonacci::Fibonacci {}.into())
+        Ok(fibonacci::actor_fibonacci::Fibonacci {}.into())
     }
     fn chameneos(&self) -> Result<Box<dyn AbstractBenchmark>, NotImplementedError> {
         Ok(chameneos::actor_chameneos::Chameneos {}.into())
@@ -162,6 +219,42 @@ impl BenchmarkFactory for ActorFactory {
     ) -> Result<Box<dyn AbstractDistributedBenchmark>, NotImplementedError> {
         Ok(sized_throughput::SizedThroughputBenchmark {}.into())
     }
+
+    fn thread_ring(&self) -> Result<Box<dyn AbstractBenchmark>, NotImplementedError> {
+        Ok(thread_ring::actor_thread_ring::ThreadRing {}.into())
+    }
+
+    fn fan_in(&self) -> Result<Box<dyn AbstractBenchmark>, NotImplementedError> {
+        Err(NotImplementedError::FutureWork)
+    }
+
+    fn fanout(&self) -> Result<Box<dyn AbstractBenchmark>, NotImplementedError> {
+        Ok(fanout::actor_fanout::Fanout {}.into())
+    }
+
+    fn big(&self) -> Result<Box<dyn AbstractBenchmark>, NotImplementedError> {
+        Ok(big::Big {}.into())
+    }
+
+    fn bank(&self) -> Result<Box<dyn AbstractBenchmark>, NotImplementedError> {
+        Ok(bank::Bank {}.into())
+    }
+
+    fn producer_consumer(&self) -> Result<Box<dyn AbstractBenchmark>, NotImplementedError> {
+        Ok(producer_consumer::ProducerConsumer {}.into())
+    }
+
+    fn dining_philosophers(&self) -> Result<Box<dyn AbstractBenchmark>, NotImplementedError> {
+        Ok(dining_philosophers::DiningPhilosophers {}.into())
+    }
+
+    fn nqueens(&self) -> Result<Box<dyn AbstractBenchmark>, NotImplementedError> {
+        Err(NotImplementedError::FutureWork)
+    }
+
+    fn parallel_sort(&self) -> Result<Box<dyn AbstractBenchmark>, NotImplementedError> {
+        Ok(parallel_sort::ParallelSort {}.into())
+    }
 }
 pub fn mixed() -> Box<dyn BenchmarkFactory> {
     Box::new(MixedFactory {})
@@ -233,4 +326,40 @@ impl BenchmarkFactory for MixedFactory {
     ) -> Result<Box<dyn AbstractDistributedBenchmark>, NotImplementedError> {
         Err(NotImplementedError::NotImplementable)
     }
+
+    fn thread_ring(&self) -> Result<Box<dyn AbstractBenchmark>, NotImplementedError> {
+        Err(NotImplementedError::NotImplementable)
+    }
+
+    fn fan_in(&self) -> Result<Box<dyn AbstractBenchmark>, NotImplementedError> {
+        Err(NotImplementedError::FutureWork)
+    }
+
+    fn fanout(&self) -> Result<Box<dyn AbstractBenchmark>, NotImplementedError> {
+        Err(NotImplementedError::NotImplementable)
+    }
+
+    fn big(&self) -> Result<Box<dyn AbstractBenchmark>, NotImplementedError> {
+        Err(NotImplementedError::NotImplementable)
+    }
+
+    fn bank(&self) -> Result<Box<dyn AbstractBenchmark>, NotImplementedError> {
+        Err(NotImplementedError::NotImplementable)
+    }
+
+    fn producer_consumer(&self) -> Result<Box<dyn AbstractBenchmark>, NotImplementedError> {
+        Err(NotImplementedError::FutureWork)
+    }
+
+    fn dining_philosophers(&self) -> Result<Box<dyn AbstractBenchmark>, NotImplementedError> {
+        Err(NotImplementedError::NotImplementable)
+    }
+
+    fn nqueens(&self) -> Result<Box<dyn AbstractBenchmark>, NotImplementedError> {
+        Err(NotImplementedError::FutureWork)
+    }
+
+    fn parallel_sort(&self) -> Result<Box<dyn AbstractBenchmark>, NotImplementedError> {
+        Err(NotImplementedError::FutureWork)
+    }
 }
@@ -1,5 +1,6 @@
 use super::*;
 
+use crate::kompact_system_provider::{BufferSettings, NetworkSettings};
 use benchmark_suite_shared::kompics_benchmarks::benchmarks::ThroughputPingPongRequest;
 use kompact::prelude::*;
 use std::{str::FromStr, sync::Arc};
@@ -13,12 +14,14 @@ use throughput_pingpong::{EitherComponents, Params};
 pub struct ClientParams {
     num_pongers: u32,
     static_only: bool,
+    network: NetworkSettings,
 }
 impl ClientParams {
-    fn new(num_pongers: u32, static_only: bool) -> ClientParams {
+    fn new(num_pongers: u32, static_only: bool, network: NetworkSettings) -> ClientParams {
         ClientParams {
             num_pongers,
             static_only,
+            network,
         }
     }
 }
@@ -47,12 +50,21 @@ impl DistributedBenchmark for PingPong {
         downcast_msg!(msg; ThroughputPingPongRequest)
     }
 
+    fn validate(c: &Self::MasterConf, _meta: &DeploymentMetaData) -> Result<(), BenchmarkError> {
+        validation::throughput_ping_pong(c)?;
+        crate::kompact_system_provider::current().validate_network(
+            "throughputpingpong",
+            &BufferSettings::default(),
+            c.get_network(),
+        )
+    }
+
     fn new_client() -> Self::Client {
         PingPongClient::new()
     }
     fn str_to_client_conf(str: String) -> Result<Self::ClientConf, BenchmarkError> {
         let split: Vec<_> = str.split(',').collect();
-        if split.len() != 2 {
+        if split.len() != 3 {
             Err(BenchmarkError::InvalidMessage(format!(
                 "String '{}' does not represent a client conf!",
                 str
@@ -72,7 +84,13 @@ impl DistributedBenchmark for PingPong {
                     str, e
                 ))
             })?;
-            Ok(ClientParams::new(num, static_only))
+            let network = split[2].parse::<NetworkSettings>().map_err(|e| {
+                BenchmarkError::InvalidMessage(format!(
+                    "String '{}' does not represent a client conf: {}",
+                    str, e
+                ))
+            })?;
+            Ok(ClientParams::new(num, static_only, network))
         }
     }
     fn str_to_client_data(str: String) -> Result<Self::ClientData, BenchmarkError> {
@@ -83,7 +101,7 @@ impl DistributedBenchmark for PingPong {
     }
 
     fn client_conf_to_str(c: Self::ClientConf) -> String {
-        format!("{},{}", c.num_pongers, c.static_only)
+        format!("{},{},{}", c.num_pongers, c.static_only, c.network)
     }
     fn client_data_to_str(d: Self::ClientData) -> String {
         d.0.into_iter()
@@ -126,10 +144,15 @@ impl DistributedBenchmarkMaster for PingPongMaster {
         _m: &DeploymentMetaData,
     ) -> Result<Self::ClientConf, BenchmarkError> {
         let params = Params::from_req(&c);
-        let system =
-            crate::kompact_system_provider::global().new_remote_system("throughputpingpong");
+        let network = NetworkSettings::from_request(c.get_network());
+        let provider = crate::kompact_system_provider::current();
+        let system = provider.new_remote_system_with_network(
+            "throughputpingpong",
+            provider.get_num_workers(),
+            &network,
+        );
         self.system = Some(system);
-        let client_conf = ClientParams::new(params.num_pairs, params.static_only);
+        let client_conf = ClientParams::new(params.num_pairs, params.static_only, network);
         self.params = Some(params);
         Ok(client_conf)
     }
@@ -246,8 +269,12 @@ impl DistributedBenchmarkClient for PingPongClient {
     fn setup(&mut self, c: Self::ClientConf) -> Self::ClientData {
         println!("Setting up ponger.");
 
-        let system =
-            crate::kompact_system_provider::global().new_remote_system("throughputpingpong");
+        let provider = crate::kompact_system_provider::current();
+        let system = provider.new_remote_system_with_network(
+            "throughputpingpong",
+            provider.get_num_workers(),
+            &c.network,
+        );
         let (pongers, ponger_refs) = if c.static_only {
             let mut vpo = Vec::with_capacity(c.num_pongers as usize);
             let mut vpor = Vec::with_capacity(c.num_pongers as usize);
@@ -543,12 +570,15 @@ mod tests {
 
     #[test]
     fn test_client_params() {
-        let params = ClientParams::new(42, true);
+        let params = ClientParams::new(42, true, NetworkSettings::default());
         let param_string = PingPong::client_conf_to_str(params.clone());
         let params_deser = PingPong::str_to_client_conf(param_string).unwrap();
         assert_eq!(params, params_deser);
 
-        let params2 = ClientParams::new(42, false);
+        let mut network = NetworkSettings::default();
+        network.buffer_config.chunk_size = Some(64000);
+        network.tcp_no_delay = Some(false);
+        let params2 = ClientParams::new(42, false, network);
         let param_string2 = PingPong::client_conf_to_str(params2.clone());
         let params_deser2 = PingPong::str_to_client_conf(param_string2).unwrap();
         assert_eq!(params2, params_deser2);
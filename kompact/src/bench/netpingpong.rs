@@ -1,5 +1,6 @@
 use super::*;
 
+use crate::kompact_system_provider::{BufferSettings, NetworkSettings};
 use benchmark_suite_shared::kompics_benchmarks::benchmarks::PingPongRequest;
 use kompact::prelude::*;
 use messages::{Run, StaticPing, StaticPong, RUN, STATIC_PING, STATIC_PONG};
@@ -11,7 +12,7 @@ pub struct PingPong;
 
 impl DistributedBenchmark for PingPong {
     type MasterConf = PingPongRequest;
-    type ClientConf = ();
+    type ClientConf = NetworkSettings;
     type ClientData = ActorPath;
     type Master = PingPongMaster;
     type Client = PingPongClient;
@@ -27,11 +28,22 @@ impl DistributedBenchmark for PingPong {
         downcast_msg!(msg; PingPongRequest)
     }
 
+    fn validate(c: &Self::MasterConf, _meta: &DeploymentMetaData) -> Result<(), BenchmarkError> {
+        validation::ping_pong(c)?;
+        crate::kompact_system_provider::current().validate_network(
+            "netpingpong",
+            &BufferSettings::default(),
+            c.get_network(),
+        )
+    }
+
     fn new_client() -> Self::Client {
         PingPongClient::new()
     }
-    fn str_to_client_conf(_str: String) -> Result<Self::ClientConf, BenchmarkError> {
-        Ok(())
+    fn str_to_client_conf(str: String) -> Result<Self::ClientConf, BenchmarkError> {
+        str.parse::<NetworkSettings>().map_err(|e| {
+            BenchmarkError::InvalidMessage(format!("Could not read client conf: {}", e))
+        })
     }
     fn str_to_client_data(str: String) -> Result<Self::ClientData, BenchmarkError> {
         let res = ActorPath::from_str(&str);
@@ -40,8 +52,8 @@ impl DistributedBenchmark for PingPong {
         })
     }
 
-    fn client_conf_to_str(_c: Self::ClientConf) -> String {
-        String::new()
+    fn client_conf_to_str(c: Self::ClientConf) -> String {
+        c.to_string()
     }
     fn client_data_to_str(d: Self::ClientData) -> String {
         d.to_string()
@@ -70,7 +82,7 @@ impl PingPongMaster {
 
 impl DistributedBenchmarkMaster for PingPongMaster {
     type MasterConf = PingPongRequest;
-    type ClientConf = ();
+    type ClientConf = NetworkSettings;
     type ClientData = ActorPath;
 
     fn setup(
@@ -79,10 +91,14 @@ impl DistributedBenchmarkMaster for PingPongMaster {
         _m: &DeploymentMetaData,
     ) -> Result<Self::ClientConf, BenchmarkError> {
         self.num = Some(c.number_of_messages);
-        let system = crate::kompact_system_provider::global()
-            .new_remote_system_with_threads("netpingpong", 1);
+        let network = NetworkSettings::from_request(c.get_network());
+        let system = crate::kompact_system_provider::current().new_remote_system_with_network(
+            "netpingpong",
+            1,
+            &network,
+        );
         self.system = Some(system);
-        Ok(())
+        Ok(network)
     }
     fn prepare_iteration(&mut self, d: Vec<Self::ClientData>) -> () {
         let ponger_ref = match self.ponger {
@@ -169,14 +185,17 @@ impl PingPongClient {
 }
 
 impl DistributedBenchmarkClient for PingPongClient {
-    type ClientConf = ();
+    type ClientConf = NetworkSettings;
     type ClientData = ActorPath;
 
-    fn setup(&mut self, _c: Self::ClientConf) -> Self::ClientData {
+    fn setup(&mut self, c: Self::ClientConf) -> Self::ClientData {
         println!("Setting up ponger.");
 
-        let system = crate::kompact_system_provider::global()
-            .new_remote_system_with_threads("netpingpong", 1);
+        let system = crate::kompact_system_provider::current().new_remote_system_with_network(
+            "netpingpong",
+            1,
+            &c,
+        );
         let (ponger, unique_reg_f) = system.create_and_register(|| Ponger::new());
         let named_reg_f = system.register_by_alias(&ponger, "ponger");
         unique_reg_f.wait_expect(Duration::from_millis(1000), "Ponger failed to register!");
@@ -0,0 +1,210 @@
+use super::*;
+
+use benchmark_suite_shared::{
+    helpers::parallel_sort::{check_sorted, merge, random_data, Half},
+    kompics_benchmarks::benchmarks::ParallelSortRequest,
+};
+use kompact::prelude::*;
+use std::{convert::TryInto, sync::Arc};
+
+#[derive(Default)]
+pub struct ParallelSort;
+
+impl Benchmark for ParallelSort {
+    type Conf = ParallelSortRequest;
+    type Instance = ParallelSortI;
+
+    fn msg_to_conf(msg: Box<dyn (::protobuf::Message)>) -> Result<Self::Conf, BenchmarkError> {
+        downcast_msg!(msg; ParallelSortRequest)
+    }
+
+    fn validate(c: &Self::Conf) -> Result<(), BenchmarkError> {
+        validation::parallel_sort(c)
+    }
+
+    fn new_instance() -> Self::Instance {
+        ParallelSortI::new()
+    }
+
+    const LABEL: &'static str = "ParallelSort";
+}
+
+pub struct ParallelSortI {
+    data_size: Option<usize>,
+    threshold: Option<usize>,
+    seed: Option<u64>,
+    system: Option<KompactSystem>,
+    data: Option<Vec<u64>>,
+    sorter: Option<Arc<Component<Sorter>>>,
+    result: Option<KFuture<Vec<u64>>>,
+    sorted: Option<Vec<u64>>,
+}
+
+impl ParallelSortI {
+    fn new() -> ParallelSortI {
+        ParallelSortI {
+            data_size: None,
+            threshold: None,
+            seed: None,
+            system: None,
+            data: None,
+            sorter: None,
+            result: None,
+            sorted: None,
+        }
+    }
+}
+
+impl BenchmarkInstance for ParallelSortI {
+    type Conf = ParallelSortRequest;
+
+    fn setup(&mut self, c: &Self::Conf) -> () {
+        self.data_size = Some(c.data_size.try_into().unwrap());
+        self.threshold = Some(c.threshold.try_into().unwrap());
+        self.seed = Some(c.seed);
+        let system = crate::kompact_system_provider::current().new_system("parallel_sort");
+        self.system = Some(system);
+    }
+
+    fn prepare_iteration(&mut self) -> () {
+        match (
+            self.system.as_ref(),
+            self.data_size,
+            self.threshold,
+            self.seed,
+        ) {
+            (Some(system), Some(data_size), Some(threshold), Some(seed)) => {
+                self.data = Some(random_data(data_size, seed));
+                let (result_promise, result) = promise::<Vec<u64>>();
+                let sorter = system
+                    .create(move || Sorter::with(threshold, ResultTarget::Done(result_promise)));
+                let sorter_f = system.start_notify(&sorter);
+                sorter_f
+                    .wait_timeout(Duration::from_millis(1000))
+                    .expect("Sorter never started!");
+                self.sorter = Some(sorter);
+                self.result = Some(result);
+            }
+            _ => unimplemented!(),
+        }
+    }
+
+    fn run_iteration(&mut self) -> () {
+        match (self.data.take(), self.sorter.as_ref()) {
+            (Some(data), Some(sorter)) => {
+                let result = self.result.take().unwrap();
+                sorter.actor_ref().tell(SorterMsg::Sort(data));
+                self.sorted = Some(result.wait());
+            }
+            _ => unimplemented!(),
+        }
+    }
+
+    fn cleanup_iteration(&mut self, last_iteration: bool, _exec_time_millis: f64) -> () {
+        let _ = self.sorter.take(); // kills itself, so just drop
+        let sorted = self.sorted.take().expect("No sorted data was returned");
+        check_sorted(&sorted, self.data_size.unwrap());
+
+        if last_iteration {
+            let system = self.system.take().unwrap();
+            system
+                .shutdown()
+                .expect("Kompics didn't shut down properly");
+            self.data_size = None;
+            self.threshold = None;
+            self.seed = None;
+        }
+    }
+}
+
+enum ResultTarget {
+    Parent(ActorRefStrong<SorterMsg>, Half),
+    Done(KPromise<Vec<u64>>),
+}
+
+#[derive(Debug)]
+enum SorterMsg {
+    Sort(Vec<u64>),
+    Sorted { half: Half, data: Vec<u64> },
+}
+
+/// Sorts its part directly if it is small enough, and otherwise has two children sort
+/// the halves and merges their results.
+#[derive(ComponentDefinition)]
+struct Sorter {
+    ctx: ComponentContext<Self>,
+    threshold: usize,
+    report_to: Option<ResultTarget>,
+    left: Option<Vec<u64>>,
+    right: Option<Vec<u64>>,
+}
+
+impl Sorter {
+    fn with(threshold: usize, report_to: ResultTarget) -> Sorter {
+        Sorter {
+            ctx: ComponentContext::uninitialised(),
+            threshold,
+            report_to: Some(report_to),
+            left: None,
+            right: None,
+        }
+    }
+
+    fn send_result(&mut self, data: Vec<u64>) -> () {
+        match self.report_to.take() {
+            Some(ResultTarget::Parent(parent_ref, half)) => {
+                parent_ref.tell(SorterMsg::Sorted { half, data });
+            }
+            Some(ResultTarget::Done(result)) => {
+                result.fulfil(data).expect("Should fulfil result!");
+            }
+            None => unreachable!("Sorter reported twice!"),
+        }
+        self.ctx.suicide();
+    }
+}
+
+ignore_lifecycle!(Sorter);
+
+impl Actor for Sorter {
+    type Message = SorterMsg;
+
+    fn receive_local(&mut self, msg: Self::Message) -> Handled {
+        match msg {
+            SorterMsg::Sort(mut data) => {
+                if data.len() <= self.threshold {
+                    data.sort_unstable();
+                    self.send_result(data);
+                } else {
+                    let self_ref = self.actor_ref().hold().expect("Live ref");
+                    let threshold = self.threshold;
+                    let right = data.split_off(data.len() / 2);
+                    for (half, part) in vec![(Half::Left, data), (Half::Right, right)] {
+                        let parent = ResultTarget::Parent(self_ref.clone(), half);
+                        let child = self
+                            .ctx
+                            .system()
+                            .create(move || Sorter::with(threshold, parent));
+                        self.ctx.system().start(&child); // don't use create_and_start to avoid unnecessary registration
+                        child.actor_ref().tell(SorterMsg::Sort(part));
+                    }
+                }
+            }
+            SorterMsg::Sorted { half, data } => {
+                match half {
+                    Half::Left => self.left = Some(data),
+                    Half::Right => self.right = Some(data),
+                }
+                if self.left.is_some() && self.right.is_some() {
+                    let merged = merge(self.left.take().unwrap(), self.right.take().unwrap());
+                    self.send_result(merged);
+                }
+            }
+        }
+        Handled::Ok
+    }
+
+    fn receive_network(&mut self, _msg: NetMessage) -> Handled {
+        unimplemented!();
+    }
+}
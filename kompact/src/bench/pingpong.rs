@@ -22,6 +22,10 @@ pub mod actor_pingpong {
             downcast_msg!(msg; PingPongRequest)
         }
 
+        fn validate(c: &Self::Conf) -> Result<(), BenchmarkError> {
+            validation::ping_pong(c)
+        }
+
         fn new_instance() -> Self::Instance {
             PingPongI::new()
         }
@@ -55,7 +59,7 @@ pub mod actor_pingpong {
         fn setup(&mut self, c: &Self::Conf) -> () {
             self.num = Some(c.number_of_messages);
             let system =
-                crate::kompact_system_provider::global().new_system_with_threads("pingpong", 2);
+                crate::kompact_system_provider::current().new_system_with_threads("pingpong", 2);
             self.system = Some(system);
         }
 
@@ -229,6 +233,10 @@ pub mod component_pingpong {
             downcast_msg!(msg; PingPongRequest)
         }
 
+        fn validate(c: &Self::Conf) -> Result<(), BenchmarkError> {
+            validation::ping_pong(c)
+        }
+
         fn new_instance() -> Self::Instance {
             PingPongI::new()
         }
@@ -262,7 +270,7 @@ pub mod component_pingpong {
         fn setup(&mut self, c: &Self::Conf) -> () {
             self.num = Some(c.number_of_messages);
             let system =
-                crate::kompact_system_provider::global().new_system_with_threads("pingpong", 2);
+                crate::kompact_system_provider::current().new_system_with_threads("pingpong", 2);
             self.system = Some(system);
         }
 
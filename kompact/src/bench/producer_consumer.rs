@@ -0,0 +1,379 @@
+use super::*;
+
+use benchmark_suite_shared::{
+    helpers::producer_consumer::{items_of_producer, synthetic_work},
+    kompics_benchmarks::benchmarks::ProducerConsumerRequest,
+};
+use kompact::prelude::*;
+use std::{collections::VecDeque, convert::TryInto, sync::Arc};
+use synchronoise::CountdownEvent;
+
+#[derive(Default)]
+pub struct ProducerConsumer;
+
+impl Benchmark for ProducerConsumer {
+    type Conf = ProducerConsumerRequest;
+    type Instance = ProducerConsumerI;
+
+    fn msg_to_conf(msg: Box<dyn (::protobuf::Message)>) -> Result<Self::Conf, BenchmarkError> {
+        downcast_msg!(msg; ProducerConsumerRequest)
+    }
+
+    fn validate(c: &Self::Conf) -> Result<(), BenchmarkError> {
+        validation::producer_consumer(c)
+    }
+
+    fn new_instance() -> Self::Instance {
+        ProducerConsumerI::new()
+    }
+
+    const LABEL: &'static str = "ProducerConsumer";
+}
+
+pub struct ProducerConsumerI {
+    buffer_size: Option<usize>,
+    num_producers: Option<usize>,
+    num_consumers: Option<usize>,
+    num_items: Option<u64>,
+    work_per_item: Option<u64>,
+    system: Option<KompactSystem>,
+    buffer: Option<Arc<Component<Buffer>>>,
+    producers: Vec<Arc<Component<Producer>>>,
+    consumers: Vec<Arc<Component<Consumer>>>,
+    latch: Option<Arc<CountdownEvent>>,
+}
+
+impl ProducerConsumerI {
+    fn new() -> ProducerConsumerI {
+        ProducerConsumerI {
+            buffer_size: None,
+            num_producers: None,
+            num_consumers: None,
+            num_items: None,
+            work_per_item: None,
+            system: None,
+            buffer: None,
+            producers: Vec::new(),
+            consumers: Vec::new(),
+            latch: None,
+        }
+    }
+}
+
+impl BenchmarkInstance for ProducerConsumerI {
+    type Conf = ProducerConsumerRequest;
+
+    fn setup(&mut self, c: &Self::Conf) -> () {
+        self.buffer_size = Some(c.buffer_size.try_into().unwrap());
+        self.num_producers = Some(c.number_of_producers.try_into().unwrap());
+        self.num_consumers = Some(c.number_of_consumers.try_into().unwrap());
+        self.num_items = Some(c.number_of_items);
+        self.work_per_item = Some(c.work_per_item);
+        let system = crate::kompact_system_provider::current().new_system("producer_consumer");
+        self.system = Some(system);
+    }
+
+    fn prepare_iteration(&mut self) -> () {
+        match (
+            self.system.as_ref(),
+            self.buffer_size,
+            self.num_producers,
+            self.num_consumers,
+            self.num_items,
+            self.work_per_item,
+        ) {
+            (
+                Some(system),
+                Some(buffer_size),
+                Some(num_producers),
+                Some(num_consumers),
+                Some(num_items),
+                Some(work_per_item),
+            ) => {
+                let latch = Arc::new(CountdownEvent::new(num_consumers));
+                let buffer = system.create(|| Buffer::with(buffer_size, num_items));
+                let producers: Vec<Arc<Component<Producer>>> = (0..num_producers)
+                    .map(|id| {
+                        let buffer_ref = buffer.actor_ref();
+                        let items = items_of_producer(id, num_producers, num_items);
+                        system.create(move || Producer::with(items, work_per_item, buffer_ref))
+                    })
+                    .collect();
+                let consumers: Vec<Arc<Component<Consumer>>> = (0..num_consumers)
+                    .map(|_| {
+                        let buffer_ref = buffer.actor_ref();
+                        let latch = latch.clone();
+                        system.create(move || Consumer::with(work_per_item, buffer_ref, latch))
+                    })
+                    .collect();
+                let mut start_fs: Vec<_> = producers
+                    .iter()
+                    .map(|p| system.start_notify(p))
+                    .chain(consumers.iter().map(|c| system.start_notify(c)))
+                    .collect();
+                start_fs.push(system.start_notify(&buffer));
+                for f in start_fs {
+                    f.wait_timeout(Duration::from_millis(1000))
+                        .expect("Component never started!");
+                }
+                self.buffer = Some(buffer);
+                self.producers = producers;
+                self.consumers = consumers;
+                self.latch = Some(latch);
+            }
+            _ => unimplemented!(),
+        }
+    }
+
+    fn run_iteration(&mut self) -> () {
+        let latch = self.latch.take().unwrap();
+        for consumer in self.consumers.iter() {
+            consumer.actor_ref().tell(ConsumerMsg::Start);
+        }
+        for producer in self.producers.iter() {
+            producer.actor_ref().tell(ProducerMsg::Start);
+        }
+        latch.wait();
+    }
+
+    fn cleanup_iteration(&mut self, last_iteration: bool, _exec_time_millis: f64) -> () {
+        let system = self.system.take().unwrap();
+        let mut consumed = 0u64;
+        for consumer in self.consumers.drain(..) {
+            let (items, _checksum) = consumer.on_definition(|def| (def.consumed, def.checksum));
+            consumed += items;
+            let f = system.kill_notify(consumer);
+            f.wait_timeout(Duration::from_millis(1000))
+                .expect("Consumer never died!");
+        }
+        assert_eq!(
+            consumed,
+            self.num_items.unwrap(),
+            "Items were lost or duplicated!"
+        );
+        for producer in self.producers.drain(..) {
+            let f = system.kill_notify(producer);
+            f.wait_timeout(Duration::from_millis(1000))
+                .expect("Producer never died!");
+        }
+        let f = system.kill_notify(self.buffer.take().unwrap());
+        f.wait_timeout(Duration::from_millis(1000))
+            .expect("Buffer never died!");
+
+        if last_iteration {
+            system
+                .shutdown()
+                .expect("Kompics didn't shut down properly");
+            self.buffer_size = None;
+            self.num_producers = None;
+            self.num_consumers = None;
+            self.num_items = None;
+            self.work_per_item = None;
+        } else {
+            self.system = Some(system);
+        }
+    }
+}
+
+#[derive(Debug)]
+enum BufferMsg {
+    /// Completed once the item has been taken into the buffer.
+    Produce(Ask<u64, ()>),
+    /// Completed with the next item, or with `None` once all items have been handed out.
+    Consume(Ask<(), Option<u64>>),
+}
+
+#[derive(Debug, Clone, Copy)]
+enum ProducerMsg {
+    Start,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum ConsumerMsg {
+    Start,
+}
+
+/// Holds up to `capacity` items, and keeps the requests it can't complete yet.
+///
+/// A full buffer holds on to the `Ask` of a `Produce` until a consumer makes room,
+/// and an empty one holds on to the `Ask` of a `Consume` until an item arrives.
+#[derive(ComponentDefinition)]
+struct Buffer {
+    ctx: ComponentContext<Self>,
+    capacity: usize,
+    items: VecDeque<u64>,
+    waiting_producers: VecDeque<Ask<u64, ()>>,
+    waiting_consumers: VecDeque<Ask<(), Option<u64>>>,
+    undelivered: u64,
+}
+
+impl Buffer {
+    fn with(capacity: usize, num_items: u64) -> Buffer {
+        Buffer {
+            ctx: ComponentContext::uninitialised(),
+            capacity,
+            items: VecDeque::with_capacity(capacity),
+            waiting_producers: VecDeque::new(),
+            waiting_consumers: VecDeque::new(),
+            undelivered: num_items,
+        }
+    }
+
+    fn deliver(&mut self, consumer: Ask<(), Option<u64>>, item: u64) -> () {
+        consumer.reply(Some(item)).expect("Sent");
+        self.undelivered -= 1u64;
+        if self.undelivered == 0 {
+            for consumer in self.waiting_consumers.drain(..) {
+                consumer.reply(None).expect("Sent");
+            }
+        }
+    }
+}
+
+ignore_lifecycle!(Buffer);
+
+impl Actor for Buffer {
+    type Message = BufferMsg;
+
+    fn receive_local(&mut self, msg: Self::Message) -> Handled {
+        match msg {
+            BufferMsg::Produce(ask) => {
+                let item = *ask.request();
+                if let Some(consumer) = self.waiting_consumers.pop_front() {
+                    // the buffer is empty, since a consumer is waiting
+                    self.deliver(consumer, item);
+                    ask.reply(()).expect("Sent");
+                } else if self.items.len() < self.capacity {
+                    self.items.push_back(item);
+                    ask.reply(()).expect("Sent");
+                } else {
+                    self.waiting_producers.push_back(ask);
+                }
+            }
+            BufferMsg::Consume(ask) => {
+                if let Some(item) = self.items.pop_front() {
+                    self.deliver(ask, item);
+                    if let Some(producer) = self.waiting_producers.pop_front() {
+                        self.items.push_back(*producer.request());
+                        producer.reply(()).expect("Sent");
+                    }
+                } else if self.undelivered == 0 {
+                    ask.reply(None).expect("Sent");
+                } else {
+                    self.waiting_consumers.push_back(ask);
+                }
+            }
+        }
+        Handled::Ok
+    }
+
+    fn receive_network(&mut self, _msg: NetMessage) -> Handled {
+        unimplemented!();
+    }
+}
+
+/// Produces its items one at a time.
+///
+/// The component is blocked on the buffer's reply for each item,
+/// so it doesn't produce the next one before the buffer has taken the last.
+#[derive(ComponentDefinition)]
+struct Producer {
+    ctx: ComponentContext<Self>,
+    remaining: u64,
+    work_per_item: u64,
+    buffer: ActorRef<BufferMsg>,
+}
+
+impl Producer {
+    fn with(num_items: u64, work_per_item: u64, buffer: ActorRef<BufferMsg>) -> Producer {
+        Producer {
+            ctx: ComponentContext::uninitialised(),
+            remaining: num_items,
+            work_per_item,
+            buffer,
+        }
+    }
+}
+
+ignore_lifecycle!(Producer);
+
+impl Actor for Producer {
+    type Message = ProducerMsg;
+
+    fn receive_local(&mut self, msg: Self::Message) -> Handled {
+        match msg {
+            ProducerMsg::Start => Handled::block_on(self, move |mut async_self| async move {
+                while async_self.remaining > 0 {
+                    async_self.remaining -= 1u64;
+                    let item = synthetic_work(async_self.work_per_item, async_self.remaining);
+                    let accepted = async_self
+                        .buffer
+                        .ask_with(|promise| BufferMsg::Produce(Ask::new(promise, item)));
+                    accepted.await.expect("Buffer dropped the item");
+                }
+            }),
+        }
+    }
+
+    fn receive_network(&mut self, _msg: NetMessage) -> Handled {
+        unimplemented!();
+    }
+}
+
+/// Asks the buffer for one item at a time, until there are none left.
+#[derive(ComponentDefinition)]
+struct Consumer {
+    ctx: ComponentContext<Self>,
+    work_per_item: u64,
+    consumed: u64,
+    checksum: u64,
+    buffer: ActorRef<BufferMsg>,
+    latch: Arc<CountdownEvent>,
+}
+
+impl Consumer {
+    fn with(
+        work_per_item: u64,
+        buffer: ActorRef<BufferMsg>,
+        latch: Arc<CountdownEvent>,
+    ) -> Consumer {
+        Consumer {
+            ctx: ComponentContext::uninitialised(),
+            work_per_item,
+            consumed: 0u64,
+            checksum: 0u64,
+            buffer,
+            latch,
+        }
+    }
+}
+
+ignore_lifecycle!(Consumer);
+
+impl Actor for Consumer {
+    type Message = ConsumerMsg;
+
+    fn receive_local(&mut self, msg: Self::Message) -> Handled {
+        match msg {
+            ConsumerMsg::Start => Handled::block_on(self, move |mut async_self| async move {
+                loop {
+                    let next = async_self
+                        .buffer
+                        .ask_with(|promise| BufferMsg::Consume(Ask::new(promise, ())));
+                    match next.await.expect("Buffer dropped the request") {
+                        Some(item) => {
+                            async_self.checksum ^= synthetic_work(async_self.work_per_item, item);
+                            async_self.consumed += 1u64;
+                        }
+                        None => break,
+                    }
+                }
+                async_self.latch.decrement().expect("Should decrement!");
+            }),
+        }
+    }
+
+    fn receive_network(&mut self, _msg: NetMessage) -> Handled {
+        unimplemented!();
+    }
+}
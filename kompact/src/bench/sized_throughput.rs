@@ -1,6 +1,9 @@
 use super::*;
 
-use crate::bench::messages::SizedThroughputMessage;
+use crate::{
+    bench::messages::SizedThroughputMessage,
+    kompact_system_provider::{BufferSettings, NetworkSettings},
+};
 use benchmark_suite_shared::kompics_benchmarks::benchmarks::SizedThroughputRequest;
 use kompact::prelude::{ser_helpers::preserialise_msg, *};
 use std::{borrow::BorrowMut, fmt::Debug, ops::Deref, str::FromStr, sync::Arc, time::Duration};
@@ -31,12 +34,21 @@ impl DistributedBenchmark for SizedThroughputBenchmark {
         downcast_msg!(msg; SizedThroughputRequest)
     }
 
+    fn validate(c: &Self::MasterConf, _meta: &DeploymentMetaData) -> Result<(), BenchmarkError> {
+        validation::sized_throughput(c)?;
+        crate::kompact_system_provider::current().validate_network(
+            "SizedThroughput",
+            &BufferSettings::default(),
+            c.get_network(),
+        )
+    }
+
     fn new_client() -> Self::Client {
         SizedThroughputClient::new()
     }
     fn str_to_client_conf(str: String) -> Result<Self::ClientConf, BenchmarkError> {
         let split: Vec<_> = str.split(',').collect();
-        if split.len() != 4 {
+        if split.len() != 5 {
             Err(BenchmarkError::InvalidMessage(format!(
                 "String '{}' does not represent a client conf!",
                 str
@@ -70,11 +82,18 @@ impl DistributedBenchmark for SizedThroughputBenchmark {
                     str, e
                 ))
             })?;
+            let network = split[4].parse::<NetworkSettings>().map_err(|e| {
+                BenchmarkError::InvalidMessage(format!(
+                    "String '{}' does not represent a client conf: {}",
+                    str, e
+                ))
+            })?;
             let mut client_conf = SizedThroughputRequest::new();
             client_conf.set_message_size(message_size);
             client_conf.set_batch_size(batch_size);
             client_conf.set_number_of_batches(number_of_batches);
             client_conf.set_number_of_pairs(number_of_pairs);
+            client_conf.set_network(network.to_request());
 
             Ok(client_conf)
         }
@@ -89,8 +108,12 @@ impl DistributedBenchmark for SizedThroughputBenchmark {
 
     fn client_conf_to_str(c: Self::ClientConf) -> String {
         format!(
-            "{},{},{},{}",
-            c.message_size, c.batch_size, c.number_of_batches, c.number_of_pairs,
+            "{},{},{},{},{}",
+            c.message_size,
+            c.batch_size,
+            c.number_of_batches,
+            c.number_of_pairs,
+            NetworkSettings::from_request(c.get_network()),
         )
     }
     fn client_data_to_str(d: Self::ClientData) -> String {
@@ -134,7 +157,12 @@ impl DistributedBenchmarkMaster for SizedThroughputMaster {
         c: Self::MasterConf,
         _m: &DeploymentMetaData,
     ) -> Result<Self::ClientConf, BenchmarkError> {
-        let system = crate::kompact_system_provider::global().new_remote_system("SizedThroughput");
+        let provider = crate::kompact_system_provider::current();
+        let system = provider.new_remote_system_with_network(
+            "SizedThroughput",
+            provider.get_num_workers(),
+            &NetworkSettings::from_request(c.get_network()),
+        );
         let client_conf = c.clone();
         let params = c.clone();
         println!(
@@ -230,7 +258,12 @@ impl DistributedBenchmarkClient for SizedThroughputClient {
     type ClientData = SizedRefs;
 
     fn setup(&mut self, c: Self::ClientConf) -> Self::ClientData {
-        let system = crate::kompact_system_provider::global().new_remote_system("SizedThroughput");
+        let provider = crate::kompact_system_provider::current();
+        let system = provider.new_remote_system_with_network(
+            "SizedThroughput",
+            provider.get_num_workers(),
+            &NetworkSettings::from_request(c.get_network()),
+        );
 
         let mut sinks: Vec<ActorPath> = Vec::new();
         for _ in 0..c.number_of_pairs {
@@ -1,5 +1,6 @@
 use super::*;
 
+use crate::kompact_system_provider::{BufferSettings, NetworkSettings};
 use benchmark_suite_shared::kompics_benchmarks::benchmarks::StreamingWindowsRequest;
 use kompact::prelude::*;
 use parse_duration;
@@ -12,6 +13,7 @@ pub struct WindowerConfig {
     batch_size: u64,
     amplification: u64,
     upstream_actor_paths: Vec<ActorPath>,
+    network: NetworkSettings,
 }
 impl WindowerConfig {
     fn new(
@@ -19,12 +21,14 @@ impl WindowerConfig {
         batch_size: u64,
         amplification: u64,
         upstream_actor_paths: Vec<ActorPath>,
+        network: NetworkSettings,
     ) -> WindowerConfig {
         WindowerConfig {
             window_size,
             batch_size,
             amplification,
             upstream_actor_paths,
+            network,
         }
     }
 }
@@ -53,12 +57,22 @@ impl DistributedBenchmark for StreamingWindows {
         downcast_msg!(msg; StreamingWindowsRequest)
     }
 
+    fn validate(c: &Self::MasterConf, _meta: &DeploymentMetaData) -> Result<(), BenchmarkError> {
+        validation::streaming_windows(c)?;
+        crate::kompact_system_provider::current().validate_network(
+            "streamingwindows",
+            &BufferSettings::default(),
+            c.get_network(),
+        )?;
+        Params::from_req(c).map(|_| ())
+    }
+
     fn new_client() -> Self::Client {
         StreamingWindowsClient::new()
     }
     fn str_to_client_conf(str: String) -> Result<Self::ClientConf, BenchmarkError> {
         let split: Vec<_> = str.split(',').collect();
-        if split.len() != 4 {
+        if split.len() != 5 {
             Err(BenchmarkError::InvalidMessage(format!(
                 "String '{}' does not represent a client conf!",
                 str
@@ -97,11 +111,18 @@ impl DistributedBenchmark for StreamingWindows {
             let actor_paths = actor_paths_res.map_err(|e| {
                 BenchmarkError::InvalidMessage(format!("Could not read client conf: {}", e))
             })?;
+            let network = split[4].parse::<NetworkSettings>().map_err(|e| {
+                BenchmarkError::InvalidMessage(format!(
+                    "String '{}' does not represent a client conf: {}",
+                    str, e
+                ))
+            })?;
             Ok(WindowerConfig::new(
                 window_size,
                 batch_size,
                 amplification,
                 actor_paths,
+                network,
             ))
         }
     }
@@ -121,11 +142,12 @@ impl DistributedBenchmark for StreamingWindows {
             .collect::<Vec<String>>()
             .join(";");
         format!(
-            "{},{},{},{}",
+            "{},{},{},{},{}",
             c.window_size.as_millis(),
             c.batch_size,
             c.amplification,
-            paths
+            paths,
+            c.network
         )
     }
     fn client_data_to_str(d: Self::ClientData) -> String {
@@ -197,7 +219,13 @@ impl DistributedBenchmarkMaster for StreamingWindowsMaster {
         _m: &DeploymentMetaData,
     ) -> Result<Self::ClientConf, BenchmarkError> {
         let params = Params::from_req(&c)?;
-        let system = crate::kompact_system_provider::global().new_remote_system("streamingwindows");
+        let network = NetworkSettings::from_request(c.get_network());
+        let provider = crate::kompact_system_provider::current();
+        let system = provider.new_remote_system_with_network(
+            "streamingwindows",
+            provider.get_num_workers(),
+            &network,
+        );
 
         let mut sources: Vec<ActorPath> = Vec::new();
         for pid in 0..params.number_of_partitions {
@@ -216,6 +244,7 @@ impl DistributedBenchmarkMaster for StreamingWindowsMaster {
             params.batch_size,
             params.window_size_amplification,
             sources,
+            network,
         );
         self.params = Some(params);
         Ok(client_conf)
@@ -324,7 +353,12 @@ impl DistributedBenchmarkClient for StreamingWindowsClient {
     fn setup(&mut self, mut c: Self::ClientConf) -> Self::ClientData {
         println!("Setting up windowers.");
 
-        let system = crate::kompact_system_provider::global().new_remote_system("streamingwindows");
+        let provider = crate::kompact_system_provider::current();
+        let system = provider.new_remote_system_with_network(
+            "streamingwindows",
+            provider.get_num_workers(),
+            &c.network,
+        );
 
         let window_size = c.window_size;
         let batch_size = c.batch_size;
@@ -0,0 +1,391 @@
+use super::*;
+
+use benchmark_suite_shared::{
+    helpers::thread_ring::token_holders, kompics_benchmarks::benchmarks::ThreadRingRequest,
+};
+use kompact::prelude::*;
+use std::{convert::TryInto, sync::Arc};
+use synchronoise::CountdownEvent;
+
+#[derive(Debug, Clone, Copy)]
+struct Token {
+    remaining: u64,
+}
+
+pub mod actor_thread_ring {
+    use super::*;
+
+    #[derive(Default)]
+    pub struct ThreadRing;
+
+    impl Benchmark for ThreadRing {
+        type Conf = ThreadRingRequest;
+        type Instance = ThreadRingI;
+
+        fn msg_to_conf(msg: Box<dyn (::protobuf::Message)>) -> Result<Self::Conf, BenchmarkError> {
+            downcast_msg!(msg; ThreadRingRequest)
+        }
+
+        fn validate(c: &Self::Conf) -> Result<(), BenchmarkError> {
+            validation::thread_ring(c)
+        }
+
+        fn new_instance() -> Self::Instance {
+            ThreadRingI::new()
+        }
+
+        const LABEL: &'static str = "ThreadRing";
+    }
+
+    pub struct ThreadRingI {
+        num_actors: Option<usize>,
+        num_passes: Option<u64>,
+        num_tokens: Option<usize>,
+        system: Option<KompactSystem>,
+        nodes: Vec<Arc<Component<RingNode>>>,
+        holders: Vec<ActorRefStrong<Token>>,
+        latch: Option<Arc<CountdownEvent>>,
+    }
+
+    impl ThreadRingI {
+        fn new() -> ThreadRingI {
+            ThreadRingI {
+                num_actors: None,
+                num_passes: None,
+                num_tokens: None,
+                system: None,
+                nodes: Vec::new(),
+                holders: Vec::new(),
+                latch: None,
+            }
+        }
+    }
+
+    impl BenchmarkInstance for ThreadRingI {
+        type Conf = ThreadRingRequest;
+
+        fn setup(&mut self, c: &Self::Conf) -> () {
+            self.num_actors = Some(c.number_of_actors.try_into().unwrap());
+            self.num_passes = Some(c.number_of_passes);
+            self.num_tokens = Some(c.number_of_tokens.try_into().unwrap());
+            let system = crate::kompact_system_provider::current().new_system("threadring");
+            self.system = Some(system);
+        }
+
+        fn prepare_iteration(&mut self) -> () {
+            match (self.system.as_ref(), self.num_actors, self.num_tokens) {
+                (Some(system), Some(num_actors), Some(num_tokens)) => {
+                    let latch = Arc::new(CountdownEvent::new(num_tokens));
+                    let nodes: Vec<Arc<Component<RingNode>>> = (0..num_actors)
+                        .map(|_| system.create(|| RingNode::with(latch.clone())))
+                        .collect();
+                    for (i, node) in nodes.iter().enumerate() {
+                        let next = nodes[(i + 1) % num_actors]
+                            .actor_ref()
+                            .hold()
+                            .expect("Live ref");
+                        node.on_definition(|def| def.next = Some(next));
+                    }
+                    let start_fs: Vec<_> = nodes.iter().map(|n| system.start_notify(n)).collect();
+                    for f in start_fs {
+                        f.wait_timeout(Duration::from_millis(1000))
+                            .expect("RingNode never started!");
+                    }
+                    self.holders = token_holders(num_actors, num_tokens)
+                        .into_iter()
+                        .map(|i| nodes[i].actor_ref().hold().expect("Live ref"))
+                        .collect();
+                    self.nodes = nodes;
+                    self.latch = Some(latch);
+                }
+                _ => unimplemented!(),
+            }
+        }
+
+        fn run_iteration(&mut self) -> () {
+            match self.num_passes {
+                Some(num_passes) => {
+                    let latch = self.latch.take().unwrap();
+                    for holder in self.holders.drain(..) {
+                        holder.tell(Token {
+                            remaining: num_passes,
+                        });
+                    }
+                    latch.wait();
+                }
+                None => unimplemented!(),
+            }
+        }
+
+        fn cleanup_iteration(&mut self, last_iteration: bool, _exec_time_millis: f64) -> () {
+            let system = self.system.take().unwrap();
+            self.holders.clear();
+            for node in self.nodes.drain(..) {
+                let f = system.kill_notify(node);
+                f.wait_timeout(Duration::from_millis(1000))
+                    .expect("RingNode never died!");
+            }
+
+            if last_iteration {
+                system
+                    .shutdown()
+                    .expect("Kompics didn't shut down properly");
+                self.num_actors = None;
+                self.num_passes = None;
+                self.num_tokens = None;
+            } else {
+                self.system = Some(system);
+            }
+        }
+    }
+
+    #[derive(ComponentDefinition)]
+    struct RingNode {
+        ctx: ComponentContext<Self>,
+        next: Option<ActorRefStrong<Token>>,
+        latch: Arc<CountdownEvent>,
+    }
+
+    impl RingNode {
+        fn with(latch: Arc<CountdownEvent>) -> RingNode {
+            RingNode {
+                ctx: ComponentContext::uninitialised(),
+                next: None,
+                latch,
+            }
+        }
+    }
+
+    impl ComponentLifecycle for RingNode {
+        fn on_kill(&mut self) -> Handled {
+            self.next = None; // the refs around the ring would keep each other alive
+            Handled::Ok
+        }
+    }
+
+    impl Actor for RingNode {
+        type Message = Token;
+
+        fn receive_local(&mut self, msg: Self::Message) -> Handled {
+            if msg.remaining > 0 {
+                self.next
+                    .as_ref()
+                    .expect("Ring was never closed")
+                    .tell(Token {
+                        remaining: msg.remaining - 1,
+                    });
+            } else {
+                self.latch.decrement().expect("Should decrement!");
+            }
+            Handled::Ok
+        }
+
+        fn receive_network(&mut self, _msg: NetMessage) -> Handled {
+            unimplemented!();
+        }
+    }
+}
+
+pub mod component_thread_ring {
+    use super::*;
+
+    /// Each node provides the port its successor requires.
+    struct RingPort;
+    impl Port for RingPort {
+        type Indication = Token;
+        type Request = Never;
+    }
+
+    #[derive(Default)]
+    pub struct ThreadRing;
+
+    impl Benchmark for ThreadRing {
+        type Conf = ThreadRingRequest;
+        type Instance = ThreadRingI;
+
+        fn msg_to_conf(msg: Box<dyn (::protobuf::Message)>) -> Result<Self::Conf, BenchmarkError> {
+            downcast_msg!(msg; ThreadRingRequest)
+        }
+
+        fn validate(c: &Self::Conf) -> Result<(), BenchmarkError> {
+            validation::thread_ring(c)
+        }
+
+        fn new_instance() -> Self::Instance {
+            ThreadRingI::new()
+        }
+
+        const LABEL: &'static str = "ThreadRing";
+    }
+
+    pub struct ThreadRingI {
+        num_actors: Option<usize>,
+        num_passes: Option<u64>,
+        num_tokens: Option<usize>,
+        system: Option<KompactSystem>,
+        nodes: Vec<Arc<Component<RingNode>>>,
+        holders: Vec<Arc<Component<RingNode>>>,
+        latch: Option<Arc<CountdownEvent>>,
+    }
+
+    impl ThreadRingI {
+        fn new() -> ThreadRingI {
+            ThreadRingI {
+                num_actors: None,
+                num_passes: None,
+                num_tokens: None,
+                system: None,
+                nodes: Vec::new(),
+                holders: Vec::new(),
+                latch: None,
+            }
+        }
+    }
+
+    impl BenchmarkInstance for ThreadRingI {
+        type Conf = ThreadRingRequest;
+
+        fn setup(&mut self, c: &Self::Conf) -> () {
+            self.num_actors = Some(c.number_of_actors.try_into().unwrap());
+            self.num_passes = Some(c.number_of_passes);
+            self.num_tokens = Some(c.number_of_tokens.try_into().unwrap());
+            let system = crate::kompact_system_provider::current().new_system("threadring");
+            self.system = Some(system);
+        }
+
+        fn prepare_iteration(&mut self) -> () {
+            match (
+                self.system.as_ref(),
+                self.num_actors,
+                self.num_passes,
+                self.num_tokens,
+            ) {
+                (Some(system), Some(num_actors), Some(num_passes), Some(num_tokens)) => {
+                    let latch = Arc::new(CountdownEvent::new(num_tokens));
+                    let holder_ids = token_holders(num_actors, num_tokens);
+                    let nodes: Vec<Arc<Component<RingNode>>> = (0..num_actors)
+                        .map(|i| {
+                            let token = if holder_ids.contains(&i) {
+                                Some(Token {
+                                    remaining: num_passes,
+                                })
+                            } else {
+                                None
+                            };
+                            system.create(|| RingNode::with(token, latch.clone()))
+                        })
+                        .collect();
+                    for (i, node) in nodes.iter().enumerate() {
+                        let next = &nodes[(i + 1) % num_actors];
+                        let node_port: ProvidedRef<RingPort> = node.provided_ref();
+                        next.connect_to_provided(node_port);
+                        // tokens are indications, so the provider needs to know its successor
+                        node.connect_to_required(next.required_ref());
+                    }
+                    // the token holders are only started in run_iteration
+                    let start_fs: Vec<_> = nodes
+                        .iter()
+                        .enumerate()
+                        .filter(|(i, _)| !holder_ids.contains(i))
+                        .map(|(_, n)| system.start_notify(n))
+                        .collect();
+                    for f in start_fs {
+                        f.wait_timeout(Duration::from_millis(1000))
+                            .expect("RingNode never started!");
+                    }
+                    self.holders = holder_ids.into_iter().map(|i| nodes[i].clone()).collect();
+                    self.nodes = nodes;
+                    self.latch = Some(latch);
+                }
+                _ => unimplemented!(),
+            }
+        }
+
+        fn run_iteration(&mut self) -> () {
+            match self.system {
+                Some(ref system) => {
+                    let latch = self.latch.take().unwrap();
+                    for holder in self.holders.drain(..) {
+                        system.start(&holder);
+                    }
+                    latch.wait();
+                }
+                None => unimplemented!(),
+            }
+        }
+
+        fn cleanup_iteration(&mut self, last_iteration: bool, _exec_time_millis: f64) -> () {
+            let system = self.system.take().unwrap();
+            self.holders.clear();
+            for node in self.nodes.drain(..) {
+                let f = system.kill_notify(node);
+                f.wait_timeout(Duration::from_millis(1000))
+                    .expect("RingNode never died!");
+            }
+
+            if last_iteration {
+                system
+                    .shutdown()
+                    .expect("Kompics didn't shut down properly");
+                self.num_actors = None;
+                self.num_passes = None;
+                self.num_tokens = None;
+            } else {
+                self.system = Some(system);
+            }
+        }
+    }
+
+    #[derive(ComponentDefinition, Actor)]
+    struct RingNode {
+        ctx: ComponentContext<Self>,
+        successor: ProvidedPort<RingPort>,
+        predecessor: RequiredPort<RingPort>,
+        token: Option<Token>,
+        latch: Arc<CountdownEvent>,
+    }
+
+    impl RingNode {
+        fn with(token: Option<Token>, latch: Arc<CountdownEvent>) -> RingNode {
+            RingNode {
+                ctx: ComponentContext::uninitialised(),
+                successor: ProvidedPort::uninitialised(),
+                predecessor: RequiredPort::uninitialised(),
+                token,
+                latch,
+            }
+        }
+
+        fn handle_token(&mut self, token: Token) -> () {
+            if token.remaining > 0 {
+                self.successor.trigger(Token {
+                    remaining: token.remaining - 1,
+                });
+            } else {
+                self.latch.decrement().expect("Should decrement!");
+            }
+        }
+    }
+
+    impl ComponentLifecycle for RingNode {
+        fn on_start(&mut self) -> Handled {
+            if let Some(token) = self.token.take() {
+                self.handle_token(token);
+            }
+            Handled::Ok
+        }
+    }
+
+    impl Provide<RingPort> for RingNode {
+        fn handle(&mut self, _event: Never) -> Handled {
+            unreachable!("Can't instantiate Never!");
+        }
+    }
+
+    impl Require<RingPort> for RingNode {
+        fn handle(&mut self, token: Token) -> Handled {
+            self.handle_token(token);
+            Handled::Ok
+        }
+    }
+}
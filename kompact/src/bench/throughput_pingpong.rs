@@ -141,6 +141,10 @@ pub mod actor_pingpong {
             downcast_msg!(msg; ThroughputPingPongRequest)
         }
 
+        fn validate(c: &Self::Conf) -> Result<(), BenchmarkError> {
+            validation::throughput_ping_pong(c)
+        }
+
         fn new_instance() -> Self::Instance {
             PingPongI::new()
         }
@@ -175,7 +179,7 @@ pub mod actor_pingpong {
 
         fn setup(&mut self, c: &Self::Conf) -> () {
             self.params = Some(Params::from_req(c));
-            let system = crate::kompact_system_provider::global().new_system("throughputpingpong");
+            let system = crate::kompact_system_provider::current().new_system("throughputpingpong");
             self.system = Some(system);
         }
 
@@ -505,6 +509,10 @@ pub mod component_pingpong {
             downcast_msg!(msg; ThroughputPingPongRequest)
         }
 
+        fn validate(c: &Self::Conf) -> Result<(), BenchmarkError> {
+            validation::throughput_ping_pong(c)
+        }
+
         fn new_instance() -> Self::Instance {
             PingPongI::new()
         }
@@ -537,7 +545,7 @@ pub mod component_pingpong {
 
         fn setup(&mut self, c: &Self::Conf) -> () {
             self.params = Some(Params::from_req(c));
-            let system = crate::kompact_system_provider::global().new_system("throughputpingpong");
+            let system = crate::kompact_system_provider::current().new_system("throughputpingpong");
             self.system = Some(system);
         }
 
@@ -1,6 +1,6 @@
 use super::*;
 use benchmark_suite_shared::{
-    benchmark_runner::{not_implemented, run, run_async},
+    benchmark_runner::{not_implemented, run_and_record, run_async},
     kompics_benchmarks::{benchmarks, benchmarks_grpc, messages},
 };
 use futures::future::Future;
@@ -44,7 +44,7 @@ impl benchmarks_grpc::BenchmarkRunner for BenchmarkRunnerActorImpl {
         println!("Got ping_pong req: {}", p.number_of_messages);
         let f = run_async(move || {
             let b = bench::pingpong::actor_pingpong::PingPong::default();
-            run(&b, &p).into()
+            run_and_record(&b, &p)
         })
         .map_err(|e| {
             println!("Converting benchmark error into grpc error: {:?}", e);
@@ -69,7 +69,7 @@ impl benchmarks_grpc::BenchmarkRunner for BenchmarkRunnerActorImpl {
         println!("Got req: {:?}", p);
         let f = run_async(move || {
             let b = bench::throughput_pingpong::actor_pingpong::PingPong::default();
-            run(&b, &p).into()
+            run_and_record(&b, &p)
         })
         .map_err(|e| {
             println!("Converting benchmark error into grpc error: {:?}", e);
@@ -109,8 +109,8 @@ impl benchmarks_grpc::BenchmarkRunner for BenchmarkRunnerActorImpl {
     ) -> grpc::SingleResponse<messages::TestResult> {
         println!("Got fibonacci req: {:?}", p);
         let f = run_async(move || {
-            let b = bench::fibonacci::Fibonacci::default();
-            run(&b, &p).into()
+            let b = bench::fibonacci::actor_fibonacci::Fibonacci::default();
+            run_and_record(&b, &p)
         })
         .map_err(|e| {
             println!("Converting benchmark error into grpc error: {:?}", e);
@@ -127,7 +127,7 @@ impl benchmarks_grpc::BenchmarkRunner for BenchmarkRunnerActorImpl {
         println!("Got chameneos req: {:?}", p);
         let f = run_async(move || {
             let b = bench::chameneos::actor_chameneos::Chameneos::default();
-            run(&b, &p).into()
+            run_and_record(&b, &p)
         })
         .map_err(|e| {
             println!("Converting benchmark error into grpc error: {:?}", e);
@@ -144,7 +144,7 @@ impl benchmarks_grpc::BenchmarkRunner for BenchmarkRunnerActorImpl {
         println!("Got APSP req: {:?}", p);
         let f = run_async(move || {
             let b = bench::all_pairs_shortest_path::actor_apsp::AllPairsShortestPath::default();
-            run(&b, &p).into()
+            run_and_record(&b, &p)
         })
         .map_err(|e| {
             println!("Converting benchmark error into grpc error: {:?}", e);
@@ -169,6 +169,141 @@ impl benchmarks_grpc::BenchmarkRunner for BenchmarkRunnerActorImpl {
         println!("Got STP req: {:?}", p);
         grpc::SingleResponse::completed(not_implemented())
     }
+
+    fn thread_ring(
+        &self,
+        _o: grpc::RequestOptions,
+        p: benchmarks::ThreadRingRequest,
+    ) -> grpc::SingleResponse<messages::TestResult> {
+        println!("Got thread ring req: {:?}", p);
+        let f = run_async(move || {
+            let b = bench::thread_ring::actor_thread_ring::ThreadRing::default();
+            run_and_record(&b, &p)
+        })
+        .map_err(|e| {
+            println!("Converting benchmark error into grpc error: {:?}", e);
+            e.into()
+        });
+        grpc::SingleResponse::no_metadata(f)
+    }
+
+    fn fan_in(
+        &self,
+        _o: grpc::RequestOptions,
+        _p: benchmarks::FanInRequest,
+    ) -> grpc::SingleResponse<messages::TestResult> {
+        grpc::SingleResponse::completed(not_implemented())
+    }
+
+    fn fanout(
+        &self,
+        _o: grpc::RequestOptions,
+        p: benchmarks::FanoutRequest,
+    ) -> grpc::SingleResponse<messages::TestResult> {
+        println!("Got fanout req: {:?}", p);
+        let f = run_async(move || {
+            let b = bench::fanout::actor_fanout::Fanout::default();
+            run_and_record(&b, &p)
+        })
+        .map_err(|e| {
+            println!("Converting benchmark error into grpc error: {:?}", e);
+            e.into()
+        });
+        grpc::SingleResponse::no_metadata(f)
+    }
+
+    fn big(
+        &self,
+        _o: grpc::RequestOptions,
+        p: benchmarks::BigRequest,
+    ) -> grpc::SingleResponse<messages::TestResult> {
+        println!("Got big req: {:?}", p);
+        let f = run_async(move || {
+            let b = bench::big::Big::default();
+            run_and_record(&b, &p)
+        })
+        .map_err(|e| {
+            println!("Converting benchmark error into grpc error: {:?}", e);
+            e.into()
+        });
+        grpc::SingleResponse::no_metadata(f)
+    }
+
+    fn bank(
+        &self,
+        _o: grpc::RequestOptions,
+        p: benchmarks::BankRequest,
+    ) -> grpc::SingleResponse<messages::TestResult> {
+        println!("Got bank req: {:?}", p);
+        let f = run_async(move || {
+            let b = bench::bank::Bank::default();
+            run_and_record(&b, &p)
+        })
+        .map_err(|e| {
+            println!("Converting benchmark error into grpc error: {:?}", e);
+            e.into()
+        });
+        grpc::SingleResponse::no_metadata(f)
+    }
+
+    fn producer_consumer(
+        &self,
+        _o: grpc::RequestOptions,
+        p: benchmarks::ProducerConsumerRequest,
+    ) -> grpc::SingleResponse<messages::TestResult> {
+        println!("Got producer_consumer req: {:?}", p);
+        let f = run_async(move || {
+            let b = bench::producer_consumer::ProducerConsumer::default();
+            run_and_record(&b, &p)
+        })
+        .map_err(|e| {
+            println!("Converting benchmark error into grpc error: {:?}", e);
+            e.into()
+        });
+        grpc::SingleResponse::no_metadata(f)
+    }
+
+    fn dining_philosophers(
+        &self,
+        _o: grpc::RequestOptions,
+        p: benchmarks::DiningPhilosophersRequest,
+    ) -> grpc::SingleResponse<messages::TestResult> {
+        println!("Got dining_philosophers req: {:?}", p);
+        let f = run_async(move || {
+            let b = bench::dining_philosophers::DiningPhilosophers::default();
+            run_and_record(&b, &p)
+        })
+        .map_err(|e| {
+            println!("Converting benchmark error into grpc error: {:?}", e);
+            e.into()
+        });
+        grpc::SingleResponse::no_metadata(f)
+    }
+
+    fn nqueens(
+        &self,
+        _o: grpc::RequestOptions,
+        _p: benchmarks::NQueensRequest,
+    ) -> grpc::SingleResponse<messages::TestResult> {
+        grpc::SingleResponse::completed(not_implemented())
+    }
+
+    fn parallel_sort(
+        &self,
+        _o: grpc::RequestOptions,
+        p: benchmarks::ParallelSortRequest,
+    ) -> grpc::SingleResponse<messages::TestResult> {
+        println!("Got parallel_sort req: {:?}", p);
+        let f = run_async(move || {
+            let b = bench::parallel_sort::ParallelSort::default();
+            run_and_record(&b, &p)
+        })
+        .map_err(|e| {
+            println!("Converting benchmark error into grpc error: {:?}", e);
+            e.into()
+        });
+        grpc::SingleResponse::no_metadata(f)
+    }
 }
 
 #[derive(Clone)]
@@ -209,7 +344,7 @@ impl benchmarks_grpc::BenchmarkRunner for BenchmarkRunnerComponentImpl {
         println!("Got req: {:?}", p);
         let f = run_async(move || {
             let b = bench::pingpong::component_pingpong::PingPong::default();
-            run(&b, &p).into()
+            run_and_record(&b, &p)
         })
         .map_err(|e| {
             println!("Converting benchmark error into grpc error: {:?}", e);
@@ -234,7 +369,7 @@ impl benchmarks_grpc::BenchmarkRunner for BenchmarkRunnerComponentImpl {
         println!("Got req: {:?}", p);
         let f = run_async(move || {
             let b = bench::throughput_pingpong::component_pingpong::PingPong::default();
-            run(&b, &p).into()
+            run_and_record(&b, &p)
         })
         .map_err(|e| {
             println!("Converting benchmark error into grpc error: {:?}", e);
@@ -272,7 +407,15 @@ impl benchmarks_grpc::BenchmarkRunner for BenchmarkRunnerComponentImpl {
         p: benchmarks::FibonacciRequest,
     ) -> grpc::SingleResponse<messages::TestResult> {
         println!("Got fibonacci req: {:?}", p);
-        grpc::SingleResponse::completed(not_implemented())
+        let f = run_async(move || {
+            let b = bench::fibonacci::component_fibonacci::Fibonacci::default();
+            run_and_record(&b, &p)
+        })
+        .map_err(|e| {
+            println!("Converting benchmark error into grpc error: {:?}", e);
+            e.into()
+        });
+        grpc::SingleResponse::no_metadata(f)
     }
 
     fn chameneos(
@@ -281,7 +424,15 @@ impl benchmarks_grpc::BenchmarkRunner for BenchmarkRunnerComponentImpl {
         p: benchmarks::ChameneosRequest,
     ) -> grpc::SingleResponse<messages::TestResult> {
         println!("Got chameneos req: {:?}", p);
-        grpc::SingleResponse::completed(not_implemented())
+        let f = run_async(move || {
+            let b = bench::chameneos::component_chameneos::Chameneos::default();
+            run_and_record(&b, &p)
+        })
+        .map_err(|e| {
+            println!("Converting benchmark error into grpc error: {:?}", e);
+            e.into()
+        });
+        grpc::SingleResponse::no_metadata(f)
     }
 
     fn all_pairs_shortest_path(
@@ -292,7 +443,7 @@ impl benchmarks_grpc::BenchmarkRunner for BenchmarkRunnerComponentImpl {
         println!("Got APSP req: {:?}", p);
         let f = run_async(move || {
             let b = bench::all_pairs_shortest_path::component_apsp::AllPairsShortestPath::default();
-            run(&b, &p).into()
+            run_and_record(&b, &p)
         })
         .map_err(|e| {
             println!("Converting benchmark error into grpc error: {:?}", e);
@@ -316,6 +467,96 @@ impl benchmarks_grpc::BenchmarkRunner for BenchmarkRunnerComponentImpl {
     ) -> grpc::SingleResponse<messages::TestResult> {
         grpc::SingleResponse::completed(not_implemented())
     }
+
+    fn thread_ring(
+        &self,
+        _o: grpc::RequestOptions,
+        p: benchmarks::ThreadRingRequest,
+    ) -> grpc::SingleResponse<messages::TestResult> {
+        println!("Got thread ring req: {:?}", p);
+        let f = run_async(move || {
+            let b = bench::thread_ring::component_thread_ring::ThreadRing::default();
+            run_and_record(&b, &p)
+        })
+        .map_err(|e| {
+            println!("Converting benchmark error into grpc error: {:?}", e);
+            e.into()
+        });
+        grpc::SingleResponse::no_metadata(f)
+    }
+
+    fn fan_in(
+        &self,
+        _o: grpc::RequestOptions,
+        _p: benchmarks::FanInRequest,
+    ) -> grpc::SingleResponse<messages::TestResult> {
+        grpc::SingleResponse::completed(not_implemented())
+    }
+
+    fn fanout(
+        &self,
+        _o: grpc::RequestOptions,
+        p: benchmarks::FanoutRequest,
+    ) -> grpc::SingleResponse<messages::TestResult> {
+        println!("Got fanout req: {:?}", p);
+        let f = run_async(move || {
+            let b = bench::fanout::component_fanout::Fanout::default();
+            run_and_record(&b, &p)
+        })
+        .map_err(|e| {
+            println!("Converting benchmark error into grpc error: {:?}", e);
+            e.into()
+        });
+        grpc::SingleResponse::no_metadata(f)
+    }
+
+    fn big(
+        &self,
+        _o: grpc::RequestOptions,
+        _p: benchmarks::BigRequest,
+    ) -> grpc::SingleResponse<messages::TestResult> {
+        grpc::SingleResponse::completed(not_implemented())
+    }
+
+    fn bank(
+        &self,
+        _o: grpc::RequestOptions,
+        _p: benchmarks::BankRequest,
+    ) -> grpc::SingleResponse<messages::TestResult> {
+        grpc::SingleResponse::completed(not_implemented())
+    }
+
+    fn producer_consumer(
+        &self,
+        _o: grpc::RequestOptions,
+        _p: benchmarks::ProducerConsumerRequest,
+    ) -> grpc::SingleResponse<messages::TestResult> {
+        grpc::SingleResponse::completed(not_implemented())
+    }
+
+    fn dining_philosophers(
+        &self,
+        _o: grpc::RequestOptions,
+        _p: benchmarks::DiningPhilosophersRequest,
+    ) -> grpc::SingleResponse<messages::TestResult> {
+        grpc::SingleResponse::completed(not_implemented())
+    }
+
+    fn nqueens(
+        &self,
+        _o: grpc::RequestOptions,
+        _p: benchmarks::NQueensRequest,
+    ) -> grpc::SingleResponse<messages::TestResult> {
+        grpc::SingleResponse::completed(not_implemented())
+    }
+
+    fn parallel_sort(
+        &self,
+        _o: grpc::RequestOptions,
+        _p: benchmarks::ParallelSortRequest,
+    ) -> grpc::SingleResponse<messages::TestResult> {
+        grpc::SingleResponse::completed(not_implemented())
+    }
 }
 
 #[derive(Clone)]
@@ -411,7 +652,7 @@ impl benchmarks_grpc::BenchmarkRunner for BenchmarkRunnerMixedImpl {
         println!("Got chameneos req: {:?}", p);
         let f = run_async(move || {
             let b = bench::chameneos::mixed_chameneos::Chameneos::default();
-            run(&b, &p).into()
+            run_and_record(&b, &p)
         })
         .map_err(|e| {
             println!("Converting benchmark error into grpc error: {:?}", e);
@@ -444,4 +685,76 @@ impl benchmarks_grpc::BenchmarkRunner for BenchmarkRunnerMixedImpl {
     ) -> grpc::SingleResponse<messages::TestResult> {
         grpc::SingleResponse::completed(not_implemented())
     }
+
+    fn thread_ring(
+        &self,
+        _o: grpc::RequestOptions,
+        _p: benchmarks::ThreadRingRequest,
+    ) -> grpc::SingleResponse<messages::TestResult> {
+        grpc::SingleResponse::completed(not_implemented())
+    }
+
+    fn fan_in(
+        &self,
+        _o: grpc::RequestOptions,
+        _p: benchmarks::FanInRequest,
+    ) -> grpc::SingleResponse<messages::TestResult> {
+        grpc::SingleResponse::completed(not_implemented())
+    }
+
+    fn fanout(
+        &self,
+        _o: grpc::RequestOptions,
+        _p: benchmarks::FanoutRequest,
+    ) -> grpc::SingleResponse<messages::TestResult> {
+        grpc::SingleResponse::completed(not_implemented())
+    }
+
+    fn big(
+        &self,
+        _o: grpc::RequestOptions,
+        _p: benchmarks::BigRequest,
+    ) -> grpc::SingleResponse<messages::TestResult> {
+        grpc::SingleResponse::completed(not_implemented())
+    }
+
+    fn bank(
+        &self,
+        _o: grpc::RequestOptions,
+        _p: benchmarks::BankRequest,
+    ) -> grpc::SingleResponse<messages::TestResult> {
+        grpc::SingleResponse::completed(not_implemented())
+    }
+
+    fn producer_consumer(
+        &self,
+        _o: grpc::RequestOptions,
+        _p: benchmarks::ProducerConsumerRequest,
+    ) -> grpc::SingleResponse<messages::TestResult> {
+        grpc::SingleResponse::completed(not_implemented())
+    }
+
+    fn dining_philosophers(
+        &self,
+        _o: grpc::RequestOptions,
+        _p: benchmarks::DiningPhilosophersRequest,
+    ) -> grpc::SingleResponse<messages::TestResult> {
+        grpc::SingleResponse::completed(not_implemented())
+    }
+
+    fn nqueens(
+        &self,
+        _o: grpc::RequestOptions,
+        _p: benchmarks::NQueensRequest,
+    ) -> grpc::SingleResponse<messages::TestResult> {
+        grpc::SingleResponse::completed(not_implemented())
+    }
+
+    fn parallel_sort(
+        &self,
+        _o: grpc::RequestOptions,
+        _p: benchmarks::ParallelSortRequest,
+    ) -> grpc::SingleResponse<messages::TestResult> {
+        grpc::SingleResponse::completed(not_implemented())
+    }
 }
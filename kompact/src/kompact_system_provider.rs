@@ -1,33 +1,151 @@
 //use super::*;
 
+use benchmark_suite_shared::{
+    affinity::{self, AffinityPolicy, Placement},
+    benchmark::BenchmarkError,
+    helpers::validation,
+    kompics_benchmarks::benchmarks::{NetworkTuning, TcpNoDelay},
+    result_writer,
+};
+use hocon::{Hocon, HoconLoader};
 use kompact::{executors::*, net::buffers::BufferConfig, prelude::*};
 use num_cpus;
-use std::net::{IpAddr, Ipv4Addr, SocketAddr};
-
-// Would be nicer to be able to declare the default value const -.-
-static mut GLOBAL: KompactSystemProvider = KompactSystemProvider {
-    public_if: IpAddr::V4(Ipv4Addr::LOCALHOST),
+use once_cell::sync::OnceCell;
+use std::{
+    cell::RefCell,
+    env, fmt,
+    net::{IpAddr, Ipv4Addr, SocketAddr},
+    path::{Path, PathBuf},
+    str::FromStr,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
 };
 
+static GLOBAL: OnceCell<Arc<KompactSystemProvider>> = OnceCell::new();
+
+thread_local! {
+    static SCOPED: RefCell<Option<Arc<KompactSystemProvider>>> = RefCell::new(None);
+}
+
+/// Set by the first call to [with_provider](with_provider).
+///
+/// From then on, a thread without a scoped provider can't tell whether the global one
+/// is the one it was meant to use, e.g. because it was spawned from within a scope.
+static SCOPES_USED: AtomicBool = AtomicBool::new(false);
+
+/// Initialises the process-wide provider.
+///
+/// Panics if it was already initialised, explicitly or by a call to [global](global).
+pub fn init_global(provider: KompactSystemProvider) {
+    if GLOBAL.set(Arc::new(provider)).is_err() {
+        panic!("The global KompactSystemProvider was already initialised!");
+    }
+}
+
 pub(crate) fn set_global_public_if(addr: IpAddr) {
-    unsafe {
-        GLOBAL.set_public_if(addr);
+    init_global(KompactSystemProvider::new(addr, ProviderConfig::load()));
+}
+
+/// The process-wide provider.
+///
+/// If it was not initialised before, it binds to localhost and uses [ProviderConfig::load](ProviderConfig::load).
+pub fn global() -> Arc<KompactSystemProvider> {
+    GLOBAL
+        .get_or_init(|| {
+            Arc::new(KompactSystemProvider::new(
+                IpAddr::V4(Ipv4Addr::LOCALHOST),
+                ProviderConfig::load(),
+            ))
+        })
+        .clone()
+}
+
+/// The provider benchmarks should use to create their systems,
+/// i.e. the one set by [with_provider](with_provider) on this thread, or else the [global](global) one.
+///
+/// Scopes don't carry over to other threads, so once any thread in the process has used
+/// [with_provider](with_provider), this panics on threads without a scope of their own,
+/// instead of silently falling back to the global provider.
+pub fn current() -> Arc<KompactSystemProvider> {
+    match SCOPED.with(|scoped| scoped.borrow().clone()) {
+        Some(provider) => provider,
+        None if SCOPES_USED.load(Ordering::SeqCst) => panic!(
+            "No KompactSystemProvider is scoped to thread {:?}, but this process uses scoped \
+             providers. Pass the provider along and call with_provider on this thread as well.",
+            std::thread::current().name().unwrap_or("<unnamed>")
+        ),
+        None => global(),
     }
 }
-pub fn global() -> &'static KompactSystemProvider {
-    unsafe { &GLOBAL }
+
+/// Runs `f` with `provider` as the [current](current) provider on this thread.
+///
+/// Threads spawned by `f` must install the provider themselves, see [current](current).
+pub fn with_provider<R, F: FnOnce() -> R>(provider: Arc<KompactSystemProvider>, f: F) -> R {
+    SCOPES_USED.store(true, Ordering::SeqCst);
+    struct Restore(Option<Arc<KompactSystemProvider>>);
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            let previous = self.0.take();
+            SCOPED.with(|scoped| *scoped.borrow_mut() = previous);
+        }
+    }
+    let previous = SCOPED.with(|scoped| scoped.borrow_mut().replace(provider));
+    let _restore = Restore(previous);
+    f()
 }
 
 pub struct KompactSystemProvider {
     public_if: IpAddr,
+    config: ProviderConfig,
+    overrides: RunOverrides,
+}
+
+/// Settings that take precedence over the [ProviderConfig](ProviderConfig) for all systems
+/// of a provider, e.g. for a single run.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct RunOverrides {
+    pub public_if: Option<IpAddr>,
+    pub threads: Option<usize>,
+    pub buffer_config: BufferSettings,
 }
 
 mod consts {
     pub const NUM_WORKERS_DEFAULT: usize = 4;
-    pub const NUM_WORKERS_MAX: usize = 64;
+    pub const THROUGHPUT_DEFAULT: usize = 50;
+    pub const MSG_PRIORITY_DEFAULT: f32 = 1.0;
+    pub const TCP_NO_DELAY_DEFAULT: bool = true;
+    // the values of Kompact's `BufferConfig::default()`
+    pub const CHUNK_SIZE_DEFAULT: usize = 128 * 1000;
+    pub const INITIAL_CHUNK_COUNT_DEFAULT: usize = 2;
+    pub const MAX_CHUNK_COUNT_DEFAULT: usize = 1000;
+    pub const ENCODE_BUF_MIN_FREE_SPACE_DEFAULT: usize = 64;
 }
 
 impl KompactSystemProvider {
+    pub fn new(public_if: IpAddr, config: ProviderConfig) -> KompactSystemProvider {
+        KompactSystemProvider {
+            public_if,
+            config,
+            overrides: RunOverrides::default(),
+        }
+    }
+
+    /// A copy of this provider with `overrides` applied on top of the existing ones.
+    pub fn with_overrides(&self, overrides: RunOverrides) -> KompactSystemProvider {
+        KompactSystemProvider {
+            public_if: self.public_if,
+            config: self.config.clone(),
+            overrides: RunOverrides {
+                public_if: overrides.public_if.or(self.overrides.public_if),
+                threads: overrides.threads.or(self.overrides.threads),
+                buffer_config: overrides.buffer_config.or(&self.overrides.buffer_config),
+            },
+        }
+    }
+
     pub fn set_public_if<I>(&mut self, s: I)
     where
         I: Into<IpAddr>,
@@ -35,6 +153,26 @@ impl KompactSystemProvider {
         self.public_if = s.into();
     }
 
+    pub fn set_config(&mut self, config: ProviderConfig) {
+        self.config = config;
+    }
+
+    pub fn config(&self) -> &ProviderConfig {
+        &self.config
+    }
+
+    pub fn overrides(&self) -> &RunOverrides {
+        &self.overrides
+    }
+
+    /// The effective settings for the system called `system`, including run overrides.
+    pub fn settings_for(&self, system: &str) -> SystemSettings {
+        let mut settings = self.config.settings_for(system);
+        settings.threads = self.overrides.threads.or(settings.threads);
+        settings.buffer_config = self.overrides.buffer_config.or(&settings.buffer_config);
+        settings
+    }
+
     pub fn new_system<I: Into<String>>(&self, name: I) -> KompactSystem {
         self.new_system_with_threads(name, self.get_num_workers())
     }
@@ -45,13 +183,11 @@ impl KompactSystemProvider {
         threads: usize,
     ) -> KompactSystem {
         let s = name.into();
+        let settings = self.settings_for(&s);
         let mut conf = KompactConfig::default();
+        let threads = settings.apply(&mut conf, threads, &self.config.custom_executors);
         conf.label(s);
-        conf.threads(threads);
-        Self::set_executor_for_threads(threads, &mut conf);
-        conf.throughput(50);
-        let system = conf.build().expect("KompactSystem");
-        system
+        settings.build(conf, threads)
     }
 
     pub fn new_remote_system<I: Into<String>>(&self, name: I) -> KompactSystem {
@@ -64,51 +200,135 @@ impl KompactSystemProvider {
         threads: usize,
     ) -> KompactSystem {
         let s = name.into();
+        let settings = self.settings_for(&s);
         let addr = SocketAddr::new(self.get_public_if(), 0);
         let mut conf = KompactConfig::default();
+        let threads = settings.apply(&mut conf, threads, &self.config.custom_executors);
         conf.label(s);
-        conf.threads(threads);
-        Self::set_executor_for_threads(threads, &mut conf);
-        conf.throughput(50);
-        conf.system_components(DeadletterBox::new, NetworkConfig::new(addr).build());
-        let system = conf.build().expect("KompactSystem");
-        system
+        let network_config = if settings.buffer_config.is_empty() {
+            NetworkConfig::new(addr)
+        } else {
+            let mut buf_conf = BufferConfig::default();
+            settings.buffer_config.apply(&mut buf_conf);
+            NetworkConfig::with_buffer_config(addr, buf_conf)
+        };
+        conf.system_components(DeadletterBox::new, network_config.build());
+        settings.build(conf, threads)
     }
 
     pub fn new_remote_system_with_threads_config<I: Into<String>>(
         &self,
         name: I,
         threads: usize,
+        conf: KompactConfig,
+        mut buf_conf: BufferConfig,
+        tcp_no_delay: bool,
+    ) -> KompactSystem {
+        let s = name.into();
+        let settings = self.settings_for(&s);
+        settings.buffer_config.apply(&mut buf_conf);
+        self.build_remote_system(s, settings, threads, conf, buf_conf, tcp_no_delay)
+    }
+
+    /// Creates a networked system with the transport settings requested by a benchmark.
+    pub fn new_remote_system_with_network<I: Into<String>>(
+        &self,
+        name: I,
+        threads: usize,
+        network: &NetworkSettings,
+    ) -> KompactSystem {
+        self.new_remote_system_with_network_config(
+            name,
+            threads,
+            KompactConfig::default(),
+            &BufferSettings::default(),
+            network,
+        )
+    }
+
+    /// Like [new_remote_system_with_network](KompactSystemProvider::new_remote_system_with_network),
+    /// but starting from `conf` and the buffer settings in `base`.
+    ///
+    /// The requested `network` settings take precedence over the provider config,
+    /// which takes precedence over `base`.
+    /// The effective buffer settings are echoed into the result record.
+    pub fn new_remote_system_with_network_config<I: Into<String>>(
+        &self,
+        name: I,
+        threads: usize,
+        conf: KompactConfig,
+        base: &BufferSettings,
+        network: &NetworkSettings,
+    ) -> KompactSystem {
+        let s = name.into();
+        let settings = self.settings_for(&s);
+        let buffers = network
+            .buffer_config
+            .or(&settings.buffer_config)
+            .or(base)
+            .or(&BufferSettings::kompact_defaults());
+        buffers.record("network");
+        self.build_remote_system(
+            s,
+            settings,
+            threads,
+            conf,
+            buffers.to_buffer_config(),
+            network.tcp_no_delay(),
+        )
+    }
+
+    /// Checks the `tuning` requested by a benchmark against the buffer settings
+    /// a system called `system` would have without it, i.e. those in `base` and the provider config.
+    pub fn validate_network(
+        &self,
+        system: &str,
+        base: &BufferSettings,
+        tuning: &NetworkTuning,
+    ) -> Result<(), BenchmarkError> {
+        let defaults = NetworkSettings {
+            buffer_config: self
+                .settings_for(system)
+                .buffer_config
+                .or(base)
+                .or(&BufferSettings::kompact_defaults()),
+            tcp_no_delay: None,
+        };
+        validation::network_tuning(tuning, &defaults.to_request())
+    }
+
+    fn build_remote_system(
+        &self,
+        name: String,
+        settings: SystemSettings,
+        threads: usize,
         mut conf: KompactConfig,
         buf_conf: BufferConfig,
         tcp_no_delay: bool,
     ) -> KompactSystem {
-        let s = name.into();
         let addr = SocketAddr::new(self.get_public_if(), 0);
-        conf.label(s);
-        conf.threads(threads);
-        Self::set_executor_for_threads(threads, &mut conf);
-        conf.throughput(50);
+        let threads = settings.apply(&mut conf, threads, &self.config.custom_executors);
+        conf.label(name);
+        buf_conf.validate();
         let mut nc = NetworkConfig::with_buffer_config(addr, buf_conf);
         nc.set_tcp_nodelay(tcp_no_delay);
+        result_writer::record_setting("kompact.tcp_no_delay", tcp_no_delay);
         conf.system_components(DeadletterBox::new, nc.build());
-        let system = conf.build().expect("KompactSystem");
-        system
+        settings.build(conf, threads)
     }
 
+    /// The configured number of workers, or else one per CPU, but at least `NUM_WORKERS_DEFAULT`.
+    ///
+    /// Counts beyond what the work-stealing pools support are handled by the `auto` executor.
     pub fn get_num_workers(&self) -> usize {
-        let n = num_cpus::get();
-        if (n >= consts::NUM_WORKERS_DEFAULT) && (n <= consts::NUM_WORKERS_MAX) {
-            n
-        } else if n >= consts::NUM_WORKERS_DEFAULT {
-            consts::NUM_WORKERS_MAX
-        } else {
-            consts::NUM_WORKERS_DEFAULT
+        if let Some(threads) = self.overrides.threads.or(self.config.base.threads) {
+            return threads;
         }
+        num_cpus::get().max(consts::NUM_WORKERS_DEFAULT)
     }
 
     pub fn get_public_if(&self) -> IpAddr {
-        self.public_if
+        self.overrides.public_if.unwrap_or(self.public_if)
     }
 
     fn set_executor_for_threads(threads: usize, conf: &mut KompactConfig) -> () {
@@ -117,16 +337,673 @@ impl KompactSystemProvider {
         } else if threads <= 64 {
             conf.executor(|t| crossbeam_workstealing_pool::large_pool(t))
         } else {
-            panic!("DynPool doesn't work atm!");
-            //conf.scheduler(|t| crossbeam_workstealing_pool::dyn_pool(t))
+            // the work-stealing pools are limited to 64 threads
+            conf.executor(|t| crossbeam_channel_pool::ThreadPool::new(t))
         };
     }
 }
 
+/// Names the executors give their worker threads, which are the only threads that get pinned.
+const EXECUTOR_THREAD_NAMES: [&str; 2] = ["cb-ws-pool-worker", "cb-channel-pool-worker"];
+
+fn is_executor_thread(name: &str) -> bool {
+    EXECUTOR_THREAD_NAMES
+        .iter()
+        .any(|prefix| affinity::thread_name_matches(name, prefix))
+}
+
 impl Default for KompactSystemProvider {
     fn default() -> Self {
-        KompactSystemProvider {
-            public_if: "127.0.0.1".parse().unwrap(),
+        KompactSystemProvider::new("127.0.0.1".parse().unwrap(), ProviderConfig::default())
+    }
+}
+
+/// Which thread pool the systems are built with.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ExecutorKind {
+    /// `small_pool` up to 32 threads, `large_pool` up to 64 threads
+    /// and the crossbeam channel pool above that.
+    Auto,
+    /// Crossbeam work-stealing pool for up to 32 threads.
+    SmallPool,
+    /// Crossbeam work-stealing pool for up to 64 threads.
+    LargePool,
+    /// Crossbeam work-stealing pool without a thread limit.
+    ///
+    /// Not part of the executor sweep, since it hasn't been shown to run the benchmarks yet.
+    DynPool,
+    /// Thread pool sharing a single crossbeam channel, without a thread limit.
+    ChannelPool,
+    /// An executor registered with [ProviderConfig::with_executor](ProviderConfig::with_executor).
+    ///
+    /// Registration needs code, so this can only be selected from code as well,
+    /// and not in the config file or the environment.
+    Custom(String),
+}
+
+impl ExecutorKind {
+    fn apply(&self, threads: usize, conf: &mut KompactConfig, custom: &[CustomExecutor]) -> () {
+        match self {
+            ExecutorKind::Auto => KompactSystemProvider::set_executor_for_threads(threads, conf),
+            ExecutorKind::SmallPool => {
+                assert!(threads <= 32, "small_pool supports at most 32 threads");
+                conf.executor(|t| crossbeam_workstealing_pool::small_pool(t));
+            }
+            ExecutorKind::LargePool => {
+                assert!(threads <= 64, "large_pool supports at most 64 threads");
+                conf.executor(|t| crossbeam_workstealing_pool::large_pool(t));
+            }
+            ExecutorKind::DynPool => {
+                conf.executor(|t| crossbeam_workstealing_pool::dyn_pool(t));
+            }
+            ExecutorKind::ChannelPool => {
+                conf.executor(|t| crossbeam_channel_pool::ThreadPool::new(t));
+            }
+            ExecutorKind::Custom(name) => {
+                let executor = custom
+                    .iter()
+                    .find(|e| &e.name == name)
+                    .unwrap_or_else(|| panic!("No executor registered as {}", name));
+                (executor.setup)(threads, conf);
+            }
+        }
+    }
+}
+
+impl FromStr for ExecutorKind {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_ref() {
+            "auto" => Ok(ExecutorKind::Auto),
+            "small" | "small_pool" => Ok(ExecutorKind::SmallPool),
+            "large" | "large_pool" => Ok(ExecutorKind::LargePool),
+            "dyn" | "dyn_pool" => Ok(ExecutorKind::DynPool),
+            "channel" | "channel_pool" => Ok(ExecutorKind::ChannelPool),
+            _ => Err(format!(
+                "Unknown executor: {}. Use auto, small_pool, large_pool, dyn_pool or channel_pool.",
+                s
+            )),
+        }
+    }
+}
+
+impl fmt::Display for ExecutorKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ExecutorKind::Auto => write!(f, "auto"),
+            ExecutorKind::SmallPool => write!(f, "small_pool"),
+            ExecutorKind::LargePool => write!(f, "large_pool"),
+            ExecutorKind::DynPool => write!(f, "dyn_pool"),
+            ExecutorKind::ChannelPool => write!(f, "channel_pool"),
+            ExecutorKind::Custom(name) => write!(f, "custom:{}", name),
+        }
+    }
+}
+
+/// A named function that installs an executor on a `KompactConfig` for the given number of threads.
+#[derive(Clone)]
+pub struct CustomExecutor {
+    pub name: String,
+    pub setup: fn(usize, &mut KompactConfig),
+}
+
+impl fmt::Debug for CustomExecutor {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "CustomExecutor({})", self.name)
+    }
+}
+
+impl PartialEq for CustomExecutor {
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name
+    }
+}
+
+/// Network buffer settings, on top of Kompact's (or the benchmark's) `BufferConfig`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct BufferSettings {
+    pub chunk_size: Option<usize>,
+    pub initial_chunk_count: Option<usize>,
+    pub max_chunk_count: Option<usize>,
+    pub encode_buf_min_free_space: Option<usize>,
+}
+
+impl BufferSettings {
+    /// All fields set to the values of Kompact's `BufferConfig::default()`.
+    pub fn kompact_defaults() -> BufferSettings {
+        BufferSettings {
+            chunk_size: Some(consts::CHUNK_SIZE_DEFAULT),
+            initial_chunk_count: Some(consts::INITIAL_CHUNK_COUNT_DEFAULT),
+            max_chunk_count: Some(consts::MAX_CHUNK_COUNT_DEFAULT),
+            encode_buf_min_free_space: Some(consts::ENCODE_BUF_MIN_FREE_SPACE_DEFAULT),
         }
     }
+
+    /// The settings in the `buffer_config` section of a Kompact config file.
+    pub fn from_config_file<P: AsRef<Path>>(path: P) -> BufferSettings {
+        let config = HoconLoader::new()
+            .load_file(path.as_ref())
+            .expect("Failed to load config file")
+            .hocon()
+            .expect("Failed to load config as HOCON");
+        BufferSettings::from_hocon(&config["buffer_config"])
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self == &BufferSettings::default()
+    }
+
+    /// Kompact's default `BufferConfig` with these settings applied.
+    pub fn to_buffer_config(&self) -> BufferConfig {
+        let mut buf_conf = BufferConfig::default();
+        self.apply(&mut buf_conf);
+        buf_conf
+    }
+
+    pub fn apply(&self, buf_conf: &mut BufferConfig) -> () {
+        if let Some(chunk_size) = self.chunk_size {
+            buf_conf.chunk_size(chunk_size);
+        }
+        if let Some(initial_chunk_count) = self.initial_chunk_count {
+            buf_conf.initial_chunk_count(initial_chunk_count);
+        }
+        if let Some(max_chunk_count) = self.max_chunk_count {
+            buf_conf.max_chunk_count(max_chunk_count);
+        }
+        if let Some(encode_buf_min_free_space) = self.encode_buf_min_free_space {
+            buf_conf.encode_buf_min_free_space(encode_buf_min_free_space);
+        }
+    }
+
+    fn or(&self, fallback: &BufferSettings) -> BufferSettings {
+        BufferSettings {
+            chunk_size: self.chunk_size.or(fallback.chunk_size),
+            initial_chunk_count: self.initial_chunk_count.or(fallback.initial_chunk_count),
+            max_chunk_count: self.max_chunk_count.or(fallback.max_chunk_count),
+            encode_buf_min_free_space: self
+                .encode_buf_min_free_space
+                .or(fallback.encode_buf_min_free_space),
+        }
+    }
+
+    /// Echoes the set fields into the result record as `<prefix>.<field>`.
+    fn record(&self, prefix: &str) -> () {
+        let fields = [
+            ("chunk_size", self.chunk_size),
+            ("initial_chunk_count", self.initial_chunk_count),
+            ("max_chunk_count", self.max_chunk_count),
+            ("encode_buf_min_free_space", self.encode_buf_min_free_space),
+        ];
+        for (field, value) in fields.iter() {
+            if let Some(value) = value {
+                result_writer::record_setting(format!("{}.{}", prefix, field), value);
+            }
+        }
+    }
+
+    fn from_hocon(h: &Hocon) -> BufferSettings {
+        BufferSettings {
+            chunk_size: hocon_usize(&h["chunk_size"]),
+            initial_chunk_count: hocon_usize(&h["initial_chunk_count"]),
+            max_chunk_count: hocon_usize(&h["max_chunk_count"]),
+            encode_buf_min_free_space: hocon_usize(&h["encode_buf_min_free_space"]),
+        }
+    }
+}
+
+/// Transport settings requested by a distributed benchmark via its `NetworkTuning` parameter.
+///
+/// Masters pass them on to their clients as part of the client conf,
+/// using the `Display`/`FromStr` representation.
+/// That representation contains neither `,` nor `;`, so it can be embedded in other client confs.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct NetworkSettings {
+    pub buffer_config: BufferSettings,
+    pub tcp_no_delay: Option<bool>,
+}
+
+impl NetworkSettings {
+    pub fn from_request(tuning: &NetworkTuning) -> NetworkSettings {
+        let non_zero = |v: u64| if v == 0 { None } else { Some(v as usize) };
+        NetworkSettings {
+            buffer_config: BufferSettings {
+                chunk_size: non_zero(tuning.chunk_size),
+                initial_chunk_count: non_zero(tuning.initial_chunk_count),
+                max_chunk_count: non_zero(tuning.max_chunk_count),
+                encode_buf_min_free_space: non_zero(tuning.encode_buf_min_free_space),
+            },
+            tcp_no_delay: match tuning.tcp_no_delay {
+                TcpNoDelay::TCP_NO_DELAY_DEFAULT => None,
+                TcpNoDelay::TCP_NO_DELAY_ON => Some(true),
+                TcpNoDelay::TCP_NO_DELAY_OFF => Some(false),
+            },
+        }
+    }
+
+    pub fn to_request(&self) -> NetworkTuning {
+        let b = &self.buffer_config;
+        let mut tuning = NetworkTuning::new();
+        tuning.set_chunk_size(b.chunk_size.unwrap_or(0) as u64);
+        tuning.set_initial_chunk_count(b.initial_chunk_count.unwrap_or(0) as u64);
+        tuning.set_max_chunk_count(b.max_chunk_count.unwrap_or(0) as u64);
+        tuning.set_encode_buf_min_free_space(b.encode_buf_min_free_space.unwrap_or(0) as u64);
+        tuning.set_tcp_no_delay(match self.tcp_no_delay {
+            None => TcpNoDelay::TCP_NO_DELAY_DEFAULT,
+            Some(true) => TcpNoDelay::TCP_NO_DELAY_ON,
+            Some(false) => TcpNoDelay::TCP_NO_DELAY_OFF,
+        });
+        tuning
+    }
+
+    pub fn tcp_no_delay(&self) -> bool {
+        self.tcp_no_delay.unwrap_or(consts::TCP_NO_DELAY_DEFAULT)
+    }
+}
+
+impl fmt::Display for NetworkSettings {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fn opt<T: ToString>(v: Option<T>) -> String {
+            v.map(|v| v.to_string()).unwrap_or_default()
+        }
+        let b = &self.buffer_config;
+        write!(
+            f,
+            "{}:{}:{}:{}:{}",
+            opt(b.chunk_size),
+            opt(b.initial_chunk_count),
+            opt(b.max_chunk_count),
+            opt(b.encode_buf_min_free_space),
+            opt(self.tcp_no_delay)
+        )
+    }
+}
+
+impl FromStr for NetworkSettings {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        fn opt<T: FromStr>(s: &str, field: &str) -> Result<Option<T>, String> {
+            if s.is_empty() {
+                Ok(None)
+            } else {
+                s.parse()
+                    .map(Some)
+                    .map_err(|_| format!("Invalid network setting {}='{}'", field, s))
+            }
+        }
+        let split: Vec<_> = s.split(':').collect();
+        if split.len() != 5 {
+            return Err(format!("'{}' does not represent network settings", s));
+        }
+        Ok(NetworkSettings {
+            buffer_config: BufferSettings {
+                chunk_size: opt(split[0], "chunk_size")?,
+                initial_chunk_count: opt(split[1], "initial_chunk_count")?,
+                max_chunk_count: opt(split[2], "max_chunk_count")?,
+                encode_buf_min_free_space: opt(split[3], "encode_buf_min_free_space")?,
+            },
+            tcp_no_delay: opt(split[4], "tcp_no_delay")?,
+        })
+    }
+}
+
+/// Scheduling and network settings for a `KompactSystem`.
+///
+/// Every field is optional, so that settings can be layered;
+/// unset fields fall back to the provider's defaults.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SystemSettings {
+    pub threads: Option<usize>,
+    pub throughput: Option<usize>,
+    pub msg_priority: Option<f32>,
+    pub executor: Option<ExecutorKind>,
+    pub affinity: Option<AffinityPolicy>,
+    pub buffer_config: BufferSettings,
+}
+
+impl SystemSettings {
+    /// Fields set in `self` take precedence over those in `fallback`.
+    pub fn or(&self, fallback: &SystemSettings) -> SystemSettings {
+        SystemSettings {
+            threads: self.threads.or(fallback.threads),
+            throughput: self.throughput.or(fallback.throughput),
+            msg_priority: self.msg_priority.or(fallback.msg_priority),
+            executor: self.executor.clone().or_else(|| fallback.executor.clone()),
+            affinity: self.affinity.clone().or_else(|| fallback.affinity.clone()),
+            buffer_config: self.buffer_config.or(&fallback.buffer_config),
+        }
+    }
+
+    /// Configures `conf` and echoes the effective settings into the result record.
+    ///
+    /// `threads` is the number of threads requested by the benchmark,
+    /// which is only used if no thread count is set.
+    /// Returns the effective number of threads.
+    fn apply(
+        &self,
+        conf: &mut KompactConfig,
+        threads: usize,
+        custom_executors: &[CustomExecutor],
+    ) -> usize {
+        let threads = self.threads.unwrap_or(threads);
+        let throughput = self.throughput.unwrap_or(consts::THROUGHPUT_DEFAULT);
+        let msg_priority = self.msg_priority.unwrap_or(consts::MSG_PRIORITY_DEFAULT);
+        let executor = self.executor.clone().unwrap_or(ExecutorKind::Auto);
+        conf.threads(threads);
+        executor.apply(threads, conf, custom_executors);
+        conf.throughput(throughput);
+        conf.msg_priority(msg_priority);
+        result_writer::record_setting("kompact.threads", threads);
+        result_writer::record_setting("kompact.throughput", throughput);
+        result_writer::record_setting("kompact.msg_priority", msg_priority);
+        result_writer::record_setting("kompact.executor", executor);
+        self.buffer_config.record("kompact.buffer_config");
+        threads
+    }
+
+    /// Builds the system, pinning its executor threads if an affinity policy is set.
+    fn build(&self, conf: KompactConfig, threads: usize) -> KompactSystem {
+        match self.affinity {
+            Some(ref policy) => Placement::for_policy(policy.clone(), threads)
+                .apply(is_executor_thread, || conf.build().expect("KompactSystem")),
+            None => conf.build().expect("KompactSystem"),
+        }
+    }
+
+    fn from_hocon(h: &Hocon) -> SystemSettings {
+        SystemSettings {
+            threads: hocon_usize(&h["threads"]),
+            throughput: hocon_usize(&h["throughput"]),
+            msg_priority: h["msg_priority"].as_f64().map(|p| p as f32),
+            executor: h["executor"].as_string().map(|e| {
+                e.parse()
+                    .unwrap_or_else(|err| panic!("Invalid provider config: {}", err))
+            }),
+            affinity: h["affinity"].as_string().map(|a| {
+                a.parse()
+                    .unwrap_or_else(|err| panic!("Invalid provider config: {}", err))
+            }),
+            buffer_config: BufferSettings::from_hocon(&h["buffer_config"]),
+        }
+    }
+
+    fn from_env() -> SystemSettings {
+        SystemSettings {
+            threads: env_var("KOMPACT_THREADS"),
+            throughput: env_var("KOMPACT_THROUGHPUT"),
+            msg_priority: env_var("KOMPACT_MSG_PRIORITY"),
+            executor: env_var("KOMPACT_EXECUTOR"),
+            affinity: AffinityPolicy::from_env(),
+            buffer_config: BufferSettings {
+                chunk_size: env_var("KOMPACT_CHUNK_SIZE"),
+                initial_chunk_count: env_var("KOMPACT_INITIAL_CHUNK_COUNT"),
+                max_chunk_count: env_var("KOMPACT_MAX_CHUNK_COUNT"),
+                encode_buf_min_free_space: env_var("KOMPACT_ENCODE_BUF_MIN_FREE_SPACE"),
+            },
+        }
+    }
+}
+
+/// Settings for all systems created by a [KompactSystemProvider](KompactSystemProvider).
+///
+/// Loaded from the HOCON file at `$KOMPACT_PROVIDER_CONFIG` (default `./configs/provider.conf`),
+/// where the `provider` block holds the settings for all systems
+/// and `provider.overrides.<system name>` those for individual benchmarks.
+/// The `KOMPACT_*` and `BENCHMARK_AFFINITY` environment variables take precedence over the `provider` block,
+/// but not over per-benchmark overrides.
+///
+/// A top-level `threads` setting only replaces the default number of workers,
+/// while an override also replaces a thread count chosen by the benchmark.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ProviderConfig {
+    base: SystemSettings,
+    overrides: Vec<(String, SystemSettings)>,
+    custom_executors: Vec<CustomExecutor>,
+}
+
+impl ProviderConfig {
+    pub const PATH_ENV: &'static str = "KOMPACT_PROVIDER_CONFIG";
+    pub const DEFAULT_PATH: &'static str = "./configs/provider.conf";
+
+    pub fn new(base: SystemSettings) -> ProviderConfig {
+        ProviderConfig {
+            base,
+            overrides: Vec::new(),
+            custom_executors: Vec::new(),
+        }
+    }
+
+    /// Makes `setup` available as `ExecutorKind::Custom(name)`.
+    pub fn with_executor<I: Into<String>>(
+        mut self,
+        name: I,
+        setup: fn(usize, &mut KompactConfig),
+    ) -> Self {
+        let name = name.into();
+        self.custom_executors.retain(|e| e.name != name);
+        self.custom_executors.push(CustomExecutor { name, setup });
+        self
+    }
+
+    pub fn with_override<I: Into<String>>(mut self, system: I, settings: SystemSettings) -> Self {
+        let system = system.into();
+        self.overrides.retain(|(name, _)| name != &system);
+        self.overrides.push((system, settings));
+        self
+    }
+
+    /// Loads the config file, if there is one, and the environment variables.
+    pub fn load() -> ProviderConfig {
+        let path: PathBuf = env::var(ProviderConfig::PATH_ENV)
+            .map(PathBuf::from)
+            .unwrap_or_else(|_| PathBuf::from(ProviderConfig::DEFAULT_PATH));
+        let mut config = if path.exists() {
+            ProviderConfig::from_file(&path)
+        } else {
+            ProviderConfig::default()
+        };
+        config.base = SystemSettings::from_env().or(&config.base);
+        config
+    }
+
+    pub fn from_file<P: AsRef<Path>>(path: P) -> ProviderConfig {
+        let config = HoconLoader::new()
+            .load_file(path.as_ref())
+            .expect("Failed to load provider config file")
+            .hocon()
+            .expect("Failed to load provider config as HOCON");
+        ProviderConfig::from_hocon(&config)
+    }
+
+    pub fn load_str(s: &str) -> ProviderConfig {
+        let config = HoconLoader::new()
+            .load_str(s)
+            .expect("Failed to load provider config")
+            .hocon()
+            .expect("Failed to load provider config as HOCON");
+        ProviderConfig::from_hocon(&config)
+    }
+
+    fn from_hocon(config: &Hocon) -> ProviderConfig {
+        let provider = &config["provider"];
+        let base = SystemSettings::from_hocon(provider);
+        let mut overrides: Vec<(String, SystemSettings)> = match &provider["overrides"] {
+            Hocon::Hash(systems) => systems
+                .iter()
+                .map(|(name, settings)| (name.clone(), SystemSettings::from_hocon(settings)))
+                .collect(),
+            _ => Vec::new(),
+        };
+        overrides.sort_by(|(a, _), (b, _)| a.cmp(b));
+        ProviderConfig {
+            base,
+            overrides,
+            custom_executors: Vec::new(),
+        }
+    }
+
+    /// The effective settings for the system called `system`.
+    pub fn settings_for(&self, system: &str) -> SystemSettings {
+        let base = SystemSettings {
+            threads: None, // only used for the default number of workers
+            ..self.base.clone()
+        };
+        match self.overrides.iter().find(|(name, _)| name == system) {
+            Some((_, settings)) => settings.or(&base),
+            None => base,
+        }
+    }
+}
+
+fn hocon_usize(h: &Hocon) -> Option<usize> {
+    h.as_i64().map(|v| {
+        assert!(v >= 0, "Invalid provider config: negative value {}", v);
+        v as usize
+    })
+}
+
+fn env_var<T: FromStr>(key: &str) -> Option<T>
+where
+    T::Err: fmt::Display,
+{
+    env::var(key).ok().filter(|v| !v.is_empty()).map(|v| {
+        v.parse()
+            .unwrap_or_else(|e| panic!("Invalid value for {}={}: {}", key, v, e))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_provider_config_overrides() {
+        let config = ProviderConfig::load_str(
+            r#"
+            provider {
+                threads = 8
+                throughput = 20
+                executor = "large"
+                buffer_config {
+                    chunk_size = 64000
+                }
+                overrides {
+                    pingpong {
+                        threads = 1
+                        msg_priority = 0.5
+                    }
+                }
+            }
+            "#,
+        );
+        let provider = KompactSystemProvider::new(IpAddr::V4(Ipv4Addr::LOCALHOST), config);
+        assert_eq!(provider.get_num_workers(), 8);
+        let pingpong = provider.settings_for("pingpong");
+        assert_eq!(pingpong.threads, Some(1));
+        assert_eq!(pingpong.throughput, Some(20));
+        assert_eq!(pingpong.msg_priority, Some(0.5));
+        assert_eq!(pingpong.executor, Some(ExecutorKind::LargePool));
+        assert_eq!(pingpong.buffer_config.chunk_size, Some(64000));
+        let fibonacci = provider.settings_for("fibonacci");
+        assert_eq!(fibonacci.threads, None);
+        assert_eq!(fibonacci.msg_priority, None);
+        assert_eq!(fibonacci.throughput, Some(20));
+    }
+
+    #[test]
+    fn test_executor_parsing() {
+        assert_eq!("small".parse(), Ok(ExecutorKind::SmallPool));
+        assert_eq!("Channel_Pool".parse(), Ok(ExecutorKind::ChannelPool));
+        // custom executors can't be registered from the config, so they can't be selected there
+        assert!("custom:pinned".parse::<ExecutorKind>().is_err());
+        assert_eq!(
+            ExecutorKind::DynPool.to_string().parse(),
+            Ok(ExecutorKind::DynPool)
+        );
+    }
+
+    fn single_threaded_pool(_threads: usize, conf: &mut KompactConfig) {
+        conf.executor(|_| crossbeam_channel_pool::ThreadPool::new(1));
+    }
+
+    #[test]
+    fn test_many_threads_and_custom_executors() {
+        let config = ProviderConfig::default()
+            .with_executor("single", single_threaded_pool)
+            .with_override(
+                "custom",
+                SystemSettings {
+                    executor: Some(ExecutorKind::Custom("single".to_string())),
+                    ..SystemSettings::default()
+                },
+            );
+        let provider = KompactSystemProvider::new(IpAddr::V4(Ipv4Addr::LOCALHOST), config);
+        for name in ["many", "custom"].iter() {
+            let system = provider.new_system_with_threads(*name, 72);
+            system
+                .shutdown()
+                .expect("Kompact didn't shut down properly");
+        }
+    }
+
+    #[test]
+    fn test_network_settings() {
+        let mut tuning = NetworkTuning::new();
+        tuning.set_chunk_size(128000);
+        tuning.set_max_chunk_count(16);
+        tuning.set_tcp_no_delay(TcpNoDelay::TCP_NO_DELAY_OFF);
+        let network = NetworkSettings::from_request(&tuning);
+        assert_eq!(network.buffer_config.chunk_size, Some(128000));
+        assert_eq!(network.buffer_config.initial_chunk_count, None);
+        assert!(!network.tcp_no_delay());
+        let s = network.to_string();
+        assert!(!s.contains(',') && !s.contains(';'), "{}", s);
+        assert_eq!(s.parse::<NetworkSettings>(), Ok(network.clone()));
+        assert_eq!(network.to_request(), tuning);
+        let default = NetworkSettings::from_request(&NetworkTuning::new());
+        assert_eq!(default, NetworkSettings::default());
+        assert_eq!(default.to_string().parse::<NetworkSettings>(), Ok(default));
+        assert!("1:2:3".parse::<NetworkSettings>().is_err());
+        assert!("x::::".parse::<NetworkSettings>().is_err());
+    }
+
+    #[test]
+    fn test_validate_network() {
+        let config = ProviderConfig::load_str(
+            r#"
+            provider {
+                overrides {
+                    netpingpong {
+                        buffer_config {
+                            chunk_size = 1000
+                        }
+                    }
+                }
+            }
+            "#,
+        );
+        let provider = KompactSystemProvider::new(IpAddr::V4(Ipv4Addr::LOCALHOST), config);
+        let base = BufferSettings::default();
+        let mut tuning = NetworkTuning::new();
+        tuning.set_encode_buf_min_free_space(2000);
+        assert!(provider
+            .validate_network("pingpong", &base, &tuning)
+            .is_ok());
+        assert!(provider
+            .validate_network("netpingpong", &base, &tuning)
+            .is_err());
+        tuning.set_chunk_size(4000);
+        assert!(provider
+            .validate_network("netpingpong", &base, &tuning)
+            .is_ok());
+        let small_chunks = BufferSettings {
+            chunk_size: Some(1000),
+            ..BufferSettings::default()
+        };
+        tuning.set_chunk_size(0);
+        assert!(provider
+            .validate_network("pingpong", &small_chunks, &tuning)
+            .is_err());
+    }
 }
@@ -2,18 +2,22 @@
 #[macro_use]
 extern crate benchmark_suite_shared;
 
-use benchmark_suite_shared::BenchmarkMain;
+use benchmark_suite_shared::{actors, result_writer, BenchmarkMain};
 use grpc;
 #[allow(unused_imports)]
 use slog::{crit, debug, error, info, warn};
 use std::env;
 
+pub mod actor_adapter;
 mod bench;
 mod benchmark_runner;
 pub mod kompact_system_provider;
 pub mod partitioning_actor;
 pub mod serialiser_ids;
 
+/// The commit of the `kompact` dependency, as resolved by the build script.
+pub const KOMPACT_REV: &str = env!("KOMPACT_REV");
+
 fn main() {
     let mut args: Vec<String> = env::args().collect();
     let mode: BenchMode = args
@@ -26,6 +30,8 @@ fn main() {
                 BenchMode::COMPONENT
             } else if lows == "mixed" {
                 BenchMode::MIXED
+            } else if lows == "generic" {
+                BenchMode::GENERIC
             } else {
                 panic!("Unkown bench mode {}", lows);
             }
@@ -33,6 +39,19 @@ fn main() {
         .expect("No bench mode was provided!");
 
     args.remove(1);
+    let implementation = match mode {
+        BenchMode::ACTOR => "Kompact Actor",
+        BenchMode::COMPONENT => "Kompact Component",
+        BenchMode::MIXED => "Kompact Mixed",
+        BenchMode::GENERIC => "Kompact Generic",
+    };
+    result_writer::init(
+        implementation,
+        &[
+            ("kompact", KOMPACT_REV),
+            (env!("CARGO_PKG_NAME"), env!("CARGO_PKG_VERSION")),
+        ],
+    );
     match mode {
         BenchMode::ACTOR => {
             BenchmarkMain::run_with(
@@ -58,6 +77,16 @@ fn main() {
                 kompact_system_provider::set_global_public_if,
             );
         }
+        BenchMode::GENERIC => {
+            BenchmarkMain::run_with(
+                args,
+                actors::runner::BenchmarkRunnerImpl::<actor_adapter::KompactAdapter>::new(),
+                Box::new(actors::benchmarks::GenericFactory::<
+                    actor_adapter::KompactAdapter,
+                >::new()),
+                kompact_system_provider::set_global_public_if,
+            );
+        }
     }
 }
 
@@ -65,6 +94,7 @@ enum BenchMode {
     ACTOR,
     COMPONENT,
     MIXED,
+    GENERIC,
 }
 
 #[cfg(test)]
@@ -91,4 +121,12 @@ mod tests {
         let benchmarks = Box::new(bench::MixedFactory {});
         test_implementation(benchmarks);
     }
+
+    #[test]
+    fn test_generic() {
+        let benchmarks = Box::new(actors::benchmarks::GenericFactory::<
+            actor_adapter::KompactAdapter,
+        >::new());
+        test_implementation(benchmarks);
+    }
 }
@@ -0,0 +1,65 @@
+//! Scoped providers live in their own test binary, since once a process uses them,
+//! threads without a scope can't fall back to the global provider anymore.
+
+use kompact_benchmarks::kompact_system_provider::{
+    current, with_provider, BufferSettings, KompactSystemProvider, RunOverrides,
+};
+use std::{
+    net::{IpAddr, Ipv4Addr},
+    panic,
+    sync::Arc,
+    thread,
+};
+
+#[test]
+fn test_scoped_providers_with_overrides() {
+    let base = KompactSystemProvider::default();
+    let handles: Vec<_> = (1..=4u8)
+        .map(|i| {
+            let provider = Arc::new(base.with_overrides(RunOverrides {
+                public_if: Some(IpAddr::V4(Ipv4Addr::new(127, 0, 0, i))),
+                threads: Some(i as usize),
+                buffer_config: BufferSettings {
+                    chunk_size: Some(1000 * i as usize),
+                    ..BufferSettings::default()
+                },
+            }));
+            thread::spawn(move || {
+                with_provider(provider.clone(), || {
+                    let p = current();
+                    assert!(Arc::ptr_eq(&p, &provider));
+                    assert_eq!(p.get_public_if(), IpAddr::V4(Ipv4Addr::new(127, 0, 0, i)));
+                    assert_eq!(p.get_num_workers(), i as usize);
+                    let settings = p.settings_for("pingpong");
+                    assert_eq!(settings.threads, Some(i as usize));
+                    assert_eq!(settings.buffer_config.chunk_size, Some(1000 * i as usize));
+                    let system = p.new_system("scoped");
+                    system
+                        .shutdown()
+                        .expect("Kompact didn't shut down properly");
+
+                    // a thread spawned from within the scope must not get the global provider
+                    let spawned = thread::spawn(|| current().get_num_workers()).join();
+                    assert!(
+                        spawned.is_err(),
+                        "Unscoped thread fell back to the global provider"
+                    );
+
+                    // unless the provider is passed along explicitly
+                    let passed = p.clone();
+                    let workers = thread::spawn(move || {
+                        with_provider(passed, || current().get_num_workers())
+                    })
+                    .join()
+                    .expect("Scoped thread failed");
+                    assert_eq!(workers, i as usize);
+                });
+                // the scope has ended on this thread as well
+                assert!(panic::catch_unwind(current).is_err());
+            })
+        })
+        .collect();
+    for handle in handles {
+        handle.join().expect("Scoped provider test failed");
+    }
+}
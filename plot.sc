@@ -529,7 +529,8 @@ private val colourMap: Map[String, style.PlotColor] = Map(
 	"KOMPICSSC2" -> style.NamedPlotColor.RED,
 	"ACTIX" -> style.NamedPlotColor.DARK_BLUE,
 	"ERLANG" -> style.NamedPlotColor.NAVY,
-	"RIKER" -> style.NamedPlotColor.MIDNIGHT_BLUE
+	"RIKER" -> style.NamedPlotColor.MIDNIGHT_BLUE,
+	"BASELINE" -> style.NamedPlotColor.GRAY40
 );
 
 private val colourMap2: Map[String, style.PlotColor] = Map(
@@ -543,8 +544,9 @@ private val colourMap2: Map[String, style.PlotColor] = Map(
 	"Kompics Scala 2.x" -> style.NamedPlotColor.RED,
 	"Actix" -> style.NamedPlotColor.DARK_BLUE,
 	"Erlang" -> style.NamedPlotColor.NAVY,
-	"Riker" -> style.NamedPlotColor.MIDNIGHT_BLUE
+	"Riker" -> style.NamedPlotColor.MIDNIGHT_BLUE,
+	"Baseline" -> style.NamedPlotColor.GRAY40
 );
 
 private val pointMap: Map[String, Int] = 
-	List("AKKA", "AKKATYPED", "KOMPACTAC", "KOMPACTCO", "KOMPACTMIX", "KOMPICSJ", "KOMPICSSC", "KOMPICSSC2", "ACTIX", "ERLANG", "RIKER").zipWithIndex.toMap.mapValues(_ + 1);
+	List("AKKA", "AKKATYPED", "KOMPACTAC", "KOMPACTCO", "KOMPACTMIX", "KOMPICSJ", "KOMPICSSC", "KOMPICSSC2", "ACTIX", "ERLANG", "RIKER", "BASELINE").zipWithIndex.toMap.mapValues(_ + 1);
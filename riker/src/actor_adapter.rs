@@ -0,0 +1,128 @@
+//! Runs actors written against `benchmark_suite_shared::actors` as Riker actors.
+
+use crate::riker_system_provider::*;
+use benchmark_suite_shared::actors::{self as generic, Envelope, Mailbox};
+use riker::actors::*;
+use std::fmt;
+use std::sync::{Arc, Mutex};
+
+#[derive(Clone)]
+pub struct RikerAdapter {
+    system: RikerSystem,
+}
+
+impl generic::ActorSystem for RikerAdapter {
+    fn start(label: &str, num_threads: usize) -> Self {
+        let system = RikerSystem::new(label, num_threads).expect("System");
+        RikerAdapter { system }
+    }
+
+    fn spawn<A: generic::Actor>(&self, actor: A) -> generic::ActorRef<A::Message> {
+        // Riker wants a factory it could call again on restarts, but generic actors are never restarted
+        let actor = Arc::new(Mutex::new(Some(actor)));
+        let adapter = self.clone();
+        let props: BoxActorProd<RikerActor<A>> = Props::new_from(move || {
+            let actor = actor
+                .lock()
+                .unwrap()
+                .take()
+                .expect("Generic actors can't be restarted");
+            RikerActor::with(actor, adapter.clone())
+        });
+        let actor_ref = self
+            .system
+            .start(props, "generic")
+            .expect("RikerActor never started!");
+        generic::ActorRef::new(RikerMailbox(actor_ref))
+    }
+
+    fn shutdown(self) -> Result<(), String> {
+        self.system
+            .shutdown()
+            .wait()
+            .map_err(|e| format!("Riker didn't shut down properly: {:?}", e))
+    }
+}
+
+/// Riker messages must be `Clone` and `Debug`, so the envelope is handed over in a shared slot.
+struct Mail<M>(Arc<Mutex<Option<Envelope<M>>>>);
+
+impl<M> Mail<M> {
+    fn new(envelope: Envelope<M>) -> Mail<M> {
+        Mail(Arc::new(Mutex::new(Some(envelope))))
+    }
+
+    fn take(&self) -> Envelope<M> {
+        self.0
+            .lock()
+            .unwrap()
+            .take()
+            .expect("Mail should only be delivered once")
+    }
+}
+impl<M> Clone for Mail<M> {
+    fn clone(&self) -> Self {
+        Mail(self.0.clone())
+    }
+}
+impl<M> fmt::Debug for Mail<M> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Mail(..)")
+    }
+}
+
+struct RikerMailbox<M: Send + 'static>(ActorRef<Mail<M>>);
+
+impl<M: Send + 'static> Mailbox<M> for RikerMailbox<M> {
+    fn enqueue(&self, envelope: Envelope<M>) -> () {
+        self.0.tell(Mail::new(envelope), None);
+    }
+}
+
+struct RikerActor<A: generic::Actor> {
+    actor: A,
+    system: RikerAdapter,
+    myself: Option<generic::ActorRef<A::Message>>,
+}
+
+impl<A: generic::Actor> RikerActor<A> {
+    fn with(actor: A, system: RikerAdapter) -> RikerActor<A> {
+        RikerActor {
+            actor,
+            system,
+            myself: None,
+        }
+    }
+
+    fn stop(&mut self, ctx: &Context<Mail<A::Message>>) -> () {
+        self.myself = None; // don't keep ourselves alive
+        ctx.stop(ctx.myself());
+    }
+}
+
+impl<A: generic::Actor> Actor for RikerActor<A> {
+    type Msg = Mail<A::Message>;
+
+    fn pre_start(&mut self, ctx: &Context<Self::Msg>) {
+        self.myself = Some(generic::ActorRef::new(RikerMailbox(ctx.myself())));
+    }
+
+    fn recv(&mut self, ctx: &Context<Self::Msg>, msg: Self::Msg, _sender: Sender) {
+        match msg.take() {
+            Envelope::Msg(msg) => {
+                let stopped = match self.myself {
+                    Some(ref myself) => {
+                        let mut generic_ctx = generic::Context::new(myself, &self.system);
+                        self.actor.receive(&mut generic_ctx, msg);
+                        generic_ctx.is_stopped()
+                    }
+                    None => return, // already stopped
+                };
+                if stopped {
+                    self.stop(ctx);
+                }
+            }
+            Envelope::Stop => self.stop(ctx),
+        }
+    }
+}
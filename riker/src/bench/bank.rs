@@ -0,0 +1,309 @@
+use super::*;
+
+use crate::riker_system_provider::*;
+use benchmark_suite_shared::helpers::bank::{TransferGenerator, INITIAL_BALANCE};
+use benchmark_suite_shared::kompics_benchmarks::benchmarks::BankRequest;
+use futures::future::RemoteHandle;
+use riker::actors::*;
+use std::convert::TryInto;
+use std::ops::Deref;
+use std::sync::Arc;
+use synchronoise::CountdownEvent;
+
+#[derive(Default)]
+pub struct Bank;
+
+impl Benchmark for Bank {
+    type Conf = BankRequest;
+    type Instance = BankI;
+
+    fn msg_to_conf(msg: Box<dyn (::protobuf::Message)>) -> Result<Self::Conf, BenchmarkError> {
+        downcast_msg!(msg; BankRequest)
+    }
+
+    fn validate(c: &Self::Conf) -> Result<(), BenchmarkError> {
+        validation::bank(c)
+    }
+
+    fn new_instance() -> Self::Instance {
+        BankI::new()
+    }
+
+    const LABEL: &'static str = "Bank";
+}
+
+pub struct BankI {
+    num_accounts: Option<usize>,
+    num_transactions: Option<u64>,
+    seed: Option<u64>,
+    system: Option<RikerSystem>,
+    teller: Option<ActorRef<TellerMsg>>,
+    accounts: Vec<ActorRef<AccountMsg>>,
+    latch: Option<Arc<CountdownEvent>>,
+}
+
+impl BankI {
+    fn new() -> BankI {
+        BankI {
+            num_accounts: None,
+            num_transactions: None,
+            seed: None,
+            system: None,
+            teller: None,
+            accounts: Vec::new(),
+            latch: None,
+        }
+    }
+}
+
+impl BenchmarkInstance for BankI {
+    type Conf = BankRequest;
+
+    fn setup(&mut self, c: &Self::Conf) -> () {
+        self.num_accounts = Some(c.number_of_accounts.try_into().unwrap());
+        self.num_transactions = Some(c.number_of_transactions);
+        self.seed = Some(c.seed);
+        let system = RikerSystem::new("bank", num_cpus::get()).expect("System");
+        self.system = Some(system);
+    }
+
+    fn prepare_iteration(&mut self) -> () {
+        match (
+            self.system.as_ref(),
+            self.num_accounts,
+            self.num_transactions,
+            self.seed,
+        ) {
+            (Some(system), Some(num_accounts), Some(num_transactions), Some(seed)) => {
+                let latch = Arc::new(CountdownEvent::new(1));
+                let generator = TransferGenerator::new(seed, num_accounts);
+                let teller = system
+                    .start(
+                        Teller::props(num_transactions, generator, latch.clone()),
+                        "teller",
+                    )
+                    .expect("Teller never started!");
+                for id in 0..num_accounts {
+                    let account = system
+                        .start(Account::props(id, teller.clone()), "account")
+                        .expect("Account never started!");
+                    self.accounts.push(account);
+                }
+                // all peers are enqueued before the Start message sent in run_iteration
+                let accounts = Accounts(self.accounts.clone());
+                for account in self.accounts.iter() {
+                    account.tell(AccountMsg::Peers(accounts.clone()), None);
+                }
+                teller.tell(TellerMsg::Accounts(accounts), None);
+                self.teller = Some(teller);
+                self.latch = Some(latch);
+            }
+            _ => unimplemented!(),
+        }
+    }
+
+    fn run_iteration(&mut self) -> () {
+        match self.teller {
+            Some(ref teller) => {
+                let latch = self.latch.take().unwrap();
+                teller.tell(TellerMsg::Start, None);
+                latch.wait();
+            }
+            None => unimplemented!(),
+        }
+    }
+
+    fn cleanup_iteration(&mut self, last_iteration: bool, _exec_time_millis: f64) -> () {
+        let system = self.system.take().unwrap();
+        let total: i64 = self
+            .accounts
+            .iter()
+            .map(|account| {
+                let balance_f: RemoteHandle<i64> = system.ask(account, AccountMsg::GetBalance);
+                balance_f.wait()
+            })
+            .sum();
+        let num_accounts = self.num_accounts.unwrap() as i64;
+        assert_eq!(
+            total,
+            num_accounts * INITIAL_BALANCE,
+            "Money was not conserved!"
+        );
+
+        system.stop(self.teller.take().unwrap());
+        for account in self.accounts.drain(..) {
+            system.stop(account);
+        }
+
+        if last_iteration {
+            system
+                .shutdown()
+                .wait()
+                .expect("Riker didn't shut down properly");
+            self.num_accounts = None;
+            self.num_transactions = None;
+            self.seed = None;
+        } else {
+            self.system = Some(system);
+        }
+    }
+}
+
+// used to break trait bound resolution cycle of Message
+#[derive(Debug, Clone)]
+struct Accounts(Vec<ActorRef<AccountMsg>>);
+// definitely safe, the compiler just endlessly recurses while trying to figure this out
+unsafe impl Send for Accounts {}
+impl Deref for Accounts {
+    type Target = Vec<ActorRef<AccountMsg>>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[derive(Debug, Clone)]
+enum TellerMsg {
+    Accounts(Accounts),
+    Start,
+    TransferDone,
+}
+
+#[derive(Debug, Clone)]
+enum AccountMsg {
+    Peers(Accounts),
+    Debit { amount: i64, destination: usize },
+    Credit { amount: i64, source: usize },
+    CreditAck,
+    GetBalance,
+}
+
+struct Teller {
+    num_transactions: u64,
+    remaining: u64,
+    generator: TransferGenerator,
+    accounts: Option<Accounts>,
+    latch: Arc<CountdownEvent>,
+}
+
+impl Teller {
+    fn with(
+        num_transactions: u64,
+        generator: TransferGenerator,
+        latch: Arc<CountdownEvent>,
+    ) -> Teller {
+        Teller {
+            num_transactions,
+            remaining: num_transactions,
+            generator,
+            accounts: None,
+            latch,
+        }
+    }
+
+    fn props(
+        num_transactions: u64,
+        generator: TransferGenerator,
+        latch: Arc<CountdownEvent>,
+    ) -> BoxActorProd<Teller> {
+        Props::new_from(move || Teller::with(num_transactions, generator.clone(), latch.clone()))
+    }
+}
+
+impl Actor for Teller {
+    type Msg = TellerMsg;
+
+    fn post_stop(&mut self) {
+        self.accounts = None; // the accounts hold a reference to the teller
+    }
+
+    fn recv(&mut self, _ctx: &Context<Self::Msg>, msg: Self::Msg, _sender: Sender) {
+        match msg {
+            TellerMsg::Accounts(accounts) => {
+                self.accounts = Some(accounts);
+            }
+            TellerMsg::Start => {
+                let accounts = self.accounts.as_ref().expect("Accounts were never set");
+                for _ in 0..self.num_transactions {
+                    let transfer = self.generator.next_transfer();
+                    let debit = AccountMsg::Debit {
+                        amount: transfer.amount,
+                        destination: transfer.destination,
+                    };
+                    accounts[transfer.source].tell(debit, None);
+                }
+            }
+            TellerMsg::TransferDone => {
+                self.remaining -= 1u64;
+                if self.remaining == 0 {
+                    self.latch.decrement().expect("Should decrement!");
+                }
+            }
+        }
+    }
+}
+
+struct Account {
+    id: usize,
+    balance: i64,
+    peers: Option<Accounts>,
+    teller: ActorRef<TellerMsg>,
+}
+
+impl Account {
+    fn with(id: usize, teller: ActorRef<TellerMsg>) -> Account {
+        Account {
+            id,
+            balance: INITIAL_BALANCE,
+            peers: None,
+            teller,
+        }
+    }
+
+    fn props(id: usize, teller: ActorRef<TellerMsg>) -> BoxActorProd<Account> {
+        Props::new_from(move || Account::with(id, teller.clone()))
+    }
+
+    fn peers(&self) -> &Accounts {
+        self.peers.as_ref().expect("Peers were never set")
+    }
+}
+
+impl Actor for Account {
+    type Msg = AccountMsg;
+
+    fn post_stop(&mut self) {
+        self.peers = None; // the accounts all hold references to each other
+    }
+
+    fn recv(&mut self, _ctx: &Context<Self::Msg>, msg: Self::Msg, sender: Sender) {
+        match msg {
+            AccountMsg::Peers(peers) => {
+                self.peers = Some(peers);
+            }
+            AccountMsg::Debit {
+                amount,
+                destination,
+            } => {
+                self.balance -= amount;
+                let credit = AccountMsg::Credit {
+                    amount,
+                    source: self.id,
+                };
+                self.peers()[destination].tell(credit, None);
+            }
+            AccountMsg::Credit { amount, source } => {
+                self.balance += amount;
+                self.peers()[source].tell(AccountMsg::CreditAck, None);
+            }
+            AccountMsg::CreditAck => self.teller.tell(TellerMsg::TransferDone, None),
+            AccountMsg::GetBalance => {
+                sender
+                    .as_ref()
+                    .expect("GetBalance needs a sender")
+                    .try_tell(self.balance, None)
+                    .expect("Should have replied");
+            }
+        }
+    }
+}
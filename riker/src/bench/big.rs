@@ -0,0 +1,248 @@
+use super::*;
+
+use crate::riker_system_provider::*;
+use benchmark_suite_shared::helpers::big::PeerSampler;
+use benchmark_suite_shared::kompics_benchmarks::benchmarks::BigRequest;
+use riker::actors::*;
+use std::convert::TryInto;
+use std::ops::Deref;
+use std::sync::Arc;
+use synchronoise::CountdownEvent;
+
+#[derive(Default)]
+pub struct Big;
+
+impl Benchmark for Big {
+    type Conf = BigRequest;
+    type Instance = BigI;
+
+    fn msg_to_conf(msg: Box<dyn (::protobuf::Message)>) -> Result<Self::Conf, BenchmarkError> {
+        downcast_msg!(msg; BigRequest)
+    }
+
+    fn validate(c: &Self::Conf) -> Result<(), BenchmarkError> {
+        validation::big(c)
+    }
+
+    fn new_instance() -> Self::Instance {
+        BigI::new()
+    }
+
+    const LABEL: &'static str = "Big";
+}
+
+pub struct BigI {
+    num_actors: Option<usize>,
+    num_pings: Option<u64>,
+    seed: Option<u64>,
+    system: Option<RikerSystem>,
+    sink: Option<ActorRef<Done>>,
+    actors: Vec<ActorRef<BigMsg>>,
+    latch: Option<Arc<CountdownEvent>>,
+}
+
+impl BigI {
+    fn new() -> BigI {
+        BigI {
+            num_actors: None,
+            num_pings: None,
+            seed: None,
+            system: None,
+            sink: None,
+            actors: Vec::new(),
+            latch: None,
+        }
+    }
+}
+
+impl BenchmarkInstance for BigI {
+    type Conf = BigRequest;
+
+    fn setup(&mut self, c: &Self::Conf) -> () {
+        self.num_actors = Some(c.number_of_actors.try_into().unwrap());
+        self.num_pings = Some(c.number_of_pings);
+        self.seed = Some(c.seed);
+        let system = RikerSystem::new("big", num_cpus::get()).expect("System");
+        self.system = Some(system);
+    }
+
+    fn prepare_iteration(&mut self) -> () {
+        match (
+            self.system.as_ref(),
+            self.num_actors,
+            self.num_pings,
+            self.seed,
+        ) {
+            (Some(system), Some(num_actors), Some(num_pings), Some(seed)) => {
+                let latch = Arc::new(CountdownEvent::new(1));
+                let sink = system
+                    .start(Sink::props(num_actors, latch.clone()), "sink")
+                    .expect("Sink never started!");
+                for id in 0..num_actors {
+                    let sampler = PeerSampler::new(seed, id, num_actors);
+                    let actor = system
+                        .start(
+                            BigActor::props(id, num_pings, sampler, sink.clone()),
+                            "big-actor",
+                        )
+                        .expect("BigActor never started!");
+                    self.actors.push(actor);
+                }
+                // all peers are enqueued before any Start message sent in run_iteration
+                let peers = Peers(self.actors.clone());
+                for actor in self.actors.iter() {
+                    actor.tell(BigMsg::Peers(peers.clone()), None);
+                }
+                self.sink = Some(sink);
+                self.latch = Some(latch);
+            }
+            _ => unimplemented!(),
+        }
+    }
+
+    fn run_iteration(&mut self) -> () {
+        let latch = self.latch.take().unwrap();
+        for actor in self.actors.iter() {
+            actor.tell(BigMsg::Start, None);
+        }
+        latch.wait();
+    }
+
+    fn cleanup_iteration(&mut self, last_iteration: bool, _exec_time_millis: f64) -> () {
+        let system = self.system.take().unwrap();
+        for actor in self.actors.drain(..) {
+            system.stop(actor);
+        }
+        system.stop(self.sink.take().unwrap());
+
+        if last_iteration {
+            system
+                .shutdown()
+                .wait()
+                .expect("Riker didn't shut down properly");
+            self.num_actors = None;
+            self.num_pings = None;
+            self.seed = None;
+        } else {
+            self.system = Some(system);
+        }
+    }
+}
+
+// used to break trait bound resolution cycle of Message
+#[derive(Debug, Clone)]
+struct Peers(Vec<ActorRef<BigMsg>>);
+// definitely safe, the compiler just endlessly recurses while trying to figure this out
+unsafe impl Send for Peers {}
+impl Deref for Peers {
+    type Target = Vec<ActorRef<BigMsg>>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[derive(Debug, Clone)]
+enum BigMsg {
+    Peers(Peers),
+    Start,
+    Ping { sender: usize },
+    Pong,
+}
+
+/// Sent to the sink by every actor that got all its pongs.
+#[derive(Debug, Clone)]
+struct Done;
+
+struct BigActor {
+    id: usize,
+    pings_left: u64,
+    sampler: PeerSampler,
+    peers: Option<Peers>,
+    sink: ActorRef<Done>,
+}
+
+impl BigActor {
+    fn with(id: usize, num_pings: u64, sampler: PeerSampler, sink: ActorRef<Done>) -> BigActor {
+        BigActor {
+            id,
+            pings_left: num_pings,
+            sampler,
+            peers: None,
+            sink,
+        }
+    }
+
+    fn props(
+        id: usize,
+        num_pings: u64,
+        sampler: PeerSampler,
+        sink: ActorRef<Done>,
+    ) -> BoxActorProd<BigActor> {
+        Props::new_from(move || BigActor::with(id, num_pings, sampler.clone(), sink.clone()))
+    }
+
+    fn peers(&self) -> &Peers {
+        self.peers.as_ref().expect("Peers were never set")
+    }
+
+    fn send_ping(&mut self) -> () {
+        self.pings_left -= 1u64;
+        let peer = self.sampler.next_peer();
+        self.peers()[peer].tell(BigMsg::Ping { sender: self.id }, None);
+    }
+}
+
+impl Actor for BigActor {
+    type Msg = BigMsg;
+
+    fn post_stop(&mut self) {
+        self.peers = None; // the actors all hold references to each other
+    }
+
+    fn recv(&mut self, _ctx: &Context<Self::Msg>, msg: Self::Msg, _sender: Sender) {
+        match msg {
+            BigMsg::Peers(peers) => {
+                self.peers = Some(peers);
+            }
+            BigMsg::Start => self.send_ping(),
+            BigMsg::Ping { sender } => self.peers()[sender].tell(BigMsg::Pong, None),
+            BigMsg::Pong => {
+                if self.pings_left > 0 {
+                    self.send_ping();
+                } else {
+                    self.sink.tell(Done, None);
+                }
+            }
+        }
+    }
+}
+
+struct Sink {
+    remaining: usize,
+    latch: Arc<CountdownEvent>,
+}
+
+impl Sink {
+    fn with(num_actors: usize, latch: Arc<CountdownEvent>) -> Sink {
+        Sink {
+            remaining: num_actors,
+            latch,
+        }
+    }
+
+    fn props(num_actors: usize, latch: Arc<CountdownEvent>) -> BoxActorProd<Sink> {
+        Props::new_from(move || Sink::with(num_actors, latch.clone()))
+    }
+}
+
+impl Actor for Sink {
+    type Msg = Done;
+
+    fn recv(&mut self, _ctx: &Context<Self::Msg>, _msg: Self::Msg, _sender: Sender) {
+        self.remaining -= 1;
+        if self.remaining == 0 {
+            self.latch.decrement().expect("Should decrement!");
+        }
+    }
+}
@@ -0,0 +1,282 @@
+use super::*;
+
+use crate::riker_system_provider::*;
+use benchmark_suite_shared::kompics_benchmarks::benchmarks::DiningPhilosophersRequest;
+use benchmark_suite_shared::result_writer;
+use futures::future::RemoteHandle;
+use riker::actors::*;
+use std::convert::TryInto;
+use std::sync::Arc;
+use synchronoise::CountdownEvent;
+
+#[derive(Default)]
+pub struct DiningPhilosophers;
+
+impl Benchmark for DiningPhilosophers {
+    type Conf = DiningPhilosophersRequest;
+    type Instance = DiningPhilosophersI;
+
+    fn msg_to_conf(msg: Box<dyn (::protobuf::Message)>) -> Result<Self::Conf, BenchmarkError> {
+        downcast_msg!(msg; DiningPhilosophersRequest)
+    }
+
+    fn validate(c: &Self::Conf) -> Result<(), BenchmarkError> {
+        validation::dining_philosophers(c)
+    }
+
+    fn new_instance() -> Self::Instance {
+        DiningPhilosophersI::new()
+    }
+
+    const LABEL: &'static str = "DiningPhilosophers";
+}
+
+pub struct DiningPhilosophersI {
+    num_philosophers: Option<usize>,
+    num_rounds: Option<u64>,
+    system: Option<RikerSystem>,
+    arbitrator: Option<ActorRef<ArbitratorMsg>>,
+    philosophers: Vec<ActorRef<PhilosopherMsg>>,
+    latch: Option<Arc<CountdownEvent>>,
+    total_denied: u64,
+    iterations: u32,
+}
+
+impl DiningPhilosophersI {
+    fn new() -> DiningPhilosophersI {
+        DiningPhilosophersI {
+            num_philosophers: None,
+            num_rounds: None,
+            system: None,
+            arbitrator: None,
+            philosophers: Vec::new(),
+            latch: None,
+            total_denied: 0,
+            iterations: 0,
+        }
+    }
+}
+
+impl BenchmarkInstance for DiningPhilosophersI {
+    type Conf = DiningPhilosophersRequest;
+
+    fn setup(&mut self, c: &Self::Conf) -> () {
+        self.num_philosophers = Some(c.number_of_philosophers.try_into().unwrap());
+        self.num_rounds = Some(c.number_of_rounds);
+        self.total_denied = 0;
+        self.iterations = 0;
+        let system = RikerSystem::new("dining_philosophers", num_cpus::get()).expect("System");
+        self.system = Some(system);
+    }
+
+    fn prepare_iteration(&mut self) -> () {
+        match (self.system.as_ref(), self.num_philosophers, self.num_rounds) {
+            (Some(system), Some(num_philosophers), Some(num_rounds)) => {
+                let latch = Arc::new(CountdownEvent::new(1));
+                let arbitrator = system
+                    .start(
+                        Arbitrator::props(num_philosophers, latch.clone()),
+                        "arbitrator",
+                    )
+                    .expect("Arbitrator never started!");
+                for id in 0..num_philosophers {
+                    let philosopher = system
+                        .start(
+                            Philosopher::props(id, num_rounds, arbitrator.clone()),
+                            "philosopher",
+                        )
+                        .expect("Philosopher never started!");
+                    self.philosophers.push(philosopher);
+                }
+                // enqueued before any Hungry message, which are only sent after run_iteration
+                arbitrator.tell(ArbitratorMsg::Philosophers(self.philosophers.clone()), None);
+                self.arbitrator = Some(arbitrator);
+                self.latch = Some(latch);
+            }
+            _ => unimplemented!(),
+        }
+    }
+
+    fn run_iteration(&mut self) -> () {
+        let latch = self.latch.take().unwrap();
+        for philosopher in self.philosophers.iter() {
+            philosopher.tell(PhilosopherMsg::Start, None);
+        }
+        latch.wait();
+    }
+
+    fn cleanup_iteration(&mut self, last_iteration: bool, _exec_time_millis: f64) -> () {
+        let system = self.system.take().unwrap();
+        let arbitrator = self.arbitrator.take().unwrap();
+        let denied_f: RemoteHandle<u64> = system.ask(&arbitrator, ArbitratorMsg::GetDenied);
+        self.total_denied += denied_f.wait();
+        self.iterations += 1;
+        system.stop(arbitrator);
+        for philosopher in self.philosophers.drain(..) {
+            system.stop(philosopher);
+        }
+
+        if last_iteration {
+            result_writer::record_metric(
+                "denied_attempts",
+                (self.total_denied as f64) / (self.iterations as f64),
+            );
+            system
+                .shutdown()
+                .wait()
+                .expect("Riker didn't shut down properly");
+            self.num_philosophers = None;
+            self.num_rounds = None;
+        } else {
+            self.system = Some(system);
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+enum ArbitratorMsg {
+    Philosophers(Vec<ActorRef<PhilosopherMsg>>),
+    Hungry { philosopher: usize },
+    Done { philosopher: usize },
+    Exit,
+    GetDenied,
+}
+
+#[derive(Debug, Clone)]
+enum PhilosopherMsg {
+    Start,
+    Denied,
+    Eat,
+}
+
+/// Owns all the forks, and hands out both of a philosopher's forks at once, or none at all.
+struct Arbitrator {
+    forks: Vec<bool>,
+    denied: u64,
+    remaining: usize,
+    philosophers: Vec<ActorRef<PhilosopherMsg>>,
+    latch: Arc<CountdownEvent>,
+}
+
+impl Arbitrator {
+    fn with(num_philosophers: usize, latch: Arc<CountdownEvent>) -> Arbitrator {
+        Arbitrator {
+            forks: vec![false; num_philosophers],
+            denied: 0u64,
+            remaining: num_philosophers,
+            philosophers: Vec::new(),
+            latch,
+        }
+    }
+
+    fn props(num_philosophers: usize, latch: Arc<CountdownEvent>) -> BoxActorProd<Arbitrator> {
+        Props::new_from(move || Arbitrator::with(num_philosophers, latch.clone()))
+    }
+
+    /// Philosopher `i` eats with forks `i` and `i + 1`.
+    fn forks_of(&self, philosopher: usize) -> (usize, usize) {
+        (philosopher, (philosopher + 1) % self.forks.len())
+    }
+}
+
+impl Actor for Arbitrator {
+    type Msg = ArbitratorMsg;
+
+    fn post_stop(&mut self) {
+        self.philosophers.clear(); // the philosophers hold a reference to the arbitrator
+    }
+
+    fn recv(&mut self, _ctx: &Context<Self::Msg>, msg: Self::Msg, sender: Sender) {
+        match msg {
+            ArbitratorMsg::Philosophers(philosophers) => {
+                self.philosophers = philosophers;
+            }
+            ArbitratorMsg::Hungry { philosopher } => {
+                let (left, right) = self.forks_of(philosopher);
+                if self.forks[left] || self.forks[right] {
+                    self.denied += 1u64;
+                    self.philosophers[philosopher].tell(PhilosopherMsg::Denied, None);
+                } else {
+                    self.forks[left] = true;
+                    self.forks[right] = true;
+                    self.philosophers[philosopher].tell(PhilosopherMsg::Eat, None);
+                }
+            }
+            ArbitratorMsg::Done { philosopher } => {
+                let (left, right) = self.forks_of(philosopher);
+                self.forks[left] = false;
+                self.forks[right] = false;
+            }
+            ArbitratorMsg::Exit => {
+                self.remaining -= 1;
+                if self.remaining == 0 {
+                    self.latch.decrement().expect("Should decrement!");
+                }
+            }
+            ArbitratorMsg::GetDenied => {
+                sender
+                    .as_ref()
+                    .expect("GetDenied needs a sender")
+                    .try_tell(self.denied, None)
+                    .expect("Should have replied");
+            }
+        }
+    }
+}
+
+struct Philosopher {
+    id: usize,
+    rounds_left: u64,
+    arbitrator: ActorRef<ArbitratorMsg>,
+}
+
+impl Philosopher {
+    fn with(id: usize, num_rounds: u64, arbitrator: ActorRef<ArbitratorMsg>) -> Philosopher {
+        Philosopher {
+            id,
+            rounds_left: num_rounds,
+            arbitrator,
+        }
+    }
+
+    fn props(
+        id: usize,
+        num_rounds: u64,
+        arbitrator: ActorRef<ArbitratorMsg>,
+    ) -> BoxActorProd<Philosopher> {
+        Props::new_from(move || Philosopher::with(id, num_rounds, arbitrator.clone()))
+    }
+
+    fn request_forks(&self) -> () {
+        self.arbitrator.tell(
+            ArbitratorMsg::Hungry {
+                philosopher: self.id,
+            },
+            None,
+        );
+    }
+}
+
+impl Actor for Philosopher {
+    type Msg = PhilosopherMsg;
+
+    fn recv(&mut self, _ctx: &Context<Self::Msg>, msg: Self::Msg, _sender: Sender) {
+        match msg {
+            PhilosopherMsg::Start | PhilosopherMsg::Denied => self.request_forks(),
+            PhilosopherMsg::Eat => {
+                self.rounds_left -= 1u64;
+                self.arbitrator.tell(
+                    ArbitratorMsg::Done {
+                        philosopher: self.id,
+                    },
+                    None,
+                );
+                if self.rounds_left > 0 {
+                    self.request_forks();
+                } else {
+                    self.arbitrator.tell(ArbitratorMsg::Exit, None);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,211 @@
+use super::*;
+
+use crate::riker_system_provider::*;
+use benchmark_suite_shared::kompics_benchmarks::benchmarks::FanoutRequest;
+use riker::actors::*;
+use std::convert::TryInto;
+use std::sync::Arc;
+use synchronoise::CountdownEvent;
+
+#[derive(Default)]
+pub struct Fanout;
+
+impl Benchmark for Fanout {
+    type Conf = FanoutRequest;
+    type Instance = FanoutI;
+
+    fn msg_to_conf(msg: Box<dyn (::protobuf::Message)>) -> Result<Self::Conf, BenchmarkError> {
+        downcast_msg!(msg; FanoutRequest)
+    }
+
+    fn validate(c: &Self::Conf) -> Result<(), BenchmarkError> {
+        validation::fanout(c)
+    }
+
+    fn new_instance() -> Self::Instance {
+        FanoutI::new()
+    }
+
+    const LABEL: &'static str = "Fanout";
+}
+
+pub struct FanoutI {
+    num_subscribers: Option<usize>,
+    num_rounds: Option<u64>,
+    system: Option<RikerSystem>,
+    publisher: Option<ActorRef<PublisherMsg>>,
+    subscribers: Vec<ActorRef<Round>>,
+    latch: Option<Arc<CountdownEvent>>,
+}
+
+impl FanoutI {
+    fn new() -> FanoutI {
+        FanoutI {
+            num_subscribers: None,
+            num_rounds: None,
+            system: None,
+            publisher: None,
+            subscribers: Vec::new(),
+            latch: None,
+        }
+    }
+}
+
+impl BenchmarkInstance for FanoutI {
+    type Conf = FanoutRequest;
+
+    fn setup(&mut self, c: &Self::Conf) -> () {
+        self.num_subscribers = Some(c.number_of_subscribers.try_into().unwrap());
+        self.num_rounds = Some(c.number_of_rounds);
+        let system = RikerSystem::new("fanout", num_cpus::get()).expect("System");
+        self.system = Some(system);
+    }
+
+    fn prepare_iteration(&mut self) -> () {
+        match (self.system.as_ref(), self.num_subscribers, self.num_rounds) {
+            (Some(system), Some(num_subscribers), Some(num_rounds)) => {
+                let latch = Arc::new(CountdownEvent::new(1));
+                let publisher = system
+                    .start(Publisher::props(num_rounds, latch.clone()), "publisher")
+                    .expect("Publisher never started!");
+                for _ in 0..num_subscribers {
+                    let subscriber = system
+                        .start(Subscriber::props(publisher.clone()), "subscriber")
+                        .expect("Subscriber never started!");
+                    self.subscribers.push(subscriber);
+                }
+                // enqueued before the Start message sent in run_iteration
+                publisher.tell(PublisherMsg::Subscribe(self.subscribers.clone()), None);
+                self.publisher = Some(publisher);
+                self.latch = Some(latch);
+            }
+            _ => unimplemented!(),
+        }
+    }
+
+    fn run_iteration(&mut self) -> () {
+        match self.publisher {
+            Some(ref publisher) => {
+                let latch = self.latch.take().unwrap();
+                publisher.tell(PublisherMsg::Start, None);
+                latch.wait();
+            }
+            None => unimplemented!(),
+        }
+    }
+
+    fn cleanup_iteration(&mut self, last_iteration: bool, _exec_time_millis: f64) -> () {
+        let system = self.system.take().unwrap();
+        system.stop(self.publisher.take().unwrap());
+        for subscriber in self.subscribers.drain(..) {
+            system.stop(subscriber);
+        }
+
+        if last_iteration {
+            system
+                .shutdown()
+                .wait()
+                .expect("Riker didn't shut down properly");
+            self.num_subscribers = None;
+            self.num_rounds = None;
+        } else {
+            self.system = Some(system);
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+enum PublisherMsg {
+    Subscribe(Vec<ActorRef<Round>>),
+    Start,
+    /// The index of the acknowledged round.
+    Ack(u64),
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Round {
+    index: u64,
+}
+
+struct Publisher {
+    subscribers: Vec<ActorRef<Round>>,
+    num_rounds: u64,
+    round: u64,
+    pending_acks: usize,
+    latch: Arc<CountdownEvent>,
+}
+
+impl Publisher {
+    fn with(num_rounds: u64, latch: Arc<CountdownEvent>) -> Publisher {
+        Publisher {
+            subscribers: Vec::new(),
+            num_rounds,
+            round: 0u64,
+            pending_acks: 0usize,
+            latch,
+        }
+    }
+
+    fn props(num_rounds: u64, latch: Arc<CountdownEvent>) -> BoxActorProd<Publisher> {
+        Props::new_from(move || Publisher::with(num_rounds, latch.clone()))
+    }
+
+    fn publish(&mut self) -> () {
+        let round = Round { index: self.round };
+        for subscriber in self.subscribers.iter() {
+            subscriber.tell(round, None);
+        }
+        self.pending_acks = self.subscribers.len();
+    }
+}
+
+impl Actor for Publisher {
+    type Msg = PublisherMsg;
+
+    fn post_stop(&mut self) {
+        self.subscribers.clear(); // the subscribers hold a reference to the publisher
+    }
+
+    fn recv(&mut self, _ctx: &Context<Self::Msg>, msg: Self::Msg, _sender: Sender) {
+        match msg {
+            PublisherMsg::Subscribe(subscribers) => {
+                self.subscribers = subscribers;
+            }
+            PublisherMsg::Start => self.publish(),
+            PublisherMsg::Ack(round) => {
+                debug_assert_eq!(round, self.round);
+                self.pending_acks -= 1;
+                if self.pending_acks == 0 {
+                    self.round += 1u64;
+                    if self.round < self.num_rounds {
+                        self.publish();
+                    } else {
+                        self.latch.decrement().expect("Should decrement!");
+                    }
+                }
+            }
+        }
+    }
+}
+
+struct Subscriber {
+    publisher: ActorRef<PublisherMsg>,
+}
+
+impl Subscriber {
+    fn with(publisher: ActorRef<PublisherMsg>) -> Subscriber {
+        Subscriber { publisher }
+    }
+
+    fn props(publisher: ActorRef<PublisherMsg>) -> BoxActorProd<Subscriber> {
+        Props::new_from(move || Subscriber::with(publisher.clone()))
+    }
+}
+
+impl Actor for Subscriber {
+    type Msg = Round;
+
+    fn recv(&mut self, _ctx: &Context<Self::Msg>, msg: Self::Msg, _sender: Sender) {
+        self.publisher.tell(PublisherMsg::Ack(msg.index), None);
+    }
+}
@@ -18,6 +18,10 @@ impl Benchmark for Fibonacci {
         downcast_msg!(msg; FibonacciRequest)
     }
 
+    fn validate(c: &Self::Conf) -> Result<(), BenchmarkError> {
+        validation::fibonacci(c)
+    }
+
     fn new_instance() -> Self::Instance {
         FibonacciI::new()
     }
@@ -1,10 +1,20 @@
 use super::*;
-use benchmark_suite_shared::benchmark::*;
+use benchmark_suite_shared::{benchmark::*, helpers::validation};
 
 pub mod all_pairs_shortest_path;
+pub mod bank;
+pub mod big;
 pub mod chameneos;
+pub mod dining_philosophers;
+pub mod fanout;
 pub mod fibonacci;
+mod net;
+pub mod net_throughput_pingpong;
+pub mod netpingpong;
+pub mod parallel_sort;
 pub mod pingpong;
+pub mod producer_consumer;
+pub mod thread_ring;
 pub mod throughput_pingpong;
 
 pub fn factory() -> Box<dyn BenchmarkFactory> {
@@ -18,6 +28,15 @@ impl BenchmarkFactory for Factory {
         match label {
             pingpong::PingPong::LABEL => self.ping_pong().map_into(),
             throughput_pingpong::PingPong::LABEL => self.throughput_ping_pong().map_into(),
+            netpingpong::PingPong::LABEL => self.net_ping_pong().map_into(),
+            net_throughput_pingpong::PingPong::LABEL => self.net_throughput_ping_pong().map_into(),
+            thread_ring::ThreadRing::LABEL => self.thread_ring().map_into(),
+            fanout::Fanout::LABEL => self.fanout().map_into(),
+            big::Big::LABEL => self.big().map_into(),
+            bank::Bank::LABEL => self.bank().map_into(),
+            producer_consumer::ProducerConsumer::LABEL => self.producer_consumer().map_into(),
+            dining_philosophers::DiningPhilosophers::LABEL => self.dining_philosophers().map_into(),
+            parallel_sort::ParallelSort::LABEL => self.parallel_sort().map_into(),
             _ => Err(NotImplementedError::NotFound),
         }
     }
@@ -30,7 +49,7 @@ impl BenchmarkFactory for Factory {
         Ok(pingpong::PingPong.into())
     }
     fn net_ping_pong(&self) -> Result<Box<dyn AbstractDistributedBenchmark>, NotImplementedError> {
-        Err(NotImplementedError::NotImplementable)
+        Ok(netpingpong::PingPong.into())
     }
 
     fn throughput_ping_pong(&self) -> Result<Box<dyn AbstractBenchmark>, NotImplementedError> {
@@ -39,7 +58,7 @@ impl BenchmarkFactory for Factory {
     fn net_throughput_ping_pong(
         &self,
     ) -> Result<Box<dyn AbstractDistributedBenchmark>, NotImplementedError> {
-        Err(NotImplementedError::NotImplementable)
+        Ok(net_throughput_pingpong::PingPong.into())
     }
 
     fn atomic_register(
@@ -77,4 +96,40 @@ impl BenchmarkFactory for Factory {
     ) -> Result<Box<dyn AbstractDistributedBenchmark>, NotImplementedError> {
         Err(NotImplementedError::NotImplementable)
     }
+
+    fn thread_ring(&self) -> Result<Box<dyn AbstractBenchmark>, NotImplementedError> {
+        Ok(thread_ring::ThreadRing {}.into())
+    }
+
+    fn fan_in(&self) -> Result<Box<dyn AbstractBenchmark>, NotImplementedError> {
+        Err(NotImplementedError::FutureWork)
+    }
+
+    fn fanout(&self) -> Result<Box<dyn AbstractBenchmark>, NotImplementedError> {
+        Ok(fanout::Fanout {}.into())
+    }
+
+    fn big(&self) -> Result<Box<dyn AbstractBenchmark>, NotImplementedError> {
+        Ok(big::Big {}.into())
+    }
+
+    fn bank(&self) -> Result<Box<dyn AbstractBenchmark>, NotImplementedError> {
+        Ok(bank::Bank {}.into())
+    }
+
+    fn producer_consumer(&self) -> Result<Box<dyn AbstractBenchmark>, NotImplementedError> {
+        Ok(producer_consumer::ProducerConsumer {}.into())
+    }
+
+    fn dining_philosophers(&self) -> Result<Box<dyn AbstractBenchmark>, NotImplementedError> {
+        Ok(dining_philosophers::DiningPhilosophers {}.into())
+    }
+
+    fn nqueens(&self) -> Result<Box<dyn AbstractBenchmark>, NotImplementedError> {
+        Err(NotImplementedError::FutureWork)
+    }
+
+    fn parallel_sort(&self) -> Result<Box<dyn AbstractBenchmark>, NotImplementedError> {
+        Ok(parallel_sort::ParallelSort {}.into())
+    }
 }
@@ -0,0 +1,146 @@
+//! Messages and the ponger for the networked benchmarks, which run on [remote](crate::remote).
+
+use crate::remote::{RemotePath, SerError, Serialisable};
+use benchmark_suite_shared::kompics_benchmarks::benchmarks::{NetworkTuning, TcpNoDelay};
+use riker::actors::*;
+
+/// The messages exchanged between pingers and pongers.
+///
+/// `Start` only ever goes to local pingers, but pingers and pongers must share a message type
+/// to be registered with the same [Remoting](crate::remote::Remoting).
+#[derive(Debug, Clone, PartialEq)]
+pub enum NetMessage {
+    Start,
+    StaticPing,
+    StaticPong,
+    Ping(u64),
+    Pong(u64),
+}
+
+impl NetMessage {
+    const START_ID: u8 = 0;
+    const STATIC_PING_ID: u8 = 1;
+    const STATIC_PONG_ID: u8 = 2;
+    const PING_ID: u8 = 3;
+    const PONG_ID: u8 = 4;
+}
+
+impl Serialisable for NetMessage {
+    fn serialise(&self, buf: &mut Vec<u8>) -> () {
+        match self {
+            NetMessage::Start => buf.push(NetMessage::START_ID),
+            NetMessage::StaticPing => buf.push(NetMessage::STATIC_PING_ID),
+            NetMessage::StaticPong => buf.push(NetMessage::STATIC_PONG_ID),
+            NetMessage::Ping(index) => {
+                buf.push(NetMessage::PING_ID);
+                buf.extend_from_slice(&index.to_be_bytes());
+            }
+            NetMessage::Pong(index) => {
+                buf.push(NetMessage::PONG_ID);
+                buf.extend_from_slice(&index.to_be_bytes());
+            }
+        }
+    }
+
+    fn deserialise(buf: &[u8]) -> Result<Self, SerError> {
+        match buf {
+            [NetMessage::START_ID] => Ok(NetMessage::Start),
+            [NetMessage::STATIC_PING_ID] => Ok(NetMessage::StaticPing),
+            [NetMessage::STATIC_PONG_ID] => Ok(NetMessage::StaticPong),
+            [id, rest @ ..] if *id == NetMessage::PING_ID || *id == NetMessage::PONG_ID => {
+                if rest.len() != 8 {
+                    return Err(SerError(format!(
+                        "Expected 8 bytes of index for message {}, got {}",
+                        id,
+                        rest.len()
+                    )));
+                }
+                let mut bytes = [0u8; 8];
+                bytes.copy_from_slice(rest);
+                let index = u64::from_be_bytes(bytes);
+                if *id == NetMessage::PING_ID {
+                    Ok(NetMessage::Ping(index))
+                } else {
+                    Ok(NetMessage::Pong(index))
+                }
+            }
+            [id, ..] => Err(SerError(format!("Unknown message id {}", id))),
+            [] => Err(SerError("Empty message".to_string())),
+        }
+    }
+}
+
+/// Whether to set `TCP_NODELAY`, which is on unless the request turns it off.
+///
+/// The buffer settings in `NetworkTuning` are specific to Kompact and ignored here.
+pub fn tcp_no_delay(tuning: &NetworkTuning) -> bool {
+    tuning.tcp_no_delay != TcpNoDelay::TCP_NO_DELAY_OFF
+}
+
+/// Reads the comma separated paths the clients send back to the master.
+pub fn paths_from_str(s: &str) -> Result<Vec<RemotePath>, String> {
+    s.split(',').map(|p| p.parse::<RemotePath>()).collect()
+}
+
+pub fn paths_to_str(paths: &[RemotePath]) -> String {
+    paths
+        .iter()
+        .map(|p| p.to_string())
+        .collect::<Vec<String>>()
+        .join(",")
+}
+
+/// Answers every ping with the matching pong.
+pub struct Ponger;
+
+impl Ponger {
+    fn new() -> Ponger {
+        Ponger
+    }
+    pub fn props() -> BoxActorProd<Ponger> {
+        Props::new_from(Ponger::new)
+    }
+}
+
+impl Actor for Ponger {
+    type Msg = NetMessage;
+
+    fn recv(&mut self, _ctx: &Context<Self::Msg>, msg: Self::Msg, sender: Sender) {
+        let reply = match msg {
+            NetMessage::StaticPing => NetMessage::StaticPong,
+            NetMessage::Ping(index) => NetMessage::Pong(index),
+            msg => {
+                eprintln!("Ponger got unexpected message {:?}", msg);
+                return;
+            }
+        };
+        match sender {
+            Some(pinger) => pinger.try_tell(reply, None).expect("Should have replied"),
+            None => eprintln!("Ponger got a ping without a (registered) sender"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_net_message_roundtrip() {
+        let msgs = vec![
+            NetMessage::Start,
+            NetMessage::StaticPing,
+            NetMessage::StaticPong,
+            NetMessage::Ping(42),
+            NetMessage::Pong(u64::max_value()),
+        ];
+        for msg in msgs {
+            let mut buf = Vec::new();
+            msg.serialise(&mut buf);
+            assert_eq!(NetMessage::deserialise(&buf), Ok(msg));
+        }
+        assert!(NetMessage::deserialise(&[3, 0, 1]).is_err());
+        assert!(NetMessage::deserialise(&[9]).is_err());
+        assert!(NetMessage::deserialise(&[]).is_err());
+    }
+}
@@ -0,0 +1,363 @@
+use super::*;
+
+use crate::remote::{RemotePath, Remoting};
+use crate::riker_system_provider::*;
+use benchmark_suite_shared::kompics_benchmarks::benchmarks::ThroughputPingPongRequest;
+use net::{NetMessage, Ponger};
+use riker::actors::*;
+use std::sync::Arc;
+use synchronoise::CountdownEvent;
+use throughput_pingpong::Params;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ClientParams {
+    num_pongers: u32,
+    tcp_no_delay: bool,
+}
+impl ClientParams {
+    fn new(num_pongers: u32, tcp_no_delay: bool) -> ClientParams {
+        ClientParams {
+            num_pongers,
+            tcp_no_delay,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ClientRefs(Vec<RemotePath>);
+
+#[derive(Default)]
+pub struct PingPong;
+
+impl DistributedBenchmark for PingPong {
+    type MasterConf = ThroughputPingPongRequest;
+    type ClientConf = ClientParams;
+    type ClientData = ClientRefs;
+    type Master = PingPongMaster;
+    type Client = PingPongClient;
+
+    const LABEL: &'static str = "NetThroughputPingPong";
+
+    fn new_master() -> Self::Master {
+        PingPongMaster::new()
+    }
+    fn msg_to_master_conf(
+        msg: Box<dyn (::protobuf::Message)>,
+    ) -> Result<Self::MasterConf, BenchmarkError> {
+        downcast_msg!(msg; ThroughputPingPongRequest)
+    }
+
+    fn validate(c: &Self::MasterConf, _meta: &DeploymentMetaData) -> Result<(), BenchmarkError> {
+        validation::throughput_ping_pong(c)
+    }
+
+    fn new_client() -> Self::Client {
+        PingPongClient::new()
+    }
+    fn str_to_client_conf(str: String) -> Result<Self::ClientConf, BenchmarkError> {
+        let split: Vec<_> = str.split(',').collect();
+        if split.len() != 2 {
+            Err(BenchmarkError::InvalidMessage(format!(
+                "String '{}' does not represent a client conf!",
+                str
+            )))
+        } else {
+            let num_str = split[0];
+            let num = num_str.parse::<u32>().map_err(|e| {
+                BenchmarkError::InvalidMessage(format!(
+                    "String '{}' does not represent a client conf: {:?}",
+                    str, e
+                ))
+            })?;
+            let tcp_no_delay = split[1].parse::<bool>().map_err(|e| {
+                BenchmarkError::InvalidMessage(format!(
+                    "String '{}' does not represent a client conf: {:?}",
+                    str, e
+                ))
+            })?;
+            Ok(ClientParams::new(num, tcp_no_delay))
+        }
+    }
+    fn str_to_client_data(str: String) -> Result<Self::ClientData, BenchmarkError> {
+        net::paths_from_str(&str).map(ClientRefs).map_err(|e| {
+            BenchmarkError::InvalidMessage(format!("Could not read client data: {}", e))
+        })
+    }
+
+    fn client_conf_to_str(c: Self::ClientConf) -> String {
+        format!("{},{}", c.num_pongers, c.tcp_no_delay)
+    }
+    fn client_data_to_str(d: Self::ClientData) -> String {
+        net::paths_to_str(&d.0)
+    }
+}
+
+pub struct PingPongMaster {
+    params: Option<Params>,
+    system: Option<RikerSystem>,
+    remoting: Option<Remoting<NetMessage>>,
+    pingers: Vec<ActorRef<NetMessage>>,
+    latch: Option<Arc<CountdownEvent>>,
+}
+
+impl PingPongMaster {
+    fn new() -> PingPongMaster {
+        PingPongMaster {
+            params: None,
+            system: None,
+            remoting: None,
+            pingers: Vec::new(),
+            latch: None,
+        }
+    }
+}
+
+impl DistributedBenchmarkMaster for PingPongMaster {
+    type MasterConf = ThroughputPingPongRequest;
+    type ClientConf = ClientParams;
+    type ClientData = ClientRefs;
+
+    fn setup(
+        &mut self,
+        c: Self::MasterConf,
+        _m: &DeploymentMetaData,
+    ) -> Result<Self::ClientConf, BenchmarkError> {
+        let params = Params::from_req(&c);
+        let tcp_no_delay = net::tcp_no_delay(c.get_network());
+        let client_params = ClientParams::new(params.num_pairs, tcp_no_delay);
+        let system = new_system("throughputpingpong");
+        let remoting =
+            Remoting::bind(&system, public_if(), tcp_no_delay).expect("Could not start remoting");
+        self.params = Some(params);
+        self.system = Some(system);
+        self.remoting = Some(remoting);
+        Ok(client_params)
+    }
+    fn prepare_iteration(&mut self, d: Vec<Self::ClientData>) -> () {
+        let ponger_paths = &d[0].0;
+        match self.params {
+            Some(ref params) => match (self.system.as_ref(), self.remoting.as_ref()) {
+                (Some(system), Some(remoting)) => {
+                    let latch = Arc::new(CountdownEvent::new(params.num_pairs as usize));
+                    for ponger_path in ponger_paths.iter() {
+                        let ponger = remoting
+                            .remote_ref(ponger_path)
+                            .expect("Could not connect to pongers");
+                        let pinger = system
+                            .start(
+                                Pinger::props(
+                                    params.num_msgs,
+                                    params.pipeline,
+                                    params.static_only,
+                                    latch.clone(),
+                                    ponger,
+                                ),
+                                "pinger",
+                            )
+                            .expect("Couldn't create Pinger");
+                        // pongs are addressed to the pinger by name
+                        remoting.register(&pinger);
+                        self.pingers.push(pinger);
+                    }
+                    println!("Connected {} pingers to their pongers", ponger_paths.len());
+                    self.latch = Some(latch);
+                }
+                _ => unimplemented!(),
+            },
+            None => unimplemented!(),
+        }
+    }
+    fn run_iteration(&mut self) -> () {
+        let latch = self.latch.take().unwrap();
+        for pinger in self.pingers.iter() {
+            pinger.tell(NetMessage::Start, None);
+        }
+        latch.wait();
+    }
+    fn cleanup_iteration(&mut self, last_iteration: bool, _exec_time_millis: f64) -> () {
+        println!("Cleaning up pinger side");
+        let system = self.system.take().unwrap();
+        let remoting = self.remoting.take().unwrap();
+        for pinger in self.pingers.drain(..) {
+            remoting.unregister(&pinger);
+            system.stop(pinger);
+        }
+
+        if last_iteration {
+            remoting.shutdown();
+            system
+                .shutdown()
+                .wait()
+                .expect("Riker didn't shut down properly");
+            self.params = None;
+        } else {
+            self.system = Some(system);
+            self.remoting = Some(remoting);
+        }
+    }
+}
+
+pub struct PingPongClient {
+    system: Option<RikerSystem>,
+    remoting: Option<Remoting<NetMessage>>,
+    pongers: Vec<ActorRef<NetMessage>>,
+}
+
+impl PingPongClient {
+    fn new() -> PingPongClient {
+        PingPongClient {
+            system: None,
+            remoting: None,
+            pongers: Vec::new(),
+        }
+    }
+}
+
+impl DistributedBenchmarkClient for PingPongClient {
+    type ClientConf = ClientParams;
+    type ClientData = ClientRefs;
+
+    fn setup(&mut self, c: Self::ClientConf) -> Self::ClientData {
+        println!("Setting up pongers.");
+
+        let system = new_system("throughputpingpong");
+        let remoting =
+            Remoting::bind(&system, public_if(), c.tcp_no_delay).expect("Could not start remoting");
+        let mut paths = Vec::with_capacity(c.num_pongers as usize);
+        for _ in 1..=c.num_pongers {
+            let ponger = system
+                .start(Ponger::props(), "ponger")
+                .expect("Should start Ponger");
+            paths.push(remoting.register(&ponger));
+            self.pongers.push(ponger);
+        }
+
+        println!(
+            "{} pongers listening on {}",
+            c.num_pongers,
+            remoting.local_addr()
+        );
+
+        self.system = Some(system);
+        self.remoting = Some(remoting);
+
+        ClientRefs(paths)
+    }
+
+    fn prepare_iteration(&mut self) -> () {
+        // nothing to do
+        println!("Preparing ponger iteration");
+    }
+
+    fn cleanup_iteration(&mut self, last_iteration: bool) -> () {
+        println!("Cleaning up ponger side");
+        if last_iteration {
+            let system = self.system.take().unwrap();
+            let remoting = self.remoting.take().unwrap();
+            remoting.shutdown();
+            for ponger in self.pongers.drain(..) {
+                system.stop(ponger);
+            }
+            system
+                .shutdown()
+                .wait()
+                .expect("Riker didn't shut down properly");
+        }
+    }
+}
+
+/// Sends `StaticPing`s if `static_only` is set, and indexed `Ping`s otherwise.
+struct Pinger {
+    latch: Arc<CountdownEvent>,
+    ponger: ActorRef<NetMessage>,
+    count: u64,
+    pipeline: u64,
+    static_only: bool,
+    sent_count: u64,
+    recv_count: u64,
+}
+
+impl Pinger {
+    fn with(
+        count: u64,
+        pipeline: u64,
+        static_only: bool,
+        latch: Arc<CountdownEvent>,
+        ponger: ActorRef<NetMessage>,
+    ) -> Pinger {
+        Pinger {
+            latch,
+            ponger,
+            count,
+            pipeline,
+            static_only,
+            sent_count: 0,
+            recv_count: 0,
+        }
+    }
+
+    fn props(
+        count: u64,
+        pipeline: u64,
+        static_only: bool,
+        latch: Arc<CountdownEvent>,
+        ponger: ActorRef<NetMessage>,
+    ) -> BoxActorProd<Pinger> {
+        Props::new_from(move || {
+            Pinger::with(count, pipeline, static_only, latch.clone(), ponger.clone())
+        })
+    }
+
+    fn send_ping(&mut self, ctx: &Context<NetMessage>) -> () {
+        let msg = if self.static_only {
+            NetMessage::StaticPing
+        } else {
+            NetMessage::Ping(self.sent_count)
+        };
+        self.ponger.tell(msg, Some(ctx.myself().into()));
+        self.sent_count += 1;
+    }
+
+    fn receive_pong(&mut self, ctx: &Context<NetMessage>) -> () {
+        self.recv_count += 1;
+        if self.recv_count < self.count {
+            if self.sent_count < self.count {
+                self.send_ping(ctx);
+            }
+        } else {
+            let _ = self.latch.decrement();
+        }
+    }
+}
+
+impl Actor for Pinger {
+    type Msg = NetMessage;
+
+    fn recv(&mut self, ctx: &Context<Self::Msg>, msg: Self::Msg, _sender: Sender) {
+        match msg {
+            NetMessage::Start => {
+                let mut pipelined: u64 = 0;
+                while (pipelined < self.pipeline) && (self.sent_count < self.count) {
+                    self.send_ping(ctx);
+                    pipelined += 1;
+                }
+            }
+            NetMessage::StaticPong if self.static_only => self.receive_pong(ctx),
+            NetMessage::Pong(_) if !self.static_only => self.receive_pong(ctx),
+            msg => eprintln!("Pinger got unexpected message {:?}", msg),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_client_params() {
+        let params = ClientParams::new(4, false);
+        let s = PingPong::client_conf_to_str(params.clone());
+        let deser = PingPong::str_to_client_conf(s).expect("ClientParams");
+        assert_eq!(deser, params);
+    }
+}
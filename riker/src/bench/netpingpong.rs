@@ -0,0 +1,261 @@
+use super::*;
+
+use crate::remote::{RemotePath, Remoting};
+use crate::riker_system_provider::*;
+use benchmark_suite_shared::kompics_benchmarks::benchmarks::PingPongRequest;
+use net::{NetMessage, Ponger};
+use riker::actors::*;
+use std::sync::Arc;
+use synchronoise::CountdownEvent;
+
+#[derive(Default)]
+pub struct PingPong;
+
+impl DistributedBenchmark for PingPong {
+    type MasterConf = PingPongRequest;
+    type ClientConf = bool;
+    type ClientData = RemotePath;
+    type Master = PingPongMaster;
+    type Client = PingPongClient;
+
+    const LABEL: &'static str = "NetPingPong";
+
+    fn new_master() -> Self::Master {
+        PingPongMaster::new()
+    }
+    fn msg_to_master_conf(
+        msg: Box<dyn (::protobuf::Message)>,
+    ) -> Result<Self::MasterConf, BenchmarkError> {
+        downcast_msg!(msg; PingPongRequest)
+    }
+
+    fn validate(c: &Self::MasterConf, _meta: &DeploymentMetaData) -> Result<(), BenchmarkError> {
+        validation::ping_pong(c)
+    }
+
+    fn new_client() -> Self::Client {
+        PingPongClient::new()
+    }
+    fn str_to_client_conf(str: String) -> Result<Self::ClientConf, BenchmarkError> {
+        str.parse::<bool>().map_err(|e| {
+            BenchmarkError::InvalidMessage(format!(
+                "String '{}' does not represent a client conf: {:?}",
+                str, e
+            ))
+        })
+    }
+    fn str_to_client_data(str: String) -> Result<Self::ClientData, BenchmarkError> {
+        str.parse::<RemotePath>().map_err(|e| {
+            BenchmarkError::InvalidMessage(format!("Could not read client data: {}", e))
+        })
+    }
+
+    fn client_conf_to_str(c: Self::ClientConf) -> String {
+        c.to_string()
+    }
+    fn client_data_to_str(d: Self::ClientData) -> String {
+        d.to_string()
+    }
+}
+
+pub struct PingPongMaster {
+    num: Option<u64>,
+    system: Option<RikerSystem>,
+    remoting: Option<Remoting<NetMessage>>,
+    pinger: Option<ActorRef<NetMessage>>,
+    latch: Option<Arc<CountdownEvent>>,
+}
+
+impl PingPongMaster {
+    fn new() -> PingPongMaster {
+        PingPongMaster {
+            num: None,
+            system: None,
+            remoting: None,
+            pinger: None,
+            latch: None,
+        }
+    }
+}
+
+impl DistributedBenchmarkMaster for PingPongMaster {
+    type MasterConf = PingPongRequest;
+    type ClientConf = bool;
+    type ClientData = RemotePath;
+
+    fn setup(
+        &mut self,
+        c: Self::MasterConf,
+        _m: &DeploymentMetaData,
+    ) -> Result<Self::ClientConf, BenchmarkError> {
+        self.num = Some(c.number_of_messages);
+        let tcp_no_delay = net::tcp_no_delay(c.get_network());
+        let system = RikerSystem::new("netpingpong", 2).expect("System");
+        let remoting =
+            Remoting::bind(&system, public_if(), tcp_no_delay).expect("Could not start remoting");
+        self.system = Some(system);
+        self.remoting = Some(remoting);
+        Ok(tcp_no_delay)
+    }
+    fn prepare_iteration(&mut self, d: Vec<Self::ClientData>) -> () {
+        let ponger_path = &d[0];
+        match self.num {
+            Some(num) => match (self.system.as_ref(), self.remoting.as_ref()) {
+                (Some(system), Some(remoting)) => {
+                    let ponger = remoting
+                        .remote_ref(ponger_path)
+                        .expect("Could not connect to ponger");
+                    println!("Connected to ponger at {}", ponger_path);
+                    let latch = Arc::new(CountdownEvent::new(1));
+                    let pinger = system
+                        .start(Pinger::props(num, latch.clone(), ponger), "pinger")
+                        .expect("Should start Pinger");
+                    // pongs are addressed to the pinger by name
+                    remoting.register(&pinger);
+
+                    self.pinger = Some(pinger);
+                    self.latch = Some(latch);
+                }
+                _ => unimplemented!(),
+            },
+            None => unimplemented!(),
+        }
+    }
+    fn run_iteration(&mut self) -> () {
+        match self.pinger {
+            Some(ref pinger) => {
+                let latch = self.latch.take().unwrap();
+                pinger.tell(NetMessage::Start, None);
+                latch.wait();
+            }
+            None => unimplemented!(),
+        }
+    }
+    fn cleanup_iteration(&mut self, last_iteration: bool, _exec_time_millis: f64) -> () {
+        println!("Cleaning up pinger side");
+        let system = self.system.take().unwrap();
+        let remoting = self.remoting.take().unwrap();
+        let pinger = self.pinger.take().unwrap();
+        remoting.unregister(&pinger);
+        system.stop(pinger);
+
+        if last_iteration {
+            remoting.shutdown();
+            system
+                .shutdown()
+                .wait()
+                .expect("Riker didn't shut down properly");
+            self.num = None;
+        } else {
+            self.system = Some(system);
+            self.remoting = Some(remoting);
+        }
+    }
+}
+
+pub struct PingPongClient {
+    system: Option<RikerSystem>,
+    remoting: Option<Remoting<NetMessage>>,
+    ponger: Option<ActorRef<NetMessage>>,
+}
+
+impl PingPongClient {
+    fn new() -> PingPongClient {
+        PingPongClient {
+            system: None,
+            remoting: None,
+            ponger: None,
+        }
+    }
+}
+
+impl DistributedBenchmarkClient for PingPongClient {
+    type ClientConf = bool;
+    type ClientData = RemotePath;
+
+    fn setup(&mut self, c: Self::ClientConf) -> Self::ClientData {
+        println!("Setting up ponger.");
+
+        let system = RikerSystem::new("netpingpong", 2).expect("System");
+        let remoting = Remoting::bind(&system, public_if(), c).expect("Could not start remoting");
+        let ponger = system
+            .start(Ponger::props(), "ponger")
+            .expect("Should start Ponger");
+        let path = remoting.register(&ponger);
+
+        println!("Ponger listening at {}", path);
+
+        self.system = Some(system);
+        self.remoting = Some(remoting);
+        self.ponger = Some(ponger);
+
+        path
+    }
+
+    fn prepare_iteration(&mut self) -> () {
+        // nothing to do
+        println!("Preparing ponger iteration");
+    }
+
+    fn cleanup_iteration(&mut self, last_iteration: bool) -> () {
+        println!("Cleaning up ponger side");
+        if last_iteration {
+            let system = self.system.take().unwrap();
+            let remoting = self.remoting.take().unwrap();
+            let ponger = self.ponger.take().unwrap();
+            remoting.shutdown();
+            system.stop(ponger);
+            system
+                .shutdown()
+                .wait()
+                .expect("Riker didn't shut down properly");
+        }
+    }
+}
+
+struct Pinger {
+    latch: Arc<CountdownEvent>,
+    ponger: ActorRef<NetMessage>,
+    count_down: u64,
+}
+
+impl Pinger {
+    fn with(count: u64, latch: Arc<CountdownEvent>, ponger: ActorRef<NetMessage>) -> Pinger {
+        Pinger {
+            latch,
+            ponger,
+            count_down: count,
+        }
+    }
+    fn props(
+        count: u64,
+        latch: Arc<CountdownEvent>,
+        ponger: ActorRef<NetMessage>,
+    ) -> BoxActorProd<Pinger> {
+        Props::new_from(move || Pinger::with(count, latch.clone(), ponger.clone()))
+    }
+
+    fn send_ping(&self, ctx: &Context<NetMessage>) -> () {
+        self.ponger
+            .tell(NetMessage::StaticPing, Some(ctx.myself().into()));
+    }
+}
+
+impl Actor for Pinger {
+    type Msg = NetMessage;
+
+    fn recv(&mut self, ctx: &Context<Self::Msg>, msg: Self::Msg, _sender: Sender) {
+        match msg {
+            NetMessage::Start => self.send_ping(ctx),
+            NetMessage::StaticPong => {
+                if self.count_down > 0 {
+                    self.count_down -= 1;
+                    self.send_ping(ctx);
+                } else {
+                    let _ = self.latch.decrement();
+                }
+            }
+            msg => eprintln!("Pinger got unexpected message {:?}", msg),
+        }
+    }
+}
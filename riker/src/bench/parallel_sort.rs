@@ -0,0 +1,202 @@
+use super::*;
+
+use crate::riker_system_provider::*;
+use benchmark_suite_shared::helpers::parallel_sort::{check_sorted, merge, random_data, Half};
+use benchmark_suite_shared::kompics_benchmarks::benchmarks::ParallelSortRequest;
+use futures::future::RemoteHandle;
+use riker::actors::*;
+use std::convert::TryInto;
+
+#[derive(Default)]
+pub struct ParallelSort;
+
+impl Benchmark for ParallelSort {
+    type Conf = ParallelSortRequest;
+    type Instance = ParallelSortI;
+
+    fn msg_to_conf(msg: Box<dyn (::protobuf::Message)>) -> Result<Self::Conf, BenchmarkError> {
+        downcast_msg!(msg; ParallelSortRequest)
+    }
+
+    fn validate(c: &Self::Conf) -> Result<(), BenchmarkError> {
+        validation::parallel_sort(c)
+    }
+
+    fn new_instance() -> Self::Instance {
+        ParallelSortI::new()
+    }
+
+    const LABEL: &'static str = "ParallelSort";
+}
+
+pub struct ParallelSortI {
+    data_size: Option<usize>,
+    threshold: Option<usize>,
+    seed: Option<u64>,
+    system: Option<RikerSystem>,
+    data: Option<Vec<u64>>,
+    sorter: Option<ActorRef<SorterMsg>>,
+    sorted: Option<Vec<u64>>,
+}
+
+impl ParallelSortI {
+    fn new() -> ParallelSortI {
+        ParallelSortI {
+            data_size: None,
+            threshold: None,
+            seed: None,
+            system: None,
+            data: None,
+            sorter: None,
+            sorted: None,
+        }
+    }
+}
+
+impl BenchmarkInstance for ParallelSortI {
+    type Conf = ParallelSortRequest;
+
+    fn setup(&mut self, c: &Self::Conf) -> () {
+        self.data_size = Some(c.data_size.try_into().unwrap());
+        self.threshold = Some(c.threshold.try_into().unwrap());
+        self.seed = Some(c.seed);
+        let system = RikerSystem::new("parallel_sort", num_cpus::get()).expect("System");
+        self.system = Some(system);
+    }
+
+    fn prepare_iteration(&mut self) -> () {
+        match (
+            self.system.as_ref(),
+            self.data_size,
+            self.threshold,
+            self.seed,
+        ) {
+            (Some(system), Some(data_size), Some(threshold), Some(seed)) => {
+                self.data = Some(random_data(data_size, seed));
+                let sorter = system
+                    .start(Sorter::props(threshold, ResultTarget::Asker), "sorter")
+                    .expect("Should start Sorter!");
+                self.sorter = Some(sorter);
+            }
+            _ => unimplemented!(),
+        }
+    }
+
+    fn run_iteration(&mut self) -> () {
+        match (self.data.take(), self.sorter.take(), self.system.as_ref()) {
+            (Some(data), Some(sorter), Some(system)) => {
+                let sorted_f: RemoteHandle<Vec<u64>> = system.ask(&sorter, SorterMsg::Sort(data));
+                self.sorted = Some(sorted_f.wait()); // the sorter stops itself once it has replied
+            }
+            _ => unimplemented!(),
+        }
+    }
+
+    fn cleanup_iteration(&mut self, last_iteration: bool, _exec_time_millis: f64) -> () {
+        let sorted = self.sorted.take().expect("No sorted data was returned");
+        check_sorted(&sorted, self.data_size.unwrap());
+
+        if last_iteration {
+            let system = self.system.take().unwrap();
+            system
+                .shutdown()
+                .wait()
+                .expect("Riker didn't shut down properly");
+            self.data_size = None;
+            self.threshold = None;
+            self.seed = None;
+        }
+    }
+}
+
+/// The top sorter replies to whoever asked it to `Sort`, the others report to their parent.
+#[derive(Clone)]
+enum ResultTarget {
+    Asker,
+    Parent(ActorRef<SorterMsg>, Half),
+}
+
+#[derive(Debug, Clone)]
+enum SorterMsg {
+    Sort(Vec<u64>),
+    Sorted { half: Half, data: Vec<u64> },
+}
+
+/// Sorts its part directly if it is small enough, and otherwise has two children sort
+/// the halves and merges their results.
+struct Sorter {
+    threshold: usize,
+    report_to: ResultTarget,
+    asker: Sender,
+    left: Option<Vec<u64>>,
+    right: Option<Vec<u64>>,
+}
+
+impl Sorter {
+    fn with(threshold: usize, report_to: ResultTarget) -> Sorter {
+        Sorter {
+            threshold,
+            report_to,
+            asker: None,
+            left: None,
+            right: None,
+        }
+    }
+
+    fn props(threshold: usize, report_to: ResultTarget) -> BoxActorProd<Sorter> {
+        Props::new_from(move || Sorter::with(threshold, report_to.clone()))
+    }
+
+    fn send_result(&mut self, data: Vec<u64>, ctx: &Context<SorterMsg>) {
+        match self.report_to {
+            ResultTarget::Parent(ref parent_ref, half) => {
+                parent_ref.tell(SorterMsg::Sorted { half, data }, None);
+            }
+            ResultTarget::Asker => {
+                self.asker
+                    .take()
+                    .expect("Sort needs a sender")
+                    .try_tell(data, None)
+                    .expect("Should have replied");
+            }
+        }
+        ctx.stop(ctx.myself());
+    }
+}
+
+impl Actor for Sorter {
+    type Msg = SorterMsg;
+
+    fn recv(&mut self, ctx: &Context<Self::Msg>, msg: Self::Msg, sender: Sender) {
+        match msg {
+            SorterMsg::Sort(mut data) => {
+                self.asker = sender;
+                if data.len() <= self.threshold {
+                    data.sort_unstable();
+                    self.send_result(data, ctx);
+                } else {
+                    let right = data.split_off(data.len() / 2);
+                    for (name, half, part) in
+                        vec![("left", Half::Left, data), ("right", Half::Right, right)]
+                    {
+                        let parent = ResultTarget::Parent(ctx.myself(), half);
+                        let child = ctx
+                            .actor_of_props(name, Sorter::props(self.threshold, parent))
+                            .expect("Sorter Child");
+                        child.tell(SorterMsg::Sort(part), None);
+                    }
+                }
+            }
+            SorterMsg::Sorted { half, data } => {
+                match half {
+                    Half::Left => self.left = Some(data),
+                    Half::Right => self.right = Some(data),
+                }
+                if self.left.is_some() && self.right.is_some() {
+                    let merged = merge(self.left.take().unwrap(), self.right.take().unwrap());
+                    self.send_result(merged, ctx);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,381 @@
+use super::*;
+
+use crate::riker_system_provider::*;
+use benchmark_suite_shared::helpers::producer_consumer::{items_of_producer, synthetic_work};
+use benchmark_suite_shared::kompics_benchmarks::benchmarks::ProducerConsumerRequest;
+use futures::future::RemoteHandle;
+use riker::actors::*;
+use std::collections::VecDeque;
+use std::convert::TryInto;
+use std::sync::Arc;
+use synchronoise::CountdownEvent;
+
+#[derive(Default)]
+pub struct ProducerConsumer;
+
+impl Benchmark for ProducerConsumer {
+    type Conf = ProducerConsumerRequest;
+    type Instance = ProducerConsumerI;
+
+    fn msg_to_conf(msg: Box<dyn (::protobuf::Message)>) -> Result<Self::Conf, BenchmarkError> {
+        downcast_msg!(msg; ProducerConsumerRequest)
+    }
+
+    fn validate(c: &Self::Conf) -> Result<(), BenchmarkError> {
+        validation::producer_consumer(c)
+    }
+
+    fn new_instance() -> Self::Instance {
+        ProducerConsumerI::new()
+    }
+
+    const LABEL: &'static str = "ProducerConsumer";
+}
+
+pub struct ProducerConsumerI {
+    buffer_size: Option<usize>,
+    num_producers: Option<usize>,
+    num_consumers: Option<usize>,
+    num_items: Option<u64>,
+    work_per_item: Option<u64>,
+    system: Option<RikerSystem>,
+    buffer: Option<ActorRef<BufferMsg>>,
+    producers: Vec<ActorRef<ProducerMsg>>,
+    consumers: Vec<ActorRef<ConsumerMsg>>,
+    latch: Option<Arc<CountdownEvent>>,
+}
+
+impl ProducerConsumerI {
+    fn new() -> ProducerConsumerI {
+        ProducerConsumerI {
+            buffer_size: None,
+            num_producers: None,
+            num_consumers: None,
+            num_items: None,
+            work_per_item: None,
+            system: None,
+            buffer: None,
+            producers: Vec::new(),
+            consumers: Vec::new(),
+            latch: None,
+        }
+    }
+}
+
+impl BenchmarkInstance for ProducerConsumerI {
+    type Conf = ProducerConsumerRequest;
+
+    fn setup(&mut self, c: &Self::Conf) -> () {
+        self.buffer_size = Some(c.buffer_size.try_into().unwrap());
+        self.num_producers = Some(c.number_of_producers.try_into().unwrap());
+        self.num_consumers = Some(c.number_of_consumers.try_into().unwrap());
+        self.num_items = Some(c.number_of_items);
+        self.work_per_item = Some(c.work_per_item);
+        let system = RikerSystem::new("producer_consumer", num_cpus::get()).expect("System");
+        self.system = Some(system);
+    }
+
+    fn prepare_iteration(&mut self) -> () {
+        match (
+            self.system.as_ref(),
+            self.buffer_size,
+            self.num_producers,
+            self.num_consumers,
+            self.num_items,
+            self.work_per_item,
+        ) {
+            (
+                Some(system),
+                Some(buffer_size),
+                Some(num_producers),
+                Some(num_consumers),
+                Some(num_items),
+                Some(work_per_item),
+            ) => {
+                let latch = Arc::new(CountdownEvent::new(num_consumers));
+                let buffer = system
+                    .start(Buffer::props(buffer_size, num_items), "buffer")
+                    .expect("Buffer never started!");
+                for id in 0..num_producers {
+                    let items = items_of_producer(id, num_producers, num_items);
+                    let producer = system
+                        .start(
+                            Producer::props(items, work_per_item, buffer.clone()),
+                            "producer",
+                        )
+                        .expect("Producer never started!");
+                    self.producers.push(producer);
+                }
+                for _ in 0..num_consumers {
+                    let consumer = system
+                        .start(
+                            Consumer::props(work_per_item, buffer.clone(), latch.clone()),
+                            "consumer",
+                        )
+                        .expect("Consumer never started!");
+                    self.consumers.push(consumer);
+                }
+                self.buffer = Some(buffer);
+                self.latch = Some(latch);
+            }
+            _ => unimplemented!(),
+        }
+    }
+
+    fn run_iteration(&mut self) -> () {
+        let latch = self.latch.take().unwrap();
+        for consumer in self.consumers.iter() {
+            consumer.tell(ConsumerMsg::Start, None);
+        }
+        for producer in self.producers.iter() {
+            producer.tell(ProducerMsg::Start, None);
+        }
+        latch.wait();
+    }
+
+    fn cleanup_iteration(&mut self, last_iteration: bool, _exec_time_millis: f64) -> () {
+        let system = self.system.take().unwrap();
+        let mut consumed = 0u64;
+        for consumer in self.consumers.drain(..) {
+            let consumed_f: RemoteHandle<(u64, u64)> =
+                system.ask(&consumer, ConsumerMsg::GetConsumed);
+            let (items, _checksum) = consumed_f.wait();
+            consumed += items;
+            system.stop(consumer);
+        }
+        assert_eq!(
+            consumed,
+            self.num_items.unwrap(),
+            "Items were lost or duplicated!"
+        );
+        for producer in self.producers.drain(..) {
+            system.stop(producer);
+        }
+        system.stop(self.buffer.take().unwrap());
+
+        if last_iteration {
+            system
+                .shutdown()
+                .wait()
+                .expect("Riker didn't shut down properly");
+            self.buffer_size = None;
+            self.num_producers = None;
+            self.num_consumers = None;
+            self.num_items = None;
+            self.work_per_item = None;
+        } else {
+            self.system = Some(system);
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+enum BufferMsg {
+    /// Answered with `ProducerMsg::Accepted` once the item has been taken into the buffer.
+    Produce {
+        item: u64,
+        producer: ActorRef<ProducerMsg>,
+    },
+    /// Answered with the next item, or with `Done` once all items have been handed out.
+    Consume { consumer: ActorRef<ConsumerMsg> },
+}
+
+#[derive(Debug, Clone)]
+enum ProducerMsg {
+    Start,
+    Accepted,
+}
+
+#[derive(Debug, Clone)]
+enum ConsumerMsg {
+    Start,
+    Item(u64),
+    Done,
+    GetConsumed,
+}
+
+/// Holds up to `capacity` items, and defers the answers to the requests it can't serve yet.
+///
+/// A full buffer keeps the producer of a `Produce` waiting until a consumer makes room,
+/// and an empty one keeps the consumer of a `Consume` waiting until an item arrives.
+struct Buffer {
+    capacity: usize,
+    items: VecDeque<u64>,
+    waiting_producers: VecDeque<(u64, ActorRef<ProducerMsg>)>,
+    waiting_consumers: VecDeque<ActorRef<ConsumerMsg>>,
+    undelivered: u64,
+}
+
+impl Buffer {
+    fn with(capacity: usize, num_items: u64) -> Buffer {
+        Buffer {
+            capacity,
+            items: VecDeque::with_capacity(capacity),
+            waiting_producers: VecDeque::new(),
+            waiting_consumers: VecDeque::new(),
+            undelivered: num_items,
+        }
+    }
+
+    fn props(capacity: usize, num_items: u64) -> BoxActorProd<Buffer> {
+        Props::new_from(move || Buffer::with(capacity, num_items))
+    }
+
+    fn deliver(&mut self, consumer: ActorRef<ConsumerMsg>, item: u64) -> () {
+        consumer.tell(ConsumerMsg::Item(item), None);
+        self.undelivered -= 1u64;
+        if self.undelivered == 0 {
+            for consumer in self.waiting_consumers.drain(..) {
+                consumer.tell(ConsumerMsg::Done, None);
+            }
+        }
+    }
+}
+
+impl Actor for Buffer {
+    type Msg = BufferMsg;
+
+    fn recv(&mut self, _ctx: &Context<Self::Msg>, msg: Self::Msg, _sender: Sender) {
+        match msg {
+            BufferMsg::Produce { item, producer } => {
+                if let Some(consumer) = self.waiting_consumers.pop_front() {
+                    // the buffer is empty, since a consumer is waiting
+                    self.deliver(consumer, item);
+                    producer.tell(ProducerMsg::Accepted, None);
+                } else if self.items.len() < self.capacity {
+                    self.items.push_back(item);
+                    producer.tell(ProducerMsg::Accepted, None);
+                } else {
+                    self.waiting_producers.push_back((item, producer));
+                }
+            }
+            BufferMsg::Consume { consumer } => {
+                if let Some(item) = self.items.pop_front() {
+                    self.deliver(consumer, item);
+                    if let Some((item, producer)) = self.waiting_producers.pop_front() {
+                        self.items.push_back(item);
+                        producer.tell(ProducerMsg::Accepted, None);
+                    }
+                } else if self.undelivered == 0 {
+                    consumer.tell(ConsumerMsg::Done, None);
+                } else {
+                    self.waiting_consumers.push_back(consumer);
+                }
+            }
+        }
+    }
+}
+
+/// Produces its items one at a time.
+///
+/// The next item is only produced once the buffer has accepted the last.
+struct Producer {
+    remaining: u64,
+    work_per_item: u64,
+    buffer: ActorRef<BufferMsg>,
+}
+
+impl Producer {
+    fn with(num_items: u64, work_per_item: u64, buffer: ActorRef<BufferMsg>) -> Producer {
+        Producer {
+            remaining: num_items,
+            work_per_item,
+            buffer,
+        }
+    }
+
+    fn props(
+        num_items: u64,
+        work_per_item: u64,
+        buffer: ActorRef<BufferMsg>,
+    ) -> BoxActorProd<Producer> {
+        Props::new_from(move || Producer::with(num_items, work_per_item, buffer.clone()))
+    }
+}
+
+impl Actor for Producer {
+    type Msg = ProducerMsg;
+
+    fn recv(&mut self, ctx: &Context<Self::Msg>, msg: Self::Msg, _sender: Sender) {
+        match msg {
+            ProducerMsg::Start | ProducerMsg::Accepted => {
+                if self.remaining > 0 {
+                    self.remaining -= 1u64;
+                    let item = synthetic_work(self.work_per_item, self.remaining);
+                    self.buffer.tell(
+                        BufferMsg::Produce {
+                            item,
+                            producer: ctx.myself(),
+                        },
+                        None,
+                    );
+                }
+            }
+        }
+    }
+}
+
+/// Asks the buffer for one item at a time, until there are none left.
+struct Consumer {
+    work_per_item: u64,
+    consumed: u64,
+    checksum: u64,
+    buffer: ActorRef<BufferMsg>,
+    latch: Arc<CountdownEvent>,
+}
+
+impl Consumer {
+    fn with(
+        work_per_item: u64,
+        buffer: ActorRef<BufferMsg>,
+        latch: Arc<CountdownEvent>,
+    ) -> Consumer {
+        Consumer {
+            work_per_item,
+            consumed: 0u64,
+            checksum: 0u64,
+            buffer,
+            latch,
+        }
+    }
+
+    fn props(
+        work_per_item: u64,
+        buffer: ActorRef<BufferMsg>,
+        latch: Arc<CountdownEvent>,
+    ) -> BoxActorProd<Consumer> {
+        Props::new_from(move || Consumer::with(work_per_item, buffer.clone(), latch.clone()))
+    }
+
+    fn request_item(&self, ctx: &Context<ConsumerMsg>) -> () {
+        self.buffer.tell(
+            BufferMsg::Consume {
+                consumer: ctx.myself(),
+            },
+            None,
+        );
+    }
+}
+
+impl Actor for Consumer {
+    type Msg = ConsumerMsg;
+
+    fn recv(&mut self, ctx: &Context<Self::Msg>, msg: Self::Msg, sender: Sender) {
+        match msg {
+            ConsumerMsg::Start => self.request_item(ctx),
+            ConsumerMsg::Item(item) => {
+                self.checksum ^= synthetic_work(self.work_per_item, item);
+                self.consumed += 1u64;
+                self.request_item(ctx);
+            }
+            ConsumerMsg::Done => self.latch.decrement().expect("Should decrement!"),
+            ConsumerMsg::GetConsumed => {
+                sender
+                    .as_ref()
+                    .expect("GetConsumed needs a sender")
+                    .try_tell((self.consumed, self.checksum), None)
+                    .expect("Should have replied");
+            }
+        }
+    }
+}
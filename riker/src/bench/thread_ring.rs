@@ -0,0 +1,185 @@
+use super::*;
+
+use crate::riker_system_provider::*;
+use benchmark_suite_shared::helpers::thread_ring::token_holders;
+use benchmark_suite_shared::kompics_benchmarks::benchmarks::ThreadRingRequest;
+use riker::actors::*;
+use std::convert::TryInto;
+use std::ops::Deref;
+use std::sync::Arc;
+use synchronoise::CountdownEvent;
+
+#[derive(Default)]
+pub struct ThreadRing;
+
+impl Benchmark for ThreadRing {
+    type Conf = ThreadRingRequest;
+    type Instance = ThreadRingI;
+
+    fn msg_to_conf(msg: Box<dyn (::protobuf::Message)>) -> Result<Self::Conf, BenchmarkError> {
+        downcast_msg!(msg; ThreadRingRequest)
+    }
+
+    fn validate(c: &Self::Conf) -> Result<(), BenchmarkError> {
+        validation::thread_ring(c)
+    }
+
+    fn new_instance() -> Self::Instance {
+        ThreadRingI::new()
+    }
+
+    const LABEL: &'static str = "ThreadRing";
+}
+
+pub struct ThreadRingI {
+    num_actors: Option<usize>,
+    num_passes: Option<u64>,
+    num_tokens: Option<usize>,
+    system: Option<RikerSystem>,
+    nodes: Vec<ActorRef<RingMsg>>,
+    latch: Option<Arc<CountdownEvent>>,
+}
+
+impl ThreadRingI {
+    fn new() -> ThreadRingI {
+        ThreadRingI {
+            num_actors: None,
+            num_passes: None,
+            num_tokens: None,
+            system: None,
+            nodes: Vec::new(),
+            latch: None,
+        }
+    }
+}
+
+impl BenchmarkInstance for ThreadRingI {
+    type Conf = ThreadRingRequest;
+
+    fn setup(&mut self, c: &Self::Conf) -> () {
+        self.num_actors = Some(c.number_of_actors.try_into().unwrap());
+        self.num_passes = Some(c.number_of_passes);
+        self.num_tokens = Some(c.number_of_tokens.try_into().unwrap());
+        let system = RikerSystem::new("threadring", num_cpus::get()).expect("System");
+        self.system = Some(system);
+    }
+
+    fn prepare_iteration(&mut self) -> () {
+        match (self.system.as_ref(), self.num_actors, self.num_tokens) {
+            (Some(system), Some(num_actors), Some(num_tokens)) => {
+                let latch = Arc::new(CountdownEvent::new(num_tokens));
+                for _ in 0..num_actors {
+                    let node = system
+                        .start(RingNode::props(latch.clone()), "ring-node")
+                        .expect("RingNode never started!");
+                    self.nodes.push(node);
+                }
+                // all connections are enqueued before any token is sent in run_iteration
+                for (i, node) in self.nodes.iter().enumerate() {
+                    let next = self.nodes[(i + 1) % num_actors].clone();
+                    node.tell(RingMsg::Connect(next.into()), None);
+                }
+                self.latch = Some(latch);
+            }
+            _ => unimplemented!(),
+        }
+    }
+
+    fn run_iteration(&mut self) -> () {
+        match (self.num_actors, self.num_passes, self.num_tokens) {
+            (Some(num_actors), Some(num_passes), Some(num_tokens)) => {
+                let latch = self.latch.take().unwrap();
+                for i in token_holders(num_actors, num_tokens) {
+                    self.nodes[i].tell(RingMsg::Token(num_passes), None);
+                }
+                latch.wait();
+            }
+            _ => unimplemented!(),
+        }
+    }
+
+    fn cleanup_iteration(&mut self, last_iteration: bool, _exec_time_millis: f64) -> () {
+        let system = self.system.take().unwrap();
+        for node in self.nodes.drain(..) {
+            system.stop(node);
+        }
+
+        if last_iteration {
+            system
+                .shutdown()
+                .wait()
+                .expect("Riker didn't shut down properly");
+            self.num_actors = None;
+            self.num_passes = None;
+            self.num_tokens = None;
+        } else {
+            self.system = Some(system);
+        }
+    }
+}
+
+// used to break trait bound resolution cycle of Message
+#[derive(Debug, Clone)]
+struct NodeRef(ActorRef<RingMsg>);
+// definitely safe, the compiler just endlessly recurses while trying to figure this out
+unsafe impl Send for NodeRef {}
+impl Deref for NodeRef {
+    type Target = ActorRef<RingMsg>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+impl From<ActorRef<RingMsg>> for NodeRef {
+    fn from(actor: ActorRef<RingMsg>) -> Self {
+        NodeRef(actor)
+    }
+}
+
+#[derive(Debug, Clone)]
+enum RingMsg {
+    Connect(NodeRef),
+    /// The number of passes the token has left.
+    Token(u64),
+}
+
+struct RingNode {
+    next: Option<NodeRef>,
+    latch: Arc<CountdownEvent>,
+}
+
+impl RingNode {
+    fn with(latch: Arc<CountdownEvent>) -> RingNode {
+        RingNode { next: None, latch }
+    }
+
+    fn props(latch: Arc<CountdownEvent>) -> BoxActorProd<RingNode> {
+        Props::new_from(move || RingNode::with(latch.clone()))
+    }
+}
+
+impl Actor for RingNode {
+    type Msg = RingMsg;
+
+    fn post_stop(&mut self) {
+        self.next = None; // the refs around the ring would keep each other alive
+    }
+
+    fn recv(&mut self, _ctx: &Context<Self::Msg>, msg: Self::Msg, _sender: Sender) {
+        match msg {
+            RingMsg::Connect(next) => {
+                self.next = Some(next);
+            }
+            RingMsg::Token(remaining) => {
+                if remaining > 0 {
+                    self.next
+                        .as_ref()
+                        .expect("Ring was never closed")
+                        .tell(RingMsg::Token(remaining - 1), None);
+                } else {
+                    self.latch.decrement().expect("Should decrement!");
+                }
+            }
+        }
+    }
+}
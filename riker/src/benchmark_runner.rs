@@ -1,5 +1,5 @@
 use super::*;
-use benchmark_suite_shared::benchmark_runner::{not_implemented, run, run_async};
+use benchmark_suite_shared::benchmark_runner::{not_implemented, run_and_record, run_async};
 use benchmark_suite_shared::kompics_benchmarks::{benchmarks, benchmarks_grpc, messages};
 use benchfutures::future::Future;
 
@@ -40,7 +40,7 @@ impl benchmarks_grpc::BenchmarkRunner for BenchmarkRunnerImpl {
         println!("Got ping_pong req: {}", p.number_of_messages);
         let f = run_async(move || {
             let b = bench::pingpong::PingPong::default();
-            run(&b, &p).into()
+            run_and_record(&b, &p)
         })
         .map_err(|e| {
             println!("Converting benchmark error into grpc error: {:?}", e);
@@ -65,7 +65,7 @@ impl benchmarks_grpc::BenchmarkRunner for BenchmarkRunnerImpl {
         println!("Got req: {:?}", p);
         let f = run_async(move || {
             let b = bench::throughput_pingpong::PingPong::default();
-            run(&b, &p).into()
+            run_and_record(&b, &p)
         })
         .map_err(|e| {
             println!("Converting benchmark error into grpc error: {:?}", e);
@@ -106,7 +106,7 @@ impl benchmarks_grpc::BenchmarkRunner for BenchmarkRunnerImpl {
         println!("Got fibonacci req: {:?}", p);
         let f = run_async(move || {
             let b = bench::fibonacci::Fibonacci::default();
-            run(&b, &p).into()
+            run_and_record(&b, &p)
         })
         .map_err(|e| {
             println!("Converting benchmark error into grpc error: {:?}", e);
@@ -123,7 +123,7 @@ impl benchmarks_grpc::BenchmarkRunner for BenchmarkRunnerImpl {
         println!("Got chameneos req: {:?}", p);
         let f = run_async(move || {
             let b = bench::chameneos::Chameneos::default();
-            run(&b, &p).into()
+            run_and_record(&b, &p)
         })
         .map_err(|e| {
             println!("Converting benchmark error into grpc error: {:?}", e);
@@ -140,7 +140,7 @@ impl benchmarks_grpc::BenchmarkRunner for BenchmarkRunnerImpl {
         println!("Got APSP req: {:?}", p);
         let f = run_async(move || {
             let b = bench::all_pairs_shortest_path::AllPairsShortestPath::default();
-            run(&b, &p).into()
+            run_and_record(&b, &p)
         })
         .map_err(|e| {
             println!("Converting benchmark error into grpc error: {:?}", e);
@@ -164,4 +164,139 @@ impl benchmarks_grpc::BenchmarkRunner for BenchmarkRunnerImpl {
     ) -> grpc::SingleResponse<messages::TestResult> {
         grpc::SingleResponse::completed(not_implemented())
     }
+
+    fn thread_ring(
+        &self,
+        _o: grpc::RequestOptions,
+        p: benchmarks::ThreadRingRequest,
+    ) -> grpc::SingleResponse<messages::TestResult> {
+        println!("Got thread ring req: {:?}", p);
+        let f = run_async(move || {
+            let b = bench::thread_ring::ThreadRing::default();
+            run_and_record(&b, &p)
+        })
+        .map_err(|e| {
+            println!("Converting benchmark error into grpc error: {:?}", e);
+            e.into()
+        });
+        grpc::SingleResponse::no_metadata(f)
+    }
+
+    fn fan_in(
+        &self,
+        _o: grpc::RequestOptions,
+        _p: benchmarks::FanInRequest,
+    ) -> grpc::SingleResponse<messages::TestResult> {
+        grpc::SingleResponse::completed(not_implemented())
+    }
+
+    fn fanout(
+        &self,
+        _o: grpc::RequestOptions,
+        p: benchmarks::FanoutRequest,
+    ) -> grpc::SingleResponse<messages::TestResult> {
+        println!("Got fanout req: {:?}", p);
+        let f = run_async(move || {
+            let b = bench::fanout::Fanout::default();
+            run_and_record(&b, &p)
+        })
+        .map_err(|e| {
+            println!("Converting benchmark error into grpc error: {:?}", e);
+            e.into()
+        });
+        grpc::SingleResponse::no_metadata(f)
+    }
+
+    fn big(
+        &self,
+        _o: grpc::RequestOptions,
+        p: benchmarks::BigRequest,
+    ) -> grpc::SingleResponse<messages::TestResult> {
+        println!("Got big req: {:?}", p);
+        let f = run_async(move || {
+            let b = bench::big::Big::default();
+            run_and_record(&b, &p)
+        })
+        .map_err(|e| {
+            println!("Converting benchmark error into grpc error: {:?}", e);
+            e.into()
+        });
+        grpc::SingleResponse::no_metadata(f)
+    }
+
+    fn bank(
+        &self,
+        _o: grpc::RequestOptions,
+        p: benchmarks::BankRequest,
+    ) -> grpc::SingleResponse<messages::TestResult> {
+        println!("Got bank req: {:?}", p);
+        let f = run_async(move || {
+            let b = bench::bank::Bank::default();
+            run_and_record(&b, &p)
+        })
+        .map_err(|e| {
+            println!("Converting benchmark error into grpc error: {:?}", e);
+            e.into()
+        });
+        grpc::SingleResponse::no_metadata(f)
+    }
+
+    fn producer_consumer(
+        &self,
+        _o: grpc::RequestOptions,
+        p: benchmarks::ProducerConsumerRequest,
+    ) -> grpc::SingleResponse<messages::TestResult> {
+        println!("Got producer_consumer req: {:?}", p);
+        let f = run_async(move || {
+            let b = bench::producer_consumer::ProducerConsumer::default();
+            run_and_record(&b, &p)
+        })
+        .map_err(|e| {
+            println!("Converting benchmark error into grpc error: {:?}", e);
+            e.into()
+        });
+        grpc::SingleResponse::no_metadata(f)
+    }
+
+    fn dining_philosophers(
+        &self,
+        _o: grpc::RequestOptions,
+        p: benchmarks::DiningPhilosophersRequest,
+    ) -> grpc::SingleResponse<messages::TestResult> {
+        println!("Got dining_philosophers req: {:?}", p);
+        let f = run_async(move || {
+            let b = bench::dining_philosophers::DiningPhilosophers::default();
+            run_and_record(&b, &p)
+        })
+        .map_err(|e| {
+            println!("Converting benchmark error into grpc error: {:?}", e);
+            e.into()
+        });
+        grpc::SingleResponse::no_metadata(f)
+    }
+
+    fn nqueens(
+        &self,
+        _o: grpc::RequestOptions,
+        _p: benchmarks::NQueensRequest,
+    ) -> grpc::SingleResponse<messages::TestResult> {
+        grpc::SingleResponse::completed(not_implemented())
+    }
+
+    fn parallel_sort(
+        &self,
+        _o: grpc::RequestOptions,
+        p: benchmarks::ParallelSortRequest,
+    ) -> grpc::SingleResponse<messages::TestResult> {
+        println!("Got parallel_sort req: {:?}", p);
+        let f = run_async(move || {
+            let b = bench::parallel_sort::ParallelSort::default();
+            run_and_record(&b, &p)
+        })
+        .map_err(|e| {
+            println!("Converting benchmark error into grpc error: {:?}", e);
+            e.into()
+        });
+        grpc::SingleResponse::no_metadata(f)
+    }
 }
@@ -1,24 +1,55 @@
 use benchmark_suite_shared;
 
 use benchmark_suite_shared::downcast_msg;
-use benchmark_suite_shared::BenchmarkMain;
+use benchmark_suite_shared::{actors, result_writer, BenchmarkMain};
 use grpc;
 #[allow(unused_imports)]
 //use slog::{crit, debug, error, info, warn};
 use std::env;
 
+pub mod actor_adapter;
 mod bench;
 mod benchmark_runner;
+pub mod remote;
 pub mod riker_system_provider;
 
+/// The version of the `riker` dependency, as resolved by the build script.
+pub const RIKER_VERSION: &str = env!("RIKER_VERSION");
+
 fn main() {
-    let args: Vec<String> = env::args().collect();
-    BenchmarkMain::run_with(
-        args,
-        benchmark_runner::BenchmarkRunnerImpl::new(),
-        bench::factory(),
-        |_| {},
+    let mut args: Vec<String> = env::args().collect();
+    // an optional leading "generic" runs the benchmarks written against the shared actor abstraction
+    let generic = args
+        .get(1)
+        .map(|s| s.eq_ignore_ascii_case("generic"))
+        .unwrap_or(false);
+    if generic {
+        args.remove(1);
+    }
+    result_writer::init(
+        if generic { "Riker Generic" } else { "Riker" },
+        &[
+            ("riker", RIKER_VERSION),
+            (env!("CARGO_PKG_NAME"), env!("CARGO_PKG_VERSION")),
+        ],
     );
+    if generic {
+        BenchmarkMain::run_with(
+            args,
+            actors::runner::BenchmarkRunnerImpl::<actor_adapter::RikerAdapter>::new(),
+            Box::new(actors::benchmarks::GenericFactory::<
+                actor_adapter::RikerAdapter,
+            >::new()),
+            riker_system_provider::set_public_if,
+        );
+    } else {
+        BenchmarkMain::run_with(
+            args,
+            benchmark_runner::BenchmarkRunnerImpl::new(),
+            bench::factory(),
+            riker_system_provider::set_public_if,
+        );
+    }
 }
 
 #[cfg(test)]
@@ -37,4 +68,12 @@ mod tests {
         let runner = benchmark_runner::BenchmarkRunnerImpl::new();
         test_local_implementation(runner);
     }
+
+    #[test]
+    fn test_generic() {
+        let benchmarks = Box::new(actors::benchmarks::GenericFactory::<
+            actor_adapter::RikerAdapter,
+        >::new());
+        test_implementation(benchmarks);
+    }
 }
@@ -0,0 +1,526 @@
+//! A minimal remoting layer for Riker, which has none of its own.
+//!
+//! Each [Remoting](Remoting) instance listens on a TCP port and maps the names of
+//! [registered](Remoting::register) actors to their `ActorRef`s.
+//! Remote actors are addressed by [RemotePath](RemotePath)s and represented locally by proxy actors,
+//! so sending to them works like sending to any other `ActorRef`.
+//! Messages are written with their [Serialisable](Serialisable) implementation.
+//!
+//! Frames are length-prefixed (`u32`, big endian) and at most [MAX_FRAME_LEN](MAX_FRAME_LEN)
+//! bytes long, since the length is read before anything else. The first frame on every connection
+//! carries the listening address of the connecting side, so that both ends can share a connection.
+//! Every other frame holds the target name, the sender name (empty for none) and the message.
+//! Senders are only passed along if they are registered themselves.
+
+use riker::actors::*;
+use std::{
+    collections::HashMap,
+    fmt,
+    io::{self, BufReader, BufWriter, Read, Write},
+    net::{IpAddr, Shutdown, SocketAddr, TcpListener, TcpStream},
+    str::FromStr,
+    sync::{
+        atomic::{AtomicBool, AtomicU64, Ordering},
+        mpsc, Arc, Mutex, RwLock,
+    },
+    thread::{self, JoinHandle},
+};
+
+/// The longest frame body that is sent or accepted.
+pub const MAX_FRAME_LEN: usize = 16 * 1024 * 1024;
+
+/// Messages that can be sent to remote actors.
+pub trait Serialisable: Sized {
+    /// Appends the serialised form of `self` to `buf`.
+    fn serialise(&self, buf: &mut Vec<u8>) -> ();
+    fn deserialise(buf: &[u8]) -> Result<Self, SerError>;
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SerError(pub String);
+
+impl fmt::Display for SerError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Serialisation error: {}", self.0)
+    }
+}
+
+/// The address of an actor registered with a [Remoting](Remoting) instance.
+///
+/// Written as `<ip>:<port>/<actor name>`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RemotePath {
+    pub addr: SocketAddr,
+    pub name: String,
+}
+
+impl fmt::Display for RemotePath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}", self.addr, self.name)
+    }
+}
+
+impl FromStr for RemotePath {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = s.splitn(2, '/');
+        let addr = parts
+            .next()
+            .and_then(|a| a.parse::<SocketAddr>().ok())
+            .ok_or_else(|| format!("'{}' does not start with a socket address", s))?;
+        match parts.next() {
+            Some(name) if !name.is_empty() => Ok(RemotePath {
+                addr,
+                name: name.to_string(),
+            }),
+            _ => Err(format!("'{}' does not contain an actor name", s)),
+        }
+    }
+}
+
+/// TCP transport and actor registry for messages of type `M`.
+pub struct Remoting<M: Message + Serialisable> {
+    inner: Arc<Inner<M>>,
+    acceptor: Option<JoinHandle<()>>,
+}
+
+impl<M: Message + Serialisable> Remoting<M> {
+    /// Starts listening on an ephemeral port on `ip`.
+    ///
+    /// `tcp_no_delay` applies to all connections of this instance.
+    pub fn bind(system: &ActorSystem, ip: IpAddr, tcp_no_delay: bool) -> io::Result<Remoting<M>> {
+        let listener = TcpListener::bind(SocketAddr::new(ip, 0))?;
+        let local_addr = listener.local_addr()?;
+        let inner = Arc::new(Inner {
+            local_addr,
+            tcp_no_delay,
+            system: system.clone(),
+            registry: RwLock::new(HashMap::new()),
+            connections: Mutex::new(HashMap::new()),
+            proxies: Mutex::new(HashMap::new()),
+            proxy_ids: AtomicU64::new(0),
+            running: AtomicBool::new(true),
+        });
+        let acceptor_inner = inner.clone();
+        let acceptor = thread::Builder::new()
+            .name(format!("riker-remote-{}", local_addr))
+            .spawn(move || acceptor_inner.accept_loop(listener))?;
+        Ok(Remoting {
+            inner,
+            acceptor: Some(acceptor),
+        })
+    }
+
+    pub fn local_addr(&self) -> SocketAddr {
+        self.inner.local_addr
+    }
+
+    /// Makes `actor` reachable from remote systems under the returned path.
+    pub fn register(&self, actor: &ActorRef<M>) -> RemotePath {
+        let name = actor.name().to_string();
+        self.inner
+            .registry
+            .write()
+            .unwrap()
+            .insert(name.clone(), actor.clone());
+        RemotePath {
+            addr: self.inner.local_addr,
+            name,
+        }
+    }
+
+    pub fn unregister(&self, actor: &ActorRef<M>) -> () {
+        self.inner.registry.write().unwrap().remove(actor.name());
+    }
+
+    /// A local reference to the actor at `path`, which forwards everything it receives.
+    pub fn remote_ref(&self, path: &RemotePath) -> io::Result<ActorRef<M>> {
+        self.inner.proxy(path)
+    }
+
+    /// Closes all connections and stops all proxies.
+    pub fn shutdown(mut self) -> () {
+        self.inner.running.store(false, Ordering::SeqCst);
+        // wake up the acceptor, so it can notice
+        let _ = TcpStream::connect(self.inner.local_addr);
+        if let Some(acceptor) = self.acceptor.take() {
+            acceptor.join().expect("Acceptor thread panicked");
+        }
+        for (_, conn) in self.inner.connections.lock().unwrap().drain() {
+            let _ = conn.stream.shutdown(Shutdown::Both);
+        }
+        for (_, proxy) in self.inner.proxies.lock().unwrap().drain() {
+            self.inner.system.stop(proxy);
+        }
+        self.inner.registry.write().unwrap().clear();
+    }
+}
+
+struct Connection {
+    frames: mpsc::Sender<Vec<u8>>,
+    stream: TcpStream,
+}
+
+struct Inner<M: Message + Serialisable> {
+    local_addr: SocketAddr,
+    tcp_no_delay: bool,
+    system: ActorSystem,
+    registry: RwLock<HashMap<String, ActorRef<M>>>,
+    connections: Mutex<HashMap<SocketAddr, Connection>>,
+    proxies: Mutex<HashMap<RemotePath, ActorRef<M>>>,
+    proxy_ids: AtomicU64,
+    running: AtomicBool,
+}
+
+impl<M: Message + Serialisable> Inner<M> {
+    fn accept_loop(self: Arc<Self>, listener: TcpListener) -> () {
+        for stream in listener.incoming() {
+            if !self.running.load(Ordering::SeqCst) {
+                break;
+            }
+            let res = stream.and_then(|mut stream| {
+                let handshake = read_frame(&mut stream)?;
+                let peer = String::from_utf8(handshake)
+                    .ok()
+                    .and_then(|s| s.parse::<SocketAddr>().ok())
+                    .ok_or_else(|| invalid_data("Invalid handshake".to_string()))?;
+                self.add_connection(peer, stream).map(|_| ())
+            });
+            if let Err(e) = res {
+                eprintln!("Could not accept connection on {}: {}", self.local_addr, e);
+            }
+        }
+    }
+
+    fn add_connection(
+        self: &Arc<Self>,
+        peer: SocketAddr,
+        stream: TcpStream,
+    ) -> io::Result<mpsc::Sender<Vec<u8>>> {
+        let conn = self.start_connection(peer, stream)?;
+        let tx = conn.frames.clone();
+        self.connections.lock().unwrap().insert(peer, conn);
+        Ok(tx)
+    }
+
+    /// Starts the reader and writer threads of a connection, which still has to be registered.
+    fn start_connection(
+        self: &Arc<Self>,
+        peer: SocketAddr,
+        stream: TcpStream,
+    ) -> io::Result<Connection> {
+        stream.set_nodelay(self.tcp_no_delay)?;
+        let (tx, rx) = mpsc::channel::<Vec<u8>>();
+        let writer = BufWriter::new(stream.try_clone()?);
+        thread::Builder::new()
+            .name(format!("riker-remote-{}-writer", peer))
+            .spawn(move || write_loop(writer, rx))?;
+        let reader = BufReader::new(stream.try_clone()?);
+        let inner = self.clone();
+        thread::Builder::new()
+            .name(format!("riker-remote-{}-reader", peer))
+            .spawn(move || inner.read_loop(peer, reader))?;
+        Ok(Connection { frames: tx, stream })
+    }
+
+    fn connection(self: &Arc<Self>, peer: SocketAddr) -> io::Result<mpsc::Sender<Vec<u8>>> {
+        // held until the new connection is registered, so that concurrent senders
+        // don't each open their own connection to the same peer
+        let mut connections = self.connections.lock().unwrap();
+        if let Some(conn) = connections.get(&peer) {
+            return Ok(conn.frames.clone());
+        }
+        let mut stream = TcpStream::connect(peer)?;
+        let mut handshake = Vec::new();
+        write_frame(&mut handshake, self.local_addr.to_string().as_bytes())?;
+        stream.write_all(&handshake)?;
+        let conn = self.start_connection(peer, stream)?;
+        let tx = conn.frames.clone();
+        connections.insert(peer, conn);
+        Ok(tx)
+    }
+
+    fn read_loop(self: Arc<Self>, peer: SocketAddr, mut reader: BufReader<TcpStream>) -> () {
+        loop {
+            match read_frame(&mut reader) {
+                Ok(frame) => {
+                    if let Err(e) = self.dispatch(peer, &frame) {
+                        eprintln!("Dropping message from {}: {}", peer, e);
+                    }
+                }
+                Err(e) => {
+                    if self.running.load(Ordering::SeqCst)
+                        && e.kind() != io::ErrorKind::UnexpectedEof
+                    {
+                        eprintln!("Connection to {} failed: {}", peer, e);
+                    }
+                    break;
+                }
+            }
+        }
+        self.connections.lock().unwrap().remove(&peer);
+    }
+
+    fn dispatch(self: &Arc<Self>, peer: SocketAddr, frame: &[u8]) -> Result<(), String> {
+        let (target, rest) = read_name(frame)?;
+        let (sender, payload) = read_name(rest)?;
+        let msg = M::deserialise(payload).map_err(|e| e.to_string())?;
+        let target = self
+            .registry
+            .read()
+            .unwrap()
+            .get(target)
+            .cloned()
+            .ok_or_else(|| format!("No actor registered as '{}'", target))?;
+        let sender: Sender = if sender.is_empty() {
+            None
+        } else {
+            let path = RemotePath {
+                addr: peer,
+                name: sender.to_string(),
+            };
+            let proxy = self.proxy(&path).map_err(|e| e.to_string())?;
+            Some(proxy.into())
+        };
+        target.tell(msg, sender);
+        Ok(())
+    }
+
+    fn proxy(self: &Arc<Self>, path: &RemotePath) -> io::Result<ActorRef<M>> {
+        let mut proxies = self.proxies.lock().unwrap();
+        if let Some(proxy) = proxies.get(path) {
+            return Ok(proxy.clone());
+        }
+        let name = format!(
+            "remote-proxy-{}",
+            self.proxy_ids.fetch_add(1, Ordering::SeqCst)
+        );
+        let inner = self.clone();
+        let target = path.clone();
+        let props = Props::new_from(move || RemoteProxy {
+            inner: inner.clone(),
+            target: target.clone(),
+        });
+        let proxy = self
+            .system
+            .actor_of_props(&name, props)
+            .map_err(|e| io::Error::new(io::ErrorKind::Other, format!("{:?}", e)))?;
+        proxies.insert(path.clone(), proxy.clone());
+        Ok(proxy)
+    }
+
+    fn send(self: &Arc<Self>, target: &RemotePath, msg: &M, sender: &Sender) -> io::Result<()> {
+        let sender_name = match sender {
+            Some(s) if self.registry.read().unwrap().contains_key(s.name()) => s.name(),
+            _ => "",
+        };
+        let mut body = Vec::new();
+        write_name(&mut body, &target.name);
+        write_name(&mut body, sender_name);
+        msg.serialise(&mut body);
+        let mut frame = Vec::with_capacity(body.len() + 4);
+        write_frame(&mut frame, &body)?;
+        self.connection(target.addr)?
+            .send(frame)
+            .map_err(|_| io::Error::new(io::ErrorKind::BrokenPipe, "Connection closed"))
+    }
+}
+
+/// Local stand-in for a remote actor.
+struct RemoteProxy<M: Message + Serialisable> {
+    inner: Arc<Inner<M>>,
+    target: RemotePath,
+}
+
+impl<M: Message + Serialisable> Actor for RemoteProxy<M> {
+    type Msg = M;
+
+    fn recv(&mut self, _ctx: &Context<Self::Msg>, msg: Self::Msg, sender: Sender) {
+        if let Err(e) = self.inner.send(&self.target, &msg, &sender) {
+            eprintln!("Could not send {:?} to {}: {}", msg, self.target, e);
+        }
+    }
+}
+
+fn write_loop(mut writer: BufWriter<TcpStream>, frames: mpsc::Receiver<Vec<u8>>) -> () {
+    let res: io::Result<()> = (|| {
+        while let Ok(frame) = frames.recv() {
+            writer.write_all(&frame)?;
+            // batch whatever else is already waiting, before flushing
+            while let Ok(frame) = frames.try_recv() {
+                writer.write_all(&frame)?;
+            }
+            writer.flush()?;
+        }
+        Ok(())
+    })();
+    if let Err(e) = res {
+        eprintln!("Could not write to connection: {}", e);
+    }
+}
+
+fn write_frame(buf: &mut Vec<u8>, body: &[u8]) -> io::Result<()> {
+    if body.len() > MAX_FRAME_LEN {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!(
+                "Frame of {} bytes exceeds {} bytes",
+                body.len(),
+                MAX_FRAME_LEN
+            ),
+        ));
+    }
+    buf.extend_from_slice(&(body.len() as u32).to_be_bytes());
+    buf.extend_from_slice(body);
+    Ok(())
+}
+
+fn read_frame<R: Read>(reader: &mut R) -> io::Result<Vec<u8>> {
+    let mut len = [0u8; 4];
+    reader.read_exact(&mut len)?;
+    let len = u32::from_be_bytes(len) as usize;
+    if len > MAX_FRAME_LEN {
+        return Err(invalid_data(format!(
+            "Frame of {} bytes exceeds {} bytes",
+            len, MAX_FRAME_LEN
+        )));
+    }
+    let mut frame = vec![0u8; len];
+    reader.read_exact(&mut frame)?;
+    Ok(frame)
+}
+
+fn write_name(buf: &mut Vec<u8>, name: &str) -> () {
+    buf.extend_from_slice(&(name.len() as u16).to_be_bytes());
+    buf.extend_from_slice(name.as_bytes());
+}
+
+fn read_name(buf: &[u8]) -> Result<(&str, &[u8]), String> {
+    if buf.len() < 2 {
+        return Err("Frame is too short".to_string());
+    }
+    let len = u16::from_be_bytes([buf[0], buf[1]]) as usize;
+    if buf.len() < 2 + len {
+        return Err("Frame is too short".to_string());
+    }
+    let name = std::str::from_utf8(&buf[2..2 + len]).map_err(|e| e.to_string())?;
+    Ok((name, &buf[2 + len..]))
+}
+
+fn invalid_data(msg: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, msg)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::riker_system_provider::*;
+    use std::{net::Ipv4Addr, time::Duration};
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Echo(u64);
+
+    impl Serialisable for Echo {
+        fn serialise(&self, buf: &mut Vec<u8>) -> () {
+            buf.extend_from_slice(&self.0.to_be_bytes());
+        }
+
+        fn deserialise(buf: &[u8]) -> Result<Self, SerError> {
+            if buf.len() != 8 {
+                return Err(SerError(format!("Expected 8 bytes, got {}", buf.len())));
+            }
+            let mut bytes = [0u8; 8];
+            bytes.copy_from_slice(buf);
+            Ok(Echo(u64::from_be_bytes(bytes)))
+        }
+    }
+
+    struct Echoer;
+
+    impl Actor for Echoer {
+        type Msg = Echo;
+
+        fn recv(&mut self, _ctx: &Context<Self::Msg>, msg: Self::Msg, sender: Sender) {
+            let sender = sender.expect("Echo without sender");
+            sender
+                .try_tell(Echo(msg.0 + 1), None)
+                .expect("Should have replied");
+        }
+    }
+
+    struct Collector(mpsc::Sender<u64>);
+
+    impl Actor for Collector {
+        type Msg = Echo;
+
+        fn recv(&mut self, _ctx: &Context<Self::Msg>, msg: Self::Msg, _sender: Sender) {
+            self.0.send(msg.0).expect("Should have collected");
+        }
+    }
+
+    #[test]
+    fn test_remote_path() {
+        let path: RemotePath = "127.0.0.1:4567/ponger-0".parse().expect("RemotePath");
+        assert_eq!(path.addr, "127.0.0.1:4567".parse().unwrap());
+        assert_eq!(path.name, "ponger-0");
+        assert_eq!(path.to_string().parse::<RemotePath>(), Ok(path));
+        assert!("127.0.0.1:4567".parse::<RemotePath>().is_err());
+        assert!("ponger/127.0.0.1:4567".parse::<RemotePath>().is_err());
+    }
+
+    #[test]
+    fn test_frames() {
+        let mut buf = Vec::new();
+        write_frame(&mut buf, b"ping").expect("Frame");
+        assert_eq!(
+            read_frame(&mut buf.as_slice()).expect("Frame"),
+            b"ping".to_vec()
+        );
+        assert!(write_frame(&mut buf, &vec![0u8; MAX_FRAME_LEN + 1]).is_err());
+        // the length is rejected before anything is allocated for it
+        let oversized = u32::MAX.to_be_bytes();
+        let err = read_frame(&mut &oversized[..]).expect_err("Oversized frame");
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn test_remote_echo() {
+        let localhost = IpAddr::V4(Ipv4Addr::LOCALHOST);
+        let echo_system = new_system("echo");
+        let echo_remoting =
+            Remoting::<Echo>::bind(&echo_system, localhost, true).expect("Remoting");
+        let echoer = echo_system
+            .start(Props::new_from(|| Echoer), "echoer")
+            .expect("Echoer");
+        let echoer_path = echo_remoting.register(&echoer);
+
+        let system = new_system("collect");
+        let remoting = Remoting::<Echo>::bind(&system, localhost, true).expect("Remoting");
+        let (tx, rx) = mpsc::channel();
+        let collector = system
+            .start(Props::new_from(move || Collector(tx.clone())), "collector")
+            .expect("Collector");
+        remoting.register(&collector);
+        let echoer_ref = remoting.remote_ref(&echoer_path).expect("Remote ref");
+        for i in 0..10u64 {
+            echoer_ref.tell(Echo(i * 10), Some(collector.clone().into()));
+        }
+        let mut replies: Vec<u64> = (0..10)
+            .map(|_| rx.recv_timeout(Duration::from_secs(5)).expect("Reply"))
+            .collect();
+        replies.sort();
+        assert_eq!(replies, (0..10u64).map(|i| i * 10 + 1).collect::<Vec<_>>());
+
+        remoting.shutdown();
+        echo_remoting.shutdown();
+        system
+            .shutdown()
+            .wait()
+            .expect("Riker didn't shut down properly");
+        echo_system
+            .shutdown()
+            .wait()
+            .expect("Riker didn't shut down properly");
+    }
+}
@@ -1,16 +1,34 @@
-use riker::actors::*;
+use benchmark_suite_shared::affinity::Placement;
 use futures_executor::ThreadPool;
 use futures_preview::executor::block_on;
 use futures_preview::prelude::*;
+use riker::actors::*;
 use riker_patterns::ask;
 use std::fmt::Debug;
 use std::sync::atomic::{AtomicU64, Ordering};
 //use futures_preview::future::RemoteHandle;
 use futures::future::RemoteHandle;
+use once_cell::sync::OnceCell;
 
+use std::net::{IpAddr, Ipv4Addr};
 use std::ops::Deref;
 use std::sync::Arc;
 
+static PUBLIC_IF: OnceCell<IpAddr> = OnceCell::new();
+
+/// Sets the interface networked benchmarks listen on; only the first call has an effect.
+pub fn set_public_if(addr: IpAddr) {
+    let _ = PUBLIC_IF.set(addr);
+}
+
+/// The interface networked benchmarks listen on, `localhost` unless set.
+pub fn public_if() -> IpAddr {
+    PUBLIC_IF
+        .get()
+        .cloned()
+        .unwrap_or(IpAddr::V4(Ipv4Addr::LOCALHOST))
+}
+
 pub fn new_system<I: Into<String>>(name: I) -> RikerSystem {
     RikerSystem::new(&name.into(), num_cpus::get()).expect("Riker ActorSystem")
 }
@@ -37,6 +55,7 @@ pub enum RikerSystemError {
     Riker(riker::system::SystemError),
 }
 
+#[derive(Clone)]
 pub struct RikerSystem {
     id_tracker: Arc<AtomicU64>,
     system: Arc<ActorSystem>,
@@ -44,16 +63,35 @@ pub struct RikerSystem {
 
 impl RikerSystem {
     pub fn new(label: &str, num_threads: usize) -> Result<RikerSystem, RikerSystemError> {
-        let exec = ThreadPool::builder()
-            .pool_size(num_threads)
-            .name_prefix(format!("riker-{}-pool", label))
-            .create()
-            .map_err(|e| RikerSystemError::ThreadPool(e))?;
-        let sys = SystemBuilder::new()
-            .name(label)
-            .exec(exec)
-            .create()
-            .map_err(|e| RikerSystemError::Riker(e))?;
+        let placement = Placement::from_env(num_threads);
+        let create = || {
+            let mut builder = ThreadPool::builder();
+            builder
+                .pool_size(num_threads)
+                .name_prefix(format!("riker-{}-pool", label));
+            if let Some(placement) = placement.clone() {
+                builder.after_start(move |index| {
+                    placement.pin_current(index);
+                });
+            }
+            let exec = builder
+                .create()
+                .map_err(|e| RikerSystemError::ThreadPool(e))?;
+            SystemBuilder::new()
+                .name(label)
+                .exec(exec)
+                .create()
+                .map_err(|e| RikerSystemError::Riker(e))
+        };
+        let sys = match placement {
+            Some(ref placement) => {
+                let sys = placement.restrict(create);
+                // the pool threads pin themselves as they start
+                placement.report(num_threads);
+                sys
+            }
+            None => create(),
+        }?;
         let wrap = RikerSystem {
             id_tracker: Arc::new(AtomicU64::new(0u64)),
             system: Arc::new(sys),
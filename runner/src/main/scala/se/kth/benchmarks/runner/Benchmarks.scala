@@ -12,7 +12,9 @@ case class BenchmarkRun[Params](name: String, symbol: String, invoke: (Runner.St
 trait Benchmark {
   def name: String;
   def symbol: String;
-  def withStub(stub: Runner.Stub, testing: Boolean)(f: (Future[TestResult], ParameterDescription, Long) => Unit): Unit;
+  def withStub(stub: Runner.Stub, testing: Boolean)(
+      f: (() => Future[TestResult], ParameterDescription, Long) => Unit
+  ): Unit;
   def requiredRuns(testing: Boolean): Long;
 }
 object Benchmark {
@@ -35,12 +37,12 @@ case class BenchmarkWithSpace[Params](b: BenchmarkRun[Params],
   override def symbol: String = b.symbol;
   def run = b.invoke;
   override def withStub(stub: Runner.Stub,
-                        testing: Boolean)(f: (Future[TestResult], ParameterDescription, Long) => Unit): Unit = {
+                        testing: Boolean)(f: (() => Future[TestResult], ParameterDescription, Long) => Unit): Unit = {
     var index = 0L;
     val useSpace = if (testing) testSpace else space;
     useSpace.foreach { p =>
       index += 1L;
-      f(run(stub, p), useSpace.describe(p), index)
+      f(() => run(stub, p), useSpace.describe(p), index)
     }
   }
   override def requiredRuns(testing: Boolean): Long = if (testing) testSpace.size else space.size;
@@ -230,6 +232,190 @@ object Benchmarks extends ParameterDescriptionImplicits {
       }
   );
 
+  val threadRing = Benchmark(
+    name = "Thread Ring",
+    symbol = "THREADRING",
+    invoke = (stub, request: ThreadRingRequest) => {
+      stub.threadRing(request)
+    },
+    space = ParameterSpacePB
+      .cross(List(2, 8, 32, 128, 512), List(1.mio), List(false, true))
+      .msg[ThreadRingRequest] {
+        case (na, np, manyTokens) => {
+          val nt = if (manyTokens) na / 2 else 1; // half the ring holds a token at start
+          ThreadRingRequest(numberOfActors = na, numberOfPasses = np, numberOfTokens = nt)
+        }
+      },
+    testSpace = ParameterSpacePB
+      .cross(List(2, 8, 32), List(100.k), List(false, true))
+      .msg[ThreadRingRequest] {
+        case (na, np, manyTokens) => {
+          val nt = if (manyTokens) na / 2 else 1;
+          ThreadRingRequest(numberOfActors = na, numberOfPasses = np, numberOfTokens = nt)
+        }
+      }
+  );
+
+  val fanIn = Benchmark(
+    name = "Fan In",
+    symbol = "FANIN",
+    invoke = (stub, request: FanInRequest) => {
+      stub.fanIn(request)
+    },
+    space = ParameterSpacePB
+      .cross(List(1, 2, 4, 8, 16, 32), List(1.mio), List(0, 100))
+      .msg[FanInRequest] {
+        case (np, nm, ps) =>
+          FanInRequest(numberOfProducers = np, messagesPerProducer = nm, pipelineSize = ps)
+      },
+    testSpace = ParameterSpacePB
+      .cross(List(1, 4, 16), List(100.k), List(0, 100))
+      .msg[FanInRequest] {
+        case (np, nm, ps) =>
+          FanInRequest(numberOfProducers = np, messagesPerProducer = nm, pipelineSize = ps)
+      }
+  );
+
+  val fanout = Benchmark(
+    name = "Fanout",
+    symbol = "FANOUT",
+    invoke = (stub, request: FanoutRequest) => {
+      stub.fanout(request)
+    },
+    space = ParameterSpacePB
+      .cross(List(1, 2, 4, 8, 16, 32, 64, 128), List(100.k))
+      .msg[FanoutRequest] {
+        case (ns, nr) => FanoutRequest(numberOfSubscribers = ns, numberOfRounds = nr)
+      },
+    testSpace = ParameterSpacePB
+      .cross(List(1, 8, 32), List(10.k))
+      .msg[FanoutRequest] {
+        case (ns, nr) => FanoutRequest(numberOfSubscribers = ns, numberOfRounds = nr)
+      }
+  );
+
+  val big = Benchmark(
+    name = "Big",
+    symbol = "BIG",
+    invoke = (stub, request: BigRequest) => {
+      stub.big(request)
+    },
+    space = ParameterSpacePB
+      .cross(List(8, 32, 128, 512), List(10.k), List(42L))
+      .msg[BigRequest] {
+        case (na, np, s) => BigRequest(numberOfActors = na, numberOfPings = np, seed = s)
+      },
+    testSpace = ParameterSpacePB
+      .cross(List(8, 32, 128), List(1.k), List(42L))
+      .msg[BigRequest] {
+        case (na, np, s) => BigRequest(numberOfActors = na, numberOfPings = np, seed = s)
+      }
+  );
+
+  val bank = Benchmark(
+    name = "Bank",
+    symbol = "BANK",
+    invoke = (stub, request: BankRequest) => {
+      stub.bank(request)
+    },
+    space = ParameterSpacePB
+      .cross(List(8, 32, 128, 512), List(100.k), List(42L))
+      .msg[BankRequest] {
+        case (na, nt, s) => BankRequest(numberOfAccounts = na, numberOfTransactions = nt, seed = s)
+      },
+    testSpace = ParameterSpacePB
+      .cross(List(8, 32, 128), List(10.k), List(42L))
+      .msg[BankRequest] {
+        case (na, nt, s) => BankRequest(numberOfAccounts = na, numberOfTransactions = nt, seed = s)
+      }
+  );
+
+  val producerConsumer = Benchmark(
+    name = "Producer Consumer",
+    symbol = "PRODCON",
+    invoke = (stub, request: ProducerConsumerRequest) => {
+      stub.producerConsumer(request)
+    },
+    space = ParameterSpacePB
+      .cross(List(1, 10, 100), List(1, 4, 16), List(1, 4, 16), List(100.k), List(0L, 100L))
+      .msg[ProducerConsumerRequest] {
+        case (bs, np, nc, ni, w) =>
+          ProducerConsumerRequest(
+            bufferSize = bs,
+            numberOfProducers = np,
+            numberOfConsumers = nc,
+            numberOfItems = ni,
+            workPerItem = w
+          )
+      },
+    testSpace = ParameterSpacePB
+      .cross(List(1, 100), List(1, 4), List(1, 4), List(10.k), List(0L, 100L))
+      .msg[ProducerConsumerRequest] {
+        case (bs, np, nc, ni, w) =>
+          ProducerConsumerRequest(
+            bufferSize = bs,
+            numberOfProducers = np,
+            numberOfConsumers = nc,
+            numberOfItems = ni,
+            workPerItem = w
+          )
+      }
+  );
+
+  val diningPhilosophers = Benchmark(
+    name = "Dining Philosophers",
+    symbol = "PHILOSOPHERS",
+    invoke = (stub, request: DiningPhilosophersRequest) => {
+      stub.diningPhilosophers(request)
+    },
+    space = ParameterSpacePB
+      .cross(List(2, 4, 8, 16, 32, 64), List(10.k))
+      .msg[DiningPhilosophersRequest] {
+        case (np, nr) => DiningPhilosophersRequest(numberOfPhilosophers = np, numberOfRounds = nr)
+      },
+    testSpace = ParameterSpacePB
+      .cross(List(2, 8, 32), List(1.k))
+      .msg[DiningPhilosophersRequest] {
+        case (np, nr) => DiningPhilosophersRequest(numberOfPhilosophers = np, numberOfRounds = nr)
+      }
+  );
+
+  val nqueens = Benchmark(
+    name = "N-Queens",
+    symbol = "NQUEENS",
+    invoke = (stub, request: NQueensRequest) => {
+      stub.nqueens(request)
+    },
+    space = ParameterSpacePB
+      .cross(List(12, 14), List(1, 2, 4, 8, 16, 32), List(2, 4))
+      .msg[NQueensRequest] {
+        case (bs, nw, sd) => NQueensRequest(boardSize = bs, numberOfWorkers = nw, splitDepth = sd)
+      },
+    testSpace = ParameterSpacePB
+      .cross(List(10, 12), List(1, 4, 16), List(2, 4))
+      .msg[NQueensRequest] {
+        case (bs, nw, sd) => NQueensRequest(boardSize = bs, numberOfWorkers = nw, splitDepth = sd)
+      }
+  );
+
+  val parallelSort = Benchmark(
+    name = "Parallel Sort",
+    symbol = "PSORT",
+    invoke = (stub, request: ParallelSortRequest) => {
+      stub.parallelSort(request)
+    },
+    space = ParameterSpacePB
+      .cross(List(1.mio, 10.mio), List(1.k, 10.k, 100.k), List(42L))
+      .msg[ParallelSortRequest] {
+        case (ds, t, s) => ParallelSortRequest(dataSize = ds, threshold = t, seed = s)
+      },
+    testSpace = ParameterSpacePB
+      .cross(List(100.k, 1.mio), List(1.k, 10.k), List(42L))
+      .msg[ParallelSortRequest] {
+        case (ds, t, s) => ParallelSortRequest(dataSize = ds, threshold = t, seed = s)
+      }
+  );
+
   val allPairsShortestPath = Benchmark(
     name = "All-Pairs Shortest Path",
     symbol = "APSP",
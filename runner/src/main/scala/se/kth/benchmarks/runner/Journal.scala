@@ -0,0 +1,143 @@
+package se.kth.benchmarks.runner
+
+import kompics.benchmarks.messages._
+import com.typesafe.scalalogging.LazyLogging
+import java.io.{File, FileWriter, PrintWriter}
+import scala.io.Source
+import scala.collection.mutable
+
+/**
+  * Append-only record of every experiment point (implementation, benchmark, parameters)
+  * that has been completed, together with its result.
+  *
+  * A run that is restarted with the same journal will skip points that finished
+  * successfully (or were reported as not implemented) and re-run everything else.
+  */
+class Journal(file: File) extends LazyLogging {
+  import Journal._;
+
+  private val entries: mutable.Map[Key, Entry] = mutable.HashMap.empty;
+  private var resumed: Int = 0;
+  private var rerun: Int = 0;
+
+  load();
+
+  private def load(): Unit = {
+    if (file.exists()) {
+      val source = Source.fromFile(file);
+      try {
+        source.getLines().zipWithIndex.foreach {
+          case (line, lineNo) =>
+            Entry.parse(line) match {
+              case Some(e) => entries += (e.key -> e) // later entries win
+              case None    => logger.warn(s"Ignoring malformed journal entry at ${file}:${lineNo + 1}")
+            }
+        }
+      } finally {
+        source.close();
+      }
+      logger.info(s"Loaded ${entries.size} journal entries from ${file}.");
+    } else {
+      val parent = file.getAbsoluteFile().getParentFile();
+      if ((parent != null) && !parent.exists()) {
+        parent.mkdirs();
+      }
+    }
+  }
+
+  /**
+    * Returns the journaled result for this point, if it does not need to be run again.
+    */
+  def completed(impl: String, bench: String, params: ParameterDescription): Option[TestResult] = {
+    entries.get(Key(impl, bench, params.toCSV)) match {
+      case Some(e) if e.isComplete => {
+        resumed += 1;
+        Some(e.result)
+      }
+      case Some(_) => {
+        rerun += 1;
+        None
+      }
+      case None => None
+    }
+  }
+
+  def record(impl: String, bench: String, params: ParameterDescription, result: TestResult): Unit = {
+    val e = Entry(Key(impl, bench, params.toCSV), result);
+    entries += (e.key -> e);
+    val fw = new FileWriter(file, true);
+    val w = new PrintWriter(fw);
+    try {
+      w.println(e.render);
+      w.flush();
+    } finally {
+      w.close();
+      fw.close();
+    }
+  }
+
+  def numResumed: Int = resumed;
+  def numRerun: Int = rerun;
+
+  def report(): Unit = {
+    if (entries.nonEmpty) {
+      logger.info(
+        s"Journal ${file}: resumed ${resumed} completed points, re-ran ${rerun} failed points, ${entries.size} points recorded in total."
+      );
+    }
+  }
+}
+
+object Journal {
+  val SEP: String = "\t";
+
+  case class Key(impl: String, bench: String, params: String);
+
+  case class Entry(key: Key, result: TestResult) {
+    def isComplete: Boolean = result match {
+      case _: TestSuccess    => true
+      case _: NotImplemented => true
+      case _                 => false
+    };
+
+    def render: String = {
+      val (status, payload) = result match {
+        case TestSuccess(nRuns, data, _) => ("SUCCESS", s"${nRuns};${data.mkString(",")}")
+        case TestFailure(reason, _)      => ("FAILURE", escape(reason))
+        case NotImplemented(_)           => ("NOT_IMPLEMENTED", "")
+        case TestResult.Empty            => ("EMPTY", "")
+      };
+      List(key.impl, key.bench, key.params, status, payload).mkString(SEP)
+    }
+  }
+  object Entry {
+    def parse(line: String): Option[Entry] = {
+      val parts = line.split(SEP, -1);
+      if (parts.length != 5) {
+        None
+      } else {
+        val key = Key(parts(0), parts(1), parts(2));
+        val payload = parts(4);
+        val resultO: Option[TestResult] = parts(3) match {
+          case "SUCCESS" => {
+            payload.split(";", 2) match {
+              case Array(nRuns, data) =>
+                scala.util
+                  .Try(TestSuccess(nRuns.toInt, if (data.isEmpty) Seq.empty else data.split(",").map(_.toDouble).toSeq))
+                  .toOption
+              case _ => None
+            }
+          }
+          case "FAILURE"         => Some(TestFailure(unescape(payload)))
+          case "NOT_IMPLEMENTED" => Some(NotImplemented())
+          case "EMPTY"           => Some(TestResult.Empty)
+          case _                 => None
+        };
+        resultO.map(r => Entry(key, r))
+      }
+    }
+  }
+
+  private def escape(s: String): String = s.replaceAll("\t", "!T!").replaceAll("""\R""", "!N!");
+  private def unescape(s: String): String = s.replaceAll("!N!", "\n").replaceAll("!T!", "\t");
+}
@@ -43,6 +43,11 @@ class Conf(arguments: Seq[String]) extends ScallopConf(arguments) {
 
   val outputFolder = opt[File](descr = "Result folder.");
 
+  val journal = opt[File](
+    descr =
+      "Journal of completed experiment points. Completed points are skipped on restart. Defaults to <output-folder>/journal."
+  );
+
   val console = opt[Boolean](descr = "Output to console instead of result folder");
 
   val testing = opt[Boolean](
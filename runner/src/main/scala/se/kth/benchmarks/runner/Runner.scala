@@ -49,10 +49,21 @@ class Runner(conf: Conf, stub: Runner.Stub) extends LazyLogging {
     }
   }
 
+  val journal: Option[Journal] = {
+    if (conf.journal.isSupplied) {
+      Some(new Journal(conf.journal()))
+    } else if (conf.outputFolder.isSupplied) {
+      Some(new Journal(conf.outputFolder().toPath().resolve("journal").toFile()))
+    } else {
+      None
+    }
+  }
+
   def runAll(): Unit = {
     val ready = awaitReady();
     if (ready) {
       Benchmarks.benchmarks.foreach(runOne);
+      journal.foreach(_.report());
     }
   }
 
@@ -67,6 +78,7 @@ class Runner(conf: Conf, stub: Runner.Stub) extends LazyLogging {
       val ready = awaitReady();
       if (ready) {
         selected.foreach(runOne);
+        journal.foreach(_.report());
       }
     }
   }
@@ -103,24 +115,33 @@ class Runner(conf: Conf, stub: Runner.Stub) extends LazyLogging {
   def runOne(b: Benchmark): Unit = {
     logger.info(s"Running ${b.name}");
     val numRuns = b.requiredRuns(testing);
-    b.withStub(stub, testing) { (f, p, i) =>
-      logger.info(s"Awaiting run result [$i/$numRuns]...");
-      val result = Await.ready(f, Duration.Inf).value.get;
-      result match {
-        case Success(r) => {
-          r match {
-            case TestResult.Empty    => logger.warn(s"Benchmark ${b.name} invocation was empty.")
-            case TestFailure(reason, _) => logger.warn(s"Benchmark ${b.name} invocation failed: ${reason}")
-            case NotImplemented(_)    => logger.info(s"Benchmark ${b.name} is not implemented.")
-            case TestSuccess(nRuns, data, _) => {
-              logger.info(s"Benchmark ${b.name} run [$i/$numRuns] finished successfully with ${nRuns} runs.");
-              sinks.foreach(_.sink(b.symbol, p, data));
+    b.withStub(stub, testing) { (invoke, p, i) =>
+      journal.flatMap(_.completed(prefix, b.symbol, p)) match {
+        case Some(_) => {
+          logger.info(s"Benchmark ${b.name} run [$i/$numRuns] was already completed. Skipping.");
+        }
+        case None => {
+          logger.info(s"Awaiting run result [$i/$numRuns]...");
+          val result = Await.ready(invoke(), Duration.Inf).value.get;
+          result match {
+            case Success(r) => {
+              r match {
+                case TestResult.Empty       => logger.warn(s"Benchmark ${b.name} invocation was empty.")
+                case TestFailure(reason, _) => logger.warn(s"Benchmark ${b.name} invocation failed: ${reason}")
+                case NotImplemented(_)      => logger.info(s"Benchmark ${b.name} is not implemented.")
+                case TestSuccess(nRuns, data, _) => {
+                  logger.info(s"Benchmark ${b.name} run [$i/$numRuns] finished successfully with ${nRuns} runs.");
+                  sinks.foreach(_.sink(b.symbol, p, data));
+                }
+              }
+              journal.foreach(_.record(prefix, b.symbol, p, r));
+              logger.info(s"Benchmark ${b.name} run [$i/$numRuns] finished.");
+            }
+            case Failure(e) => {
+              logger.warn(s"Benchmark ${b.name} run [$i/$numRuns] invocation failed.", e);
+              journal.foreach(_.record(prefix, b.symbol, p, TestFailure(s"Invocation failed: ${e.getMessage}")));
             }
           }
-          logger.info(s"Benchmark ${b.name} run [$i/$numRuns] finished.");
-        }
-        case Failure(e) => {
-          logger.warn(s"Benchmark ${b.name} run [$i/$numRuns] invocation failed.", e);
         }
       }
     }
@@ -16,6 +16,20 @@ class ParameterTest extends FunSuite with Matchers {
     pp_deser should equal(pp);
   }
 
+  test("NetPingPong with network tuning should CSV rountrip") {
+    val bench = Benchmarks.netPingPong;
+    Benchmarks.benchmarkLookup(bench.symbol) should be theSameInstanceAs bench;
+    val tuning =
+      NetworkTuning(chunkSize = 128000, maxChunkCount = 16, tcpNoDelay = TcpNoDelay.TCP_NO_DELAY_OFF);
+    val pp = PingPongRequest(numberOfMessages = 100, network = Some(tuning));
+    val space = bench.space.asInstanceOf[ParameterSpacePB[PingPongRequest]];
+    val descr = space.describe(pp);
+    val csv = descr.toCSV;
+    Console.err.println(s"NetPingPong CSV: $csv");
+    val pp_deser = space.paramsFromCSV(csv);
+    pp_deser should equal(pp);
+  }
+
   test("Throughput PingPong should CSV rountrip") {
     val bench = Benchmarks.throughputPingPong;
     Benchmarks.benchmarkLookup(bench.symbol) should be theSameInstanceAs bench;
@@ -68,6 +82,121 @@ class ParameterTest extends FunSuite with Matchers {
     pp_deser should equal(pp);
   }
 
+  test("ThreadRing should CSV rountrip") {
+    val bench = Benchmarks.threadRing;
+    Benchmarks.benchmarkLookup(bench.symbol) should be theSameInstanceAs bench;
+    val pp =
+      ThreadRingRequest(numberOfActors = 32, numberOfPasses = 100000L, numberOfTokens = 16);
+    val space = bench.space.asInstanceOf[ParameterSpacePB[ThreadRingRequest]];
+    val descr = space.describe(pp);
+    val csv = descr.toCSV;
+    Console.err.println(s"ThreadRing CSV: $csv");
+    val pp_deser = space.paramsFromCSV(csv);
+    pp_deser should equal(pp);
+  }
+
+  test("FanIn should CSV rountrip") {
+    val bench = Benchmarks.fanIn;
+    Benchmarks.benchmarkLookup(bench.symbol) should be theSameInstanceAs bench;
+    val pp = FanInRequest(numberOfProducers = 8, messagesPerProducer = 100000L, pipelineSize = 100);
+    val space = bench.space.asInstanceOf[ParameterSpacePB[FanInRequest]];
+    val descr = space.describe(pp);
+    val csv = descr.toCSV;
+    Console.err.println(s"FanIn CSV: $csv");
+    val pp_deser = space.paramsFromCSV(csv);
+    pp_deser should equal(pp);
+  }
+
+  test("Fanout should CSV rountrip") {
+    val bench = Benchmarks.fanout;
+    Benchmarks.benchmarkLookup(bench.symbol) should be theSameInstanceAs bench;
+    val pp = FanoutRequest(numberOfSubscribers = 16, numberOfRounds = 100000L);
+    val space = bench.space.asInstanceOf[ParameterSpacePB[FanoutRequest]];
+    val descr = space.describe(pp);
+    val csv = descr.toCSV;
+    Console.err.println(s"Fanout CSV: $csv");
+    val pp_deser = space.paramsFromCSV(csv);
+    pp_deser should equal(pp);
+  }
+
+  test("Big should CSV rountrip") {
+    val bench = Benchmarks.big;
+    Benchmarks.benchmarkLookup(bench.symbol) should be theSameInstanceAs bench;
+    val pp = BigRequest(numberOfActors = 128, numberOfPings = 10000L, seed = 42L);
+    val space = bench.space.asInstanceOf[ParameterSpacePB[BigRequest]];
+    val descr = space.describe(pp);
+    val csv = descr.toCSV;
+    Console.err.println(s"Big CSV: $csv");
+    val pp_deser = space.paramsFromCSV(csv);
+    pp_deser should equal(pp);
+  }
+
+  test("Bank should CSV rountrip") {
+    val bench = Benchmarks.bank;
+    Benchmarks.benchmarkLookup(bench.symbol) should be theSameInstanceAs bench;
+    val pp = BankRequest(numberOfAccounts = 128, numberOfTransactions = 100000L, seed = 42L);
+    val space = bench.space.asInstanceOf[ParameterSpacePB[BankRequest]];
+    val descr = space.describe(pp);
+    val csv = descr.toCSV;
+    Console.err.println(s"Bank CSV: $csv");
+    val pp_deser = space.paramsFromCSV(csv);
+    pp_deser should equal(pp);
+  }
+
+  test("ProducerConsumer should CSV rountrip") {
+    val bench = Benchmarks.producerConsumer;
+    Benchmarks.benchmarkLookup(bench.symbol) should be theSameInstanceAs bench;
+    val pp = ProducerConsumerRequest(
+      bufferSize = 10,
+      numberOfProducers = 4,
+      numberOfConsumers = 16,
+      numberOfItems = 100000L,
+      workPerItem = 100L
+    );
+    val space = bench.space.asInstanceOf[ParameterSpacePB[ProducerConsumerRequest]];
+    val descr = space.describe(pp);
+    val csv = descr.toCSV;
+    Console.err.println(s"ProducerConsumer CSV: $csv");
+    val pp_deser = space.paramsFromCSV(csv);
+    pp_deser should equal(pp);
+  }
+
+  test("DiningPhilosophers should CSV rountrip") {
+    val bench = Benchmarks.diningPhilosophers;
+    Benchmarks.benchmarkLookup(bench.symbol) should be theSameInstanceAs bench;
+    val pp = DiningPhilosophersRequest(numberOfPhilosophers = 16, numberOfRounds = 10000L);
+    val space = bench.space.asInstanceOf[ParameterSpacePB[DiningPhilosophersRequest]];
+    val descr = space.describe(pp);
+    val csv = descr.toCSV;
+    Console.err.println(s"DiningPhilosophers CSV: $csv");
+    val pp_deser = space.paramsFromCSV(csv);
+    pp_deser should equal(pp);
+  }
+
+  test("NQueens should CSV rountrip") {
+    val bench = Benchmarks.nqueens;
+    Benchmarks.benchmarkLookup(bench.symbol) should be theSameInstanceAs bench;
+    val pp = NQueensRequest(boardSize = 12, numberOfWorkers = 8, splitDepth = 3);
+    val space = bench.space.asInstanceOf[ParameterSpacePB[NQueensRequest]];
+    val descr = space.describe(pp);
+    val csv = descr.toCSV;
+    Console.err.println(s"NQueens CSV: $csv");
+    val pp_deser = space.paramsFromCSV(csv);
+    pp_deser should equal(pp);
+  }
+
+  test("ParallelSort should CSV rountrip") {
+    val bench = Benchmarks.parallelSort;
+    Benchmarks.benchmarkLookup(bench.symbol) should be theSameInstanceAs bench;
+    val pp = ParallelSortRequest(dataSize = 1000000L, threshold = 10000L, seed = 42L);
+    val space = bench.space.asInstanceOf[ParameterSpacePB[ParallelSortRequest]];
+    val descr = space.describe(pp);
+    val csv = descr.toCSV;
+    Console.err.println(s"ParallelSort CSV: $csv");
+    val pp_deser = space.paramsFromCSV(csv);
+    pp_deser should equal(pp);
+  }
+
   test("APSP should CSV rountrip") {
     val bench = Benchmarks.allPairsShortestPath;
     Benchmarks.benchmarkLookup(bench.symbol) should be theSameInstanceAs bench;
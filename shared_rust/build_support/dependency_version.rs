@@ -0,0 +1,68 @@
+//! Shared by the build scripts of the implementation crates, via `#[path]`.
+//!
+//! Exposes the resolved version of a dependency as a compile-time environment variable,
+//! so the result records can't drift from what was actually built.
+
+use std::{env, fs, path::PathBuf};
+
+/// Sets `var` to the version of `dependency` for the crate being built.
+///
+/// Git dependencies are described by their commit, everything else by its version.
+/// Prefers the resolved version in `Cargo.lock`, and falls back to the requirement
+/// in `Cargo.toml` if there is no lock file yet.
+pub fn export(dependency: &str, var: &str) {
+    let manifest_dir =
+        PathBuf::from(env::var("CARGO_MANIFEST_DIR").expect("CARGO_MANIFEST_DIR is not set"));
+    let lock = manifest_dir.join("Cargo.lock");
+    let manifest = manifest_dir.join("Cargo.toml");
+    println!("cargo:rerun-if-changed={}", lock.display());
+    println!("cargo:rerun-if-changed={}", manifest.display());
+    let version = fs::read_to_string(&lock)
+        .ok()
+        .and_then(|content| from_lock(&content, dependency))
+        .or_else(|| {
+            fs::read_to_string(&manifest)
+                .ok()
+                .and_then(|content| from_manifest(&content, dependency))
+        })
+        .unwrap_or_else(|| panic!("Could not determine the version of {}", dependency));
+    println!("cargo:rustc-env={}={}", var, version);
+}
+
+fn quoted_value<'a>(line: &'a str, key: &str) -> Option<&'a str> {
+    let rest = line.trim().strip_prefix(key)?.trim_start().strip_prefix('=')?;
+    let start = rest.find('"')? + 1;
+    let end = start + rest[start..].find('"')?;
+    Some(&rest[start..end])
+}
+
+fn from_lock(content: &str, dependency: &str) -> Option<String> {
+    content.split("[[package]]").find_map(|package| {
+        let mut lines = package.lines();
+        if lines.clone().find_map(|l| quoted_value(l, "name")) != Some(dependency) {
+            return None;
+        }
+        let source = lines.clone().find_map(|l| quoted_value(l, "source"));
+        match source {
+            Some(s) if s.starts_with("git+") => s.rsplit('#').next().map(str::to_string),
+            _ => lines.find_map(|l| quoted_value(l, "version")).map(str::to_string),
+        }
+    })
+}
+
+fn from_manifest(content: &str, dependency: &str) -> Option<String> {
+    content
+        .lines()
+        .filter(|l| !l.trim_start().starts_with('#'))
+        .find(|l| l.split(|c: char| c.is_whitespace() || c == '=').next() == Some(dependency))
+        .and_then(|line| {
+            let spec = &line[line.find('=')? + 1..];
+            if spec.trim_start().starts_with('{') {
+                ["rev", "tag", "branch", "version"].iter().find_map(|key| {
+                    spec.find(key).and_then(|i| quoted_value(&spec[i..], key)).map(str::to_string)
+                })
+            } else {
+                quoted_value(line, dependency).map(str::to_string)
+            }
+        })
+}
@@ -0,0 +1,213 @@
+use super::*;
+use crate::{
+    downcast_msg, helpers::chameneos::ChameneosColour,
+    kompics_benchmarks::benchmarks::ChameneosRequest,
+};
+use std::convert::TryInto;
+
+pub struct Chameneos<S> {
+    _marker: PhantomData<fn() -> S>,
+}
+impl<S> Default for Chameneos<S> {
+    fn default() -> Self { Chameneos { _marker: PhantomData } }
+}
+
+impl<S: ActorSystem> Benchmark for Chameneos<S> {
+    type Conf = ChameneosRequest;
+    type Instance = ChameneosI<S>;
+
+    const LABEL: &'static str = "Chameneos";
+
+    fn msg_to_conf(msg: Box<dyn (::protobuf::Message)>) -> Result<Self::Conf, BenchmarkError> {
+        downcast_msg!(msg; ChameneosRequest)
+    }
+
+    fn validate(c: &Self::Conf) -> Result<(), BenchmarkError> { validation::chameneos(c) }
+
+    fn new_instance() -> Self::Instance { ChameneosI::new() }
+}
+
+pub struct ChameneosI<S> {
+    num_chameneos: Option<usize>,
+    num_meetings:  Option<u64>,
+    system:        Option<S>,
+    mall:          Option<ActorRef<MallMsg>>,
+    chameneos:     Vec<ActorRef<ChameneoMsg>>,
+    done:          Option<Reply<()>>,
+}
+
+impl<S: ActorSystem> ChameneosI<S> {
+    fn new() -> ChameneosI<S> {
+        ChameneosI {
+            num_chameneos: None,
+            num_meetings:  None,
+            system:        None,
+            mall:          None,
+            chameneos:     Vec::new(),
+            done:          None,
+        }
+    }
+}
+
+impl<S: ActorSystem> BenchmarkInstance for ChameneosI<S> {
+    type Conf = ChameneosRequest;
+
+    fn setup(&mut self, c: &Self::Conf) -> () {
+        self.num_chameneos = Some(c.number_of_chameneos.try_into().unwrap());
+        self.num_meetings = Some(c.number_of_meetings);
+        self.system = Some(S::start("chameneos", num_cpus::get()));
+    }
+
+    fn prepare_iteration(&mut self) -> () {
+        match (self.num_chameneos, self.num_meetings, self.system.as_ref()) {
+            (Some(num_chameneos), Some(num_meetings), Some(system)) => {
+                let (done_ref, done) = reply_to();
+                let mall = system.spawn(Mall::with(num_meetings, num_chameneos, done_ref));
+                for i in 0usize..num_chameneos {
+                    let chameneo =
+                        system.spawn(Chameneo::with(mall.clone(), ChameneosColour::for_id(i)));
+                    self.chameneos.push(chameneo);
+                }
+                self.mall = Some(mall);
+                self.done = Some(done);
+            },
+            _ => unimplemented!(),
+        }
+    }
+
+    fn run_iteration(&mut self) -> () {
+        let done = self.done.take().unwrap();
+        for chameneo in self.chameneos.drain(..) {
+            chameneo.tell(ChameneoMsg::Start);
+        }
+        done.wait();
+    }
+
+    fn cleanup_iteration(&mut self, last_iteration: bool, _exec_time_millis: f64) -> () {
+        // the chameneos stop themselves
+        if let Some(mall) = self.mall.take() {
+            mall.stop();
+        }
+        if last_iteration {
+            let system = self.system.take().unwrap();
+            system.shutdown().expect("System didn't shut down properly");
+            self.num_chameneos = None;
+            self.num_meetings = None;
+        }
+    }
+}
+
+pub enum MallMsg {
+    MeetingCount(u64),
+    Meet { colour: ChameneosColour, chameneo: ActorRef<ChameneoMsg> },
+}
+
+pub enum ChameneoMsg {
+    Start,
+    Meet { colour: ChameneosColour, chameneo: ActorRef<ChameneoMsg> },
+    Change { colour: ChameneosColour },
+    Exit,
+}
+
+struct Mall {
+    num_meetings:     u64,
+    num_chameneos:    usize,
+    done:             ActorRef<()>,
+    waiting_chameneo: Option<ActorRef<ChameneoMsg>>,
+    sum_meetings:     u64,
+    meetings_count:   u64,
+    num_faded:        usize,
+}
+
+impl Mall {
+    fn with(num_meetings: u64, num_chameneos: usize, done: ActorRef<()>) -> Mall {
+        Mall {
+            num_meetings,
+            num_chameneos,
+            done,
+            waiting_chameneo: None,
+            sum_meetings: 0u64,
+            meetings_count: 0u64,
+            num_faded: 0usize,
+        }
+    }
+}
+
+impl Actor for Mall {
+    type Message = MallMsg;
+
+    fn receive<S: ActorSystem>(&mut self, _ctx: &mut Context<'_, S, MallMsg>, msg: MallMsg) -> () {
+        match msg {
+            MallMsg::MeetingCount(count) => {
+                self.num_faded += 1usize;
+                self.sum_meetings += count;
+                if self.num_faded == self.num_chameneos {
+                    debug_assert_eq!(self.sum_meetings, 2u64 * self.meetings_count);
+                    self.done.tell(());
+                }
+            },
+            MallMsg::Meet { colour, chameneo } => {
+                if self.meetings_count < self.num_meetings {
+                    match self.waiting_chameneo.take() {
+                        Some(other) => {
+                            self.meetings_count += 1u64;
+                            other.tell(ChameneoMsg::Meet { colour, chameneo });
+                        },
+                        None => {
+                            self.waiting_chameneo = Some(chameneo);
+                        },
+                    }
+                } else {
+                    chameneo.tell(ChameneoMsg::Exit);
+                }
+            },
+        }
+    }
+}
+
+struct Chameneo {
+    mall:     ActorRef<MallMsg>,
+    colour:   ChameneosColour,
+    meetings: u64,
+}
+
+impl Chameneo {
+    fn with(mall: ActorRef<MallMsg>, initial_colour: ChameneosColour) -> Chameneo {
+        Chameneo { mall, colour: initial_colour, meetings: 0u64 }
+    }
+}
+
+impl Actor for Chameneo {
+    type Message = ChameneoMsg;
+
+    fn receive<S: ActorSystem>(
+        &mut self,
+        ctx: &mut Context<'_, S, ChameneoMsg>,
+        msg: ChameneoMsg,
+    ) -> () {
+        match msg {
+            ChameneoMsg::Start => {
+                self.mall
+                    .tell(MallMsg::Meet { colour: self.colour, chameneo: ctx.myself().clone() });
+            },
+            ChameneoMsg::Meet { colour: other_colour, chameneo } => {
+                self.colour = self.colour.complement(other_colour);
+                self.meetings += 1u64;
+                chameneo.tell(ChameneoMsg::Change { colour: self.colour });
+                self.mall
+                    .tell(MallMsg::Meet { colour: self.colour, chameneo: ctx.myself().clone() });
+            },
+            ChameneoMsg::Change { colour: new_colour } => {
+                self.colour = new_colour;
+                self.meetings += 1u64;
+                self.mall
+                    .tell(MallMsg::Meet { colour: self.colour, chameneo: ctx.myself().clone() });
+            },
+            ChameneoMsg::Exit => {
+                self.colour = ChameneosColour::Faded;
+                self.mall.tell(MallMsg::MeetingCount(self.meetings));
+                ctx.stop();
+            },
+        }
+    }
+}
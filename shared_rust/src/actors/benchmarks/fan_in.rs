@@ -0,0 +1,223 @@
+use super::*;
+use crate::{downcast_msg, kompics_benchmarks::benchmarks::FanInRequest, result_writer};
+use std::convert::TryInto;
+
+pub struct FanIn<S> {
+    _marker: PhantomData<fn() -> S>,
+}
+impl<S> Default for FanIn<S> {
+    fn default() -> Self { FanIn { _marker: PhantomData } }
+}
+
+impl<S: ActorSystem> Benchmark for FanIn<S> {
+    type Conf = FanInRequest;
+    type Instance = FanInI<S>;
+
+    const LABEL: &'static str = "FanIn";
+
+    fn msg_to_conf(msg: Box<dyn (::protobuf::Message)>) -> Result<Self::Conf, BenchmarkError> {
+        downcast_msg!(msg; FanInRequest)
+    }
+
+    fn validate(c: &Self::Conf) -> Result<(), BenchmarkError> { validation::fan_in(c) }
+
+    fn new_instance() -> Self::Instance { FanInI::new() }
+}
+
+pub struct FanInI<S> {
+    num_producers:     Option<usize>,
+    num_messages:      Option<u64>,
+    pipeline_size:     Option<u64>,
+    system:            Option<S>,
+    aggregator:        Option<ActorRef<AggregatorMsg>>,
+    producers:         Vec<ActorRef<ProducerMsg>>,
+    done:              Option<Reply<()>>,
+    total_time_millis: f64,
+    iterations:        u32,
+}
+
+impl<S: ActorSystem> FanInI<S> {
+    fn new() -> FanInI<S> {
+        FanInI {
+            num_producers:     None,
+            num_messages:      None,
+            pipeline_size:     None,
+            system:            None,
+            aggregator:        None,
+            producers:         Vec::new(),
+            done:              None,
+            total_time_millis: 0.0,
+            iterations:        0,
+        }
+    }
+}
+
+impl<S: ActorSystem> BenchmarkInstance for FanInI<S> {
+    type Conf = FanInRequest;
+
+    fn setup(&mut self, c: &Self::Conf) -> () {
+        self.num_producers = Some(c.number_of_producers.try_into().unwrap());
+        self.num_messages = Some(c.messages_per_producer);
+        self.pipeline_size = Some(c.pipeline_size.into());
+        self.total_time_millis = 0.0;
+        self.iterations = 0;
+        self.system = Some(S::start("fanin", num_cpus::get()));
+    }
+
+    fn prepare_iteration(&mut self) -> () {
+        match (self.num_producers, self.num_messages, self.pipeline_size, self.system.as_ref()) {
+            (Some(num_producers), Some(num_messages), Some(pipeline_size), Some(system)) => {
+                let (done_ref, done) = reply_to();
+                let expected = (num_producers as u64) * num_messages;
+                let aggregator =
+                    system.spawn(Aggregator::with(expected, pipeline_size > 0, done_ref));
+                for id in 0usize..num_producers {
+                    let producer = system.spawn(Producer::with(
+                        id,
+                        num_messages,
+                        pipeline_size,
+                        aggregator.clone(),
+                    ));
+                    self.producers.push(producer);
+                }
+                // handled before any item, since the producers only send once started
+                aggregator.tell(AggregatorMsg::Producers(self.producers.clone()));
+                self.aggregator = Some(aggregator);
+                self.done = Some(done);
+            },
+            _ => unimplemented!(),
+        }
+    }
+
+    fn run_iteration(&mut self) -> () {
+        let done = self.done.take().unwrap();
+        for producer in self.producers.iter() {
+            producer.tell(ProducerMsg::Start);
+        }
+        done.wait();
+    }
+
+    fn cleanup_iteration(&mut self, last_iteration: bool, exec_time_millis: f64) -> () {
+        for producer in self.producers.drain(..) {
+            producer.stop();
+        }
+        self.aggregator.take().unwrap().stop();
+        self.total_time_millis += exec_time_millis;
+        self.iterations += 1;
+
+        if last_iteration {
+            let messages = (self.num_producers.unwrap() as f64)
+                * (self.num_messages.unwrap() as f64)
+                * (self.iterations as f64);
+            result_writer::record_metric(
+                "messages_per_second",
+                messages / (self.total_time_millis / 1000.0),
+            );
+            let system = self.system.take().unwrap();
+            system.shutdown().expect("System didn't shut down properly");
+            self.num_producers = None;
+            self.num_messages = None;
+            self.pipeline_size = None;
+        }
+    }
+}
+
+pub enum AggregatorMsg {
+    Producers(Vec<ActorRef<ProducerMsg>>),
+    Item { producer: usize },
+}
+
+pub enum ProducerMsg {
+    Start,
+    Ack,
+}
+
+struct Aggregator {
+    expected:  u64,
+    received:  u64,
+    ack:       bool,
+    producers: Vec<ActorRef<ProducerMsg>>,
+    done:      ActorRef<()>,
+}
+
+impl Aggregator {
+    fn with(expected: u64, ack: bool, done: ActorRef<()>) -> Aggregator {
+        Aggregator { expected, received: 0u64, ack, producers: Vec::new(), done }
+    }
+}
+
+impl Actor for Aggregator {
+    type Message = AggregatorMsg;
+
+    fn receive<S: ActorSystem>(
+        &mut self,
+        _ctx: &mut Context<'_, S, AggregatorMsg>,
+        msg: AggregatorMsg,
+    ) -> () {
+        match msg {
+            AggregatorMsg::Producers(producers) => {
+                self.producers = producers;
+            },
+            AggregatorMsg::Item { producer } => {
+                self.received += 1u64;
+                if self.ack {
+                    self.producers[producer].tell(ProducerMsg::Ack);
+                }
+                if self.received == self.expected {
+                    // the producers hold a reference to the aggregator as well
+                    self.producers.clear();
+                    self.done.tell(());
+                }
+            },
+        }
+    }
+}
+
+/// Sends its messages all at once if `pipeline_size == 0`, and otherwise keeps at most
+/// `pipeline_size` of them unacknowledged.
+struct Producer {
+    id:            usize,
+    remaining:     u64,
+    pipeline_size: u64,
+    aggregator:    ActorRef<AggregatorMsg>,
+}
+
+impl Producer {
+    fn with(
+        id: usize,
+        num_messages: u64,
+        pipeline_size: u64,
+        aggregator: ActorRef<AggregatorMsg>,
+    ) -> Producer {
+        Producer { id, remaining: num_messages, pipeline_size, aggregator }
+    }
+
+    fn send(&mut self, n: u64) -> () {
+        let n = n.min(self.remaining);
+        for _ in 0u64..n {
+            self.aggregator.tell(AggregatorMsg::Item { producer: self.id });
+        }
+        self.remaining -= n;
+    }
+}
+
+impl Actor for Producer {
+    type Message = ProducerMsg;
+
+    fn receive<S: ActorSystem>(
+        &mut self,
+        _ctx: &mut Context<'_, S, ProducerMsg>,
+        msg: ProducerMsg,
+    ) -> () {
+        match msg {
+            ProducerMsg::Start => {
+                if self.pipeline_size == 0 {
+                    self.send(self.remaining);
+                } else {
+                    self.send(self.pipeline_size);
+                }
+            },
+            ProducerMsg::Ack => self.send(1u64),
+        }
+    }
+}
@@ -0,0 +1,141 @@
+use super::*;
+use crate::{downcast_msg, kompics_benchmarks::benchmarks::FibonacciRequest};
+
+pub struct Fibonacci<S> {
+    _marker: PhantomData<fn() -> S>,
+}
+impl<S> Default for Fibonacci<S> {
+    fn default() -> Self { Fibonacci { _marker: PhantomData } }
+}
+
+impl<S: ActorSystem> Benchmark for Fibonacci<S> {
+    type Conf = FibonacciRequest;
+    type Instance = FibonacciI<S>;
+
+    const LABEL: &'static str = "Fibonacci";
+
+    fn msg_to_conf(msg: Box<dyn (::protobuf::Message)>) -> Result<Self::Conf, BenchmarkError> {
+        downcast_msg!(msg; FibonacciRequest)
+    }
+
+    fn validate(c: &Self::Conf) -> Result<(), BenchmarkError> { validation::fibonacci(c) }
+
+    fn new_instance() -> Self::Instance { FibonacciI::new() }
+}
+
+pub struct FibonacciI<S> {
+    fib_number: Option<u32>,
+    system:     Option<S>,
+    fib:        Option<ActorRef<FibonacciMsg>>,
+    result:     Option<Reply<u64>>,
+}
+
+impl<S: ActorSystem> FibonacciI<S> {
+    fn new() -> FibonacciI<S> {
+        FibonacciI { fib_number: None, system: None, fib: None, result: None }
+    }
+}
+
+impl<S: ActorSystem> BenchmarkInstance for FibonacciI<S> {
+    type Conf = FibonacciRequest;
+
+    fn setup(&mut self, c: &Self::Conf) -> () {
+        self.fib_number = Some(c.fib_number);
+        self.system = Some(S::start("fibonacci", num_cpus::get()));
+    }
+
+    fn prepare_iteration(&mut self) -> () {
+        match self.system {
+            Some(ref system) => {
+                let (result_ref, result) = reply_to();
+                self.fib = Some(system.spawn(FibonacciActor::with(ResultTarget::Done(result_ref))));
+                self.result = Some(result);
+            },
+            None => unimplemented!(),
+        }
+    }
+
+    fn run_iteration(&mut self) -> () {
+        match (self.fib_number, self.fib.take()) {
+            (Some(fib_number), Some(fib)) => {
+                let result = self.result.take().unwrap();
+                fib.tell(FibonacciMsg::Request { n: fib_number });
+                let _ = result.wait();
+            },
+            _ => unimplemented!(),
+        }
+    }
+
+    fn cleanup_iteration(&mut self, last_iteration: bool, _exec_time_millis: f64) -> () {
+        // the actors stop themselves once they have reported their result
+        if last_iteration {
+            let system = self.system.take().unwrap();
+            system.shutdown().expect("System didn't shut down properly");
+            self.fib_number = None;
+        }
+    }
+}
+
+pub enum FibonacciMsg {
+    Request { n: u32 },
+    Response { value: u64 },
+}
+
+enum ResultTarget {
+    Parent(ActorRef<FibonacciMsg>),
+    Done(ActorRef<u64>),
+}
+
+struct FibonacciActor {
+    report_to:     ResultTarget,
+    result:        u64,
+    num_responses: u8,
+}
+
+impl FibonacciActor {
+    fn with(report_to: ResultTarget) -> FibonacciActor {
+        FibonacciActor { report_to, result: 0u64, num_responses: 0u8 }
+    }
+
+    fn send_result<S: ActorSystem>(&mut self, ctx: &mut Context<'_, S, FibonacciMsg>, value: u64) {
+        match self.report_to {
+            ResultTarget::Parent(ref parent) => parent.tell(FibonacciMsg::Response { value }),
+            ResultTarget::Done(ref done) => done.tell(value),
+        }
+        ctx.stop();
+    }
+}
+
+impl Actor for FibonacciActor {
+    type Message = FibonacciMsg;
+
+    fn receive<S: ActorSystem>(
+        &mut self,
+        ctx: &mut Context<'_, S, FibonacciMsg>,
+        msg: FibonacciMsg,
+    ) -> () {
+        match msg {
+            FibonacciMsg::Request { n } => {
+                if n <= 2u32 {
+                    self.send_result(ctx, 1u64);
+                } else {
+                    let f1 =
+                        ctx.spawn(FibonacciActor::with(ResultTarget::Parent(ctx.myself().clone())));
+                    f1.tell(FibonacciMsg::Request { n: n - 1u32 });
+                    let f2 =
+                        ctx.spawn(FibonacciActor::with(ResultTarget::Parent(ctx.myself().clone())));
+                    f2.tell(FibonacciMsg::Request { n: n - 2u32 });
+                }
+            },
+            FibonacciMsg::Response { value } => {
+                self.num_responses += 1u8;
+                self.result += value;
+
+                if self.num_responses == 2u8 {
+                    let result = self.result;
+                    self.send_result(ctx, result);
+                }
+            },
+        }
+    }
+}
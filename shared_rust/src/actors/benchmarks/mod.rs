@@ -0,0 +1,133 @@
+//! Benchmarks written once against the [actors](super) abstraction.
+use super::*;
+use crate::{benchmark::*, helpers::validation};
+use std::marker::PhantomData;
+
+pub mod chameneos;
+pub mod fan_in;
+pub mod fibonacci;
+pub mod nqueens;
+pub mod parallel_sort;
+pub mod pingpong;
+pub mod producer_consumer;
+
+/// Offers the benchmarks of this module, run on the framework behind `S`.
+pub struct GenericFactory<S> {
+    _marker: PhantomData<fn() -> S>,
+}
+impl<S: ActorSystem> GenericFactory<S> {
+    pub fn new() -> GenericFactory<S> { GenericFactory { _marker: PhantomData } }
+}
+impl<S> Clone for GenericFactory<S> {
+    fn clone(&self) -> Self { GenericFactory { _marker: PhantomData } }
+}
+
+impl<S: ActorSystem> BenchmarkFactory for GenericFactory<S> {
+    fn by_label(&self, label: &str) -> Result<AbstractBench, NotImplementedError> {
+        // the labels depend on `S`, so they can't be used as patterns
+        match label {
+            l if l == pingpong::PingPong::<S>::LABEL => self.ping_pong().map_into(),
+            l if l == fibonacci::Fibonacci::<S>::LABEL => self.fibonacci().map_into(),
+            l if l == chameneos::Chameneos::<S>::LABEL => self.chameneos().map_into(),
+            l if l == fan_in::FanIn::<S>::LABEL => self.fan_in().map_into(),
+            l if l == producer_consumer::ProducerConsumer::<S>::LABEL => {
+                self.producer_consumer().map_into()
+            },
+            l if l == nqueens::NQueens::<S>::LABEL => self.nqueens().map_into(),
+            l if l == parallel_sort::ParallelSort::<S>::LABEL => self.parallel_sort().map_into(),
+            _ => Err(NotImplementedError::NotFound),
+        }
+    }
+
+    fn box_clone(&self) -> Box<dyn BenchmarkFactory> { Box::new(GenericFactory::<S>::new()) }
+
+    fn ping_pong(&self) -> Result<Box<dyn AbstractBenchmark>, NotImplementedError> {
+        Ok(pingpong::PingPong::<S>::default().into())
+    }
+
+    fn net_ping_pong(&self) -> Result<Box<dyn AbstractDistributedBenchmark>, NotImplementedError> {
+        Err(NotImplementedError::NotImplementable)
+    }
+
+    fn throughput_ping_pong(&self) -> Result<Box<dyn AbstractBenchmark>, NotImplementedError> {
+        Err(NotImplementedError::FutureWork)
+    }
+
+    fn net_throughput_ping_pong(
+        &self,
+    ) -> Result<Box<dyn AbstractDistributedBenchmark>, NotImplementedError> {
+        Err(NotImplementedError::NotImplementable)
+    }
+
+    fn atomic_register(
+        &self,
+    ) -> Result<Box<dyn AbstractDistributedBenchmark>, NotImplementedError> {
+        Err(NotImplementedError::NotImplementable)
+    }
+
+    fn streaming_windows(
+        &self,
+    ) -> Result<Box<dyn AbstractDistributedBenchmark>, NotImplementedError> {
+        Err(NotImplementedError::NotImplementable)
+    }
+
+    fn fibonacci(&self) -> Result<Box<dyn AbstractBenchmark>, NotImplementedError> {
+        Ok(fibonacci::Fibonacci::<S>::default().into())
+    }
+
+    fn chameneos(&self) -> Result<Box<dyn AbstractBenchmark>, NotImplementedError> {
+        Ok(chameneos::Chameneos::<S>::default().into())
+    }
+
+    fn all_pairs_shortest_path(&self) -> Result<Box<dyn AbstractBenchmark>, NotImplementedError> {
+        Err(NotImplementedError::FutureWork)
+    }
+
+    fn atomic_broadcast(
+        &self,
+    ) -> Result<Box<dyn AbstractDistributedBenchmark>, NotImplementedError> {
+        Err(NotImplementedError::NotImplementable)
+    }
+
+    fn sized_throughput(
+        &self,
+    ) -> Result<Box<dyn AbstractDistributedBenchmark>, NotImplementedError> {
+        Err(NotImplementedError::NotImplementable)
+    }
+
+    fn thread_ring(&self) -> Result<Box<dyn AbstractBenchmark>, NotImplementedError> {
+        Err(NotImplementedError::FutureWork)
+    }
+
+    fn fan_in(&self) -> Result<Box<dyn AbstractBenchmark>, NotImplementedError> {
+        Ok(fan_in::FanIn::<S>::default().into())
+    }
+
+    fn fanout(&self) -> Result<Box<dyn AbstractBenchmark>, NotImplementedError> {
+        Err(NotImplementedError::FutureWork)
+    }
+
+    fn big(&self) -> Result<Box<dyn AbstractBenchmark>, NotImplementedError> {
+        Err(NotImplementedError::FutureWork)
+    }
+
+    fn bank(&self) -> Result<Box<dyn AbstractBenchmark>, NotImplementedError> {
+        Err(NotImplementedError::FutureWork)
+    }
+
+    fn producer_consumer(&self) -> Result<Box<dyn AbstractBenchmark>, NotImplementedError> {
+        Ok(producer_consumer::ProducerConsumer::<S>::default().into())
+    }
+
+    fn dining_philosophers(&self) -> Result<Box<dyn AbstractBenchmark>, NotImplementedError> {
+        Err(NotImplementedError::FutureWork)
+    }
+
+    fn nqueens(&self) -> Result<Box<dyn AbstractBenchmark>, NotImplementedError> {
+        Ok(nqueens::NQueens::<S>::default().into())
+    }
+
+    fn parallel_sort(&self) -> Result<Box<dyn AbstractBenchmark>, NotImplementedError> {
+        Ok(parallel_sort::ParallelSort::<S>::default().into())
+    }
+}
@@ -0,0 +1,230 @@
+use super::*;
+use crate::{
+    downcast_msg,
+    helpers::nqueens::{known_solutions, Board},
+    kompics_benchmarks::benchmarks::NQueensRequest,
+};
+use std::{collections::VecDeque, convert::TryInto};
+
+pub struct NQueens<S> {
+    _marker: PhantomData<fn() -> S>,
+}
+impl<S> Default for NQueens<S> {
+    fn default() -> Self { NQueens { _marker: PhantomData } }
+}
+
+impl<S: ActorSystem> Benchmark for NQueens<S> {
+    type Conf = NQueensRequest;
+    type Instance = NQueensI<S>;
+
+    const LABEL: &'static str = "NQueens";
+
+    fn msg_to_conf(msg: Box<dyn (::protobuf::Message)>) -> Result<Self::Conf, BenchmarkError> {
+        downcast_msg!(msg; NQueensRequest)
+    }
+
+    fn validate(c: &Self::Conf) -> Result<(), BenchmarkError> { validation::nqueens(c) }
+
+    fn new_instance() -> Self::Instance { NQueensI::new() }
+}
+
+pub struct NQueensI<S> {
+    board_size:  Option<usize>,
+    num_workers: Option<usize>,
+    split_depth: Option<usize>,
+    system:      Option<S>,
+    master:      Option<ActorRef<MasterMsg>>,
+    workers:     Vec<ActorRef<WorkerMsg>>,
+    done:        Option<Reply<u64>>,
+    solutions:   Option<u64>,
+}
+
+impl<S: ActorSystem> NQueensI<S> {
+    fn new() -> NQueensI<S> {
+        NQueensI {
+            board_size:  None,
+            num_workers: None,
+            split_depth: None,
+            system:      None,
+            master:      None,
+            workers:     Vec::new(),
+            done:        None,
+            solutions:   None,
+        }
+    }
+}
+
+impl<S: ActorSystem> BenchmarkInstance for NQueensI<S> {
+    type Conf = NQueensRequest;
+
+    fn setup(&mut self, c: &Self::Conf) -> () {
+        self.board_size = Some(c.board_size.try_into().unwrap());
+        self.num_workers = Some(c.number_of_workers.try_into().unwrap());
+        self.split_depth = Some(c.split_depth.try_into().unwrap());
+        self.system = Some(S::start("nqueens", num_cpus::get()));
+    }
+
+    fn prepare_iteration(&mut self) -> () {
+        match (self.board_size, self.num_workers, self.split_depth, self.system.as_ref()) {
+            (Some(board_size), Some(num_workers), Some(split_depth), Some(system)) => {
+                let (done_ref, done) = reply_to();
+                let master = system.spawn(Master::with(board_size, split_depth, done_ref));
+                for id in 0usize..num_workers {
+                    let worker = system.spawn(Worker::with(id, master.clone()));
+                    self.workers.push(worker);
+                }
+                master.tell(MasterMsg::Workers(self.workers.clone()));
+                self.master = Some(master);
+                self.done = Some(done);
+            },
+            _ => unimplemented!(),
+        }
+    }
+
+    fn run_iteration(&mut self) -> () {
+        match self.master {
+            Some(ref master) => {
+                let done = self.done.take().unwrap();
+                master.tell(MasterMsg::Start);
+                self.solutions = Some(done.wait());
+            },
+            None => unimplemented!(),
+        }
+    }
+
+    fn cleanup_iteration(&mut self, last_iteration: bool, _exec_time_millis: f64) -> () {
+        for worker in self.workers.drain(..) {
+            worker.stop();
+        }
+        self.master.take().unwrap().stop();
+        let solutions = self.solutions.take().expect("No solutions were reported");
+        let board_size = self.board_size.unwrap();
+        assert_eq!(
+            Some(solutions),
+            known_solutions(board_size),
+            "Wrong number of solutions for board size {}",
+            board_size
+        );
+
+        if last_iteration {
+            let system = self.system.take().unwrap();
+            system.shutdown().expect("System didn't shut down properly");
+            self.board_size = None;
+            self.num_workers = None;
+            self.split_depth = None;
+        }
+    }
+}
+
+pub enum MasterMsg {
+    Workers(Vec<ActorRef<WorkerMsg>>),
+    Start,
+    /// The solutions of the worker's last unit, which also asks for the next one.
+    Result {
+        worker:    usize,
+        solutions: u64,
+    },
+}
+
+pub enum WorkerMsg {
+    Work(Board),
+}
+
+/// Hands out the units one at a time, to whichever worker has just finished its last one.
+struct Master {
+    board_size:  usize,
+    split_depth: usize,
+    units:       VecDeque<Board>,
+    solutions:   u64,
+    busy:        usize,
+    workers:     Vec<ActorRef<WorkerMsg>>,
+    done:        ActorRef<u64>,
+}
+
+impl Master {
+    fn with(board_size: usize, split_depth: usize, done: ActorRef<u64>) -> Master {
+        Master {
+            board_size,
+            split_depth,
+            units: VecDeque::new(),
+            solutions: 0u64,
+            busy: 0usize,
+            workers: Vec::new(),
+            done,
+        }
+    }
+
+    fn assign(&mut self, worker: usize) -> () {
+        if let Some(unit) = self.units.pop_front() {
+            self.workers[worker].tell(WorkerMsg::Work(unit));
+            self.busy += 1;
+        }
+    }
+
+    fn finish(&mut self) -> () {
+        self.done.tell(self.solutions);
+        // the workers hold a reference to the master as well
+        self.workers.clear();
+    }
+}
+
+impl Actor for Master {
+    type Message = MasterMsg;
+
+    fn receive<S: ActorSystem>(
+        &mut self,
+        _ctx: &mut Context<'_, S, MasterMsg>,
+        msg: MasterMsg,
+    ) -> () {
+        match msg {
+            MasterMsg::Workers(workers) => {
+                self.workers = workers;
+            },
+            MasterMsg::Start => {
+                self.units = Board::empty(self.board_size).split(self.split_depth).into();
+                if self.units.is_empty() {
+                    // no partial board survives the split, e.g. for board sizes 2 and 3
+                    self.finish();
+                } else {
+                    for worker in 0..self.workers.len() {
+                        self.assign(worker);
+                    }
+                }
+            },
+            MasterMsg::Result { worker, solutions } => {
+                self.solutions += solutions;
+                self.busy -= 1;
+                self.assign(worker);
+                if self.busy == 0 {
+                    self.finish();
+                }
+            },
+        }
+    }
+}
+
+struct Worker {
+    id:     usize,
+    master: ActorRef<MasterMsg>,
+}
+
+impl Worker {
+    fn with(id: usize, master: ActorRef<MasterMsg>) -> Worker { Worker { id, master } }
+}
+
+impl Actor for Worker {
+    type Message = WorkerMsg;
+
+    fn receive<S: ActorSystem>(
+        &mut self,
+        _ctx: &mut Context<'_, S, WorkerMsg>,
+        msg: WorkerMsg,
+    ) -> () {
+        match msg {
+            WorkerMsg::Work(unit) => {
+                let solutions = unit.count_solutions();
+                self.master.tell(MasterMsg::Result { worker: self.id, solutions });
+            },
+        }
+    }
+}
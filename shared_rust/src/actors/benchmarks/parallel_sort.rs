@@ -0,0 +1,172 @@
+use super::*;
+use crate::{
+    downcast_msg,
+    helpers::parallel_sort::{check_sorted, merge, random_data, Half},
+    kompics_benchmarks::benchmarks::ParallelSortRequest,
+};
+use std::convert::TryInto;
+
+pub struct ParallelSort<S> {
+    _marker: PhantomData<fn() -> S>,
+}
+impl<S> Default for ParallelSort<S> {
+    fn default() -> Self { ParallelSort { _marker: PhantomData } }
+}
+
+impl<S: ActorSystem> Benchmark for ParallelSort<S> {
+    type Conf = ParallelSortRequest;
+    type Instance = ParallelSortI<S>;
+
+    const LABEL: &'static str = "ParallelSort";
+
+    fn msg_to_conf(msg: Box<dyn (::protobuf::Message)>) -> Result<Self::Conf, BenchmarkError> {
+        downcast_msg!(msg; ParallelSortRequest)
+    }
+
+    fn validate(c: &Self::Conf) -> Result<(), BenchmarkError> { validation::parallel_sort(c) }
+
+    fn new_instance() -> Self::Instance { ParallelSortI::new() }
+}
+
+pub struct ParallelSortI<S> {
+    data_size: Option<usize>,
+    threshold: Option<usize>,
+    seed:      Option<u64>,
+    system:    Option<S>,
+    data:      Option<Vec<u64>>,
+    sorter:    Option<ActorRef<SorterMsg>>,
+    result:    Option<Reply<Vec<u64>>>,
+    sorted:    Option<Vec<u64>>,
+}
+
+impl<S: ActorSystem> ParallelSortI<S> {
+    fn new() -> ParallelSortI<S> {
+        ParallelSortI {
+            data_size: None,
+            threshold: None,
+            seed:      None,
+            system:    None,
+            data:      None,
+            sorter:    None,
+            result:    None,
+            sorted:    None,
+        }
+    }
+}
+
+impl<S: ActorSystem> BenchmarkInstance for ParallelSortI<S> {
+    type Conf = ParallelSortRequest;
+
+    fn setup(&mut self, c: &Self::Conf) -> () {
+        self.data_size = Some(c.data_size.try_into().unwrap());
+        self.threshold = Some(c.threshold.try_into().unwrap());
+        self.seed = Some(c.seed);
+        self.system = Some(S::start("parallel_sort", num_cpus::get()));
+    }
+
+    fn prepare_iteration(&mut self) -> () {
+        match (self.data_size, self.threshold, self.seed, self.system.as_ref()) {
+            (Some(data_size), Some(threshold), Some(seed), Some(system)) => {
+                self.data = Some(random_data(data_size, seed));
+                let (result_ref, result) = reply_to();
+                self.sorter = Some(system.spawn(Sorter::with(threshold, Parent::Done(result_ref))));
+                self.result = Some(result);
+            },
+            _ => unimplemented!(),
+        }
+    }
+
+    fn run_iteration(&mut self) -> () {
+        match (self.data.take(), self.sorter.take()) {
+            (Some(data), Some(sorter)) => {
+                let result = self.result.take().unwrap();
+                sorter.tell(SorterMsg::Sort(data));
+                self.sorted = Some(result.wait());
+            },
+            _ => unimplemented!(),
+        }
+    }
+
+    fn cleanup_iteration(&mut self, last_iteration: bool, _exec_time_millis: f64) -> () {
+        // the actors stop themselves once they have reported their part
+        let sorted = self.sorted.take().expect("No sorted data was returned");
+        check_sorted(&sorted, self.data_size.unwrap());
+
+        if last_iteration {
+            let system = self.system.take().unwrap();
+            system.shutdown().expect("System didn't shut down properly");
+            self.data_size = None;
+            self.threshold = None;
+            self.seed = None;
+        }
+    }
+}
+
+pub enum SorterMsg {
+    Sort(Vec<u64>),
+    Sorted { half: Half, data: Vec<u64> },
+}
+
+enum Parent {
+    Sorter(ActorRef<SorterMsg>, Half),
+    Done(ActorRef<Vec<u64>>),
+}
+
+/// Sorts its part directly if it is small enough, and otherwise has two children sort
+/// the halves and merges their results.
+struct Sorter {
+    threshold: usize,
+    parent:    Parent,
+    left:      Option<Vec<u64>>,
+    right:     Option<Vec<u64>>,
+}
+
+impl Sorter {
+    fn with(threshold: usize, parent: Parent) -> Sorter {
+        Sorter { threshold, parent, left: None, right: None }
+    }
+
+    fn send_result<S: ActorSystem>(&mut self, ctx: &mut Context<'_, S, SorterMsg>, data: Vec<u64>) {
+        match self.parent {
+            Parent::Sorter(ref parent, half) => parent.tell(SorterMsg::Sorted { half, data }),
+            Parent::Done(ref done) => done.tell(data),
+        }
+        ctx.stop();
+    }
+}
+
+impl Actor for Sorter {
+    type Message = SorterMsg;
+
+    fn receive<S: ActorSystem>(
+        &mut self,
+        ctx: &mut Context<'_, S, SorterMsg>,
+        msg: SorterMsg,
+    ) -> () {
+        match msg {
+            SorterMsg::Sort(mut data) => {
+                if data.len() <= self.threshold {
+                    data.sort_unstable();
+                    self.send_result(ctx, data);
+                } else {
+                    let right = data.split_off(data.len() / 2);
+                    for (half, part) in vec![(Half::Left, data), (Half::Right, right)] {
+                        let parent = Parent::Sorter(ctx.myself().clone(), half);
+                        let child = ctx.spawn(Sorter::with(self.threshold, parent));
+                        child.tell(SorterMsg::Sort(part));
+                    }
+                }
+            },
+            SorterMsg::Sorted { half, data } => {
+                match half {
+                    Half::Left => self.left = Some(data),
+                    Half::Right => self.right = Some(data),
+                }
+                if self.left.is_some() && self.right.is_some() {
+                    let merged = merge(self.left.take().unwrap(), self.right.take().unwrap());
+                    self.send_result(ctx, merged);
+                }
+            },
+        }
+    }
+}
@@ -0,0 +1,136 @@
+use super::*;
+use crate::{downcast_msg, kompics_benchmarks::benchmarks::PingPongRequest};
+
+pub struct PingPong<S> {
+    _marker: PhantomData<fn() -> S>,
+}
+impl<S> Default for PingPong<S> {
+    fn default() -> Self { PingPong { _marker: PhantomData } }
+}
+
+impl<S: ActorSystem> Benchmark for PingPong<S> {
+    type Conf = PingPongRequest;
+    type Instance = PingPongI<S>;
+
+    const LABEL: &'static str = "PingPong";
+
+    fn msg_to_conf(msg: Box<dyn (::protobuf::Message)>) -> Result<Self::Conf, BenchmarkError> {
+        downcast_msg!(msg; PingPongRequest)
+    }
+
+    fn validate(c: &Self::Conf) -> Result<(), BenchmarkError> { validation::ping_pong(c) }
+
+    fn new_instance() -> Self::Instance { PingPongI::new() }
+}
+
+pub struct PingPongI<S> {
+    num:    Option<u64>,
+    system: Option<S>,
+    pinger: Option<ActorRef<PingerMsg>>,
+    ponger: Option<ActorRef<Ping>>,
+    done:   Option<Reply<()>>,
+}
+
+impl<S: ActorSystem> PingPongI<S> {
+    fn new() -> PingPongI<S> {
+        PingPongI { num: None, system: None, pinger: None, ponger: None, done: None }
+    }
+}
+
+impl<S: ActorSystem> BenchmarkInstance for PingPongI<S> {
+    type Conf = PingPongRequest;
+
+    fn setup(&mut self, c: &Self::Conf) -> () {
+        self.num = Some(c.number_of_messages);
+        self.system = Some(S::start("pingpong", 2));
+    }
+
+    fn prepare_iteration(&mut self) -> () {
+        match (self.num, self.system.as_ref()) {
+            (Some(num), Some(system)) => {
+                let ponger = system.spawn(Ponger);
+                let (done_ref, done) = reply_to();
+                let pinger = system.spawn(Pinger {
+                    count_down: num,
+                    ponger:     ponger.clone(),
+                    done:       done_ref,
+                });
+                self.ponger = Some(ponger);
+                self.pinger = Some(pinger);
+                self.done = Some(done);
+            },
+            _ => unimplemented!(),
+        }
+    }
+
+    fn run_iteration(&mut self) -> () {
+        match self.pinger {
+            Some(ref pinger) => {
+                let done = self.done.take().unwrap();
+                pinger.tell(PingerMsg::Start);
+                done.wait();
+            },
+            None => unimplemented!(),
+        }
+    }
+
+    fn cleanup_iteration(&mut self, last_iteration: bool, _exec_time_millis: f64) -> () {
+        self.pinger.take().unwrap().stop();
+        self.ponger.take().unwrap().stop();
+
+        if last_iteration {
+            let system = self.system.take().unwrap();
+            system.shutdown().expect("System didn't shut down properly");
+            self.num = None;
+        }
+    }
+}
+
+pub enum PingerMsg {
+    Start,
+    Pong,
+}
+
+pub struct Ping {
+    sender: ActorRef<PingerMsg>,
+}
+
+struct Pinger {
+    count_down: u64,
+    ponger:     ActorRef<Ping>,
+    done:       ActorRef<()>,
+}
+
+impl Actor for Pinger {
+    type Message = PingerMsg;
+
+    fn receive<S: ActorSystem>(
+        &mut self,
+        ctx: &mut Context<'_, S, PingerMsg>,
+        msg: PingerMsg,
+    ) -> () {
+        match msg {
+            PingerMsg::Start => {
+                self.ponger.tell(Ping { sender: ctx.myself().clone() });
+            },
+            PingerMsg::Pong => {
+                if self.count_down > 0 {
+                    self.count_down -= 1;
+                    self.ponger.tell(Ping { sender: ctx.myself().clone() });
+                } else {
+                    self.done.tell(());
+                }
+            },
+        }
+    }
+}
+
+struct Ponger;
+
+impl Actor for Ponger {
+    type Message = Ping;
+
+    fn receive<S: ActorSystem>(&mut self, _ctx: &mut Context<'_, S, Ping>, msg: Ping) -> () {
+        msg.sender.tell(PingerMsg::Pong);
+    }
+}
@@ -0,0 +1,364 @@
+use super::*;
+use crate::{
+    downcast_msg,
+    helpers::producer_consumer::{items_of_producer, synthetic_work},
+    kompics_benchmarks::benchmarks::ProducerConsumerRequest,
+};
+use std::{collections::VecDeque, convert::TryInto};
+
+pub struct ProducerConsumer<S> {
+    _marker: PhantomData<fn() -> S>,
+}
+impl<S> Default for ProducerConsumer<S> {
+    fn default() -> Self { ProducerConsumer { _marker: PhantomData } }
+}
+
+impl<S: ActorSystem> Benchmark for ProducerConsumer<S> {
+    type Conf = ProducerConsumerRequest;
+    type Instance = ProducerConsumerI<S>;
+
+    const LABEL: &'static str = "ProducerConsumer";
+
+    fn msg_to_conf(msg: Box<dyn (::protobuf::Message)>) -> Result<Self::Conf, BenchmarkError> {
+        downcast_msg!(msg; ProducerConsumerRequest)
+    }
+
+    fn validate(c: &Self::Conf) -> Result<(), BenchmarkError> { validation::producer_consumer(c) }
+
+    fn new_instance() -> Self::Instance { ProducerConsumerI::new() }
+}
+
+pub struct ProducerConsumerI<S> {
+    buffer_size:   Option<usize>,
+    num_producers: Option<usize>,
+    num_consumers: Option<usize>,
+    num_items:     Option<u64>,
+    work_per_item: Option<u64>,
+    system:        Option<S>,
+    buffer:        Option<ActorRef<BufferMsg>>,
+    producers:     Vec<ActorRef<ProducerMsg>>,
+    consumers:     Vec<ActorRef<ConsumerMsg>>,
+    done:          Option<Reply<ConsumerDone>>,
+    consumed:      Option<u64>,
+}
+
+impl<S: ActorSystem> ProducerConsumerI<S> {
+    fn new() -> ProducerConsumerI<S> {
+        ProducerConsumerI {
+            buffer_size:   None,
+            num_producers: None,
+            num_consumers: None,
+            num_items:     None,
+            work_per_item: None,
+            system:        None,
+            buffer:        None,
+            producers:     Vec::new(),
+            consumers:     Vec::new(),
+            done:          None,
+            consumed:      None,
+        }
+    }
+}
+
+impl<S: ActorSystem> BenchmarkInstance for ProducerConsumerI<S> {
+    type Conf = ProducerConsumerRequest;
+
+    fn setup(&mut self, c: &Self::Conf) -> () {
+        self.buffer_size = Some(c.buffer_size.try_into().unwrap());
+        self.num_producers = Some(c.number_of_producers.try_into().unwrap());
+        self.num_consumers = Some(c.number_of_consumers.try_into().unwrap());
+        self.num_items = Some(c.number_of_items);
+        self.work_per_item = Some(c.work_per_item);
+        self.system = Some(S::start("producer_consumer", num_cpus::get()));
+    }
+
+    fn prepare_iteration(&mut self) -> () {
+        match (
+            self.buffer_size,
+            self.num_producers,
+            self.num_consumers,
+            self.num_items,
+            self.work_per_item,
+            self.system.as_ref(),
+        ) {
+            (
+                Some(buffer_size),
+                Some(num_producers),
+                Some(num_consumers),
+                Some(num_items),
+                Some(work_per_item),
+                Some(system),
+            ) => {
+                let (done_ref, done) = reply_to();
+                let buffer = system.spawn(Buffer::with(buffer_size, num_items));
+                for id in 0usize..num_producers {
+                    let producer = system.spawn(Producer::with(
+                        id,
+                        items_of_producer(id, num_producers, num_items),
+                        work_per_item,
+                        buffer.clone(),
+                    ));
+                    self.producers.push(producer);
+                }
+                for id in 0usize..num_consumers {
+                    let consumer = system.spawn(Consumer::with(
+                        id,
+                        work_per_item,
+                        buffer.clone(),
+                        done_ref.clone(),
+                    ));
+                    self.consumers.push(consumer);
+                }
+                // handled before any request, since producers and consumers only send once started
+                buffer.tell(BufferMsg::Peers {
+                    producers: self.producers.clone(),
+                    consumers: self.consumers.clone(),
+                });
+                self.buffer = Some(buffer);
+                self.done = Some(done);
+            },
+            _ => unimplemented!(),
+        }
+    }
+
+    fn run_iteration(&mut self) -> () {
+        let done = self.done.take().unwrap();
+        for consumer in self.consumers.iter() {
+            consumer.tell(ConsumerMsg::Start);
+        }
+        for producer in self.producers.iter() {
+            producer.tell(ProducerMsg::Start);
+        }
+        let mut consumed = 0u64;
+        for _ in 0..self.consumers.len() {
+            consumed += done.wait().items;
+        }
+        self.consumed = Some(consumed);
+    }
+
+    fn cleanup_iteration(&mut self, last_iteration: bool, _exec_time_millis: f64) -> () {
+        for producer in self.producers.drain(..) {
+            producer.stop();
+        }
+        for consumer in self.consumers.drain(..) {
+            consumer.stop();
+        }
+        self.buffer.take().unwrap().stop();
+        let consumed = self.consumed.take().expect("No consumed items were reported");
+        assert_eq!(consumed, self.num_items.unwrap(), "Items were lost or duplicated!");
+
+        if last_iteration {
+            let system = self.system.take().unwrap();
+            system.shutdown().expect("System didn't shut down properly");
+            self.buffer_size = None;
+            self.num_producers = None;
+            self.num_consumers = None;
+            self.num_items = None;
+            self.work_per_item = None;
+        }
+    }
+}
+
+pub enum BufferMsg {
+    Peers { producers: Vec<ActorRef<ProducerMsg>>, consumers: Vec<ActorRef<ConsumerMsg>> },
+    Produce { producer: usize, item: u64 },
+    Consume { consumer: usize },
+}
+
+pub enum ProducerMsg {
+    Start,
+    /// The last item was taken into the buffer.
+    Accepted,
+}
+
+pub enum ConsumerMsg {
+    Start,
+    Item(u64),
+    /// All items have been handed out.
+    Finished,
+}
+
+/// What every consumer replies once it is finished.
+///
+/// The checksum is only sent, so that the consumers' work can't be optimised away.
+pub struct ConsumerDone {
+    items:     u64,
+    _checksum: u64,
+}
+
+/// Holds up to `capacity` items, and defers the messages it can't handle yet.
+///
+/// A producer only produces its next item once the previous one has been accepted, so a
+/// deferred `Produce` blocks its producer until a consumer makes room. Likewise a consumer
+/// only asks again once it got an item, so a deferred `Consume` blocks it until an item arrives.
+struct Buffer {
+    capacity:           usize,
+    items:              VecDeque<u64>,
+    deferred_producers: VecDeque<(usize, u64)>,
+    deferred_consumers: VecDeque<usize>,
+    undelivered:        u64,
+    finished:           usize,
+    producers:          Vec<ActorRef<ProducerMsg>>,
+    consumers:          Vec<ActorRef<ConsumerMsg>>,
+}
+
+impl Buffer {
+    fn with(capacity: usize, num_items: u64) -> Buffer {
+        Buffer {
+            capacity,
+            items: VecDeque::with_capacity(capacity),
+            deferred_producers: VecDeque::new(),
+            deferred_consumers: VecDeque::new(),
+            undelivered: num_items,
+            finished: 0usize,
+            producers: Vec::new(),
+            consumers: Vec::new(),
+        }
+    }
+
+    fn deliver(&mut self, consumer: usize, item: u64) -> () {
+        self.consumers[consumer].tell(ConsumerMsg::Item(item));
+        self.undelivered -= 1u64;
+        if self.undelivered == 0 {
+            while let Some(consumer) = self.deferred_consumers.pop_front() {
+                self.finish(consumer);
+            }
+        }
+    }
+
+    fn finish(&mut self, consumer: usize) -> () {
+        self.consumers[consumer].tell(ConsumerMsg::Finished);
+        self.finished += 1;
+        if self.finished == self.consumers.len() {
+            // the producers and consumers hold a reference to the buffer as well
+            self.producers.clear();
+            self.consumers.clear();
+        }
+    }
+}
+
+impl Actor for Buffer {
+    type Message = BufferMsg;
+
+    fn receive<S: ActorSystem>(
+        &mut self,
+        _ctx: &mut Context<'_, S, BufferMsg>,
+        msg: BufferMsg,
+    ) -> () {
+        match msg {
+            BufferMsg::Peers { producers, consumers } => {
+                self.producers = producers;
+                self.consumers = consumers;
+            },
+            BufferMsg::Produce { producer, item } => {
+                if let Some(consumer) = self.deferred_consumers.pop_front() {
+                    // the buffer is empty, since a consumer is waiting
+                    self.deliver(consumer, item);
+                    self.producers[producer].tell(ProducerMsg::Accepted);
+                } else if self.items.len() < self.capacity {
+                    self.items.push_back(item);
+                    self.producers[producer].tell(ProducerMsg::Accepted);
+                } else {
+                    self.deferred_producers.push_back((producer, item));
+                }
+            },
+            BufferMsg::Consume { consumer } => {
+                if let Some(item) = self.items.pop_front() {
+                    self.deliver(consumer, item);
+                    if let Some((producer, item)) = self.deferred_producers.pop_front() {
+                        self.items.push_back(item);
+                        self.producers[producer].tell(ProducerMsg::Accepted);
+                    }
+                } else if self.undelivered == 0 {
+                    self.finish(consumer);
+                } else {
+                    self.deferred_consumers.push_back(consumer);
+                }
+            },
+        }
+    }
+}
+
+struct Producer {
+    id:            usize,
+    remaining:     u64,
+    work_per_item: u64,
+    buffer:        ActorRef<BufferMsg>,
+}
+
+impl Producer {
+    fn with(
+        id: usize,
+        num_items: u64,
+        work_per_item: u64,
+        buffer: ActorRef<BufferMsg>,
+    ) -> Producer {
+        Producer { id, remaining: num_items, work_per_item, buffer }
+    }
+
+    fn produce(&mut self) -> () {
+        if self.remaining > 0 {
+            self.remaining -= 1u64;
+            let item = synthetic_work(self.work_per_item, self.remaining);
+            self.buffer.tell(BufferMsg::Produce { producer: self.id, item });
+        }
+    }
+}
+
+impl Actor for Producer {
+    type Message = ProducerMsg;
+
+    fn receive<S: ActorSystem>(
+        &mut self,
+        _ctx: &mut Context<'_, S, ProducerMsg>,
+        msg: ProducerMsg,
+    ) -> () {
+        match msg {
+            ProducerMsg::Start | ProducerMsg::Accepted => self.produce(),
+        }
+    }
+}
+
+struct Consumer {
+    id:            usize,
+    work_per_item: u64,
+    consumed:      u64,
+    checksum:      u64,
+    buffer:        ActorRef<BufferMsg>,
+    done:          ActorRef<ConsumerDone>,
+}
+
+impl Consumer {
+    fn with(
+        id: usize,
+        work_per_item: u64,
+        buffer: ActorRef<BufferMsg>,
+        done: ActorRef<ConsumerDone>,
+    ) -> Consumer {
+        Consumer { id, work_per_item, consumed: 0u64, checksum: 0u64, buffer, done }
+    }
+
+    fn request(&self) -> () { self.buffer.tell(BufferMsg::Consume { consumer: self.id }); }
+}
+
+impl Actor for Consumer {
+    type Message = ConsumerMsg;
+
+    fn receive<S: ActorSystem>(
+        &mut self,
+        _ctx: &mut Context<'_, S, ConsumerMsg>,
+        msg: ConsumerMsg,
+    ) -> () {
+        match msg {
+            ConsumerMsg::Start => self.request(),
+            ConsumerMsg::Item(item) => {
+                self.checksum ^= synthetic_work(self.work_per_item, item);
+                self.consumed += 1u64;
+                self.request();
+            },
+            ConsumerMsg::Finished => {
+                self.done.tell(ConsumerDone { items: self.consumed, _checksum: self.checksum });
+            },
+        }
+    }
+}
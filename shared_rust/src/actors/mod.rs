@@ -0,0 +1,130 @@
+//! A thin actor abstraction, so that a benchmark can be written once and run on every framework.
+//!
+//! A benchmark implements [Actor](Actor) for its actors and drives them through an
+//! [ActorSystem](ActorSystem). Each framework crate provides an adapter implementing
+//! [ActorSystem](ActorSystem), which wraps every [Actor](Actor) into one of its native actors.
+//!
+//! The abstraction is deliberately minimal: spawn, tell, self-reference, stop, and replies to
+//! plain threads via [reply_to](reply_to). The framework-specific implementations of the
+//! benchmarks remain the idiomatic ones, the [benchmarks](benchmarks) written against this module
+//! are run next to them for comparison.
+use crossbeam::channel::{self, Receiver, Sender};
+use std::{fmt, sync::Arc};
+
+pub mod benchmarks;
+pub mod runner;
+
+/// What an adapter delivers to the native actor wrapping an [Actor](Actor).
+pub enum Envelope<M> {
+    Msg(M),
+    Stop,
+}
+impl<M> fmt::Debug for Envelope<M> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Envelope::Msg(_) => write!(f, "Envelope::Msg(..)"),
+            Envelope::Stop => write!(f, "Envelope::Stop"),
+        }
+    }
+}
+
+/// The sending side of a native actor, as provided by an adapter.
+pub trait Mailbox<M>: Send + Sync {
+    fn enqueue(&self, envelope: Envelope<M>) -> ();
+}
+
+/// A framework independent reference to an actor accepting messages of type `M`.
+pub struct ActorRef<M> {
+    mailbox: Arc<dyn Mailbox<M>>,
+}
+impl<M> ActorRef<M> {
+    pub fn new<B: Mailbox<M> + 'static>(mailbox: B) -> ActorRef<M> {
+        ActorRef { mailbox: Arc::new(mailbox) }
+    }
+
+    pub fn tell(&self, msg: M) -> () { self.mailbox.enqueue(Envelope::Msg(msg)); }
+
+    /// Asks the actor to stop after it has handled the messages sent before.
+    pub fn stop(&self) -> () { self.mailbox.enqueue(Envelope::Stop); }
+}
+impl<M> Clone for ActorRef<M> {
+    fn clone(&self) -> Self { ActorRef { mailbox: self.mailbox.clone() } }
+}
+impl<M> fmt::Debug for ActorRef<M> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { write!(f, "ActorRef(..)") }
+}
+
+/// What an actor can do while handling a message.
+pub struct Context<'a, S, M> {
+    myself:  &'a ActorRef<M>,
+    system:  &'a S,
+    stopped: bool,
+}
+impl<'a, S: ActorSystem, M> Context<'a, S, M> {
+    /// Only used by adapters.
+    pub fn new(myself: &'a ActorRef<M>, system: &'a S) -> Context<'a, S, M> {
+        Context { myself, system, stopped: false }
+    }
+
+    pub fn myself(&self) -> &ActorRef<M> { self.myself }
+
+    pub fn system(&self) -> &S { self.system }
+
+    pub fn spawn<A: Actor>(&self, actor: A) -> ActorRef<A::Message> { self.system.spawn(actor) }
+
+    /// Stops this actor once the current message has been handled.
+    pub fn stop(&mut self) -> () { self.stopped = true; }
+
+    /// Only used by adapters, to find out if the actor asked to be stopped.
+    pub fn is_stopped(&self) -> bool { self.stopped }
+}
+
+pub trait Actor: Send + Sized + 'static {
+    type Message: Send + 'static;
+
+    fn receive<S: ActorSystem>(
+        &mut self,
+        ctx: &mut Context<'_, S, Self::Message>,
+        msg: Self::Message,
+    ) -> ();
+}
+
+/// A handle to a running actor system, implemented once per framework.
+///
+/// Handles are cheap to clone and may be used from any thread, including from within actors.
+pub trait ActorSystem: Clone + Send + Sync + 'static {
+    /// Starts a new system, using up to `num_threads` threads if the framework allows it.
+    fn start(label: &str, num_threads: usize) -> Self;
+
+    fn spawn<A: Actor>(&self, actor: A) -> ActorRef<A::Message>;
+
+    /// Shuts the system down, blocking until it has stopped.
+    fn shutdown(self) -> Result<(), String>;
+}
+
+/// An [ActorRef](ActorRef) whose messages can be awaited on a plain thread, e.g. the one running
+/// the benchmark.
+pub fn reply_to<R: Send + 'static>() -> (ActorRef<R>, Reply<R>) {
+    let (tx, rx) = channel::unbounded();
+    (ActorRef::new(ChannelMailbox(tx)), Reply(rx))
+}
+
+pub struct Reply<R>(Receiver<R>);
+impl<R> Reply<R> {
+    /// Blocks until the next message arrives.
+    pub fn wait(&self) -> R {
+        self.0.recv().expect("All references to the reply channel were dropped")
+    }
+}
+
+struct ChannelMailbox<R>(Sender<R>);
+impl<R: Send> Mailbox<R> for ChannelMailbox<R> {
+    fn enqueue(&self, envelope: Envelope<R>) -> () {
+        match envelope {
+            Envelope::Msg(msg) => {
+                let _ = self.0.send(msg); // ignore if no one is waiting anymore
+            },
+            Envelope::Stop => (), // there is nothing to stop
+        }
+    }
+}
@@ -0,0 +1,263 @@
+//! The local benchmark runner for the benchmarks in [benchmarks](super::benchmarks).
+use super::{benchmarks as bench, ActorSystem};
+use crate::{
+    benchmark_runner::{not_implemented, run_and_record, run_async},
+    kompics_benchmarks::{benchmarks, benchmarks_grpc, messages},
+};
+use futures::future::Future;
+use std::marker::PhantomData;
+
+pub struct BenchmarkRunnerImpl<S> {
+    _marker: PhantomData<fn() -> S>,
+}
+
+impl<S: ActorSystem> BenchmarkRunnerImpl<S> {
+    pub fn new() -> BenchmarkRunnerImpl<S> { BenchmarkRunnerImpl { _marker: PhantomData } }
+}
+impl<S> Clone for BenchmarkRunnerImpl<S> {
+    fn clone(&self) -> Self { BenchmarkRunnerImpl { _marker: PhantomData } }
+}
+
+impl<S: ActorSystem> benchmarks_grpc::BenchmarkRunner for BenchmarkRunnerImpl<S> {
+    fn ready(
+        &self,
+        _o: grpc::RequestOptions,
+        _p: messages::ReadyRequest,
+    ) -> grpc::SingleResponse<messages::ReadyResponse> {
+        println!("Got ready? req.");
+        let mut msg = messages::ReadyResponse::new();
+        msg.set_status(true);
+        grpc::SingleResponse::completed(msg)
+    }
+
+    fn shutdown(
+        &self,
+        _o: grpc::RequestOptions,
+        _p: messages::ShutdownRequest,
+    ) -> ::grpc::SingleResponse<messages::ShutdownAck> {
+        unimplemented!();
+    }
+
+    fn ping_pong(
+        &self,
+        _o: grpc::RequestOptions,
+        p: benchmarks::PingPongRequest,
+    ) -> grpc::SingleResponse<messages::TestResult> {
+        println!("Got ping_pong req: {}", p.number_of_messages);
+        let f = run_async(move || {
+            let b = bench::pingpong::PingPong::<S>::default();
+            run_and_record(&b, &p)
+        })
+        .map_err(|e| {
+            println!("Converting benchmark error into grpc error: {:?}", e);
+            e.into()
+        });
+        grpc::SingleResponse::no_metadata(f)
+    }
+
+    fn net_ping_pong(
+        &self,
+        _o: grpc::RequestOptions,
+        _p: benchmarks::PingPongRequest,
+    ) -> grpc::SingleResponse<messages::TestResult> {
+        grpc::SingleResponse::completed(not_implemented())
+    }
+
+    fn throughput_ping_pong(
+        &self,
+        _o: grpc::RequestOptions,
+        _p: benchmarks::ThroughputPingPongRequest,
+    ) -> grpc::SingleResponse<messages::TestResult> {
+        grpc::SingleResponse::completed(not_implemented())
+    }
+
+    fn net_throughput_ping_pong(
+        &self,
+        _o: grpc::RequestOptions,
+        _p: benchmarks::ThroughputPingPongRequest,
+    ) -> grpc::SingleResponse<messages::TestResult> {
+        grpc::SingleResponse::completed(not_implemented())
+    }
+
+    fn atomic_register(
+        &self,
+        _o: grpc::RequestOptions,
+        _p: benchmarks::AtomicRegisterRequest,
+    ) -> grpc::SingleResponse<messages::TestResult> {
+        grpc::SingleResponse::completed(not_implemented())
+    }
+
+    fn streaming_windows(
+        &self,
+        _o: grpc::RequestOptions,
+        _p: benchmarks::StreamingWindowsRequest,
+    ) -> grpc::SingleResponse<messages::TestResult> {
+        grpc::SingleResponse::completed(not_implemented())
+    }
+
+    fn fibonacci(
+        &self,
+        _o: grpc::RequestOptions,
+        p: benchmarks::FibonacciRequest,
+    ) -> grpc::SingleResponse<messages::TestResult> {
+        println!("Got fibonacci req: {:?}", p);
+        let f = run_async(move || {
+            let b = bench::fibonacci::Fibonacci::<S>::default();
+            run_and_record(&b, &p)
+        })
+        .map_err(|e| {
+            println!("Converting benchmark error into grpc error: {:?}", e);
+            e.into()
+        });
+        grpc::SingleResponse::no_metadata(f)
+    }
+
+    fn chameneos(
+        &self,
+        _o: grpc::RequestOptions,
+        p: benchmarks::ChameneosRequest,
+    ) -> grpc::SingleResponse<messages::TestResult> {
+        println!("Got chameneos req: {:?}", p);
+        let f = run_async(move || {
+            let b = bench::chameneos::Chameneos::<S>::default();
+            run_and_record(&b, &p)
+        })
+        .map_err(|e| {
+            println!("Converting benchmark error into grpc error: {:?}", e);
+            e.into()
+        });
+        grpc::SingleResponse::no_metadata(f)
+    }
+
+    fn all_pairs_shortest_path(
+        &self,
+        _o: grpc::RequestOptions,
+        _p: benchmarks::APSPRequest,
+    ) -> grpc::SingleResponse<messages::TestResult> {
+        grpc::SingleResponse::completed(not_implemented())
+    }
+
+    fn atomic_broadcast(
+        &self,
+        _o: grpc::RequestOptions,
+        _p: benchmarks::AtomicBroadcastRequest,
+    ) -> grpc::SingleResponse<messages::TestResult> {
+        grpc::SingleResponse::completed(not_implemented())
+    }
+
+    fn sized_throughput(
+        &self,
+        _o: grpc::RequestOptions,
+        _p: benchmarks::SizedThroughputRequest,
+    ) -> grpc::SingleResponse<messages::TestResult> {
+        grpc::SingleResponse::completed(not_implemented())
+    }
+
+    fn thread_ring(
+        &self,
+        _o: grpc::RequestOptions,
+        _p: benchmarks::ThreadRingRequest,
+    ) -> grpc::SingleResponse<messages::TestResult> {
+        grpc::SingleResponse::completed(not_implemented())
+    }
+
+    fn fan_in(
+        &self,
+        _o: grpc::RequestOptions,
+        p: benchmarks::FanInRequest,
+    ) -> grpc::SingleResponse<messages::TestResult> {
+        println!("Got fan_in req: {:?}", p);
+        let f = run_async(move || {
+            let b = bench::fan_in::FanIn::<S>::default();
+            run_and_record(&b, &p)
+        })
+        .map_err(|e| {
+            println!("Converting benchmark error into grpc error: {:?}", e);
+            e.into()
+        });
+        grpc::SingleResponse::no_metadata(f)
+    }
+
+    fn fanout(
+        &self,
+        _o: grpc::RequestOptions,
+        _p: benchmarks::FanoutRequest,
+    ) -> grpc::SingleResponse<messages::TestResult> {
+        grpc::SingleResponse::completed(not_implemented())
+    }
+
+    fn big(
+        &self,
+        _o: grpc::RequestOptions,
+        _p: benchmarks::BigRequest,
+    ) -> grpc::SingleResponse<messages::TestResult> {
+        grpc::SingleResponse::completed(not_implemented())
+    }
+
+    fn bank(
+        &self,
+        _o: grpc::RequestOptions,
+        _p: benchmarks::BankRequest,
+    ) -> grpc::SingleResponse<messages::TestResult> {
+        grpc::SingleResponse::completed(not_implemented())
+    }
+
+    fn producer_consumer(
+        &self,
+        _o: grpc::RequestOptions,
+        p: benchmarks::ProducerConsumerRequest,
+    ) -> grpc::SingleResponse<messages::TestResult> {
+        println!("Got producer_consumer req: {:?}", p);
+        let f = run_async(move || {
+            let b = bench::producer_consumer::ProducerConsumer::<S>::default();
+            run_and_record(&b, &p)
+        })
+        .map_err(|e| {
+            println!("Converting benchmark error into grpc error: {:?}", e);
+            e.into()
+        });
+        grpc::SingleResponse::no_metadata(f)
+    }
+
+    fn dining_philosophers(
+        &self,
+        _o: grpc::RequestOptions,
+        _p: benchmarks::DiningPhilosophersRequest,
+    ) -> grpc::SingleResponse<messages::TestResult> {
+        grpc::SingleResponse::completed(not_implemented())
+    }
+
+    fn nqueens(
+        &self,
+        _o: grpc::RequestOptions,
+        p: benchmarks::NQueensRequest,
+    ) -> grpc::SingleResponse<messages::TestResult> {
+        println!("Got nqueens req: {:?}", p);
+        let f = run_async(move || {
+            let b = bench::nqueens::NQueens::<S>::default();
+            run_and_record(&b, &p)
+        })
+        .map_err(|e| {
+            println!("Converting benchmark error into grpc error: {:?}", e);
+            e.into()
+        });
+        grpc::SingleResponse::no_metadata(f)
+    }
+
+    fn parallel_sort(
+        &self,
+        _o: grpc::RequestOptions,
+        p: benchmarks::ParallelSortRequest,
+    ) -> grpc::SingleResponse<messages::TestResult> {
+        println!("Got parallel_sort req: {:?}", p);
+        let f = run_async(move || {
+            let b = bench::parallel_sort::ParallelSort::<S>::default();
+            run_and_record(&b, &p)
+        })
+        .map_err(|e| {
+            println!("Converting benchmark error into grpc error: {:?}", e);
+            e.into()
+        });
+        grpc::SingleResponse::no_metadata(f)
+    }
+}
@@ -0,0 +1,447 @@
+//! Placement of actor system worker threads on CPUs.
+//!
+//! The policy is read from the `BENCHMARK_AFFINITY` environment variable
+//! (or from an implementation specific config) and is one of
+//! `compact`, `spread`, `cores:<list>` (e.g. `cores:0,2,4-7`) or `numa:<node>`.
+//!
+//! Only the framework's own worker threads are pinned, one CPU each. Where the framework
+//! offers a thread start hook, the hook calls [Placement::pin_current](Placement::pin_current).
+//! Otherwise [Placement::apply](Placement::apply) wraps the creation of the system and picks
+//! the workers among the threads spawned meanwhile by their names. Either way, the creating
+//! thread's mask is restricted to the placement's CPUs for the duration, so that helper
+//! threads spawned from within the system inherit it.
+use crate::result_writer;
+use std::{collections::BTreeSet, fmt, fs, io, path::Path, str::FromStr};
+
+/// Environment variable holding the [AffinityPolicy](AffinityPolicy).
+pub const AFFINITY_ENV: &str = "BENCHMARK_AFFINITY";
+pub const SYSFS_CPU: &str = "/sys/devices/system/cpu";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AffinityPolicy {
+    /// Fill hyperthreads of one core, then cores of one package, then packages.
+    Compact,
+    /// One thread per physical core, alternating packages, before using hyperthreads.
+    Spread,
+    /// Exactly these CPUs, in this order.
+    Cores(Vec<usize>),
+    /// Only CPUs of this NUMA node, in compact order.
+    NumaNode(usize),
+}
+impl AffinityPolicy {
+    /// Reads the policy from [AFFINITY_ENV](AFFINITY_ENV), where an empty value or `none` means no pinning.
+    pub fn from_env() -> Option<AffinityPolicy> {
+        match std::env::var(AFFINITY_ENV) {
+            Ok(ref s) if s.is_empty() || s.eq_ignore_ascii_case("none") => None,
+            Ok(s) => Some(s.parse().unwrap_or_else(|e| panic!("Invalid {}: {}", AFFINITY_ENV, e))),
+            Err(_) => None,
+        }
+    }
+}
+impl FromStr for AffinityPolicy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let lower = s.trim().to_lowercase();
+        match lower.split_once(':') {
+            None if lower == "compact" => Ok(AffinityPolicy::Compact),
+            None if lower == "spread" => Ok(AffinityPolicy::Spread),
+            Some(("cores", list)) => parse_cpu_list(list).map(AffinityPolicy::Cores),
+            Some(("numa", node)) => node
+                .trim()
+                .parse()
+                .map(AffinityPolicy::NumaNode)
+                .map_err(|e| format!("Invalid NUMA node '{}': {}", node, e)),
+            _ => Err(format!(
+                "Unknown affinity policy '{}'. Use compact, spread, cores:<list> or numa:<node>.",
+                s
+            )),
+        }
+    }
+}
+impl fmt::Display for AffinityPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AffinityPolicy::Compact => write!(f, "compact"),
+            AffinityPolicy::Spread => write!(f, "spread"),
+            AffinityPolicy::Cores(cpus) => write!(f, "cores:{}", format_cpu_list(cpus)),
+            AffinityPolicy::NumaNode(node) => write!(f, "numa:{}", node),
+        }
+    }
+}
+
+/// Parses the kernel's cpu list format, e.g. `0-3,8,10-11`.
+pub fn parse_cpu_list(s: &str) -> Result<Vec<usize>, String> {
+    let mut cpus = Vec::new();
+    for part in s.trim().split(',').map(str::trim).filter(|p| !p.is_empty()) {
+        let parse = |n: &str| {
+            n.trim().parse::<usize>().map_err(|e| format!("Invalid cpu '{}' in '{}': {}", n, s, e))
+        };
+        match part.split_once('-') {
+            Some((from, to)) => {
+                let (from, to) = (parse(from)?, parse(to)?);
+                if from > to {
+                    return Err(format!("Invalid cpu range '{}'", part));
+                }
+                cpus.extend(from..=to);
+            },
+            None => cpus.push(parse(part)?),
+        }
+    }
+    Ok(cpus)
+}
+
+fn format_cpu_list(cpus: &[usize]) -> String {
+    cpus.iter().map(|c| c.to_string()).collect::<Vec<_>>().join(",")
+}
+
+/// The longest thread name the kernel keeps, excluding the terminating nul.
+pub const MAX_THREAD_NAME_LEN: usize = 15;
+
+/// Whether a thread name as reported by the kernel starts with `prefix`.
+///
+/// The kernel truncates names to [MAX_THREAD_NAME_LEN](MAX_THREAD_NAME_LEN) bytes, so longer
+/// prefixes only have to match up to there.
+pub fn thread_name_matches(name: &str, prefix: &str) -> bool {
+    let len = prefix.len().min(MAX_THREAD_NAME_LEN);
+    name.as_bytes().starts_with(&prefix.as_bytes()[..len])
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cpu {
+    pub id:         usize,
+    pub core_id:    usize,
+    pub package_id: usize,
+    pub numa_node:  usize,
+}
+
+/// The online CPUs of this machine, as described in `/sys/devices/system/cpu`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CpuTopology {
+    pub cpus: Vec<Cpu>,
+}
+impl CpuTopology {
+    pub fn read() -> io::Result<CpuTopology> { CpuTopology::read_from(Path::new(SYSFS_CPU)) }
+
+    pub fn read_from(root: &Path) -> io::Result<CpuTopology> {
+        let online = fs::read_to_string(root.join("online"))?;
+        let ids =
+            parse_cpu_list(&online).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        let cpus = ids
+            .into_iter()
+            .map(|id| {
+                let dir = root.join(format!("cpu{}", id));
+                let read_id = |file: &str| -> Option<usize> {
+                    fs::read_to_string(dir.join("topology").join(file)).ok()?.trim().parse().ok()
+                };
+                Cpu {
+                    id,
+                    core_id: read_id("core_id").unwrap_or(id),
+                    package_id: read_id("physical_package_id").unwrap_or(0),
+                    numa_node: numa_node_of(&dir).unwrap_or(0),
+                }
+            })
+            .collect();
+        Ok(CpuTopology { cpus })
+    }
+
+    /// CPUs in the order in which `policy` assigns them to threads.
+    pub fn order(&self, policy: &AffinityPolicy) -> Result<Vec<usize>, String> {
+        let mut compact: Vec<&Cpu> = self.cpus.iter().collect();
+        compact.sort_by_key(|c| (c.numa_node, c.package_id, c.core_id, c.id));
+        match policy {
+            AffinityPolicy::Compact => Ok(compact.iter().map(|c| c.id).collect()),
+            AffinityPolicy::Spread => {
+                // (hyperthread index on its core, core index on its package, package, cpu)
+                let mut keyed: Vec<(usize, usize, usize, usize)> = compact
+                    .iter()
+                    .map(|c| {
+                        let mut package_cores: Vec<usize> = compact
+                            .iter()
+                            .filter(|o| o.package_id == c.package_id)
+                            .map(|o| o.core_id)
+                            .collect();
+                        package_cores.dedup();
+                        let core_rank =
+                            package_cores.iter().position(|&id| id == c.core_id).unwrap();
+                        let thread_rank = compact
+                            .iter()
+                            .filter(|o| {
+                                o.package_id == c.package_id
+                                    && o.core_id == c.core_id
+                                    && o.id < c.id
+                            })
+                            .count();
+                        (thread_rank, core_rank, c.package_id, c.id)
+                    })
+                    .collect();
+                keyed.sort();
+                Ok(keyed.into_iter().map(|(_, _, _, id)| id).collect())
+            },
+            AffinityPolicy::Cores(cpus) => {
+                if cpus.is_empty() {
+                    return Err("Empty core list".to_string());
+                }
+                match cpus.iter().find(|&&id| !self.cpus.iter().any(|c| c.id == id)) {
+                    Some(id) => Err(format!("CPU {} is not online", id)),
+                    None => Ok(cpus.clone()),
+                }
+            },
+            AffinityPolicy::NumaNode(node) => {
+                let cpus: Vec<usize> =
+                    compact.iter().filter(|c| c.numa_node == *node).map(|c| c.id).collect();
+                if cpus.is_empty() {
+                    Err(format!("NUMA node {} has no online CPUs", node))
+                } else {
+                    Ok(cpus)
+                }
+            },
+        }
+    }
+}
+
+fn numa_node_of(cpu_dir: &Path) -> Option<usize> {
+    fs::read_dir(cpu_dir).ok()?.filter_map(|e| e.ok()).find_map(|e| {
+        let name = e.file_name();
+        name.to_str()?.strip_prefix("node")?.parse().ok()
+    })
+}
+
+/// The CPU for every worker thread of one system.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Placement {
+    pub policy: AffinityPolicy,
+    /// One entry per thread; CPUs are reused if there are more threads than CPUs.
+    pub cpus:   Vec<usize>,
+}
+impl Placement {
+    pub fn plan(
+        policy: AffinityPolicy,
+        topology: &CpuTopology,
+        threads: usize,
+    ) -> Result<Placement, String> {
+        let order = topology.order(&policy)?;
+        let cpus = order.into_iter().cycle().take(threads.max(1)).collect();
+        Ok(Placement { policy, cpus })
+    }
+
+    /// Plans a placement for the policy in [AFFINITY_ENV](AFFINITY_ENV), if any.
+    pub fn from_env(threads: usize) -> Option<Placement> {
+        AffinityPolicy::from_env().map(|policy| Placement::for_policy(policy, threads))
+    }
+
+    /// Plans a placement on this machine, panicking if it is impossible.
+    pub fn for_policy(policy: AffinityPolicy, threads: usize) -> Placement {
+        let topology = CpuTopology::read()
+            .unwrap_or_else(|e| panic!("Could not read CPU topology from {}: {}", SYSFS_CPU, e));
+        Placement::plan(policy, &topology, threads)
+            .unwrap_or_else(|e| panic!("Invalid affinity policy: {}", e))
+    }
+
+    /// The distinct CPUs used by this placement.
+    pub fn cpu_set(&self) -> Vec<usize> {
+        self.cpus.iter().cloned().collect::<BTreeSet<_>>().into_iter().collect()
+    }
+
+    /// Runs `f` with the calling thread's mask restricted to the placement's CPUs.
+    ///
+    /// Threads spawned from the calling thread during `f` inherit the restricted mask.
+    pub fn restrict<T, F: FnOnce() -> T>(&self, f: F) -> T {
+        let saved = sys::get_affinity(0);
+        if let Err(e) = sys::set_affinity(0, &self.cpu_set()) {
+            eprintln!("Could not restrict affinity to {:?}: {}", self.cpu_set(), e);
+        }
+        let res = f();
+        match saved {
+            Ok(cpus) => {
+                if let Err(e) = sys::set_affinity(0, &cpus) {
+                    eprintln!("Could not restore affinity: {}", e);
+                }
+            },
+            Err(e) => eprintln!("Could not read affinity: {}", e),
+        }
+        res
+    }
+
+    /// Pins the calling thread to the CPU of the `index`th worker.
+    ///
+    /// Meant for thread start hooks, which don't report anything themselves.
+    pub fn pin_current(&self, index: usize) -> bool {
+        let cpu = self.cpus[index % self.cpus.len()];
+        match sys::set_affinity(0, &[cpu]) {
+            Ok(_) => true,
+            Err(e) => {
+                eprintln!("Could not pin worker {} to cpu {}: {}", index, cpu, e);
+                false
+            },
+        }
+    }
+
+    /// Runs `f`, which is expected to create the system's threads, and pins those of them
+    /// whose names `is_worker` accepts, in creation order.
+    ///
+    /// Threads with other names, and threads spawned elsewhere in the process meanwhile,
+    /// are left alone. The placement is reported with the next result record.
+    pub fn apply<T, F, P>(&self, is_worker: P, f: F) -> T
+    where
+        F: FnOnce() -> T,
+        P: Fn(&str) -> bool,
+    {
+        let before = sys::thread_ids();
+        let res = self.restrict(f);
+        let mut workers: Vec<i32> = sys::thread_ids()
+            .into_iter()
+            .filter(|tid| !before.contains(tid))
+            .filter(|tid| sys::thread_name(*tid).filter(|name| is_worker(name)).is_some())
+            .collect();
+        workers.sort_unstable(); // roughly creation order
+        if workers.is_empty() {
+            eprintln!("No worker threads were recognised, so none were pinned");
+        }
+        let mut pinned = 0usize;
+        for (i, tid) in workers.iter().enumerate() {
+            let cpu = self.cpus[i % self.cpus.len()];
+            match sys::set_affinity(*tid, &[cpu]) {
+                Ok(_) => pinned += 1,
+                Err(e) => eprintln!("Could not pin thread {} to cpu {}: {}", tid, cpu, e),
+            }
+        }
+        self.report(pinned);
+        res
+    }
+
+    /// Records the placement with the next result record.
+    pub fn report(&self, pinned_threads: usize) {
+        result_writer::record_setting("affinity.policy", &self.policy);
+        result_writer::record_setting("affinity.cpus", format_cpu_list(&self.cpus));
+        result_writer::record_setting("affinity.pinned_threads", pinned_threads);
+    }
+}
+
+#[cfg(target_os = "linux")]
+mod sys {
+    use super::*;
+    use std::mem;
+
+    pub(super) fn thread_ids() -> BTreeSet<i32> {
+        fs::read_dir("/proc/self/task")
+            .map(|entries| {
+                entries
+                    .filter_map(|e| e.ok())
+                    .filter_map(|e| e.file_name().to_str().and_then(|s| s.parse().ok()))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    pub(super) fn thread_name(tid: i32) -> Option<String> {
+        fs::read_to_string(format!("/proc/self/task/{}/comm", tid))
+            .ok()
+            .map(|name| name.trim_end_matches('\n').to_string())
+    }
+
+    /// `tid == 0` refers to the calling thread.
+    pub(super) fn get_affinity(tid: i32) -> io::Result<Vec<usize>> {
+        unsafe {
+            let mut set: libc::cpu_set_t = mem::zeroed();
+            if libc::sched_getaffinity(tid, mem::size_of::<libc::cpu_set_t>(), &mut set) != 0 {
+                return Err(io::Error::last_os_error());
+            }
+            Ok((0..libc::CPU_SETSIZE as usize).filter(|&cpu| libc::CPU_ISSET(cpu, &set)).collect())
+        }
+    }
+
+    /// `tid == 0` refers to the calling thread.
+    pub(super) fn set_affinity(tid: i32, cpus: &[usize]) -> io::Result<()> {
+        unsafe {
+            let mut set: libc::cpu_set_t = mem::zeroed();
+            for &cpu in cpus {
+                libc::CPU_SET(cpu, &mut set);
+            }
+            if libc::sched_setaffinity(tid, mem::size_of::<libc::cpu_set_t>(), &set) != 0 {
+                return Err(io::Error::last_os_error());
+            }
+            Ok(())
+        }
+    }
+}
+
+#[cfg(not(target_os = "linux"))]
+mod sys {
+    use super::*;
+
+    fn unsupported() -> io::Error {
+        io::Error::new(io::ErrorKind::Other, "thread affinity is only supported on Linux")
+    }
+
+    pub(super) fn thread_ids() -> BTreeSet<i32> { BTreeSet::new() }
+
+    pub(super) fn thread_name(_tid: i32) -> Option<String> { None }
+
+    pub(super) fn get_affinity(_tid: i32) -> io::Result<Vec<usize>> { Err(unsupported()) }
+
+    pub(super) fn set_affinity(_tid: i32, _cpus: &[usize]) -> io::Result<()> { Err(unsupported()) }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    /// 2 packages with 2 cores with 2 hyperthreads each, one NUMA node per package.
+    fn fake_topology() -> (PathBuf, CpuTopology) {
+        let root = std::env::temp_dir().join(format!("affinity_test_{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(&root).unwrap();
+        fs::write(root.join("online"), "0-7\n").unwrap();
+        // Linux style numbering: hyperthread siblings are n and n + 4
+        for id in 0..8 {
+            let dir = root.join(format!("cpu{}", id));
+            fs::create_dir_all(dir.join("topology")).unwrap();
+            let package = (id % 4) / 2;
+            fs::write(dir.join("topology/core_id"), format!("{}\n", id % 2)).unwrap();
+            fs::write(dir.join("topology/physical_package_id"), format!("{}\n", package)).unwrap();
+            fs::create_dir_all(dir.join(format!("node{}", package))).unwrap();
+        }
+        let topology = CpuTopology::read_from(&root).unwrap();
+        (root, topology)
+    }
+
+    #[test]
+    fn test_policy_parsing() {
+        assert_eq!("compact".parse(), Ok(AffinityPolicy::Compact));
+        assert_eq!("Spread".parse(), Ok(AffinityPolicy::Spread));
+        assert_eq!("cores:0,2,4-6".parse(), Ok(AffinityPolicy::Cores(vec![0, 2, 4, 5, 6])));
+        assert_eq!("numa:1".parse(), Ok(AffinityPolicy::NumaNode(1)));
+        assert!("numa:x".parse::<AffinityPolicy>().is_err());
+        assert!("cores:3-1".parse::<AffinityPolicy>().is_err());
+        assert!("fancy".parse::<AffinityPolicy>().is_err());
+        assert_eq!(AffinityPolicy::Cores(vec![1, 3]).to_string(), "cores:1,3");
+    }
+
+    #[test]
+    fn test_placement_orders() {
+        let (root, topology) = fake_topology();
+        assert_eq!(topology.cpus.len(), 8);
+        assert_eq!(topology.cpus[6].package_id, 1);
+        assert_eq!(topology.cpus[6].numa_node, 1);
+        assert_eq!(topology.order(&AffinityPolicy::Compact).unwrap(), vec![0, 4, 1, 5, 2, 6, 3, 7]);
+        assert_eq!(topology.order(&AffinityPolicy::Spread).unwrap(), vec![0, 2, 1, 3, 4, 6, 5, 7]);
+        assert_eq!(topology.order(&AffinityPolicy::NumaNode(1)).unwrap(), vec![2, 6, 3, 7]);
+        assert!(topology.order(&AffinityPolicy::NumaNode(2)).is_err());
+        assert!(topology.order(&AffinityPolicy::Cores(vec![1, 9])).is_err());
+        let placement = Placement::plan(AffinityPolicy::NumaNode(0), &topology, 6).unwrap();
+        assert_eq!(placement.cpus, vec![0, 4, 1, 5, 0, 4]);
+        assert_eq!(placement.cpu_set(), vec![0, 1, 4, 5]);
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_thread_name_matches() {
+        assert!(thread_name_matches("riker-pp-pool-3", "riker-pp-pool-"));
+        // "cb-channel-pool-worker" as truncated by the kernel
+        assert!(thread_name_matches("cb-channel-pool", "cb-channel-pool-worker"));
+        assert!(!thread_name_matches("cb-ws-pool-work", "cb-channel-pool-worker"));
+        assert!(!thread_name_matches("timer", "cb-ws-pool-worker"));
+    }
+}
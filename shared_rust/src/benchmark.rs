@@ -10,6 +10,10 @@ mod local_benchmark {
         type Instance: BenchmarkInstance<Conf = Self::Conf>;
 
         fn msg_to_conf(msg: Box<dyn (::protobuf::Message)>) -> Result<Self::Conf, BenchmarkError>;
+        /// Checks the parameters before any instance is created.
+        ///
+        /// Violations should be reported as [InvalidTest](BenchmarkError::InvalidTest).
+        fn validate(_c: &Self::Conf) -> Result<(), BenchmarkError> { Ok(()) }
         fn new_instance() -> Self::Instance;
 
         const LABEL: &'static str;
@@ -89,8 +93,10 @@ mod local_benchmark {
 
     impl<B: Benchmark> AbstractBenchmarkInstance for BenchmarkInstanceObject<B> {
         fn setup(&mut self, msg: Box<dyn (::protobuf::Message)>) -> Result<(), BenchmarkError> {
+            let conf = B::msg_to_conf(msg).and_then(|c| B::validate(&c).map(|_| c))?;
             self.bi = B::new_instance();
-            B::msg_to_conf(msg).map(|c| self.bi.setup(&c))
+            self.bi.setup(&conf);
+            Ok(())
         }
 
         fn prepare_iteration(&mut self) -> () { self.bi.prepare_iteration() }
@@ -138,6 +144,15 @@ mod distributed_benchmark {
         fn msg_to_master_conf(
             msg: Box<dyn (::protobuf::Message)>,
         ) -> Result<Self::MasterConf, BenchmarkError>;
+        /// Checks the parameters against the deployment before the master is set up.
+        ///
+        /// Violations should be reported as [InvalidTest](BenchmarkError::InvalidTest).
+        fn validate(
+            _c: &Self::MasterConf,
+            _meta: &DeploymentMetaData,
+        ) -> Result<(), BenchmarkError> {
+            Ok(())
+        }
 
         fn new_client() -> Self::Client;
         fn str_to_client_conf(str: String) -> Result<Self::ClientConf, BenchmarkError>;
@@ -274,7 +289,7 @@ mod distributed_benchmark {
             msg: Box<dyn (::protobuf::Message)>,
             meta: &DeploymentMetaData,
         ) -> Result<ClientConfHolder, BenchmarkError> {
-            let res = B::msg_to_master_conf(msg);
+            let res = B::msg_to_master_conf(msg).and_then(|c| B::validate(&c, meta).map(|_| c));
             res.and_then(|c| {
                 self.bm.setup(c, meta).map(|cconf| {
                     let cconf_ser = B::client_conf_to_str(cconf);
@@ -384,6 +399,15 @@ pub trait BenchmarkFactory: Send + Sync {
         &self,
     ) -> Result<Box<dyn AbstractDistributedBenchmark>, NotImplementedError>;
     fn sized_throughput(&self) -> Result<Box<dyn AbstractDistributedBenchmark>, NotImplementedError>;
+    fn thread_ring(&self) -> Result<Box<dyn AbstractBenchmark>, NotImplementedError>;
+    fn fan_in(&self) -> Result<Box<dyn AbstractBenchmark>, NotImplementedError>;
+    fn fanout(&self) -> Result<Box<dyn AbstractBenchmark>, NotImplementedError>;
+    fn big(&self) -> Result<Box<dyn AbstractBenchmark>, NotImplementedError>;
+    fn bank(&self) -> Result<Box<dyn AbstractBenchmark>, NotImplementedError>;
+    fn producer_consumer(&self) -> Result<Box<dyn AbstractBenchmark>, NotImplementedError>;
+    fn dining_philosophers(&self) -> Result<Box<dyn AbstractBenchmark>, NotImplementedError>;
+    fn nqueens(&self) -> Result<Box<dyn AbstractBenchmark>, NotImplementedError>;
+    fn parallel_sort(&self) -> Result<Box<dyn AbstractBenchmark>, NotImplementedError>;
 }
 
 impl Clone for Box<dyn BenchmarkFactory> {
@@ -427,6 +451,13 @@ pub enum BenchmarkError {
     InvalidDeployment(String),
 }
 
+impl BenchmarkError {
+    /// An [InvalidTest](BenchmarkError::InvalidTest) error for the parameter `field`.
+    pub fn invalid_param<R: std::fmt::Display>(field: &str, reason: R) -> BenchmarkError {
+        BenchmarkError::InvalidTest(format!("Invalid parameter {}: {}", field, reason))
+    }
+}
+
 impl From<grpc::Error> for BenchmarkError {
     fn from(error: grpc::Error) -> Self { BenchmarkError::RPCError(error) }
 }
@@ -676,6 +707,42 @@ pub(crate) mod tests {
         ) -> Result<Box<dyn AbstractDistributedBenchmark>, NotImplementedError> {
             Ok(Test3B {}.into())
         }
+
+        fn thread_ring(&self) -> Result<Box<dyn AbstractBenchmark>, NotImplementedError> {
+            Ok(Test2B {}.into())
+        }
+
+        fn fan_in(&self) -> Result<Box<dyn AbstractBenchmark>, NotImplementedError> {
+            Ok(Test2B {}.into())
+        }
+
+        fn fanout(&self) -> Result<Box<dyn AbstractBenchmark>, NotImplementedError> {
+            Ok(Test2B {}.into())
+        }
+
+        fn big(&self) -> Result<Box<dyn AbstractBenchmark>, NotImplementedError> {
+            Ok(Test2B {}.into())
+        }
+
+        fn bank(&self) -> Result<Box<dyn AbstractBenchmark>, NotImplementedError> {
+            Ok(Test2B {}.into())
+        }
+
+        fn producer_consumer(&self) -> Result<Box<dyn AbstractBenchmark>, NotImplementedError> {
+            Ok(Test2B {}.into())
+        }
+
+        fn dining_philosophers(&self) -> Result<Box<dyn AbstractBenchmark>, NotImplementedError> {
+            Ok(Test2B {}.into())
+        }
+
+        fn nqueens(&self) -> Result<Box<dyn AbstractBenchmark>, NotImplementedError> {
+            Ok(Test2B {}.into())
+        }
+
+        fn parallel_sort(&self) -> Result<Box<dyn AbstractBenchmark>, NotImplementedError> {
+            Ok(Test2B {}.into())
+        }
     }
 
     #[test]
@@ -6,6 +6,7 @@ use crate::{
         distributed_grpc::{self, BenchmarkClient},
         messages,
     },
+    result_writer,
 };
 use crossbeam::channel as cbchannel;
 use futures::{future, sync::oneshot, Future};
@@ -350,6 +351,7 @@ impl BenchmarkMaster {
         invocation: BenchInvocation,
     ) -> () {
         let msg = invocation.msg;
+        let parameters = result_writer::describe_parameters(&*msg);
         let (res, label) = match invocation.benchmark {
             AbstractBench::Local(b) => {
                 let label = b.label();
@@ -365,6 +367,7 @@ impl BenchmarkMaster {
         let blogger = self.logger.new(o!("benchmark" => label));
         match res {
             Ok(tr) => {
+                result_writer::record(label, parameters, &tr);
                 promise.send(tr).expect("Receiver was closed?!?");
             },
             Err(e) => {
@@ -699,6 +702,96 @@ impl benchmarks_grpc::BenchmarkRunner for RunnerHandler {
         let b_res = self.benchmarks.sized_throughput();
         self.enqueue_if_implemented(b_res, |b| BenchInvocation::new(b.into(), p))
     }
+
+    fn thread_ring(
+        &self,
+        _o: grpc::RequestOptions,
+        p: benchmarks::ThreadRingRequest,
+    ) -> grpc::SingleResponse<messages::TestResult> {
+        info!(self.logger, "Got Thread Ring req: {:?}", p);
+        let b_res = self.benchmarks.thread_ring();
+        self.enqueue_if_implemented(b_res, |b| BenchInvocation::new(b.into(), p))
+    }
+
+    fn fan_in(
+        &self,
+        _o: grpc::RequestOptions,
+        p: benchmarks::FanInRequest,
+    ) -> grpc::SingleResponse<messages::TestResult> {
+        info!(self.logger, "Got Fan In req: {:?}", p);
+        let b_res = self.benchmarks.fan_in();
+        self.enqueue_if_implemented(b_res, |b| BenchInvocation::new(b.into(), p))
+    }
+
+    fn fanout(
+        &self,
+        _o: grpc::RequestOptions,
+        p: benchmarks::FanoutRequest,
+    ) -> grpc::SingleResponse<messages::TestResult> {
+        info!(self.logger, "Got Fanout req: {:?}", p);
+        let b_res = self.benchmarks.fanout();
+        self.enqueue_if_implemented(b_res, |b| BenchInvocation::new(b.into(), p))
+    }
+
+    fn big(
+        &self,
+        _o: grpc::RequestOptions,
+        p: benchmarks::BigRequest,
+    ) -> grpc::SingleResponse<messages::TestResult> {
+        info!(self.logger, "Got Big req: {:?}", p);
+        let b_res = self.benchmarks.big();
+        self.enqueue_if_implemented(b_res, |b| BenchInvocation::new(b.into(), p))
+    }
+
+    fn bank(
+        &self,
+        _o: grpc::RequestOptions,
+        p: benchmarks::BankRequest,
+    ) -> grpc::SingleResponse<messages::TestResult> {
+        info!(self.logger, "Got Bank req: {:?}", p);
+        let b_res = self.benchmarks.bank();
+        self.enqueue_if_implemented(b_res, |b| BenchInvocation::new(b.into(), p))
+    }
+
+    fn producer_consumer(
+        &self,
+        _o: grpc::RequestOptions,
+        p: benchmarks::ProducerConsumerRequest,
+    ) -> grpc::SingleResponse<messages::TestResult> {
+        info!(self.logger, "Got ProducerConsumer req: {:?}", p);
+        let b_res = self.benchmarks.producer_consumer();
+        self.enqueue_if_implemented(b_res, |b| BenchInvocation::new(b.into(), p))
+    }
+
+    fn dining_philosophers(
+        &self,
+        _o: grpc::RequestOptions,
+        p: benchmarks::DiningPhilosophersRequest,
+    ) -> grpc::SingleResponse<messages::TestResult> {
+        info!(self.logger, "Got DiningPhilosophers req: {:?}", p);
+        let b_res = self.benchmarks.dining_philosophers();
+        self.enqueue_if_implemented(b_res, |b| BenchInvocation::new(b.into(), p))
+    }
+
+    fn nqueens(
+        &self,
+        _o: grpc::RequestOptions,
+        p: benchmarks::NQueensRequest,
+    ) -> grpc::SingleResponse<messages::TestResult> {
+        info!(self.logger, "Got NQueens req: {:?}", p);
+        let b_res = self.benchmarks.nqueens();
+        self.enqueue_if_implemented(b_res, |b| BenchInvocation::new(b.into(), p))
+    }
+
+    fn parallel_sort(
+        &self,
+        _o: grpc::RequestOptions,
+        p: benchmarks::ParallelSortRequest,
+    ) -> grpc::SingleResponse<messages::TestResult> {
+        info!(self.logger, "Got ParallelSort req: {:?}", p);
+        let b_res = self.benchmarks.parallel_sort();
+        self.enqueue_if_implemented(b_res, |b| BenchInvocation::new(b.into(), p))
+    }
 }
 
 #[derive(Clone)]
@@ -2,6 +2,7 @@ use crate::{
     benchmark::{Benchmark, BenchmarkError, BenchmarkInstance, *},
     benchmark_master::ClientEntry,
     kompics_benchmarks::*,
+    result_writer,
 };
 use futures::future::{self, Future};
 //use slog::{crit, debug, error, info, o, warn, Drain, Logger};
@@ -100,6 +101,7 @@ pub(crate) const RSE_TARGET: f64 = 0.1; // 10% RSE
 pub(crate) const NS_TO_MS: f64 = 1.0 / (1000.0 * 1000.0);
 
 pub fn run<B: Benchmark>(_b: &B, c: &B::Conf) -> Result<Vec<f64>, BenchmarkError> {
+    B::validate(c)?;
     let mut bi = B::new_instance();
     bi.setup(c);
     let mut results = Vec::with_capacity(MIN_RUNS);
@@ -142,6 +144,561 @@ pub fn run<B: Benchmark>(_b: &B, c: &B::Conf) -> Result<Vec<f64>, BenchmarkError
     // }
 }
 
+/// Like [run](run), but also writes a result record if a result writer was configured.
+pub fn run_and_record<B: Benchmark>(b: &B, c: &B::Conf) -> messages::TestResult
+where B::Conf: ::protobuf::Message {
+    let parameters = result_writer::describe_parameters(c);
+    let res: messages::TestResult = run(b, c).into();
+    result_writer::record(B::LABEL, parameters, &res);
+    res
+}
+
+/// A benchmark configuration with a single parameter that controls the problem size,
+/// such that larger values lead to longer iterations.
+pub trait Scalable: Sized {
+    /// Name of the scaling parameter, as it appears in the request.
+    const SCALING_PARAMETER: &'static str;
+
+    fn scaling_value(&self) -> u64;
+    fn with_scaling_value(&self, value: u64) -> Self;
+}
+
+impl Scalable for benchmarks::PingPongRequest {
+    const SCALING_PARAMETER: &'static str = "number_of_messages";
+
+    fn scaling_value(&self) -> u64 { self.number_of_messages }
+
+    fn with_scaling_value(&self, value: u64) -> Self {
+        let mut c = self.clone();
+        c.set_number_of_messages(value);
+        c
+    }
+}
+
+impl Scalable for benchmarks::ThroughputPingPongRequest {
+    const SCALING_PARAMETER: &'static str = "messages_per_pair";
+
+    fn scaling_value(&self) -> u64 { self.messages_per_pair }
+
+    fn with_scaling_value(&self, value: u64) -> Self {
+        let mut c = self.clone();
+        c.set_messages_per_pair(value);
+        c
+    }
+}
+
+impl Scalable for benchmarks::FibonacciRequest {
+    const SCALING_PARAMETER: &'static str = "fib_number";
+
+    fn scaling_value(&self) -> u64 { self.fib_number as u64 }
+
+    fn with_scaling_value(&self, value: u64) -> Self {
+        let mut c = self.clone();
+        c.set_fib_number(clamp_u32(value));
+        c
+    }
+}
+
+impl Scalable for benchmarks::ChameneosRequest {
+    const SCALING_PARAMETER: &'static str = "number_of_meetings";
+
+    fn scaling_value(&self) -> u64 { self.number_of_meetings }
+
+    fn with_scaling_value(&self, value: u64) -> Self {
+        let mut c = self.clone();
+        c.set_number_of_meetings(value);
+        c
+    }
+}
+
+impl Scalable for benchmarks::ThreadRingRequest {
+    const SCALING_PARAMETER: &'static str = "number_of_passes";
+
+    fn scaling_value(&self) -> u64 { self.number_of_passes }
+
+    fn with_scaling_value(&self, value: u64) -> Self {
+        let mut c = self.clone();
+        c.set_number_of_passes(value);
+        c
+    }
+}
+
+impl Scalable for benchmarks::FanInRequest {
+    const SCALING_PARAMETER: &'static str = "messages_per_producer";
+
+    fn scaling_value(&self) -> u64 { self.messages_per_producer }
+
+    fn with_scaling_value(&self, value: u64) -> Self {
+        let mut c = self.clone();
+        c.set_messages_per_producer(value);
+        c
+    }
+}
+
+impl Scalable for benchmarks::FanoutRequest {
+    const SCALING_PARAMETER: &'static str = "number_of_rounds";
+
+    fn scaling_value(&self) -> u64 { self.number_of_rounds }
+
+    fn with_scaling_value(&self, value: u64) -> Self {
+        let mut c = self.clone();
+        c.set_number_of_rounds(value);
+        c
+    }
+}
+
+impl Scalable for benchmarks::BigRequest {
+    const SCALING_PARAMETER: &'static str = "number_of_pings";
+
+    fn scaling_value(&self) -> u64 { self.number_of_pings }
+
+    fn with_scaling_value(&self, value: u64) -> Self {
+        let mut c = self.clone();
+        c.set_number_of_pings(value);
+        c
+    }
+}
+
+impl Scalable for benchmarks::BankRequest {
+    const SCALING_PARAMETER: &'static str = "number_of_transactions";
+
+    fn scaling_value(&self) -> u64 { self.number_of_transactions }
+
+    fn with_scaling_value(&self, value: u64) -> Self {
+        let mut c = self.clone();
+        c.set_number_of_transactions(value);
+        c
+    }
+}
+
+impl Scalable for benchmarks::ProducerConsumerRequest {
+    const SCALING_PARAMETER: &'static str = "number_of_items";
+
+    fn scaling_value(&self) -> u64 { self.number_of_items }
+
+    fn with_scaling_value(&self, value: u64) -> Self {
+        let mut c = self.clone();
+        c.set_number_of_items(value);
+        c
+    }
+}
+
+impl Scalable for benchmarks::DiningPhilosophersRequest {
+    const SCALING_PARAMETER: &'static str = "number_of_rounds";
+
+    fn scaling_value(&self) -> u64 { self.number_of_rounds }
+
+    fn with_scaling_value(&self, value: u64) -> Self {
+        let mut c = self.clone();
+        c.set_number_of_rounds(value);
+        c
+    }
+}
+
+impl Scalable for benchmarks::NQueensRequest {
+    const SCALING_PARAMETER: &'static str = "board_size";
+
+    fn scaling_value(&self) -> u64 { self.board_size as u64 }
+
+    fn with_scaling_value(&self, value: u64) -> Self {
+        let mut c = self.clone();
+        c.set_board_size(clamp_u32(value));
+        c
+    }
+}
+
+impl Scalable for benchmarks::ParallelSortRequest {
+    const SCALING_PARAMETER: &'static str = "data_size";
+
+    fn scaling_value(&self) -> u64 { self.data_size }
+
+    fn with_scaling_value(&self, value: u64) -> Self {
+        let mut c = self.clone();
+        c.set_data_size(value);
+        c
+    }
+}
+
+/// The scaling value is the number of blocks per side,
+/// so that `number_of_nodes` always stays a multiple of `block_size`.
+impl Scalable for benchmarks::APSPRequest {
+    const SCALING_PARAMETER: &'static str = "number_of_nodes";
+
+    fn scaling_value(&self) -> u64 {
+        if self.block_size == 0 {
+            0
+        } else {
+            (self.number_of_nodes / self.block_size) as u64
+        }
+    }
+
+    fn with_scaling_value(&self, value: u64) -> Self {
+        let mut c = self.clone();
+        let block_size = self.block_size.max(1);
+        c.set_number_of_nodes(clamp_u32(value.saturating_mul(block_size as u64)));
+        c
+    }
+}
+
+impl Scalable for benchmarks::SizedThroughputRequest {
+    const SCALING_PARAMETER: &'static str = "number_of_batches";
+
+    fn scaling_value(&self) -> u64 { self.number_of_batches as u64 }
+
+    fn with_scaling_value(&self, value: u64) -> Self {
+        let mut c = self.clone();
+        c.set_number_of_batches(clamp_u32(value));
+        c
+    }
+}
+
+fn clamp_u32(value: u64) -> u32 { value.min(u32::MAX as u64) as u32 }
+
+/// Object-safe counterpart of [Scalable](Scalable), for requests whose type is only known
+/// at runtime, e.g. from a benchmark label.
+pub trait ScalableRequest {
+    fn parameter(&self) -> &'static str;
+    fn start_value(&self) -> u64;
+    /// A copy of the request with the scaling parameter set to `value`.
+    fn scaled(&self, value: u64) -> Box<dyn ::protobuf::Message>;
+    fn message_mut(&mut self) -> &mut dyn ::protobuf::Message;
+}
+impl<C> ScalableRequest for C
+where C: Scalable + ::protobuf::Message + Clone
+{
+    fn parameter(&self) -> &'static str { C::SCALING_PARAMETER }
+
+    fn start_value(&self) -> u64 { self.scaling_value() }
+
+    fn scaled(&self, value: u64) -> Box<dyn ::protobuf::Message> {
+        Box::new(self.with_scaling_value(value))
+    }
+
+    fn message_mut(&mut self) -> &mut dyn ::protobuf::Message { self }
+}
+
+/// An empty request for the local benchmark with the given label,
+/// if it has a scaling parameter.
+pub fn scalable_request(label: &str) -> Option<Box<dyn ScalableRequest>> {
+    let request: Box<dyn ScalableRequest> = match label {
+        "PingPong" => Box::new(benchmarks::PingPongRequest::new()),
+        "ThroughputPingPong" => Box::new(benchmarks::ThroughputPingPongRequest::new()),
+        "Fibonacci" => Box::new(benchmarks::FibonacciRequest::new()),
+        "Chameneos" => Box::new(benchmarks::ChameneosRequest::new()),
+        "AllPairsShortestPath" => Box::new(benchmarks::APSPRequest::new()),
+        "ThreadRing" => Box::new(benchmarks::ThreadRingRequest::new()),
+        "FanIn" => Box::new(benchmarks::FanInRequest::new()),
+        "Fanout" => Box::new(benchmarks::FanoutRequest::new()),
+        "Big" => Box::new(benchmarks::BigRequest::new()),
+        "Bank" => Box::new(benchmarks::BankRequest::new()),
+        "ProducerConsumer" => Box::new(benchmarks::ProducerConsumerRequest::new()),
+        "DiningPhilosophers" => Box::new(benchmarks::DiningPhilosophersRequest::new()),
+        "NQueens" => Box::new(benchmarks::NQueensRequest::new()),
+        "ParallelSort" => Box::new(benchmarks::ParallelSortRequest::new()),
+        _ => return None,
+    };
+    Some(request)
+}
+
+/// Sets the scalar field `name` of `msg` from its textual `value`.
+///
+/// Enums are given by their number. Nested messages and repeated fields are not supported.
+pub fn set_parameter(
+    msg: &mut dyn ::protobuf::Message,
+    name: &str,
+    value: &str,
+) -> Result<(), String> {
+    use ::protobuf::descriptor::{FieldDescriptorProto_Label, FieldDescriptorProto_Type};
+
+    let field = msg
+        .descriptor()
+        .fields()
+        .iter()
+        .find(|f| f.name() == name)
+        .ok_or_else(|| format!("{} has no parameter {}", msg.descriptor().name(), name))?;
+    let proto = field.proto();
+    if proto.get_label() == FieldDescriptorProto_Label::LABEL_REPEATED {
+        return Err(format!("Parameter {} is repeated, which is not supported", name));
+    }
+    let number = proto.get_number() as u32;
+    let invalid =
+        |e: &dyn std::fmt::Display| format!("Invalid value for {}: {} ({})", name, value, e);
+    // go through the wire format, since the generated setters can't be looked up by name
+    let mut bytes: Vec<u8> = Vec::new();
+    {
+        let mut os = ::protobuf::CodedOutputStream::vec(&mut bytes);
+        let res = match proto.get_field_type() {
+            FieldDescriptorProto_Type::TYPE_UINT64 => {
+                os.write_uint64(number, value.parse().map_err(|e| invalid(&e))?)
+            },
+            FieldDescriptorProto_Type::TYPE_UINT32 => {
+                os.write_uint32(number, value.parse().map_err(|e| invalid(&e))?)
+            },
+            FieldDescriptorProto_Type::TYPE_INT64 => {
+                os.write_int64(number, value.parse().map_err(|e| invalid(&e))?)
+            },
+            FieldDescriptorProto_Type::TYPE_INT32 => {
+                os.write_int32(number, value.parse().map_err(|e| invalid(&e))?)
+            },
+            FieldDescriptorProto_Type::TYPE_ENUM => {
+                os.write_enum(number, value.parse().map_err(|e| invalid(&e))?)
+            },
+            FieldDescriptorProto_Type::TYPE_BOOL => {
+                os.write_bool(number, value.parse().map_err(|e| invalid(&e))?)
+            },
+            FieldDescriptorProto_Type::TYPE_DOUBLE => {
+                os.write_double(number, value.parse().map_err(|e| invalid(&e))?)
+            },
+            FieldDescriptorProto_Type::TYPE_FLOAT => {
+                os.write_float(number, value.parse().map_err(|e| invalid(&e))?)
+            },
+            FieldDescriptorProto_Type::TYPE_STRING => os.write_string(number, value),
+            t => return Err(format!("Parameter {} has unsupported type {:?}", name, t)),
+        };
+        res.and_then(|_| os.flush()).map_err(|e| invalid(&e))?;
+    }
+    msg.merge_from_bytes(&bytes).map_err(|e| invalid(&e))
+}
+
+/// Parameters of the search done by [calibrate](calibrate).
+#[derive(Debug, Clone)]
+pub struct CalibrationConfig {
+    /// The desired duration of a single iteration.
+    pub target_millis:       f64,
+    /// Accepted relative deviation from `target_millis`.
+    pub tolerance:           f64,
+    pub min_value:           u64,
+    pub max_value:           u64,
+    /// Upper bound on the number of values that are tried.
+    pub max_steps:           usize,
+    /// Iterations measured per value; their median is compared to the target.
+    pub iterations_per_step: usize,
+}
+impl Default for CalibrationConfig {
+    fn default() -> Self {
+        CalibrationConfig {
+            target_millis:       500.0,
+            tolerance:           0.2,
+            min_value:           1,
+            max_value:           u32::MAX as u64,
+            max_steps:           30,
+            iterations_per_step: 3,
+        }
+    }
+}
+
+/// The iterations measured for one value of the scaling parameter.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CalibrationStep {
+    pub value:         u64,
+    pub run_results:   Vec<f64>,
+    pub median_millis: f64,
+}
+impl CalibrationStep {
+    fn new(value: u64, run_results: Vec<f64>) -> CalibrationStep {
+        let mut sorted = run_results.clone();
+        sorted.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+        let median_millis = if sorted.is_empty() {
+            f64::NAN
+        } else if sorted.len() % 2 == 0 {
+            (sorted[sorted.len() / 2 - 1] + sorted[sorted.len() / 2]) / 2.0
+        } else {
+            sorted[sorted.len() / 2]
+        };
+        CalibrationStep { value, run_results, median_millis }
+    }
+}
+
+/// Outcome of [calibrate](calibrate).
+///
+/// If the target could not be hit within the configured bounds,
+/// `converged` is false and `value` is the closest value that was tried.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Calibration {
+    pub parameter:     &'static str,
+    pub value:         u64,
+    pub target_millis: f64,
+    pub converged:     bool,
+    pub steps:         Vec<CalibrationStep>,
+}
+impl Calibration {
+    /// The step that was measured for the chosen value.
+    pub fn chosen_step(&self) -> Option<&CalibrationStep> {
+        self.steps.iter().rev().find(|s| s.value == self.value)
+    }
+}
+impl std::fmt::Display for Calibration {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        writeln!(
+            f,
+            "Calibrated {}={} for a target of {}ms ({}):",
+            self.parameter,
+            self.value,
+            self.target_millis,
+            if self.converged { "converged" } else { "did not converge" }
+        )?;
+        for step in self.steps.iter() {
+            writeln!(f, "  {}={} -> {:.3}ms", self.parameter, step.value, step.median_millis)?;
+        }
+        Ok(())
+    }
+}
+
+/// Searches for the value of the scaling parameter of `c` at which a single iteration
+/// takes about [target_millis](CalibrationConfig::target_millis).
+///
+/// Starting from the value in `c`, the value is doubled (or halved) until the target is
+/// bracketed, and then bisected until an iteration is within the tolerance.
+/// Every tried value runs on a fresh instance.
+///
+/// Only the tried values are validated, so the starting value may be one the benchmark
+/// would reject, e.g. zero.
+pub fn calibrate<B: Benchmark>(
+    _b: &B,
+    c: &B::Conf,
+    config: &CalibrationConfig,
+) -> Result<Calibration, BenchmarkError>
+where
+    B::Conf: Scalable,
+{
+    let (value, converged, steps) = calibration_search(c.scaling_value(), config, |value| {
+        let conf = c.with_scaling_value(value);
+        B::validate(&conf)?;
+        Ok(sample::<B>(&conf, config.iterations_per_step.max(1)))
+    })?;
+    Ok(Calibration {
+        parameter: <B::Conf as Scalable>::SCALING_PARAMETER,
+        value,
+        target_millis: config.target_millis,
+        converged,
+        steps,
+    })
+}
+
+/// Like [calibrate](calibrate), but for a benchmark and request that are only known at runtime.
+pub fn calibrate_abstract(
+    b: &dyn AbstractBenchmark,
+    request: &dyn ScalableRequest,
+    config: &CalibrationConfig,
+) -> Result<Calibration, BenchmarkError> {
+    let (value, converged, steps) = calibration_search(request.start_value(), config, |value| {
+        let mut bi = b.new_instance();
+        // also validates the request
+        bi.setup(request.scaled(value))?;
+        let iterations = config.iterations_per_step.max(1);
+        let mut results = Vec::with_capacity(iterations);
+        for i in 0..iterations {
+            bi.prepare_iteration();
+            let res = measure(|| bi.run_iteration());
+            results.push(res);
+            bi.cleanup_iteration(i + 1 == iterations, res);
+        }
+        Ok(results)
+    })?;
+    Ok(Calibration {
+        parameter: request.parameter(),
+        value,
+        target_millis: config.target_millis,
+        converged,
+        steps,
+    })
+}
+
+fn sample<B: Benchmark>(c: &B::Conf, iterations: usize) -> Vec<f64> {
+    let mut bi = B::new_instance();
+    bi.setup(c);
+    let mut results = Vec::with_capacity(iterations);
+    for i in 0..iterations {
+        bi.prepare_iteration();
+        let res = measure(|| bi.run_iteration());
+        results.push(res);
+        bi.cleanup_iteration(i + 1 == iterations, res);
+    }
+    results
+}
+
+fn calibration_search<F>(
+    start: u64,
+    config: &CalibrationConfig,
+    mut measure_at: F,
+) -> Result<(u64, bool, Vec<CalibrationStep>), BenchmarkError>
+where
+    F: FnMut(u64) -> Result<Vec<f64>, BenchmarkError>,
+{
+    let target = config.target_millis;
+    let min_value = config.min_value.max(1);
+    let max_value = config.max_value.max(min_value);
+    let mut steps: Vec<CalibrationStep> = Vec::new();
+    let mut probe = |value: u64, steps: &mut Vec<CalibrationStep>| -> Result<f64, BenchmarkError> {
+        let step = CalibrationStep::new(value, measure_at(value)?);
+        let t = step.median_millis;
+        steps.push(step);
+        Ok(t)
+    };
+    let hit = |t: f64| (t - target).abs() <= target * config.tolerance;
+
+    let mut value = start.max(min_value).min(max_value);
+    let mut t = probe(value, &mut steps)?;
+    if hit(t) {
+        return Ok((value, true, steps));
+    }
+    // lower bound is too fast, upper bound is too slow
+    let (mut lo, mut hi) = if t < target {
+        loop {
+            if value >= max_value || steps.len() >= config.max_steps {
+                return Ok(closest(target, steps));
+            }
+            let lo = value;
+            value = value.saturating_mul(2).min(max_value);
+            t = probe(value, &mut steps)?;
+            if hit(t) {
+                return Ok((value, true, steps));
+            } else if t > target {
+                break (lo, value);
+            }
+        }
+    } else {
+        loop {
+            if value <= min_value || steps.len() >= config.max_steps {
+                return Ok(closest(target, steps));
+            }
+            let hi = value;
+            value = (value / 2).max(min_value);
+            t = probe(value, &mut steps)?;
+            if hit(t) {
+                return Ok((value, true, steps));
+            } else if t < target {
+                break (value, hi);
+            }
+        }
+    };
+    while hi - lo > 1 && steps.len() < config.max_steps {
+        value = lo + (hi - lo) / 2;
+        t = probe(value, &mut steps)?;
+        if hit(t) {
+            return Ok((value, true, steps));
+        } else if t < target {
+            lo = value;
+        } else {
+            hi = value;
+        }
+    }
+    Ok(closest(target, steps))
+}
+
+fn closest(target: f64, steps: Vec<CalibrationStep>) -> (u64, bool, Vec<CalibrationStep>) {
+    let value = steps
+        .iter()
+        .min_by(|a, b| {
+            let da = (a.median_millis - target).abs();
+            let db = (b.median_millis - target).abs();
+            da.partial_cmp(&db).unwrap_or(std::cmp::Ordering::Equal)
+        })
+        .map(|s| s.value)
+        .expect("at least one value was measured");
+    (value, false, steps)
+}
+
 impl From<Result<Vec<f64>, BenchmarkError>> for messages::TestResult {
     fn from(res: Result<Vec<f64>, BenchmarkError>) -> Self {
         match res {
@@ -260,3 +817,49 @@ impl DistributedIteration {
         f.map(move |_| (self, is_final))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn linear(ms_per_unit: f64) -> impl FnMut(u64) -> Result<Vec<f64>, BenchmarkError> {
+        move |value| Ok(vec![value as f64 * ms_per_unit; 3])
+    }
+
+    #[test]
+    fn test_calibration_doubles_and_bisects() {
+        let config = CalibrationConfig::default();
+        let (value, converged, steps) =
+            calibration_search(1, &config, linear(0.01)).expect("search");
+        assert!(converged);
+        let t = value as f64 * 0.01;
+        assert!((t - config.target_millis).abs() <= config.target_millis * config.tolerance);
+        assert_eq!(steps.last().unwrap().value, value);
+        assert!(steps.len() <= config.max_steps);
+    }
+
+    #[test]
+    fn test_calibration_halves_from_large_start() {
+        let config = CalibrationConfig { tolerance: 0.05, ..CalibrationConfig::default() };
+        // fibonacci-like growth
+        let measure = |value: u64| Ok(vec![1.6f64.powi(value as i32) / 1000.0]);
+        let (value, converged, _) = calibration_search(1000, &config, measure).expect("search");
+        let t = 1.6f64.powi(value as i32) / 1000.0;
+        if converged {
+            assert!((t - config.target_millis).abs() <= config.target_millis * config.tolerance);
+        } else {
+            // bracketed between two neighbouring values that are both outside the tolerance
+            assert!(t > config.target_millis / 1.6 && t < config.target_millis * 1.6, "{}", t);
+        }
+    }
+
+    #[test]
+    fn test_calibration_respects_max_value() {
+        let config = CalibrationConfig { max_value: 100, ..CalibrationConfig::default() };
+        let (value, converged, steps) =
+            calibration_search(1, &config, linear(0.01)).expect("search");
+        assert!(!converged);
+        assert_eq!(value, 100);
+        assert!(steps.iter().all(|s| s.value <= 100));
+    }
+}
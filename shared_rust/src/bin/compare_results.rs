@@ -0,0 +1,57 @@
+//! Compares a candidate result file against a baseline and reports regressions.
+//!
+//! Usage: `compare_results [--alpha A] [--min-effect E] [--min-change C] <baseline.jsonl> <candidate.jsonl>`
+//!
+//! Exits with 1 if any run got significantly slower or started failing, and with 2 on invalid input.
+use benchmark_suite_shared::{
+    regression::{self, Thresholds},
+    result_writer,
+};
+use std::{env, process};
+
+const USAGE: &str = "Usage: compare_results [--alpha A] [--min-effect E] [--min-change C] <baseline.jsonl> <candidate.jsonl>
+
+  --alpha       significance level of the Mann-Whitney U test (default 0.01)
+  --min-effect  minimum absolute rank-biserial correlation (default 0.3)
+  --min-change  minimum relative change of the median, e.g. 0.05 for 5% (default 0.05)";
+
+fn fail(msg: &str) -> ! {
+    eprintln!("{}", msg);
+    eprintln!("{}", USAGE);
+    process::exit(2);
+}
+
+fn parse_threshold(flag: &str, value: Option<String>) -> f64 {
+    let value = value.unwrap_or_else(|| fail(&format!("Missing value for {}", flag)));
+    value.parse().unwrap_or_else(|_| fail(&format!("Invalid value for {}: {}", flag, value)))
+}
+
+fn main() {
+    let mut thresholds = Thresholds::default();
+    let mut files: Vec<String> = Vec::new();
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--alpha" => thresholds.alpha = parse_threshold(&arg, args.next()),
+            "--min-effect" => thresholds.min_effect_size = parse_threshold(&arg, args.next()),
+            "--min-change" => thresholds.min_relative_change = parse_threshold(&arg, args.next()),
+            "-h" | "--help" => {
+                println!("{}", USAGE);
+                return;
+            },
+            _ => files.push(arg),
+        }
+    }
+    if files.len() != 2 {
+        fail(&format!("Expected a baseline and a candidate file, but got {} files.", files.len()));
+    }
+    let baseline = result_writer::read_json_lines(&files[0])
+        .unwrap_or_else(|e| fail(&format!("Could not read baseline {}: {}", files[0], e)));
+    let candidate = result_writer::read_json_lines(&files[1])
+        .unwrap_or_else(|e| fail(&format!("Could not read candidate {}: {}", files[1], e)));
+    let report = regression::compare(&baseline, &candidate, thresholds);
+    println!("{}", report);
+    if report.has_regressions() || report.has_failures() {
+        process::exit(1);
+    }
+}
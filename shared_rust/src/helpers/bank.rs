@@ -0,0 +1,54 @@
+use rand::{rngs::SmallRng, Rng, SeedableRng};
+
+/// The balance every account starts an iteration with.
+pub const INITIAL_BALANCE: i64 = 1_000_000;
+/// The largest amount a single transfer moves.
+pub const MAX_AMOUNT: i64 = 1_000;
+
+/// A single transfer of `amount` from account `source` to account `destination`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Transfer {
+    pub source:      usize,
+    pub destination: usize,
+    pub amount:      i64,
+}
+
+/// Draws the teller's transfers between distinct accounts from a stream seeded by the request.
+///
+/// Accounts may be overdrawn, since only the total amount of money is checked.
+#[derive(Debug, Clone)]
+pub struct TransferGenerator {
+    rng:          SmallRng,
+    num_accounts: usize,
+}
+impl TransferGenerator {
+    pub fn new(seed: u64, num_accounts: usize) -> TransferGenerator {
+        assert!(num_accounts > 1, "A transfer needs at least two accounts");
+        TransferGenerator { rng: SmallRng::seed_from_u64(seed), num_accounts }
+    }
+
+    pub fn next_transfer(&mut self) -> Transfer {
+        let source = self.rng.gen_range(0usize, self.num_accounts);
+        let i = self.rng.gen_range(0usize, self.num_accounts - 1);
+        let destination = if i >= source { i + 1 } else { i };
+        let amount = self.rng.gen_range(1i64, MAX_AMOUNT + 1);
+        Transfer { source, destination, amount }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_transfer_generator() {
+        let mut gen = TransferGenerator::new(42, 5);
+        let transfers: Vec<Transfer> = (0..100).map(|_| gen.next_transfer()).collect();
+        assert!(transfers.iter().all(|t| t.source < 5 && t.destination < 5));
+        assert!(transfers.iter().all(|t| t.source != t.destination));
+        assert!(transfers.iter().all(|t| t.amount > 0 && t.amount <= MAX_AMOUNT));
+        let mut same_seed = TransferGenerator::new(42, 5);
+        let transfers2: Vec<Transfer> = (0..100).map(|_| same_seed.next_transfer()).collect();
+        assert_eq!(transfers, transfers2);
+    }
+}
@@ -0,0 +1,49 @@
+use rand::{rngs::SmallRng, Rng, SeedableRng};
+
+/// Picks the destinations of one actor's pings, uniformly among all other actors.
+///
+/// Each actor draws from its own stream derived from the request's seed,
+/// so a run is reproducible independently of the order the actors are scheduled in.
+#[derive(Debug, Clone)]
+pub struct PeerSampler {
+    rng:        SmallRng,
+    own_id:     usize,
+    num_actors: usize,
+}
+impl PeerSampler {
+    pub fn new(seed: u64, own_id: usize, num_actors: usize) -> PeerSampler {
+        assert!(num_actors > 1, "An actor needs at least one peer");
+        // spread the ids, so that neighbouring actors don't get similar seeds
+        let actor_seed = seed ^ (own_id as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15);
+        PeerSampler { rng: SmallRng::seed_from_u64(actor_seed), own_id, num_actors }
+    }
+
+    pub fn next_peer(&mut self) -> usize {
+        let i = self.rng.gen_range(0usize, self.num_actors - 1);
+        if i >= self.own_id {
+            i + 1
+        } else {
+            i
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_peer_sampler() {
+        let mut sampler = PeerSampler::new(42, 3, 5);
+        let peers: Vec<usize> = (0..100).map(|_| sampler.next_peer()).collect();
+        assert!(peers.iter().all(|&p| p < 5 && p != 3));
+        for p in &[0usize, 1, 2, 4] {
+            assert!(peers.contains(p), "Peer {} was never picked", p);
+        }
+        let mut same_seed = PeerSampler::new(42, 3, 5);
+        let peers2: Vec<usize> = (0..100).map(|_| same_seed.next_peer()).collect();
+        assert_eq!(peers, peers2);
+        let mut two = PeerSampler::new(7, 0, 2);
+        assert!((0..10).all(|_| two.next_peer() == 1));
+    }
+}
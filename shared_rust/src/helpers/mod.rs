@@ -1,2 +1,9 @@
+pub mod bank;
+pub mod big;
 pub mod chameneos;
 pub mod graphs;
+pub mod nqueens;
+pub mod parallel_sort;
+pub mod producer_consumer;
+pub mod thread_ring;
+pub mod validation;
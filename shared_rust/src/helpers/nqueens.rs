@@ -0,0 +1,127 @@
+/// Number of distinct solutions for board sizes `0..=MAX_BOARD_SIZE` (OEIS A000170).
+const KNOWN_SOLUTIONS: [u64; 28] = [
+    1,
+    1,
+    0,
+    0,
+    2,
+    10,
+    4,
+    40,
+    92,
+    352,
+    724,
+    2_680,
+    14_200,
+    73_712,
+    365_596,
+    2_279_184,
+    14_772_512,
+    95_815_104,
+    666_090_624,
+    4_968_057_848,
+    39_029_188_884,
+    314_666_222_712,
+    2_691_008_701_644,
+    24_233_937_684_440,
+    227_514_171_973_736,
+    2_207_893_435_808_352,
+    22_317_699_616_364_044,
+    234_907_967_154_122_528,
+];
+
+/// The largest board whose solution count is known, and still fits into the masks of a [Board].
+pub const MAX_BOARD_SIZE: usize = KNOWN_SOLUTIONS.len() - 1;
+
+/// The number of ways to place `n` non-attacking queens on an `n`×`n` board, if it is known.
+pub fn known_solutions(n: usize) -> Option<u64> { KNOWN_SOLUTIONS.get(n).cloned() }
+
+/// A board with queens placed on its first `row` rows, one per row.
+///
+/// Instead of the positions, only the columns and diagonals they attack on the next row are kept.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Board {
+    size:            usize,
+    row:             usize,
+    columns:         u32,
+    left_diagonals:  u32,
+    right_diagonals: u32,
+}
+impl Board {
+    pub fn empty(size: usize) -> Board {
+        assert!(size <= MAX_BOARD_SIZE, "Board size {} is too large", size);
+        Board { size, row: 0, columns: 0, left_diagonals: 0, right_diagonals: 0 }
+    }
+
+    pub fn row(&self) -> usize { self.row }
+
+    fn full(&self) -> u32 { ((1u64 << self.size) - 1) as u32 }
+
+    /// All boards with a queen added on the next row.
+    fn children(&self) -> impl Iterator<Item = Board> {
+        let board = *self;
+        let mut free = !(self.columns | self.left_diagonals | self.right_diagonals) & self.full();
+        std::iter::from_fn(move || {
+            if free == 0 {
+                None
+            } else {
+                let queen = free & free.wrapping_neg();
+                free ^= queen;
+                Some(Board {
+                    size:            board.size,
+                    row:             board.row + 1,
+                    columns:         board.columns | queen,
+                    left_diagonals:  ((board.left_diagonals | queen) << 1) & board.full(),
+                    right_diagonals: (board.right_diagonals | queen) >> 1,
+                })
+            }
+        })
+    }
+
+    /// Counts the solutions that complete this board, sequentially.
+    pub fn count_solutions(&self) -> u64 {
+        if self.row == self.size {
+            1
+        } else {
+            self.children().map(|child| child.count_solutions()).sum()
+        }
+    }
+
+    /// All valid boards with queens on the first `depth` rows, or fewer if the board is smaller.
+    ///
+    /// Together they cover every solution exactly once, so they make up the work units of a
+    /// parallel search.
+    pub fn split(&self, depth: usize) -> Vec<Board> {
+        if self.row >= depth.min(self.size) {
+            vec![*self]
+        } else {
+            self.children().flat_map(|child| child.split(depth)).collect()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_count_solutions() {
+        for n in 0usize..=10 {
+            assert_eq!(Some(Board::empty(n).count_solutions()), known_solutions(n), "n={}", n);
+        }
+        assert_eq!(known_solutions(MAX_BOARD_SIZE + 1), None);
+    }
+
+    #[test]
+    fn test_split() {
+        let board = Board::empty(8);
+        assert_eq!(board.split(0), vec![board]);
+        for depth in 1usize..=9 {
+            let units = board.split(depth);
+            assert!(units.iter().all(|b| b.row() == depth.min(8)));
+            let total: u64 = units.iter().map(Board::count_solutions).sum();
+            assert_eq!(total, 92, "depth={}", depth);
+        }
+        assert_eq!(board.split(1).len(), 8);
+    }
+}
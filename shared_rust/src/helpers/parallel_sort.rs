@@ -0,0 +1,59 @@
+use rand::{rngs::SmallRng, Rng, SeedableRng};
+
+/// Which part of its parent's data a sorter got.
+#[derive(Debug, Clone, Copy)]
+pub enum Half {
+    Left,
+    Right,
+}
+
+/// The unsorted input of an iteration, the same for every run with the same `seed`.
+pub fn random_data(data_size: usize, seed: u64) -> Vec<u64> {
+    let mut rng = SmallRng::seed_from_u64(seed);
+    (0..data_size).map(|_| rng.gen()).collect()
+}
+
+/// Merges two sorted parts into one sorted `Vec`, moving rather than copying the elements.
+pub fn merge(left: Vec<u64>, right: Vec<u64>) -> Vec<u64> {
+    let mut merged = Vec::with_capacity(left.len() + right.len());
+    let mut left = left.into_iter().peekable();
+    let mut right = right.into_iter().peekable();
+    loop {
+        let take_left = match (left.peek(), right.peek()) {
+            (Some(l), Some(r)) => l <= r,
+            (Some(_), None) => true,
+            (None, Some(_)) => false,
+            (None, None) => return merged,
+        };
+        if take_left {
+            merged.extend(left.next());
+        } else {
+            merged.extend(right.next());
+        }
+    }
+}
+
+/// Checks the result of an iteration against the size of its input.
+pub fn check_sorted(sorted: &[u64], data_size: usize) -> () {
+    assert_eq!(sorted.len(), data_size, "Elements were lost or duplicated!");
+    assert!(sorted.windows(2).all(|w| w[0] <= w[1]), "Data was not sorted!");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_merge() {
+        assert_eq!(merge(vec![1, 4, 5], vec![2, 3, 6, 7]), vec![1, 2, 3, 4, 5, 6, 7]);
+        assert_eq!(merge(vec![], vec![2, 3]), vec![2, 3]);
+        let mut data = random_data(1000, 42);
+        assert_eq!(data, random_data(1000, 42));
+        let right = data.split_off(400);
+        let mut left = data;
+        left.sort_unstable();
+        let mut right = right;
+        right.sort_unstable();
+        check_sorted(&merge(left, right), 1000);
+    }
+}
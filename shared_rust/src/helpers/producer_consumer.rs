@@ -0,0 +1,31 @@
+/// The number of items producer `id` produces, so that `num_producers` produce `num_items` in total.
+pub fn items_of_producer(id: usize, num_producers: usize, num_items: u64) -> u64 {
+    let producers = num_producers as u64;
+    // the first producers make up for the remainder of the split
+    let extra = if (id as u64) < num_items % producers { 1u64 } else { 0u64 };
+    num_items / producers + extra
+}
+
+/// Stands in for the cost of producing or consuming an item, without being optimised away.
+pub fn synthetic_work(rounds: u64, input: u64) -> u64 {
+    let mut x = input | 1; // xorshift gets stuck on 0
+    for _ in 0u64..rounds {
+        x ^= x << 13;
+        x ^= x >> 7;
+        x ^= x << 17;
+    }
+    x
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_items_of_producer() {
+        let items: Vec<u64> = (0usize..4).map(|id| items_of_producer(id, 4, 10)).collect();
+        assert_eq!(items, vec![3, 3, 2, 2]);
+        assert_eq!(items_of_producer(0, 1, 10), 10);
+        assert_eq!(items_of_producer(2, 4, 2), 0);
+    }
+}
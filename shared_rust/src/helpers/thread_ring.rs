@@ -0,0 +1,18 @@
+/// Indices of the ring members that hold a token when an iteration starts.
+///
+/// The tokens are spread evenly around the ring, so they don't start out queued behind each other.
+pub fn token_holders(number_of_actors: usize, number_of_tokens: usize) -> Vec<usize> {
+    (0usize..number_of_tokens).map(|i| (i * number_of_actors) / number_of_tokens).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_token_holders() {
+        assert_eq!(token_holders(10, 1), vec![0]);
+        assert_eq!(token_holders(10, 3), vec![0, 3, 6]);
+        assert_eq!(token_holders(4, 4), vec![0, 1, 2, 3]);
+    }
+}
@@ -0,0 +1,445 @@
+//! Parameter checks shared by all implementations of the common benchmarks.
+//!
+//! Each function is meant to be used directly from
+//! [Benchmark::validate](crate::benchmark::Benchmark::validate) or
+//! [DistributedBenchmark::validate](crate::benchmark::DistributedBenchmark::validate).
+use super::nqueens;
+use crate::{
+    benchmark::{BenchmarkError, DeploymentMetaData},
+    kompics_benchmarks::benchmarks::*,
+};
+
+/// Fails with a field-specific [InvalidTest](BenchmarkError::InvalidTest) unless `value > 0`.
+pub fn positive<N>(field: &str, value: N) -> Result<(), BenchmarkError>
+where N: PartialOrd + Default + std::fmt::Display {
+    if value > N::default() {
+        Ok(())
+    } else {
+        Err(BenchmarkError::invalid_param(field, format!("must be positive, but was {}", value)))
+    }
+}
+
+pub fn ping_pong(c: &PingPongRequest) -> Result<(), BenchmarkError> {
+    positive("number_of_messages", c.number_of_messages)
+}
+
+pub fn throughput_ping_pong(c: &ThroughputPingPongRequest) -> Result<(), BenchmarkError> {
+    positive("messages_per_pair", c.messages_per_pair)?;
+    positive("pipeline_size", c.pipeline_size)?;
+    positive("parallelism", c.parallelism)
+}
+
+pub fn fibonacci(c: &FibonacciRequest) -> Result<(), BenchmarkError> {
+    positive("fib_number", c.fib_number)
+}
+
+pub fn chameneos(c: &ChameneosRequest) -> Result<(), BenchmarkError> {
+    if c.number_of_chameneos < 2 {
+        return Err(BenchmarkError::invalid_param(
+            "number_of_chameneos",
+            format!("at least 2 are needed for a meeting, but was {}", c.number_of_chameneos),
+        ));
+    }
+    positive("number_of_meetings", c.number_of_meetings)
+}
+
+pub fn thread_ring(c: &ThreadRingRequest) -> Result<(), BenchmarkError> {
+    if c.number_of_actors < 2 {
+        return Err(BenchmarkError::invalid_param(
+            "number_of_actors",
+            format!("at least 2 are needed for a ring, but was {}", c.number_of_actors),
+        ));
+    }
+    positive("number_of_passes", c.number_of_passes)?;
+    positive("number_of_tokens", c.number_of_tokens)?;
+    if c.number_of_tokens > c.number_of_actors {
+        return Err(BenchmarkError::invalid_param(
+            "number_of_tokens",
+            format!(
+                "must be at most number_of_actors={}, but was {}",
+                c.number_of_actors, c.number_of_tokens
+            ),
+        ));
+    }
+    Ok(())
+}
+
+pub fn fan_in(c: &FanInRequest) -> Result<(), BenchmarkError> {
+    positive("number_of_producers", c.number_of_producers)?;
+    positive("messages_per_producer", c.messages_per_producer)
+}
+
+pub fn fanout(c: &FanoutRequest) -> Result<(), BenchmarkError> {
+    positive("number_of_subscribers", c.number_of_subscribers)?;
+    positive("number_of_rounds", c.number_of_rounds)
+}
+
+pub fn big(c: &BigRequest) -> Result<(), BenchmarkError> {
+    if c.number_of_actors < 2 {
+        return Err(BenchmarkError::invalid_param(
+            "number_of_actors",
+            format!("at least 2 are needed to have peers, but was {}", c.number_of_actors),
+        ));
+    }
+    positive("number_of_pings", c.number_of_pings)
+}
+
+pub fn bank(c: &BankRequest) -> Result<(), BenchmarkError> {
+    if c.number_of_accounts < 2 {
+        return Err(BenchmarkError::invalid_param(
+            "number_of_accounts",
+            format!("at least 2 are needed for a transfer, but was {}", c.number_of_accounts),
+        ));
+    }
+    positive("number_of_transactions", c.number_of_transactions)
+}
+
+pub fn producer_consumer(c: &ProducerConsumerRequest) -> Result<(), BenchmarkError> {
+    positive("buffer_size", c.buffer_size)?;
+    positive("number_of_producers", c.number_of_producers)?;
+    positive("number_of_consumers", c.number_of_consumers)?;
+    positive("number_of_items", c.number_of_items)
+}
+
+pub fn dining_philosophers(c: &DiningPhilosophersRequest) -> Result<(), BenchmarkError> {
+    if c.number_of_philosophers < 2 {
+        return Err(BenchmarkError::invalid_param(
+            "number_of_philosophers",
+            format!("at least 2 are needed to share forks, but was {}", c.number_of_philosophers),
+        ));
+    }
+    positive("number_of_rounds", c.number_of_rounds)
+}
+
+pub fn nqueens(c: &NQueensRequest) -> Result<(), BenchmarkError> {
+    positive("board_size", c.board_size)?;
+    if c.board_size as usize > nqueens::MAX_BOARD_SIZE {
+        return Err(BenchmarkError::invalid_param(
+            "board_size",
+            format!(
+                "the solution count is only known up to {}, but was {}",
+                nqueens::MAX_BOARD_SIZE,
+                c.board_size
+            ),
+        ));
+    }
+    positive("number_of_workers", c.number_of_workers)?;
+    if c.split_depth > c.board_size {
+        Err(BenchmarkError::invalid_param(
+            "split_depth",
+            format!("must not exceed board_size={}, but was {}", c.board_size, c.split_depth),
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+pub fn parallel_sort(c: &ParallelSortRequest) -> Result<(), BenchmarkError> {
+    positive("data_size", c.data_size)?;
+    positive("threshold", c.threshold)
+}
+
+pub fn all_pairs_shortest_path(c: &APSPRequest) -> Result<(), BenchmarkError> {
+    positive("number_of_nodes", c.number_of_nodes)?;
+    positive("block_size", c.block_size)?;
+    if c.number_of_nodes % c.block_size != 0 {
+        Err(BenchmarkError::invalid_param(
+            "block_size",
+            format!("{} does not divide number_of_nodes={}", c.block_size, c.number_of_nodes),
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+pub fn atomic_register(
+    c: &AtomicRegisterRequest,
+    meta: &DeploymentMetaData,
+) -> Result<(), BenchmarkError> {
+    for (field, value) in
+        [("read_workload", c.read_workload), ("write_workload", c.write_workload)].iter()
+    {
+        if *value < 0.0 || *value > 1.0 {
+            return Err(BenchmarkError::invalid_param(
+                field,
+                format!("must be in [0, 1], but was {}", value),
+            ));
+        }
+    }
+    if c.read_workload + c.write_workload > 1.0 {
+        return Err(BenchmarkError::invalid_param(
+            "write_workload",
+            format!(
+                "read_workload + write_workload must be at most 1, but was {} + {}",
+                c.read_workload, c.write_workload
+            ),
+        ));
+    }
+    positive("partition_size", c.partition_size)?;
+    positive("number_of_keys", c.number_of_keys)?;
+    if meta.number_of_clients() < c.partition_size - 1 {
+        return Err(BenchmarkError::invalid_param(
+            "partition_size",
+            format!(
+                "{} needs {} clients, but only {} are available",
+                c.partition_size,
+                c.partition_size - 1,
+                meta.number_of_clients()
+            ),
+        ));
+    }
+    Ok(())
+}
+
+pub fn streaming_windows(c: &StreamingWindowsRequest) -> Result<(), BenchmarkError> {
+    positive("number_of_partitions", c.number_of_partitions)?;
+    positive("batch_size", c.batch_size)?;
+    positive("number_of_windows", c.number_of_windows)?;
+    positive("window_size_amplification", c.window_size_amplification)?;
+    if c.window_size.is_empty() {
+        return Err(BenchmarkError::invalid_param("window_size", "must not be empty"));
+    }
+    Ok(())
+}
+
+pub fn sized_throughput(c: &SizedThroughputRequest) -> Result<(), BenchmarkError> {
+    positive("message_size", c.message_size)?;
+    positive("batch_size", c.batch_size)?;
+    positive("number_of_batches", c.number_of_batches)?;
+    positive("number_of_pairs", c.number_of_pairs)
+}
+
+/// Checks the transport settings shared by the distributed benchmarks.
+///
+/// Zero values in `c` mean "implementation default" and are replaced by those in `defaults`,
+/// so that the checks apply to the values that will actually be used.
+pub fn network_tuning(c: &NetworkTuning, defaults: &NetworkTuning) -> Result<(), BenchmarkError> {
+    let or_default = |v: u64, default: u64| if v == 0 { default } else { v };
+    let chunk_size = or_default(c.chunk_size, defaults.chunk_size);
+    let initial_chunk_count = or_default(c.initial_chunk_count, defaults.initial_chunk_count);
+    let max_chunk_count = or_default(c.max_chunk_count, defaults.max_chunk_count);
+    let encode_buf_min_free_space =
+        or_default(c.encode_buf_min_free_space, defaults.encode_buf_min_free_space);
+    if chunk_size < 128 {
+        return Err(BenchmarkError::invalid_param(
+            "network.chunk_size",
+            format!("must be at least 128, but was {}", chunk_size),
+        ));
+    }
+    if encode_buf_min_free_space >= chunk_size {
+        return Err(BenchmarkError::invalid_param(
+            "network.encode_buf_min_free_space",
+            format!(
+                "must be smaller than chunk_size={}, but was {}",
+                chunk_size, encode_buf_min_free_space
+            ),
+        ));
+    }
+    if initial_chunk_count > max_chunk_count {
+        return Err(BenchmarkError::invalid_param(
+            "network.initial_chunk_count",
+            format!(
+                "must be at most max_chunk_count={}, but was {}",
+                max_chunk_count, initial_chunk_count
+            ),
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_apsp_validation() {
+        let mut req = APSPRequest::new();
+        req.set_number_of_nodes(12);
+        req.set_block_size(4);
+        assert!(all_pairs_shortest_path(&req).is_ok());
+        req.set_block_size(5);
+        match all_pairs_shortest_path(&req) {
+            Err(BenchmarkError::InvalidTest(msg)) => assert!(msg.contains("block_size"), "{}", msg),
+            r => panic!("Unexpected result {:?}", r),
+        }
+        req.set_block_size(0);
+        assert!(all_pairs_shortest_path(&req).is_err());
+    }
+
+    #[test]
+    fn test_throughput_ping_pong_validation() {
+        let mut req = ThroughputPingPongRequest::new();
+        req.set_messages_per_pair(100);
+        req.set_pipeline_size(10);
+        req.set_parallelism(0);
+        match throughput_ping_pong(&req) {
+            Err(BenchmarkError::InvalidTest(msg)) => {
+                assert!(msg.contains("parallelism"), "{}", msg)
+            },
+            r => panic!("Unexpected result {:?}", r),
+        }
+        req.set_parallelism(2);
+        assert!(throughput_ping_pong(&req).is_ok());
+    }
+
+    fn assert_invalid(result: Result<(), BenchmarkError>, field: &str) {
+        match result {
+            Err(BenchmarkError::InvalidTest(msg)) => assert!(msg.contains(field), "{}", msg),
+            r => panic!("Unexpected result {:?}", r),
+        }
+    }
+
+    #[test]
+    fn test_thread_ring_validation() {
+        let mut req = ThreadRingRequest::new();
+        req.set_number_of_actors(10);
+        req.set_number_of_passes(100);
+        req.set_number_of_tokens(1);
+        assert!(thread_ring(&req).is_ok());
+        req.set_number_of_tokens(11);
+        assert_invalid(thread_ring(&req), "number_of_tokens");
+        req.set_number_of_tokens(10);
+        assert!(thread_ring(&req).is_ok());
+        req.set_number_of_actors(1);
+        req.set_number_of_tokens(1);
+        assert!(thread_ring(&req).is_err());
+    }
+
+    #[test]
+    fn test_fan_in_validation() {
+        let mut req = FanInRequest::new();
+        req.set_number_of_producers(4);
+        req.set_messages_per_producer(100);
+        assert!(fan_in(&req).is_ok());
+        req.set_pipeline_size(10);
+        assert!(fan_in(&req).is_ok());
+        req.set_number_of_producers(0);
+        assert_invalid(fan_in(&req), "number_of_producers");
+    }
+
+    #[test]
+    fn test_fanout_validation() {
+        let mut req = FanoutRequest::new();
+        req.set_number_of_subscribers(8);
+        req.set_number_of_rounds(100);
+        assert!(fanout(&req).is_ok());
+        req.set_number_of_rounds(0);
+        assert_invalid(fanout(&req), "number_of_rounds");
+    }
+
+    #[test]
+    fn test_big_validation() {
+        let mut req = BigRequest::new();
+        req.set_number_of_actors(10);
+        req.set_number_of_pings(100);
+        assert!(big(&req).is_ok());
+        req.set_seed(42);
+        assert!(big(&req).is_ok());
+        req.set_number_of_actors(1);
+        assert_invalid(big(&req), "number_of_actors");
+    }
+
+    #[test]
+    fn test_bank_validation() {
+        let mut req = BankRequest::new();
+        req.set_number_of_accounts(10);
+        req.set_number_of_transactions(1000);
+        assert!(bank(&req).is_ok());
+        req.set_number_of_transactions(0);
+        assert_invalid(bank(&req), "number_of_transactions");
+        req.set_number_of_transactions(1000);
+        req.set_number_of_accounts(1);
+        assert_invalid(bank(&req), "number_of_accounts");
+    }
+
+    #[test]
+    fn test_producer_consumer_validation() {
+        let mut req = ProducerConsumerRequest::new();
+        req.set_buffer_size(10);
+        req.set_number_of_producers(4);
+        req.set_number_of_consumers(4);
+        req.set_number_of_items(1000);
+        assert!(producer_consumer(&req).is_ok());
+        req.set_work_per_item(100);
+        assert!(producer_consumer(&req).is_ok());
+        req.set_buffer_size(0);
+        assert_invalid(producer_consumer(&req), "buffer_size");
+    }
+
+    #[test]
+    fn test_dining_philosophers_validation() {
+        let mut req = DiningPhilosophersRequest::new();
+        req.set_number_of_philosophers(5);
+        req.set_number_of_rounds(100);
+        assert!(dining_philosophers(&req).is_ok());
+        req.set_number_of_philosophers(1);
+        assert_invalid(dining_philosophers(&req), "number_of_philosophers");
+        req.set_number_of_philosophers(5);
+        req.set_number_of_rounds(0);
+        assert_invalid(dining_philosophers(&req), "number_of_rounds");
+    }
+
+    #[test]
+    fn test_nqueens_validation() {
+        let mut req = NQueensRequest::new();
+        req.set_board_size(8);
+        req.set_number_of_workers(4);
+        req.set_split_depth(2);
+        assert!(nqueens(&req).is_ok());
+        req.set_split_depth(9);
+        assert_invalid(nqueens(&req), "split_depth");
+        req.set_split_depth(2);
+        req.set_board_size(64);
+        assert_invalid(nqueens(&req), "board_size");
+    }
+
+    #[test]
+    fn test_parallel_sort_validation() {
+        let mut req = ParallelSortRequest::new();
+        req.set_data_size(100_000);
+        req.set_threshold(1000);
+        assert!(parallel_sort(&req).is_ok());
+        req.set_seed(42);
+        assert!(parallel_sort(&req).is_ok());
+        req.set_threshold(0);
+        assert_invalid(parallel_sort(&req), "threshold");
+    }
+
+    #[test]
+    fn test_network_tuning_validation() {
+        let mut defaults = NetworkTuning::new();
+        defaults.set_chunk_size(128000);
+        defaults.set_initial_chunk_count(2);
+        defaults.set_max_chunk_count(1000);
+        defaults.set_encode_buf_min_free_space(64);
+        let mut tuning = NetworkTuning::new();
+        assert!(network_tuning(&tuning, &defaults).is_ok());
+        tuning.set_chunk_size(100);
+        match network_tuning(&tuning, &defaults) {
+            Err(BenchmarkError::InvalidTest(msg)) => assert!(msg.contains("chunk_size"), "{}", msg),
+            r => panic!("Unexpected result {:?}", r),
+        }
+        tuning.set_chunk_size(1024);
+        tuning.set_encode_buf_min_free_space(1024);
+        match network_tuning(&tuning, &defaults) {
+            Err(BenchmarkError::InvalidTest(msg)) => {
+                assert!(msg.contains("encode_buf_min_free_space"), "{}", msg)
+            },
+            r => panic!("Unexpected result {:?}", r),
+        }
+        // checked against the default chunk_size
+        tuning.set_chunk_size(0);
+        tuning.set_encode_buf_min_free_space(200000);
+        assert!(network_tuning(&tuning, &defaults).is_err());
+        tuning.set_encode_buf_min_free_space(64);
+        tuning.set_initial_chunk_count(4);
+        tuning.set_max_chunk_count(2);
+        assert!(network_tuning(&tuning, &defaults).is_err());
+        // checked against the default max_chunk_count
+        tuning.set_max_chunk_count(0);
+        tuning.set_initial_chunk_count(2000);
+        assert!(network_tuning(&tuning, &defaults).is_err());
+        tuning.set_initial_chunk_count(4);
+        assert!(network_tuning(&tuning, &defaults).is_ok());
+    }
+}
@@ -1,12 +1,16 @@
 #![feature(array_map)]
 #![feature(unsized_locals)]
 #![feature(impl_trait_in_bindings)]
+pub mod actors;
+pub mod affinity;
 pub mod benchmark;
 pub mod benchmark_client;
 pub mod benchmark_master;
 pub mod benchmark_runner;
 pub mod helpers;
 pub mod kompics_benchmarks;
+pub mod regression;
+pub mod result_writer;
 
 pub use self::benchmark::*;
 use self::kompics_benchmarks::*;
@@ -40,7 +44,16 @@ impl BenchmarkMain {
         let _scope_guard = slog_scope::set_global_logger(logger.clone());
         let _log_guard = slog_stdlog::init().unwrap();
 
-        if args.len() <= 2 {
+        if args.get(1).map(|s| s == CALIBRATE_MODE).unwrap_or(false) {
+            match BenchmarkMain::calibrate(&args[2..], benchmarks.as_ref()) {
+                Ok(calibration) => println!("{}", calibration),
+                Err(e) => {
+                    eprintln!("{}", e);
+                    eprintln!("{}", CALIBRATE_USAGE);
+                    std::process::exit(1);
+                },
+            }
+        } else if args.len() <= 2 {
             // local mode
             let bench_runner_addr: String =
                 args.get(1).map(|s| s.clone()).unwrap_or("127.0.0.1:45678".to_string());
@@ -89,8 +102,55 @@ impl BenchmarkMain {
             panic!("Too many args={} provided!", args.len());
         };
     }
+
+    /// Runs the calibration mode on the arguments after `calibrate`.
+    ///
+    /// See [CALIBRATE_USAGE](CALIBRATE_USAGE) for the expected arguments.
+    pub fn calibrate(
+        args: &[String],
+        benchmarks: &dyn BenchmarkFactory,
+    ) -> Result<benchmark_runner::Calibration, String> {
+        let (label, target) = match args {
+            [label, target, ..] => (label, target),
+            _ => return Err("Expected a benchmark label and a target time.".to_string()),
+        };
+        let target_millis: f64 =
+            target.parse().map_err(|_| format!("Invalid target time: {}", target))?;
+        let mut request = benchmark_runner::scalable_request(label)
+            .ok_or_else(|| format!("Benchmark {} can't be calibrated.", label))?;
+        for param in args[2..].iter() {
+            let mut parts = param.splitn(2, '=');
+            match (parts.next(), parts.next()) {
+                (Some(name), Some(value)) => {
+                    benchmark_runner::set_parameter(request.message_mut(), name, value)?
+                },
+                _ => return Err(format!("Expected <parameter>=<value>, but got {}", param)),
+            }
+        }
+        let benchmark = match benchmarks.by_label(label) {
+            Ok(AbstractBench::Local(b)) => b,
+            Ok(AbstractBench::Distributed(_)) => {
+                return Err(format!("Benchmark {} is distributed and can't be calibrated.", label))
+            },
+            Err(e) => return Err(format!("Benchmark {} is not available: {:?}", label, e)),
+        };
+        let config = benchmark_runner::CalibrationConfig {
+            target_millis,
+            ..benchmark_runner::CalibrationConfig::default()
+        };
+        benchmark_runner::calibrate_abstract(benchmark.as_ref(), request.as_ref(), &config)
+            .map_err(|e| format!("Calibration of {} failed: {:?}", label, e))
+    }
 }
 
+/// The first argument that selects the calibration mode.
+pub const CALIBRATE_MODE: &str = "calibrate";
+pub const CALIBRATE_USAGE: &str =
+    "Usage: calibrate <benchmark label> <target millis> [<parameter>=<value>...]
+
+Searches for the value of the benchmark's scaling parameter at which a single iteration
+takes about <target millis>, starting from the given parameters.";
+
 fn force_shutdown() {
     std::thread::spawn(|| {
         thread::sleep(Duration::from_millis(500));
@@ -357,6 +417,121 @@ pub mod test_utils {
         let apspres = apspres_f.wait().expect("apsp result");
         check_result("AllPairsShortestPath", apspres);
 
+        /*
+         * Thread Ring
+         */
+        let mut trr = benchmarks::ThreadRingRequest::new();
+        trr.set_number_of_actors(10);
+        trr.set_number_of_passes(100);
+        trr.set_number_of_tokens(1);
+        let trres_f = bench_stub
+            .thread_ring(grpc::RequestOptions::default(), trr.clone())
+            .drop_metadata();
+        let trres = trres_f.wait().expect("tr result");
+        check_result("ThreadRing (Single)", trres);
+
+        trr.set_number_of_tokens(5);
+        let trres2_f =
+            bench_stub.thread_ring(grpc::RequestOptions::default(), trr).drop_metadata();
+        let trres2 = trres2_f.wait().expect("tr result");
+        check_result("ThreadRing (Many)", trres2);
+
+        /*
+         * Fan In
+         */
+        let mut fir = benchmarks::FanInRequest::new();
+        fir.set_number_of_producers(4);
+        fir.set_messages_per_producer(100);
+        let fires_f =
+            bench_stub.fan_in(grpc::RequestOptions::default(), fir.clone()).drop_metadata();
+        let fires = fires_f.wait().expect("fi result");
+        check_result("FanIn (Unlimited)", fires);
+
+        fir.set_pipeline_size(10);
+        let fires2_f = bench_stub.fan_in(grpc::RequestOptions::default(), fir).drop_metadata();
+        let fires2 = fires2_f.wait().expect("fi result");
+        check_result("FanIn (Pipelined)", fires2);
+
+        /*
+         * Fanout
+         */
+        let mut fanr = benchmarks::FanoutRequest::new();
+        fanr.set_number_of_subscribers(10);
+        fanr.set_number_of_rounds(100);
+        let fanres_f = bench_stub.fanout(grpc::RequestOptions::default(), fanr).drop_metadata();
+        let fanres = fanres_f.wait().expect("fanout result");
+        check_result("Fanout", fanres);
+
+        /*
+         * Big
+         */
+        let mut bigr = benchmarks::BigRequest::new();
+        bigr.set_number_of_actors(10);
+        bigr.set_number_of_pings(100);
+        bigr.set_seed(42);
+        let bigres_f = bench_stub.big(grpc::RequestOptions::default(), bigr).drop_metadata();
+        let bigres = bigres_f.wait().expect("big result");
+        check_result("Big", bigres);
+
+        /*
+         * Bank
+         */
+        let mut bankr = benchmarks::BankRequest::new();
+        bankr.set_number_of_accounts(10);
+        bankr.set_number_of_transactions(1000);
+        bankr.set_seed(42);
+        let bankres_f = bench_stub.bank(grpc::RequestOptions::default(), bankr).drop_metadata();
+        let bankres = bankres_f.wait().expect("bank result");
+        check_result("Bank", bankres);
+
+        /*
+         * ProducerConsumer
+         */
+        let mut pcr = benchmarks::ProducerConsumerRequest::new();
+        pcr.set_buffer_size(10);
+        pcr.set_number_of_producers(4);
+        pcr.set_number_of_consumers(4);
+        pcr.set_number_of_items(1000);
+        pcr.set_work_per_item(10);
+        let pcres_f =
+            bench_stub.producer_consumer(grpc::RequestOptions::default(), pcr).drop_metadata();
+        let pcres = pcres_f.wait().expect("producer_consumer result");
+        check_result("ProducerConsumer", pcres);
+
+        /*
+         * DiningPhilosophers
+         */
+        let mut dpr = benchmarks::DiningPhilosophersRequest::new();
+        dpr.set_number_of_philosophers(5);
+        dpr.set_number_of_rounds(100);
+        let dpres_f =
+            bench_stub.dining_philosophers(grpc::RequestOptions::default(), dpr).drop_metadata();
+        let dpres = dpres_f.wait().expect("dining_philosophers result");
+        check_result("DiningPhilosophers", dpres);
+
+        /*
+         * NQueens
+         */
+        let mut nqr = benchmarks::NQueensRequest::new();
+        nqr.set_board_size(8);
+        nqr.set_number_of_workers(4);
+        nqr.set_split_depth(2);
+        let nqres_f = bench_stub.nqueens(grpc::RequestOptions::default(), nqr).drop_metadata();
+        let nqres = nqres_f.wait().expect("nqueens result");
+        check_result("NQueens", nqres);
+
+        /*
+         * ParallelSort
+         */
+        let mut psr = benchmarks::ParallelSortRequest::new();
+        psr.set_data_size(10_000);
+        psr.set_threshold(1000);
+        psr.set_seed(42);
+        let psres_f =
+            bench_stub.parallel_sort(grpc::RequestOptions::default(), psr).drop_metadata();
+        let psres = psres_f.wait().expect("parallel_sort result");
+        check_result("ParallelSort", psres);
+
         /*
          * Sized Throughput
          */
@@ -562,6 +737,121 @@ pub mod test_utils {
         let apspres = apspres_f.wait().expect("apsp result");
         check_result("AllPairsShortestPath", apspres);
 
+        /*
+         * Thread Ring
+         */
+        let mut trr = benchmarks::ThreadRingRequest::new();
+        trr.set_number_of_actors(10);
+        trr.set_number_of_passes(100);
+        trr.set_number_of_tokens(1);
+        let trres_f = bench_stub
+            .thread_ring(grpc::RequestOptions::default(), trr.clone())
+            .drop_metadata();
+        let trres = trres_f.wait().expect("tr result");
+        check_result("ThreadRing (Single)", trres);
+
+        trr.set_number_of_tokens(5);
+        let trres2_f =
+            bench_stub.thread_ring(grpc::RequestOptions::default(), trr).drop_metadata();
+        let trres2 = trres2_f.wait().expect("tr result");
+        check_result("ThreadRing (Many)", trres2);
+
+        /*
+         * Fan In
+         */
+        let mut fir = benchmarks::FanInRequest::new();
+        fir.set_number_of_producers(4);
+        fir.set_messages_per_producer(100);
+        let fires_f =
+            bench_stub.fan_in(grpc::RequestOptions::default(), fir.clone()).drop_metadata();
+        let fires = fires_f.wait().expect("fi result");
+        check_result("FanIn (Unlimited)", fires);
+
+        fir.set_pipeline_size(10);
+        let fires2_f = bench_stub.fan_in(grpc::RequestOptions::default(), fir).drop_metadata();
+        let fires2 = fires2_f.wait().expect("fi result");
+        check_result("FanIn (Pipelined)", fires2);
+
+        /*
+         * Fanout
+         */
+        let mut fanr = benchmarks::FanoutRequest::new();
+        fanr.set_number_of_subscribers(10);
+        fanr.set_number_of_rounds(100);
+        let fanres_f = bench_stub.fanout(grpc::RequestOptions::default(), fanr).drop_metadata();
+        let fanres = fanres_f.wait().expect("fanout result");
+        check_result("Fanout", fanres);
+
+        /*
+         * Big
+         */
+        let mut bigr = benchmarks::BigRequest::new();
+        bigr.set_number_of_actors(10);
+        bigr.set_number_of_pings(100);
+        bigr.set_seed(42);
+        let bigres_f = bench_stub.big(grpc::RequestOptions::default(), bigr).drop_metadata();
+        let bigres = bigres_f.wait().expect("big result");
+        check_result("Big", bigres);
+
+        /*
+         * Bank
+         */
+        let mut bankr = benchmarks::BankRequest::new();
+        bankr.set_number_of_accounts(10);
+        bankr.set_number_of_transactions(1000);
+        bankr.set_seed(42);
+        let bankres_f = bench_stub.bank(grpc::RequestOptions::default(), bankr).drop_metadata();
+        let bankres = bankres_f.wait().expect("bank result");
+        check_result("Bank", bankres);
+
+        /*
+         * ProducerConsumer
+         */
+        let mut pcr = benchmarks::ProducerConsumerRequest::new();
+        pcr.set_buffer_size(10);
+        pcr.set_number_of_producers(4);
+        pcr.set_number_of_consumers(4);
+        pcr.set_number_of_items(1000);
+        pcr.set_work_per_item(10);
+        let pcres_f =
+            bench_stub.producer_consumer(grpc::RequestOptions::default(), pcr).drop_metadata();
+        let pcres = pcres_f.wait().expect("producer_consumer result");
+        check_result("ProducerConsumer", pcres);
+
+        /*
+         * DiningPhilosophers
+         */
+        let mut dpr = benchmarks::DiningPhilosophersRequest::new();
+        dpr.set_number_of_philosophers(5);
+        dpr.set_number_of_rounds(100);
+        let dpres_f =
+            bench_stub.dining_philosophers(grpc::RequestOptions::default(), dpr).drop_metadata();
+        let dpres = dpres_f.wait().expect("dining_philosophers result");
+        check_result("DiningPhilosophers", dpres);
+
+        /*
+         * NQueens
+         */
+        let mut nqr = benchmarks::NQueensRequest::new();
+        nqr.set_board_size(8);
+        nqr.set_number_of_workers(4);
+        nqr.set_split_depth(2);
+        let nqres_f = bench_stub.nqueens(grpc::RequestOptions::default(), nqr).drop_metadata();
+        let nqres = nqres_f.wait().expect("nqueens result");
+        check_result("NQueens", nqres);
+
+        /*
+         * ParallelSort
+         */
+        let mut psr = benchmarks::ParallelSortRequest::new();
+        psr.set_data_size(10_000);
+        psr.set_threshold(1000);
+        psr.set_seed(42);
+        let psres_f =
+            bench_stub.parallel_sort(grpc::RequestOptions::default(), psr).drop_metadata();
+        let psres = psres_f.wait().expect("parallel_sort result");
+        check_result("ParallelSort", psres);
+
         info!(logger, "Sending shutdown request to runner");
         runner_shutdown.store(true, Ordering::Relaxed);
         runner_handle.thread().unpark();
@@ -726,6 +1016,38 @@ mod tests {
         }
     }
 
+    /// An iteration sleeps for 100µs per unit of `fib_number`.
+    #[derive(Default)]
+    struct TestScalableBench;
+    impl Benchmark for TestScalableBench {
+        type Conf = benchmarks::FibonacciRequest;
+        type Instance = TestScalableBenchI;
+
+        const LABEL: &'static str = "Fibonacci";
+
+        fn msg_to_conf(msg: Box<dyn (::protobuf::Message)>) -> Result<Self::Conf, BenchmarkError> {
+            downcast_msg!(msg; benchmarks::FibonacciRequest)
+        }
+
+        fn validate(c: &Self::Conf) -> Result<(), BenchmarkError> {
+            helpers::validation::fibonacci(c)
+        }
+
+        fn new_instance() -> Self::Instance { TestScalableBenchI { units: 0 } }
+    }
+    struct TestScalableBenchI {
+        units: u64,
+    }
+    impl BenchmarkInstance for TestScalableBenchI {
+        type Conf = benchmarks::FibonacciRequest;
+
+        fn setup(&mut self, c: &Self::Conf) -> () { self.units = c.fib_number as u64; }
+
+        fn run_iteration(&mut self) -> () {
+            std::thread::sleep(Duration::from_micros(100 * self.units));
+        }
+    }
+
     struct TestDistributedBench {
         //_data: std::marker::PhantomData<M>,
     }
@@ -826,6 +1148,7 @@ mod tests {
             match label {
                 TestLocalBench::LABEL => self.ping_pong().map_into(),
                 TestDistributedBench::LABEL => self.net_ping_pong().map_into(),
+                TestScalableBench::LABEL => self.fibonacci().map_into(),
                 _ => Err(NotImplementedError::NotFound),
             }
         }
@@ -865,7 +1188,7 @@ mod tests {
         }
 
         fn fibonacci(&self) -> Result<Box<dyn AbstractBenchmark>, NotImplementedError> {
-            Ok(TestLocalBench {}.into())
+            Ok(TestScalableBench {}.into())
         }
 
         fn chameneos(&self) -> Result<Box<dyn AbstractBenchmark>, NotImplementedError> {
@@ -887,6 +1210,42 @@ mod tests {
         ) -> Result<Box<dyn AbstractDistributedBenchmark>, NotImplementedError> {
             Ok(TestDistributedBench::new().into())
         }
+
+        fn thread_ring(&self) -> Result<Box<dyn AbstractBenchmark>, NotImplementedError> {
+            Ok(TestLocalBench {}.into())
+        }
+
+        fn fan_in(&self) -> Result<Box<dyn AbstractBenchmark>, NotImplementedError> {
+            Ok(TestLocalBench {}.into())
+        }
+
+        fn fanout(&self) -> Result<Box<dyn AbstractBenchmark>, NotImplementedError> {
+            Ok(TestLocalBench {}.into())
+        }
+
+        fn big(&self) -> Result<Box<dyn AbstractBenchmark>, NotImplementedError> {
+            Ok(TestLocalBench {}.into())
+        }
+
+        fn bank(&self) -> Result<Box<dyn AbstractBenchmark>, NotImplementedError> {
+            Ok(TestLocalBench {}.into())
+        }
+
+        fn producer_consumer(&self) -> Result<Box<dyn AbstractBenchmark>, NotImplementedError> {
+            Ok(TestLocalBench {}.into())
+        }
+
+        fn dining_philosophers(&self) -> Result<Box<dyn AbstractBenchmark>, NotImplementedError> {
+            Ok(TestLocalBench {}.into())
+        }
+
+        fn nqueens(&self) -> Result<Box<dyn AbstractBenchmark>, NotImplementedError> {
+            Ok(TestLocalBench {}.into())
+        }
+
+        fn parallel_sort(&self) -> Result<Box<dyn AbstractBenchmark>, NotImplementedError> {
+            Ok(TestLocalBench {}.into())
+        }
     }
 
     impl benchmarks_grpc::BenchmarkRunner for TestFactory {
@@ -1049,6 +1408,167 @@ mod tests {
             grpc::SingleResponse::completed(benchmark_runner::not_implemented())
         }
 
+        fn thread_ring(
+            &self,
+            _o: grpc::RequestOptions,
+            p: benchmarks::ThreadRingRequest,
+        ) -> grpc::SingleResponse<messages::TestResult> {
+            println!("Got thread ring req: {:?}", p);
+            let bench = benchmark::BenchmarkFactory::thread_ring(self);
+            let f = benchmark_runner::run_async(move || match bench {
+                Ok(b) => b.run(Box::new(p)).into(),
+                Err(e) => Err(BenchmarkError::NotImplemented(e)).into(),
+            })
+            .map_err(|e| {
+                println!("Converting benchmark error into grpc error: {:?}", e);
+                e.into()
+            });
+            grpc::SingleResponse::no_metadata(f)
+        }
+
+        fn fan_in(
+            &self,
+            _o: grpc::RequestOptions,
+            p: benchmarks::FanInRequest,
+        ) -> grpc::SingleResponse<messages::TestResult> {
+            println!("Got fan in req: {:?}", p);
+            let bench = benchmark::BenchmarkFactory::fan_in(self);
+            let f = benchmark_runner::run_async(move || match bench {
+                Ok(b) => b.run(Box::new(p)).into(),
+                Err(e) => Err(BenchmarkError::NotImplemented(e)).into(),
+            })
+            .map_err(|e| {
+                println!("Converting benchmark error into grpc error: {:?}", e);
+                e.into()
+            });
+            grpc::SingleResponse::no_metadata(f)
+        }
+
+        fn fanout(
+            &self,
+            _o: grpc::RequestOptions,
+            p: benchmarks::FanoutRequest,
+        ) -> grpc::SingleResponse<messages::TestResult> {
+            println!("Got fanout req: {:?}", p);
+            let bench = benchmark::BenchmarkFactory::fanout(self);
+            let f = benchmark_runner::run_async(move || match bench {
+                Ok(b) => b.run(Box::new(p)).into(),
+                Err(e) => Err(BenchmarkError::NotImplemented(e)).into(),
+            })
+            .map_err(|e| {
+                println!("Converting benchmark error into grpc error: {:?}", e);
+                e.into()
+            });
+            grpc::SingleResponse::no_metadata(f)
+        }
+
+        fn big(
+            &self,
+            _o: grpc::RequestOptions,
+            p: benchmarks::BigRequest,
+        ) -> grpc::SingleResponse<messages::TestResult> {
+            println!("Got big req: {:?}", p);
+            let bench = benchmark::BenchmarkFactory::big(self);
+            let f = benchmark_runner::run_async(move || match bench {
+                Ok(b) => b.run(Box::new(p)).into(),
+                Err(e) => Err(BenchmarkError::NotImplemented(e)).into(),
+            })
+            .map_err(|e| {
+                println!("Converting benchmark error into grpc error: {:?}", e);
+                e.into()
+            });
+            grpc::SingleResponse::no_metadata(f)
+        }
+
+        fn bank(
+            &self,
+            _o: grpc::RequestOptions,
+            p: benchmarks::BankRequest,
+        ) -> grpc::SingleResponse<messages::TestResult> {
+            println!("Got bank req: {:?}", p);
+            let bench = benchmark::BenchmarkFactory::bank(self);
+            let f = benchmark_runner::run_async(move || match bench {
+                Ok(b) => b.run(Box::new(p)).into(),
+                Err(e) => Err(BenchmarkError::NotImplemented(e)).into(),
+            })
+            .map_err(|e| {
+                println!("Converting benchmark error into grpc error: {:?}", e);
+                e.into()
+            });
+            grpc::SingleResponse::no_metadata(f)
+        }
+
+        fn producer_consumer(
+            &self,
+            _o: grpc::RequestOptions,
+            p: benchmarks::ProducerConsumerRequest,
+        ) -> grpc::SingleResponse<messages::TestResult> {
+            println!("Got producer_consumer req: {:?}", p);
+            let bench = benchmark::BenchmarkFactory::producer_consumer(self);
+            let f = benchmark_runner::run_async(move || match bench {
+                Ok(b) => b.run(Box::new(p)).into(),
+                Err(e) => Err(BenchmarkError::NotImplemented(e)).into(),
+            })
+            .map_err(|e| {
+                println!("Converting benchmark error into grpc error: {:?}", e);
+                e.into()
+            });
+            grpc::SingleResponse::no_metadata(f)
+        }
+
+        fn dining_philosophers(
+            &self,
+            _o: grpc::RequestOptions,
+            p: benchmarks::DiningPhilosophersRequest,
+        ) -> grpc::SingleResponse<messages::TestResult> {
+            println!("Got dining_philosophers req: {:?}", p);
+            let bench = benchmark::BenchmarkFactory::dining_philosophers(self);
+            let f = benchmark_runner::run_async(move || match bench {
+                Ok(b) => b.run(Box::new(p)).into(),
+                Err(e) => Err(BenchmarkError::NotImplemented(e)).into(),
+            })
+            .map_err(|e| {
+                println!("Converting benchmark error into grpc error: {:?}", e);
+                e.into()
+            });
+            grpc::SingleResponse::no_metadata(f)
+        }
+
+        fn nqueens(
+            &self,
+            _o: grpc::RequestOptions,
+            p: benchmarks::NQueensRequest,
+        ) -> grpc::SingleResponse<messages::TestResult> {
+            println!("Got nqueens req: {:?}", p);
+            let bench = benchmark::BenchmarkFactory::nqueens(self);
+            let f = benchmark_runner::run_async(move || match bench {
+                Ok(b) => b.run(Box::new(p)).into(),
+                Err(e) => Err(BenchmarkError::NotImplemented(e)).into(),
+            })
+            .map_err(|e| {
+                println!("Converting benchmark error into grpc error: {:?}", e);
+                e.into()
+            });
+            grpc::SingleResponse::no_metadata(f)
+        }
+
+        fn parallel_sort(
+            &self,
+            _o: grpc::RequestOptions,
+            p: benchmarks::ParallelSortRequest,
+        ) -> grpc::SingleResponse<messages::TestResult> {
+            println!("Got parallel_sort req: {:?}", p);
+            let bench = benchmark::BenchmarkFactory::parallel_sort(self);
+            let f = benchmark_runner::run_async(move || match bench {
+                Ok(b) => b.run(Box::new(p)).into(),
+                Err(e) => Err(BenchmarkError::NotImplemented(e)).into(),
+            })
+            .map_err(|e| {
+                println!("Converting benchmark error into grpc error: {:?}", e);
+                e.into()
+            });
+            grpc::SingleResponse::no_metadata(f)
+        }
     }
 
     #[test]
@@ -1062,4 +1582,29 @@ mod tests {
         let runner = TestFactory {};
         test_local_implementation(runner);
     }
+
+    fn calibrate_args(args: &[&str]) -> Result<benchmark_runner::Calibration, String> {
+        let args: Vec<String> = args.iter().map(|s| s.to_string()).collect();
+        BenchmarkMain::calibrate(&args, &TestFactory {})
+    }
+
+    #[test]
+    fn test_calibrate() {
+        // zero is rejected by validation, but only the tried values need to be valid
+        let calibration =
+            calibrate_args(&["Fibonacci", "20", "fib_number=0"]).expect("calibration");
+        println!("{}", calibration);
+        assert_eq!(calibration.parameter, "fib_number");
+        assert!(calibration.converged);
+        assert!(calibration.steps.iter().all(|s| s.value > 0));
+        let step = calibration.chosen_step().expect("chosen step");
+        assert!((step.median_millis - 20.0).abs() <= 20.0 * 0.2, "{}", step.median_millis);
+
+        assert!(calibrate_args(&["Fibonacci"]).is_err());
+        assert!(calibrate_args(&["Fibonacci", "20", "fib_number=abc"]).is_err());
+        assert!(calibrate_args(&["Fibonacci", "20", "number_of_messages=1"]).is_err());
+        assert!(calibrate_args(&["NetPingPong", "20"]).is_err());
+        // a known request type, but not provided by this factory
+        assert!(calibrate_args(&["Chameneos", "20"]).is_err());
+    }
 }
@@ -0,0 +1,448 @@
+//! Statistical comparison of two sets of [RunRecord](crate::result_writer::RunRecord)s.
+//!
+//! Runs are matched by implementation, benchmark and parameters, and their `run_results`
+//! are compared with a two-sided Mann–Whitney U test. The direction and size of a change
+//! is given by the rank-biserial correlation (Cliff's delta) and the relative change
+//! of the medians.
+use crate::result_writer::RunRecord;
+use std::{cmp::Ordering, collections::BTreeMap, fmt};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Thresholds {
+    /// Significance level for the Mann–Whitney U test.
+    pub alpha:               f64,
+    /// Minimum absolute rank-biserial correlation to count as a change.
+    pub min_effect_size:     f64,
+    /// Minimum absolute relative change of the median to count as a change.
+    pub min_relative_change: f64,
+}
+impl Default for Thresholds {
+    fn default() -> Self {
+        Thresholds {
+            alpha:               0.01,
+            min_effect_size:     0.3,
+            min_relative_change: 0.05,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MannWhitney {
+    /// U statistic of the candidate sample, i.e. the number of (baseline, candidate)
+    /// pairs where the candidate is larger, counting ties as one half.
+    pub u:           f64,
+    pub z:           f64,
+    /// Two-sided p-value from the normal approximation.
+    pub p_value:     f64,
+    /// Rank-biserial correlation in [-1, 1]. Positive means the candidate is larger.
+    pub effect_size: f64,
+}
+
+/// Runs a two-sided Mann–Whitney U test with tie correction.
+///
+/// Returns `None` if either sample is empty.
+pub fn mann_whitney_u(baseline: &[f64], candidate: &[f64]) -> Option<MannWhitney> {
+    let n1 = baseline.len();
+    let n2 = candidate.len();
+    if n1 == 0 || n2 == 0 {
+        return None;
+    }
+    let mut combined: Vec<(f64, bool)> =
+        baseline.iter().map(|v| (*v, false)).chain(candidate.iter().map(|v| (*v, true))).collect();
+    combined.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(Ordering::Equal));
+    let n = combined.len();
+    let mut candidate_rank_sum = 0.0;
+    let mut tie_term = 0.0;
+    let mut i = 0;
+    while i < n {
+        let mut j = i + 1;
+        while j < n && combined[j].0 == combined[i].0 {
+            j += 1;
+        }
+        // ranks are 1-based, tied values share the average rank
+        let avg_rank = ((i + 1 + j) as f64) / 2.0;
+        for entry in &combined[i..j] {
+            if entry.1 {
+                candidate_rank_sum += avg_rank;
+            }
+        }
+        let t = (j - i) as f64;
+        tie_term += t * t * t - t;
+        i = j;
+    }
+    let n1f = n1 as f64;
+    let n2f = n2 as f64;
+    let nf = n as f64;
+    let u = candidate_rank_sum - n2f * (n2f + 1.0) / 2.0;
+    let mean_u = n1f * n2f / 2.0;
+    let variance =
+        if n > 1 { n1f * n2f / 12.0 * ((nf + 1.0) - tie_term / (nf * (nf - 1.0))) } else { 0.0 };
+    let (z, p_value) = if variance > 0.0 {
+        let diff = u - mean_u;
+        // continuity correction
+        let corrected = if diff > 0.0 { (diff - 0.5).max(0.0) } else { (diff + 0.5).min(0.0) };
+        let z = corrected / variance.sqrt();
+        (z, (2.0 * (1.0 - standard_normal_cdf(z.abs()))).min(1.0))
+    } else {
+        (0.0, 1.0)
+    };
+    let effect_size = 2.0 * u / (n1f * n2f) - 1.0;
+    Some(MannWhitney { u, z, p_value, effect_size })
+}
+
+fn standard_normal_cdf(x: f64) -> f64 { 0.5 * (1.0 + erf(x / std::f64::consts::SQRT_2)) }
+
+// Abramowitz and Stegun 7.1.26, max error 1.5e-7
+fn erf(x: f64) -> f64 {
+    let sign = if x < 0.0 { -1.0 } else { 1.0 };
+    let x = x.abs();
+    let t = 1.0 / (1.0 + 0.3275911 * x);
+    let poly = t
+        * (0.254829592
+            + t * (-0.284496736 + t * (1.421413741 + t * (-1.453152027 + t * 1.061405429))));
+    sign * (1.0 - poly * (-x * x).exp())
+}
+
+fn median(data: &[f64]) -> f64 {
+    let mut sorted = data.to_vec();
+    sorted.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+    let len = sorted.len();
+    if len == 0 {
+        f64::NAN
+    } else if len % 2 == 0 {
+        (sorted[len / 2 - 1] + sorted[len / 2]) / 2.0
+    } else {
+        sorted[len / 2]
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    /// The candidate is significantly slower.
+    Regression,
+    /// The candidate is significantly faster.
+    Improvement,
+    NoChange,
+    /// The run succeeded in the baseline, but failed in the candidate.
+    Failure,
+    /// One of the runs has no results to compare, or the baseline failed.
+    Incomparable,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct RunKey {
+    pub implementation: String,
+    pub benchmark:      String,
+    pub parameters:     String,
+}
+impl RunKey {
+    fn of(record: &RunRecord) -> RunKey {
+        RunKey {
+            implementation: record.provenance.implementation.clone(),
+            benchmark:      record.benchmark.clone(),
+            parameters:     record.parameters.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Comparison {
+    pub key:              RunKey,
+    pub baseline_median:  f64,
+    pub candidate_median: f64,
+    /// `(candidate - baseline) / baseline` of the medians.
+    ///
+    /// `None` if it is undefined, because the baseline median is zero.
+    pub relative_change:  Option<f64>,
+    pub test:             Option<MannWhitney>,
+    pub verdict:          Verdict,
+    /// Why the candidate failed, if the verdict is [Failure](Verdict::Failure).
+    pub failure_reason:   Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Report {
+    pub thresholds:  Thresholds,
+    pub comparisons: Vec<Comparison>,
+    /// Runs that only appear in the baseline.
+    pub missing:     Vec<RunKey>,
+    /// Runs that only appear in the candidate.
+    pub added:       Vec<RunKey>,
+}
+impl Report {
+    pub fn regressions(&self) -> impl Iterator<Item = &Comparison> {
+        self.comparisons.iter().filter(|c| c.verdict == Verdict::Regression)
+    }
+
+    pub fn improvements(&self) -> impl Iterator<Item = &Comparison> {
+        self.comparisons.iter().filter(|c| c.verdict == Verdict::Improvement)
+    }
+
+    pub fn failures(&self) -> impl Iterator<Item = &Comparison> {
+        self.comparisons.iter().filter(|c| c.verdict == Verdict::Failure)
+    }
+
+    pub fn has_regressions(&self) -> bool { self.regressions().next().is_some() }
+
+    pub fn has_failures(&self) -> bool { self.failures().next().is_some() }
+}
+
+fn index(records: &[RunRecord]) -> BTreeMap<RunKey, &RunRecord> {
+    // later records for the same key replace earlier ones, e.g. after a resumed run
+    records.iter().map(|r| (RunKey::of(r), r)).collect()
+}
+
+pub fn compare(baseline: &[RunRecord], candidate: &[RunRecord], thresholds: Thresholds) -> Report {
+    let baseline_index = index(baseline);
+    let candidate_index = index(candidate);
+    let mut comparisons = Vec::new();
+    let mut missing = Vec::new();
+    for (key, base) in baseline_index.iter() {
+        match candidate_index.get(key) {
+            Some(cand) => {
+                comparisons.push(compare_runs(key.clone(), base, cand, &thresholds));
+            },
+            None => missing.push(key.clone()),
+        }
+    }
+    let added =
+        candidate_index.keys().filter(|key| !baseline_index.contains_key(key)).cloned().collect();
+    Report { thresholds, comparisons, missing, added }
+}
+
+fn compare_runs(
+    key: RunKey,
+    baseline: &RunRecord,
+    candidate: &RunRecord,
+    thresholds: &Thresholds,
+) -> Comparison {
+    let baseline_median = median(&baseline.run_results);
+    let candidate_median = median(&candidate.run_results);
+    let relative_change = if baseline_median != 0.0 {
+        Some((candidate_median - baseline_median) / baseline_median)
+    } else {
+        None
+    };
+    let test = mann_whitney_u(&baseline.run_results, &candidate.run_results);
+    let verdict = match test {
+        _ if baseline.success && !candidate.success => Verdict::Failure,
+        Some(ref t) if baseline.success => {
+            // without a relative change, only the test and the effect size decide
+            let significant = t.p_value < thresholds.alpha
+                && t.effect_size.abs() >= thresholds.min_effect_size
+                && relative_change.map_or(true, |c| c.abs() >= thresholds.min_relative_change);
+            if !significant {
+                Verdict::NoChange
+            } else if relative_change.unwrap_or(t.effect_size) > 0.0 {
+                Verdict::Regression
+            } else {
+                Verdict::Improvement
+            }
+        },
+        _ => Verdict::Incomparable,
+    };
+    let failure_reason = if verdict == Verdict::Failure {
+        Some(candidate.failure_reason.clone().unwrap_or_else(|| "unknown".to_string()))
+    } else {
+        None
+    };
+    Comparison {
+        key,
+        baseline_median,
+        candidate_median,
+        relative_change,
+        test,
+        verdict,
+        failure_reason,
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Regression => write!(f, "REGRESSION"),
+            Verdict::Improvement => write!(f, "IMPROVEMENT"),
+            Verdict::NoChange => write!(f, "no change"),
+            Verdict::Failure => write!(f, "FAILED"),
+            Verdict::Incomparable => write!(f, "incomparable"),
+        }
+    }
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "Compared {} runs (alpha={}, min effect size={}, min relative change={}%)",
+            self.comparisons.len(),
+            self.thresholds.alpha,
+            self.thresholds.min_effect_size,
+            self.thresholds.min_relative_change * 100.0
+        )?;
+        for c in self.comparisons.iter() {
+            if let Some(ref reason) = c.failure_reason {
+                writeln!(
+                    f,
+                    "[{}] {} {} ({}): succeeded in the baseline, but failed with: {}",
+                    c.verdict, c.key.implementation, c.key.benchmark, c.key.parameters, reason
+                )?;
+                continue;
+            }
+            let change = match c.relative_change {
+                Some(change) => format!("{:+.2}%", change * 100.0),
+                None => "relative change undefined, the baseline median is 0".to_string(),
+            };
+            let (p_value, effect_size) =
+                c.test.map(|t| (t.p_value, t.effect_size)).unwrap_or((f64::NAN, f64::NAN));
+            writeln!(
+                f,
+                "[{}] {} {} ({}): median {:.3}ms -> {:.3}ms ({}), p={:.4}, effect={:+.3}",
+                c.verdict,
+                c.key.implementation,
+                c.key.benchmark,
+                c.key.parameters,
+                c.baseline_median,
+                c.candidate_median,
+                change,
+                p_value,
+                effect_size
+            )?;
+        }
+        for key in self.missing.iter() {
+            writeln!(
+                f,
+                "[missing] {} {} ({}) has no candidate run",
+                key.implementation, key.benchmark, key.parameters
+            )?;
+        }
+        for key in self.added.iter() {
+            writeln!(
+                f,
+                "[new] {} {} ({}) has no baseline run",
+                key.implementation, key.benchmark, key.parameters
+            )?;
+        }
+        write!(
+            f,
+            "{} regressions, {} failures, {} improvements",
+            self.regressions().count(),
+            self.failures().count(),
+            self.improvements().count()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::result_writer::Provenance;
+
+    fn record(params: &str, run_results: Vec<f64>) -> RunRecord {
+        RunRecord {
+            benchmark: "PingPong".to_string(),
+            parameters: params.to_string(),
+            success: true,
+            failure_reason: None,
+            run_results,
+            metrics: BTreeMap::new(),
+            settings: BTreeMap::new(),
+            provenance: Provenance {
+                implementation: "TEST".to_string(),
+                crate_versions: BTreeMap::new(),
+                cpu_model:      "test".to_string(),
+                core_count:     1,
+                kernel:         "test".to_string(),
+                hostname:       "test".to_string(),
+                timestamp:      "now".to_string(),
+            },
+        }
+    }
+
+    #[test]
+    fn test_mann_whitney_separated() {
+        let a = [1.0, 2.0, 3.0, 4.0, 5.0];
+        let b = [6.0, 7.0, 8.0, 9.0, 10.0];
+        let t = mann_whitney_u(&a, &b).unwrap();
+        assert_eq!(t.u, 25.0);
+        assert_eq!(t.effect_size, 1.0);
+        assert!(t.p_value < 0.05, "p={}", t.p_value);
+        let t_rev = mann_whitney_u(&b, &a).unwrap();
+        assert_eq!(t_rev.u, 0.0);
+        assert_eq!(t_rev.effect_size, -1.0);
+    }
+
+    #[test]
+    fn test_mann_whitney_identical() {
+        let a = [3.0, 3.0, 3.0];
+        let t = mann_whitney_u(&a, &a).unwrap();
+        assert_eq!(t.effect_size, 0.0);
+        assert_eq!(t.p_value, 1.0);
+        assert!(mann_whitney_u(&[], &a).is_none());
+    }
+
+    #[test]
+    fn test_compare_report() {
+        let base_data: Vec<f64> = (0..30).map(|i| 100.0 + (i % 5) as f64).collect();
+        let slow_data: Vec<f64> = base_data.iter().map(|v| v * 1.5).collect();
+        let fast_data: Vec<f64> = base_data.iter().map(|v| v * 0.5).collect();
+        let baseline = vec![
+            record("n: 1", base_data.clone()),
+            record("n: 2", base_data.clone()),
+            record("n: 3", base_data.clone()),
+            record("n: 4", base_data.clone()),
+        ];
+        let candidate = vec![
+            record("n: 1", slow_data),
+            record("n: 2", fast_data),
+            record("n: 3", base_data.clone()),
+            record("n: 5", base_data),
+        ];
+        let report = compare(&baseline, &candidate, Thresholds::default());
+        assert_eq!(report.comparisons.len(), 3);
+        assert_eq!(report.comparisons[0].verdict, Verdict::Regression);
+        assert_eq!(report.comparisons[1].verdict, Verdict::Improvement);
+        assert_eq!(report.comparisons[2].verdict, Verdict::NoChange);
+        assert_eq!(report.missing.len(), 1);
+        assert_eq!(report.added.len(), 1);
+        assert!(report.has_regressions());
+    }
+
+    #[test]
+    fn test_compare_failed_candidate() {
+        let data: Vec<f64> = (0..10).map(|i| 100.0 + i as f64).collect();
+        let mut failed = record("n: 1", Vec::new());
+        failed.success = false;
+        failed.failure_reason = Some("timeout".to_string());
+        let report =
+            compare(&[record("n: 1", data.clone())], &[failed.clone()], Thresholds::default());
+        assert_eq!(report.comparisons.len(), 1);
+        assert_eq!(report.comparisons[0].verdict, Verdict::Failure);
+        assert_eq!(report.comparisons[0].failure_reason.as_deref(), Some("timeout"));
+        assert!(report.missing.is_empty());
+        assert!(report.has_failures());
+        // a run that was already failing in the baseline is not a new failure
+        let report = compare(&[failed.clone()], &[failed], Thresholds::default());
+        assert_eq!(report.comparisons[0].verdict, Verdict::Incomparable);
+        assert!(!report.has_failures());
+    }
+
+    #[test]
+    fn test_compare_zero_baseline_median() {
+        let zeros = vec![0.0; 20];
+        let slow: Vec<f64> = (0..20).map(|i| 1.0 + i as f64).collect();
+        let report = compare(
+            &[record("n: 1", zeros.clone())],
+            &[record("n: 1", slow)],
+            Thresholds::default(),
+        );
+        assert_eq!(report.comparisons[0].relative_change, None);
+        assert_eq!(report.comparisons[0].verdict, Verdict::Regression);
+        let report = compare(
+            &[record("n: 1", zeros.clone())],
+            &[record("n: 1", zeros)],
+            Thresholds::default(),
+        );
+        assert_eq!(report.comparisons[0].verdict, Verdict::NoChange);
+        assert!(format!("{}", report).contains("baseline median is 0"));
+    }
+}
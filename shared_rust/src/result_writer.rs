@@ -0,0 +1,355 @@
+//! Machine-readable result records.
+//!
+//! If the `BENCHMARK_RESULTS` environment variable points to a file, every completed
+//! benchmark run is appended to it as a [RunRecord](RunRecord), either as JSON Lines
+//! or, if the file name ends in `.csv`, as CSV.
+//!
+//! Implementations must call [init](init) once at startup to describe themselves.
+use crate::kompics_benchmarks::messages;
+use once_cell::sync::{Lazy, OnceCell};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::BTreeMap,
+    fs::{self, File, OpenOptions},
+    io::{self, Write},
+    path::{Path, PathBuf},
+    sync::Mutex,
+};
+use time;
+
+/// Environment variable holding the path to the results file.
+pub const RESULTS_ENV: &str = "BENCHMARK_RESULTS";
+
+static WRITER: OnceCell<Mutex<ResultWriter>> = OnceCell::new();
+static PENDING_METRICS: Lazy<Mutex<BTreeMap<String, f64>>> =
+    Lazy::new(|| Mutex::new(BTreeMap::new()));
+static PENDING_SETTINGS: Lazy<Mutex<BTreeMap<String, String>>> =
+    Lazy::new(|| Mutex::new(BTreeMap::new()));
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ResultFormat {
+    JsonLines,
+    Csv,
+}
+impl ResultFormat {
+    pub fn for_path(path: &Path) -> ResultFormat {
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some("csv") => ResultFormat::Csv,
+            _ => ResultFormat::JsonLines,
+        }
+    }
+}
+
+/// Where a result came from.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Provenance {
+    pub implementation: String,
+    pub crate_versions: BTreeMap<String, String>,
+    pub cpu_model:      String,
+    pub core_count:     usize,
+    pub kernel:         String,
+    pub hostname:       String,
+    pub timestamp:      String,
+}
+impl Provenance {
+    /// Collects information about the current host.
+    ///
+    /// The `timestamp` is set to the current time and should be refreshed per record.
+    pub fn collect(implementation: &str, crate_versions: &[(&str, &str)]) -> Provenance {
+        let cpuinfo = fs::read_to_string("/proc/cpuinfo").unwrap_or_default();
+        let cpu_model = cpuinfo
+            .lines()
+            .find(|line| line.starts_with("model name"))
+            .and_then(|line| line.split_once(':'))
+            .map(|(_, model)| model.trim().to_string())
+            .unwrap_or_else(|| "unknown".to_string());
+        let core_count = cpuinfo.lines().filter(|line| line.starts_with("processor")).count();
+        let kernel = read_trimmed("/proc/sys/kernel/osrelease");
+        let hostname = std::env::var("HOSTNAME")
+            .ok()
+            .filter(|h| !h.is_empty())
+            .unwrap_or_else(|| read_trimmed("/proc/sys/kernel/hostname"));
+        let mut versions: BTreeMap<String, String> = crate_versions
+            .iter()
+            .map(|(krate, version)| (krate.to_string(), version.to_string()))
+            .collect();
+        versions
+            .entry(env!("CARGO_PKG_NAME").to_string())
+            .or_insert_with(|| env!("CARGO_PKG_VERSION").to_string());
+        Provenance {
+            implementation: implementation.to_string(),
+            crate_versions: versions,
+            cpu_model,
+            core_count,
+            kernel,
+            hostname,
+            timestamp: now(),
+        }
+    }
+}
+
+fn read_trimmed(path: &str) -> String {
+    fs::read_to_string(path).map(|s| s.trim().to_string()).unwrap_or_else(|_| "unknown".to_string())
+}
+
+fn now() -> String { format!("{}", time::now_utc().rfc3339()) }
+
+/// One complete benchmark run, i.e. all iterations for a single parameter point.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RunRecord {
+    pub benchmark:      String,
+    /// Parameters in rust-protobuf's text format.
+    ///
+    /// This is not the runner's `PARAMS` column, which comes from ScalaPB and escapes
+    /// commas and newlines, so to join the two, parse both back into the request message.
+    pub parameters:     String,
+    pub success:        bool,
+    pub failure_reason: Option<String>,
+    /// Iteration timings in milliseconds.
+    pub run_results:    Vec<f64>,
+    pub metrics:        BTreeMap<String, f64>,
+    /// The effective configuration of the implementation, e.g. scheduler settings.
+    #[serde(default)]
+    pub settings:       BTreeMap<String, String>,
+    pub provenance:     Provenance,
+}
+impl RunRecord {
+    pub const CSV_HEADER: &'static str = "BENCHMARK,PARAMS,SUCCESS,FAILURE,RUN_RESULTS,METRICS,SETTINGS,IMPL,CRATE_VERSIONS,CPU_MODEL,CORE_COUNT,KERNEL,HOSTNAME,TIMESTAMP";
+
+    pub fn new(
+        benchmark: &str,
+        parameters: String,
+        result: &messages::TestResult,
+        metrics: BTreeMap<String, f64>,
+        settings: BTreeMap<String, String>,
+        provenance: Provenance,
+    ) -> RunRecord {
+        let (success, failure_reason, run_results) = if result.has_success() {
+            (true, None, result.get_success().run_results.clone())
+        } else if result.has_failure() {
+            (false, Some(result.get_failure().reason.clone()), Vec::new())
+        } else {
+            (false, Some("not implemented".to_string()), Vec::new())
+        };
+        RunRecord {
+            benchmark: benchmark.to_string(),
+            parameters,
+            success,
+            failure_reason,
+            run_results,
+            metrics,
+            settings,
+            provenance,
+        }
+    }
+
+    pub fn to_csv(&self) -> String {
+        let join_map = |m: Vec<String>| m.join(";");
+        let fields = vec![
+            self.benchmark.clone(),
+            self.parameters.clone(),
+            self.success.to_string(),
+            self.failure_reason.clone().unwrap_or_default(),
+            join_map(self.run_results.iter().map(|r| r.to_string()).collect()),
+            join_map(self.metrics.iter().map(|(k, v)| format!("{}={}", k, v)).collect()),
+            join_map(self.settings.iter().map(|(k, v)| format!("{}={}", k, v)).collect()),
+            self.provenance.implementation.clone(),
+            join_map(
+                self.provenance
+                    .crate_versions
+                    .iter()
+                    .map(|(k, v)| format!("{}={}", k, v))
+                    .collect(),
+            ),
+            self.provenance.cpu_model.clone(),
+            self.provenance.core_count.to_string(),
+            self.provenance.kernel.clone(),
+            self.provenance.hostname.clone(),
+            self.provenance.timestamp.clone(),
+        ];
+        fields.iter().map(|f| csv_escape(f)).collect::<Vec<_>>().join(",")
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("RunRecord should always serialise")
+    }
+}
+
+fn csv_escape(s: &str) -> String {
+    if s.contains(',') || s.contains('"') || s.contains('\n') {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
+
+/// Reads all records from a JSON Lines file produced by a [ResultWriter](ResultWriter).
+pub fn read_json_lines<P: AsRef<Path>>(path: P) -> io::Result<Vec<RunRecord>> {
+    let content = fs::read_to_string(path)?;
+    content
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| {
+            serde_json::from_str(line).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+        })
+        .collect()
+}
+
+pub struct ResultWriter {
+    path:       PathBuf,
+    format:     ResultFormat,
+    provenance: Provenance,
+}
+impl ResultWriter {
+    pub fn new(path: PathBuf, format: ResultFormat, provenance: Provenance) -> ResultWriter {
+        ResultWriter { path, format, provenance }
+    }
+
+    pub fn path(&self) -> &Path { &self.path }
+
+    pub fn write(
+        &self,
+        benchmark: &str,
+        parameters: String,
+        result: &messages::TestResult,
+        metrics: BTreeMap<String, f64>,
+        settings: BTreeMap<String, String>,
+    ) -> io::Result<()> {
+        let mut provenance = self.provenance.clone();
+        provenance.timestamp = now();
+        let record = RunRecord::new(benchmark, parameters, result, metrics, settings, provenance);
+        self.write_record(&record)
+    }
+
+    pub fn write_record(&self, record: &RunRecord) -> io::Result<()> {
+        let is_new = fs::metadata(&self.path).map(|m| m.len() == 0).unwrap_or(true);
+        let mut file: File = OpenOptions::new().create(true).append(true).open(&self.path)?;
+        match self.format {
+            ResultFormat::JsonLines => writeln!(file, "{}", record.to_json())?,
+            ResultFormat::Csv => {
+                if is_new {
+                    writeln!(file, "{}", RunRecord::CSV_HEADER)?;
+                }
+                writeln!(file, "{}", record.to_csv())?;
+            },
+        }
+        file.flush()
+    }
+}
+
+/// Sets up the global result writer, if [RESULTS_ENV](RESULTS_ENV) is set.
+///
+/// `crate_versions` should list the versions (or git revisions) of the actor framework
+/// the implementation is built against.
+pub fn init(implementation: &str, crate_versions: &[(&str, &str)]) {
+    if let Ok(path_str) = std::env::var(RESULTS_ENV) {
+        let path = PathBuf::from(path_str);
+        if let Some(parent) = path.parent() {
+            if !parent.as_os_str().is_empty() {
+                fs::create_dir_all(parent).unwrap_or_else(|e| {
+                    panic!("Could not create results folder {}: {}", parent.display(), e)
+                });
+            }
+        }
+        let format = ResultFormat::for_path(&path);
+        let provenance = Provenance::collect(implementation, crate_versions);
+        let writer = ResultWriter::new(path, format, provenance);
+        if WRITER.set(Mutex::new(writer)).is_err() {
+            eprintln!("Result writer was already initialised. Ignoring.");
+        }
+    }
+}
+
+/// Attaches an additional metric to the next record that is written.
+pub fn record_metric<K: Into<String>>(key: K, value: f64) {
+    PENDING_METRICS.lock().unwrap().insert(key.into(), value);
+}
+
+/// Attaches a configuration setting to the next record that is written.
+pub fn record_setting<K: Into<String>, V: ToString>(key: K, value: V) {
+    PENDING_SETTINGS.lock().unwrap().insert(key.into(), value.to_string());
+}
+
+/// Renders benchmark parameters in rust-protobuf's text format (see [RunRecord::parameters](RunRecord)).
+pub fn describe_parameters(msg: &dyn ::protobuf::Message) -> String {
+    ::protobuf::text_format::print_to_string(msg)
+}
+
+/// Writes a record for a completed run, if a result writer is configured.
+pub fn record(benchmark: &str, parameters: String, result: &messages::TestResult) {
+    let metrics = std::mem::take(&mut *PENDING_METRICS.lock().unwrap());
+    let settings = std::mem::take(&mut *PENDING_SETTINGS.lock().unwrap());
+    if let Some(writer) = WRITER.get() {
+        let guard = writer.lock().unwrap();
+        if let Err(e) = guard.write(benchmark, parameters, result, metrics, settings) {
+            eprintln!("Could not write result record to {}: {}", guard.path().display(), e);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn success(data: Vec<f64>) -> messages::TestResult {
+        let mut ts = messages::TestSuccess::new();
+        ts.set_number_of_runs(data.len() as u32);
+        ts.set_run_results(data);
+        let mut tr = messages::TestResult::new();
+        tr.set_success(ts);
+        tr
+    }
+
+    #[test]
+    fn test_json_lines_roundtrip() {
+        let dir = std::env::temp_dir().join(format!("result_writer_test_{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("results.jsonl");
+        let _ = fs::remove_file(&path);
+        let provenance = Provenance::collect("TEST", &[("kompact", "abc123")]);
+        let writer = ResultWriter::new(path.clone(), ResultFormat::for_path(&path), provenance);
+        let mut metrics = BTreeMap::new();
+        metrics.insert("latency_p50".to_string(), 12.0);
+        let mut settings = BTreeMap::new();
+        settings.insert("kompact.throughput".to_string(), "50".to_string());
+        writer
+            .write(
+                "PingPong",
+                "number_of_messages: 10".to_string(),
+                &success(vec![1.0, 2.0]),
+                metrics,
+                settings,
+            )
+            .unwrap();
+        writer
+            .write(
+                "PingPong",
+                "number_of_messages: 20".to_string(),
+                &success(vec![3.0]),
+                BTreeMap::new(),
+                BTreeMap::new(),
+            )
+            .unwrap();
+        let records = read_json_lines(&path).unwrap();
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].run_results, vec![1.0, 2.0]);
+        assert_eq!(records[0].metrics.get("latency_p50"), Some(&12.0));
+        assert_eq!(
+            records[0].provenance.crate_versions.get("kompact").map(|s| s.as_str()),
+            Some("abc123")
+        );
+        assert_eq!(
+            records[0].settings.get("kompact.throughput").map(|s| s.as_str()),
+            Some("50")
+        );
+        assert!(records[1].success);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_csv_escaping() {
+        assert_eq!(csv_escape("plain"), "plain");
+        assert_eq!(csv_escape("a,b"), "\"a,b\"");
+        assert_eq!(csv_escape("say \"hi\""), "\"say \"\"hi\"\"\"");
+    }
+}
@@ -63,4 +63,13 @@ trait BenchmarkFactory {
   def fibonacci: Benchmark;
   def atomicBroadcast(): DistributedBenchmark;
   def sizedThroughput: DistributedBenchmark;
+  def threadRing(): Benchmark;
+  def fanIn(): Benchmark;
+  def fanout(): Benchmark;
+  def big(): Benchmark;
+  def bank(): Benchmark;
+  def producerConsumer(): Benchmark;
+  def diningPhilosophers(): Benchmark;
+  def nqueens(): Benchmark;
+  def parallelSort(): Benchmark;
 }
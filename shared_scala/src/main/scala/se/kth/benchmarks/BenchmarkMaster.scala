@@ -121,6 +121,42 @@ class BenchmarkMaster(val runnerPort: Int, val masterPort: Int, val waitFor: Int
       val b = benchmarks.sizedThroughput;
       runBenchmark(b, request)
     };
+    override def threadRing(request: ThreadRingRequest): Future[TestResult] = queueIfNotReady {
+      val b = benchmarks.threadRing;
+      runBenchmark(b, request)
+    };
+    override def fanIn(request: FanInRequest): Future[TestResult] = queueIfNotReady {
+      val b = benchmarks.fanIn;
+      runBenchmark(b, request)
+    };
+    override def fanout(request: FanoutRequest): Future[TestResult] = queueIfNotReady {
+      val b = benchmarks.fanout;
+      runBenchmark(b, request)
+    };
+    override def big(request: BigRequest): Future[TestResult] = queueIfNotReady {
+      val b = benchmarks.big;
+      runBenchmark(b, request)
+    };
+    override def bank(request: BankRequest): Future[TestResult] = queueIfNotReady {
+      val b = benchmarks.bank;
+      runBenchmark(b, request)
+    };
+    override def producerConsumer(request: ProducerConsumerRequest): Future[TestResult] = queueIfNotReady {
+      val b = benchmarks.producerConsumer;
+      runBenchmark(b, request)
+    };
+    override def diningPhilosophers(request: DiningPhilosophersRequest): Future[TestResult] = queueIfNotReady {
+      val b = benchmarks.diningPhilosophers;
+      runBenchmark(b, request)
+    };
+    override def nqueens(request: NQueensRequest): Future[TestResult] = queueIfNotReady {
+      val b = benchmarks.nqueens;
+      runBenchmark(b, request)
+    };
+    override def parallelSort(request: ParallelSortRequest): Future[TestResult] = queueIfNotReady {
+      val b = benchmarks.parallelSort;
+      runBenchmark(b, request)
+    };
     override def shutdown(request: ShutdownRequest): Future[ShutdownAck] = {
       logger.info(s"Got shutdown request with force=${request.force}");
 
@@ -199,6 +199,96 @@ class DistributedTest(val benchFactory: BenchmarkFactory) extends Matchers with
       checkResult("AllPairsShortestPath", apspResF);
       logger.info("Finished test AllPairsShortestPath");
 
+      /*
+       * Thread Ring
+       */
+      logger.info("Starting test ThreadRing");
+      val trr = ThreadRingRequest().withNumberOfActors(10).withNumberOfPasses(100).withNumberOfTokens(1);
+      val trResF = benchStub.threadRing(trr);
+      checkResult("ThreadRing (single)", trResF);
+      val trResF2 = benchStub.threadRing(trr.withNumberOfTokens(5));
+      checkResult("ThreadRing (many)", trResF2);
+      logger.info("Finished test ThreadRing");
+
+      /*
+       * Fan In
+       */
+      logger.info("Starting test FanIn");
+      val fir = FanInRequest().withNumberOfProducers(4).withMessagesPerProducer(100);
+      val fiResF = benchStub.fanIn(fir);
+      checkResult("FanIn (unlimited)", fiResF);
+      val fiResF2 = benchStub.fanIn(fir.withPipelineSize(10));
+      checkResult("FanIn (pipelined)", fiResF2);
+      logger.info("Finished test FanIn");
+
+      /*
+       * Fanout
+       */
+      logger.info("Starting test Fanout");
+      val fOutR = FanoutRequest().withNumberOfSubscribers(10).withNumberOfRounds(100);
+      val fOutResF = benchStub.fanout(fOutR);
+      checkResult("Fanout", fOutResF);
+      logger.info("Finished test Fanout");
+
+      /*
+       * Big
+       */
+      logger.info("Starting test Big");
+      val bigr = BigRequest().withNumberOfActors(10).withNumberOfPings(100).withSeed(42L);
+      val bigResF = benchStub.big(bigr);
+      checkResult("Big", bigResF);
+      logger.info("Finished test Big");
+
+      /*
+       * Bank
+       */
+      logger.info("Starting test Bank");
+      val bankr = BankRequest().withNumberOfAccounts(10).withNumberOfTransactions(1000).withSeed(42L);
+      val bankResF = benchStub.bank(bankr);
+      checkResult("Bank", bankResF);
+      logger.info("Finished test Bank");
+
+      /*
+       * ProducerConsumer
+       */
+      logger.info("Starting test ProducerConsumer");
+      val pcr = ProducerConsumerRequest()
+        .withBufferSize(10)
+        .withNumberOfProducers(4)
+        .withNumberOfConsumers(4)
+        .withNumberOfItems(1000)
+        .withWorkPerItem(10);
+      val pcResF = benchStub.producerConsumer(pcr);
+      checkResult("ProducerConsumer", pcResF);
+      logger.info("Finished test ProducerConsumer");
+
+      /*
+       * DiningPhilosophers
+       */
+      logger.info("Starting test DiningPhilosophers");
+      val dpr = DiningPhilosophersRequest().withNumberOfPhilosophers(5).withNumberOfRounds(100);
+      val dpResF = benchStub.diningPhilosophers(dpr);
+      checkResult("DiningPhilosophers", dpResF);
+      logger.info("Finished test DiningPhilosophers");
+
+      /*
+       * NQueens
+       */
+      logger.info("Starting test NQueens");
+      val nqr = NQueensRequest().withBoardSize(8).withNumberOfWorkers(4).withSplitDepth(2);
+      val nqResF = benchStub.nqueens(nqr);
+      checkResult("NQueens", nqResF);
+      logger.info("Finished test NQueens");
+
+      /*
+       * ParallelSort
+       */
+      logger.info("Starting test ParallelSort");
+      val psr = ParallelSortRequest().withDataSize(10000).withThreshold(1000).withSeed(42L);
+      val psResF = benchStub.parallelSort(psr);
+      checkResult("ParallelSort", psResF);
+      logger.info("Finished test ParallelSort");
+
       /*
        * Clean Up
        */